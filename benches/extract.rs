@@ -0,0 +1,60 @@
+//! Compare the extraction of eithers and chains against direct extractors.
+//!
+//! Run with `cargo bench --features test-util`.
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use tokio::runtime::Runtime;
+
+mod scenarios;
+
+use scenarios::Bodies;
+
+fn extract(c: &mut Criterion) {
+    let runtime = Runtime::new().unwrap();
+
+    for (label, len) in scenarios::SIZES {
+        let bodies = Bodies::new(len);
+        let mut group = c.benchmark_group(format!("extract/{}", label));
+
+        group.bench_function("direct_json", |b| {
+            b.to_async(&runtime).iter_batched(
+                || bodies.json_request(),
+                scenarios::direct_json,
+                BatchSize::SmallInput,
+            )
+        });
+        group.bench_function("either_left", |b| {
+            b.to_async(&runtime).iter_batched(
+                || bodies.json_request(),
+                scenarios::either_left,
+                BatchSize::SmallInput,
+            )
+        });
+        group.bench_function("last_of_five", |b| {
+            b.to_async(&runtime).iter_batched(
+                || bodies.text_request(),
+                scenarios::last_of_five,
+                BatchSize::SmallInput,
+            )
+        });
+        group.bench_function("flat_last_of_five", |b| {
+            b.to_async(&runtime).iter_batched(
+                || bodies.text_request(),
+                scenarios::flat_last_of_five,
+                BatchSize::SmallInput,
+            )
+        });
+        group.bench_function("double_rejection", |b| {
+            b.to_async(&runtime).iter_batched(
+                || bodies.text_request(),
+                scenarios::double_rejection,
+                BatchSize::SmallInput,
+            )
+        });
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, extract);
+criterion_main!(benches);
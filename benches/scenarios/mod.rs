@@ -0,0 +1,98 @@
+//! The extraction scenarios of the benchmarks, shared with their smoke test.
+
+use axum::{Form, Json};
+use axum_either::{
+    test::{self, TestBody},
+    AxumEither, OneOf5,
+};
+use bytes::Bytes;
+use http::Request;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Payload {
+    pub id: u32,
+    pub name: String,
+}
+
+pub type JsonOrForm = AxumEither<Json<Payload>, Form<Payload>>;
+
+/// A chain of five extractors where a `text/plain` request matches only the last one
+pub type FiveFormats = axum_either::one_of!(
+    Json<Payload>,
+    Form<Payload>,
+    Json<Vec<u32>>,
+    Form<Vec<(String, String)>>,
+    String
+);
+
+/// The flat equivalent of [`FiveFormats`]
+pub type FlatFiveFormats =
+    OneOf5<Json<Payload>, Form<Payload>, Json<Vec<u32>>, Form<Vec<(String, String)>>, String>;
+
+/// The labels and the approximate lengths of the benchmarked bodies
+pub const SIZES: [(&str, usize); 2] = [("small", 16), ("1MiB", 1 << 20)];
+
+/// The serialized bodies of one size, cloning them is cheap
+#[derive(Debug, Clone)]
+pub struct Bodies {
+    pub json: Bytes,
+    pub text: Bytes,
+}
+
+impl Bodies {
+    pub fn new(len: usize) -> Self {
+        let payload = Payload {
+            id: 7,
+            name: "a".repeat(len),
+        };
+        Self {
+            json: serde_json::to_vec(&payload).unwrap().into(),
+            text: "a".repeat(len).into(),
+        }
+    }
+
+    pub fn json_request(&self) -> Request<TestBody> {
+        test::raw_request("application/json", self.json.clone())
+    }
+
+    pub fn text_request(&self) -> Request<TestBody> {
+        test::raw_request("text/plain", self.text.clone())
+    }
+}
+
+/// The baseline, a plain `Json` extractor
+pub async fn direct_json(request: Request<TestBody>) -> bool {
+    test::extract::<Json<Payload>, _>(request).await.is_ok()
+}
+
+/// The left variant of an either matches
+pub async fn either_left(request: Request<TestBody>) -> bool {
+    matches!(
+        test::extract::<JsonOrForm, _>(request).await,
+        Ok(AxumEither::Left(_))
+    )
+}
+
+/// Only the last variant of a five element chain matches
+pub async fn last_of_five(request: Request<TestBody>) -> bool {
+    matches!(
+        test::extract::<FiveFormats, _>(request).await,
+        Ok(AxumEither::Right(AxumEither::Right(AxumEither::Right(
+            AxumEither::Right(_)
+        ))))
+    )
+}
+
+/// Only the last variant of a five element flat enum matches
+pub async fn flat_last_of_five(request: Request<TestBody>) -> bool {
+    matches!(
+        test::extract::<FlatFiveFormats, _>(request).await,
+        Ok(OneOf5::V4(_))
+    )
+}
+
+/// Both variants of an either reject the request
+pub async fn double_rejection(request: Request<TestBody>) -> bool {
+    test::extract::<JsonOrForm, _>(request).await.is_err()
+}
@@ -0,0 +1,67 @@
+// Return errors from handlers responding with one of two types.
+
+use std::net::{SocketAddr, TcpListener as StdTcpListener};
+use tokio::net::TcpListener;
+
+use axum::{
+    extract::Path,
+    http::StatusCode,
+    response::{Html, Redirect},
+    Json, Router,
+};
+use axum_either::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let listener = TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], 8080))).await?;
+    run(listener.into_std()?).await
+}
+
+pub async fn run(listener: StdTcpListener) -> anyhow::Result<()> {
+    use axum::routing::get;
+
+    let router = Router::new()
+        .route("/users/:id", get(user))
+        .route("/users/:id/page", get(user_page));
+
+    axum::Server::from_tcp(listener)?
+        .serve(router.into_make_service())
+        .await?;
+
+    Ok(())
+}
+
+/// Deleted users are redirected to the list of users, unknown users are not found
+pub async fn user(Path(id): Path<u32>) -> Result<AxumEither<Json<User>, Redirect>, StatusCode> {
+    match find_user(id)? {
+        Some(user) => Ok(AxumEither::json_left(user)),
+        None => Ok(AxumEither::redirect_right("/users")),
+    }
+}
+
+/// The same user rendered as html, `ok_left` wraps any responder
+pub async fn user_page(
+    Path(id): Path<u32>,
+) -> Result<AxumEither<Html<String>, Redirect>, StatusCode> {
+    match find_user(id)? {
+        Some(user) => AxumEither::ok_left(Html(format!("<h1>{}</h1>", user.name))),
+        None => AxumEither::ok_right(Redirect::to("/users")),
+    }
+}
+
+/// `Ok(None)` for deleted users
+fn find_user(id: u32) -> Result<Option<User>, StatusCode> {
+    match id {
+        1 => Ok(Some(User {
+            name: "Reimu".into(),
+        })),
+        2 => Ok(None),
+        _ => Err(StatusCode::NOT_FOUND),
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+pub struct User {
+    pub name: String,
+}
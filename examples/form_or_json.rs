@@ -7,7 +7,7 @@ use std::{
 use tokio::net::TcpListener;
 
 use axum::{response::Html, Form, Json, Router};
-use axum_either::AxumEither;
+use axum_either::prelude::*;
 use serde::{Deserialize, Serialize};
 
 #[tokio::main]
@@ -17,21 +17,24 @@ async fn main() -> anyhow::Result<()> {
 }
 
 pub async fn run(listener: StdTcpListener) -> anyhow::Result<()> {
-    use axum::routing::{get, post};
-
-    // Setup the router with our routes
-    let router = Router::new()
-        .route("/", get(index))
-        .route("/hello", post(hello))
-        .route("/bye", post(bye));
-
     axum::Server::from_tcp(listener)?
-        .serve(router.into_make_service())
+        .serve(router().into_make_service())
         .await?;
 
     Ok(())
 }
 
+/// Setup the router with our routes
+pub fn router() -> Router {
+    use axum::routing::{get, post};
+
+    Router::new()
+        .route("/", get(index))
+        .route("/hello", post(hello))
+        .route("/bye", post(bye))
+        .route("/greet", post(greet))
+}
+
 pub async fn index() -> Html<&'static str> {
     Html(include_str!("./web/form_or_json.html"))
 }
@@ -55,6 +58,29 @@ pub async fn hello(
     }
 }
 
+/// A route which accepts both a form and json and responds in the format the client accepts
+pub async fn greet(
+    accept: Accept,
+    request: AxumEither<Json<HelloRequest>, Form<HelloRequest>>,
+) -> Tagged<Negotiate<Json<HelloResponse>, Html<String>>> {
+    let name = request
+        .map_lr(|Json(req)| req.name, |Form(req)| req.name)
+        .into_inner();
+    let response = HelloResponse {
+        msg: format!("Greetings {}!", name).into(),
+        name,
+    };
+
+    Tagged(Negotiate::new(
+        &accept,
+        response,
+        ("application/json", Json),
+        ("text/html", |response: HelloResponse| {
+            Html(format!("<p>{}</p>", response.msg))
+        }),
+    ))
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct HelloRequest {
     pub name: Cow<'static, str>,
@@ -2,7 +2,7 @@ use std::net::{SocketAddr, TcpListener as StdTcpListener};
 use tokio::net::TcpListener;
 
 use axum::{Form, Json, Router};
-use axum_either::AxumEither;
+use axum_either::prelude::*;
 use serde::{Deserialize, Serialize};
 
 #[tokio::main]
@@ -28,9 +28,9 @@ pub async fn run(listener: StdTcpListener) -> anyhow::Result<()> {
 }
 
 pub async fn get_request_type(
-    request: axum_either::one_of!(Json<Request>, Form<Request>, String),
+    request: one_of!(Json<Request>, Form<Request>, String),
 ) -> &'static str {
-    axum_either::match_one_of! {request,
+    match_one_of! {request,
         _ => "Json",
         _ => "Form",
         _ => "String",
@@ -38,9 +38,9 @@ pub async fn get_request_type(
 }
 
 pub async fn echo(
-    request: axum_either::one_of!(Json<Request>, Form<Request>, String),
-) -> axum_either::one_of!(Json<Request>, Form<Request>, String) {
-    let either = axum_either::map_one_of! {request,
+    request: one_of!(Json<Request>, Form<Request>, String),
+) -> one_of!(Json<Request>, Form<Request>, String) {
+    let either = map_one_of! {request,
         Json(j) => Json(j),
         f => f,
         s => s,
@@ -48,10 +48,8 @@ pub async fn echo(
     either
 }
 
-pub async fn as_string(
-    request: axum_either::one_of!(Json<Request>, Form<Request>, String),
-) -> String {
-    let resp = axum_either::match_one_of! {request,
+pub async fn as_string(request: one_of!(Json<Request>, Form<Request>, String)) -> String {
+    let resp = match_one_of! {request,
         Json(j) => format!("Json: {:?}", j),
         f => format!("Format: {:?}", f),
         s => format!("String: {:?}", s),
@@ -0,0 +1,42 @@
+// Look up items by their numeric id or by their slug on the same route.
+
+use std::net::{SocketAddr, TcpListener as StdTcpListener};
+use tokio::net::TcpListener;
+
+use axum::Router;
+use axum_either::{prelude::*, PathEither};
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let listener = TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], 8080))).await?;
+    run(listener.into_std()?).await
+}
+
+pub async fn run(listener: StdTcpListener) -> anyhow::Result<()> {
+    use axum::routing::get;
+
+    let router = Router::new()
+        .route("/items/:id", get(item))
+        .route("/flags/:flag", get(flag));
+
+    axum::Server::from_tcp(listener)?
+        .serve(router.into_make_service())
+        .await?;
+
+    Ok(())
+}
+
+/// Numeric ids are tried first, everything else is a slug
+pub async fn item(id: PathEither<u64, String>) -> String {
+    let kind = if id.is_left() { "id" } else { "slug" };
+    let value = id.into_inner().map_left(|id| id.to_string()).into_inner();
+    format!("{} {}", kind, value)
+}
+
+/// Flags are addressed by number or by their state, anything else is rejected
+pub async fn flag(flag: PathEither<u64, bool>) -> String {
+    match flag.into_inner() {
+        AxumEither::Left(index) => format!("flag {}", index),
+        AxumEither::Right(state) => format!("flags {}", state),
+    }
+}
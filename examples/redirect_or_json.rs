@@ -0,0 +1,50 @@
+// Answer form submissions with a redirect and json requests with a json body.
+
+use std::net::{SocketAddr, TcpListener as StdTcpListener};
+use tokio::net::TcpListener;
+
+use axum::{response::Redirect, Form, Json, Router};
+use axum_either::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let listener = TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], 8080))).await?;
+    run(listener.into_std()?).await
+}
+
+pub async fn run(listener: StdTcpListener) -> anyhow::Result<()> {
+    use axum::routing::post;
+
+    let router = Router::new().route("/subscribe", post(subscribe));
+
+    axum::Server::from_tcp(listener)?
+        .serve(router.into_make_service())
+        .await?;
+
+    Ok(())
+}
+
+/// Browsers submitting the form are sent back to the page, api clients get the subscription
+pub async fn subscribe(
+    request: AxumEither<Json<SubscribeRequest>, Form<SubscribeRequest>>,
+) -> AxumEither<Json<SubscribeResponse>, Redirect> {
+    request.respond_matching(
+        |Json(req)| {
+            Json(SubscribeResponse {
+                subscribed: req.email,
+            })
+        },
+        |Form(_req)| Redirect::to("/subscribed"),
+    )
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SubscribeRequest {
+    pub email: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+pub struct SubscribeResponse {
+    pub subscribed: String,
+}
@@ -0,0 +1,60 @@
+// Count which formats clients send with the VariantRecorderLayer and expose the counts on an
+// admin route.
+
+use std::{
+    collections::HashMap,
+    net::{SocketAddr, TcpListener as StdTcpListener},
+    sync::Arc,
+};
+use tokio::net::TcpListener;
+
+use axum::{extract::Extension, Form, Json, Router};
+use axum_either::{
+    prelude::*,
+    recorder::{RecorderHandle, VariantRecorderLayer},
+};
+use serde::Deserialize;
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let listener = TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], 8080))).await?;
+    run(listener.into_std()?).await
+}
+
+pub async fn run(listener: StdTcpListener) -> anyhow::Result<()> {
+    use axum::routing::{get, post};
+
+    let recorder = VariantRecorderLayer::new();
+    let router = Router::new()
+        .route("/hello", post(hello))
+        .route("/admin/variants", get(variants))
+        .layer(Extension(recorder.handle()))
+        .layer(recorder);
+
+    axum::Server::from_tcp(listener)?
+        .serve(router.into_make_service())
+        .await?;
+
+    Ok(())
+}
+
+#[derive(Deserialize)]
+pub struct HelloRequest {
+    name: String,
+}
+
+/// Greets the sender of a json or form request
+pub async fn hello(request: AxumEither<Json<HelloRequest>, Form<HelloRequest>>) -> String {
+    let name = match request {
+        AxumEither::Left(Json(request)) => request.name,
+        AxumEither::Right(Form(request)) => request.name,
+    };
+    format!("Hello {}!", name)
+}
+
+/// How often every variant was extracted
+pub async fn variants(
+    Extension(handle): Extension<Arc<RecorderHandle>>,
+) -> Json<HashMap<&'static str, u64>> {
+    Json(handle.counts())
+}
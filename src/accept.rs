@@ -0,0 +1,263 @@
+//! Parse the `Accept` header into media ranges ordered by preference.
+//!
+//! The `Accept-Language` header is parsed into language ranges the same way, see
+//! [`parse_accept_language`].
+//!
+//! This module is only available with the `negotiation` feature, which is enabled by default.
+//!
+//! The header is parsed here instead of with the `headers` crate: version 0.3, which axum 0.5
+//! builds on, does not provide a typed `Accept` header, and `mime` only parses single media
+//! types, not lists of ranges with quality values. The parser follows the grammar of
+//! RFC 7231: malformed ranges are skipped without discarding the rest of the header, parameters
+//! after `q` are accept extensions and a missing `q` is `1`.
+//!
+//! # Examples
+//! ```
+//! use axum_either::accept::parse_accept;
+//! use http::{header, HeaderMap};
+//!
+//! let mut headers = HeaderMap::new();
+//! headers.insert(header::ACCEPT, "text/*;q=0.3, text/html;q=0.7, */*;q=0.5".parse().unwrap());
+//! let ranges: Vec<_> = parse_accept(&headers)
+//!     .into_iter()
+//!     .map(|range| format!("{}/{}", range.ty, range.subtype))
+//!     .collect();
+//! assert_eq!(ranges, ["text/html", "*/*", "text/*"]);
+//! ```
+
+use http::{header, HeaderMap};
+use std::cmp::Ordering;
+
+/// A single entry of an `Accept` header
+#[derive(Debug, Clone, PartialEq)]
+pub struct MediaRange {
+    /// The type, `*` for any type
+    pub ty: String,
+    /// The subtype, `*` for any subtype
+    pub subtype: String,
+    /// The media type parameters like `charset` or `version` in the order of the header, names
+    /// are lowercase and quoted values are unquoted
+    pub params: Vec<(String, String)>,
+    /// The quality value between 0 and 1
+    pub q: f32,
+}
+
+impl MediaRange {
+    /// Parse a single media range like `text/html;level=1;q=0.7`, `None` if it is malformed.
+    ///
+    /// Parameters after `q` are accept extensions and are not part of the media range.
+    pub fn parse(range: &str) -> Option<Self> {
+        let mut parts = range.split(';');
+        let (ty, subtype) = parts.next()?.trim().split_once('/')?;
+        if ty.is_empty() || subtype.is_empty() || (ty == "*" && subtype != "*") {
+            return None;
+        }
+
+        let mut params = Vec::new();
+        let mut q = 1.0;
+        for param in parts {
+            let (name, value) = match parse_param(param) {
+                Some(param) => param,
+                None => continue,
+            };
+            if name == "q" {
+                q = value.parse().ok().filter(|q| (0.0..=1.0).contains(q))?;
+                break;
+            }
+            params.push((name, value));
+        }
+
+        Some(Self {
+            ty: ty.to_ascii_lowercase(),
+            subtype: subtype.to_ascii_lowercase(),
+            params,
+            q,
+        })
+    }
+
+    /// The value of the parameter `name`, the name is compared case insensitively
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(param, _)| param.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// The number of wildcards in this range, less wildcards are more specific
+    fn wildcards(&self) -> u8 {
+        u8::from(self.ty == "*") + u8::from(self.subtype == "*")
+    }
+
+    /// How specifically this range matches the media type, `None` if it does not match.
+    ///
+    /// A parameter of the range excludes media types with a different value for it. Parameters
+    /// missing in `params` are ignored, so `application/json;charset=utf-8` still accepts
+    /// `application/json`. The specificity compares the type and subtype first, then the number
+    /// of matching parameters and finally whether no parameter of the range was missing.
+    pub(crate) fn specificity(
+        &self,
+        ty: &str,
+        subtype: &str,
+        params: &[(String, String)],
+    ) -> Option<(u8, usize, bool)> {
+        let specificity = match (self.ty.as_str(), self.subtype.as_str()) {
+            ("*", "*") => 0,
+            (t, "*") if t.eq_ignore_ascii_case(ty) => 1,
+            (t, s) if t.eq_ignore_ascii_case(ty) && s.eq_ignore_ascii_case(subtype) => 2,
+            _ => return None,
+        };
+        let mut matched = 0;
+        for (name, value) in &self.params {
+            match params.iter().find(|(other, _)| other == name) {
+                Some((_, other)) if other.eq_ignore_ascii_case(value) => matched += 1,
+                Some(_) => return None,
+                None => {}
+            }
+        }
+        Some((specificity, matched, matched == self.params.len()))
+    }
+}
+
+/// Split a media type like `text/html; charset=utf-8` into its type, subtype and parameters
+pub(crate) fn parse_media_type(media_type: &str) -> (&str, &str, Vec<(String, String)>) {
+    let mut parts = media_type.split(';');
+    let essence = parts.next().unwrap_or_default().trim();
+    let (ty, subtype) = essence.split_once('/').unwrap_or((essence, ""));
+    (ty, subtype, parts.filter_map(parse_param).collect())
+}
+
+/// Parse a parameter like `charset="utf-8"` into its lowercase name and unquoted value
+fn parse_param(param: &str) -> Option<(String, String)> {
+    let (name, value) = param.trim().split_once('=')?;
+    let name = name.trim();
+    let value = value.trim();
+    let value = value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .unwrap_or(value);
+    if name.is_empty() {
+        return None;
+    }
+    Some((name.to_ascii_lowercase(), value.to_owned()))
+}
+
+/// Parse all `Accept` headers in `headers`, the most preferred media range comes first.
+///
+/// Ranges are ordered by their quality, ties are broken by specificity: `text/html;level=1` comes
+/// before `text/html`, which comes before `text/*` which comes before `*/*`. Ranges with the same
+/// quality and specificity keep the order of the header. Malformed ranges are skipped without
+/// affecting the others.
+pub fn parse_accept(headers: &HeaderMap) -> Vec<MediaRange> {
+    let mut ranges: Vec<_> = headers
+        .get_all(header::ACCEPT)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .filter_map(MediaRange::parse)
+        .collect();
+    ranges.sort_by(|a, b| {
+        b.q.partial_cmp(&a.q)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| a.wildcards().cmp(&b.wildcards()))
+            .then_with(|| b.params.len().cmp(&a.params.len()))
+    });
+    ranges
+}
+
+/// A single entry of an `Accept-Language` header
+#[derive(Debug, Clone, PartialEq)]
+pub struct LanguageRange {
+    /// The language tag in lowercase like `de-at`, `*` for any language
+    pub tag: String,
+    /// The quality value between 0 and 1
+    pub q: f32,
+}
+
+impl LanguageRange {
+    /// Parse a single language range like `de-AT;q=0.7`, `None` if it is malformed
+    pub fn parse(range: &str) -> Option<Self> {
+        let mut parts = range.split(';');
+        let tag = parts.next()?.trim();
+        let valid = tag == "*"
+            || (!tag.is_empty()
+                && tag.split('-').all(|subtag| {
+                    (1..=8).contains(&subtag.len())
+                        && subtag.bytes().all(|b| b.is_ascii_alphanumeric())
+                }));
+        if !valid {
+            return None;
+        }
+
+        let mut q = 1.0;
+        for param in parts {
+            if let Some((name, value)) = param.trim().split_once('=') {
+                if name.trim().eq_ignore_ascii_case("q") {
+                    q = value
+                        .trim()
+                        .parse()
+                        .ok()
+                        .filter(|q| (0.0..=1.0).contains(q))?;
+                }
+            }
+        }
+
+        Some(Self {
+            tag: tag.to_ascii_lowercase(),
+            q,
+        })
+    }
+
+    /// How specifically this range matches `language`, `None` if it does not match.
+    ///
+    /// An exact match is the most specific, then a range which is a prefix of the language
+    /// (`de` matches `de-AT`), then a language which is a prefix of the range (`de-AT` matches
+    /// `de`) and finally `*`. Prefixes only end at a `-`.
+    pub(crate) fn specificity(&self, language: &str) -> Option<u8> {
+        let is_prefix = |prefix: &str, tag: &str| {
+            tag.len() > prefix.len()
+                && tag.as_bytes()[prefix.len()] == b'-'
+                && tag.as_bytes()[..prefix.len()].eq_ignore_ascii_case(prefix.as_bytes())
+        };
+        if self.tag == "*" {
+            Some(0)
+        } else if self.tag.eq_ignore_ascii_case(language) {
+            Some(3)
+        } else if is_prefix(&self.tag, language) {
+            Some(2)
+        } else if is_prefix(language, &self.tag) {
+            Some(1)
+        } else {
+            None
+        }
+    }
+}
+
+/// Parse all `Accept-Language` headers in `headers`, the most preferred range comes first.
+///
+/// Ranges are ordered by their quality, ties keep the order of the header. Malformed ranges are
+/// skipped without affecting the others.
+///
+/// # Examples
+/// ```
+/// use axum_either::accept::parse_accept_language;
+/// use http::{header, HeaderMap};
+///
+/// let mut headers = HeaderMap::new();
+/// headers.insert(header::ACCEPT_LANGUAGE, "en;q=0.5, de-AT, *;q=0.1".parse().unwrap());
+/// let tags: Vec<_> = parse_accept_language(&headers)
+///     .into_iter()
+///     .map(|range| range.tag)
+///     .collect();
+/// assert_eq!(tags, ["de-at", "en", "*"]);
+/// ```
+pub fn parse_accept_language(headers: &HeaderMap) -> Vec<LanguageRange> {
+    let mut ranges: Vec<_> = headers
+        .get_all(header::ACCEPT_LANGUAGE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .filter_map(LanguageRange::parse)
+        .collect();
+    ranges.sort_by(|a, b| b.q.partial_cmp(&a.q).unwrap_or(Ordering::Equal));
+    ranges
+}
@@ -0,0 +1,153 @@
+//! Describe [`AxumEither`] in OpenAPI documents generated with aide.
+//!
+//! The request bodies and responses of both sides are merged into a single operation, nested
+//! chains are merged recursively. [`MsgPack`](crate::MsgPack) and [`Cbor`](crate::Cbor) are
+//! described like aide describes `Json`, with the schema of their payload.
+
+#[cfg(any(feature = "msgpack", feature = "cbor"))]
+use aide::openapi::{MediaType, SchemaObject};
+use aide::{
+    gen::GenContext,
+    openapi::{Operation, ReferenceOr, RequestBody, Response},
+    OperationInput, OperationOutput,
+};
+
+use crate::AxumEither;
+
+/// Merges the request bodies of both sides, each side contributes its content types
+impl<L, R> OperationInput for AxumEither<L, R>
+where
+    L: OperationInput,
+    R: OperationInput,
+{
+    fn operation_input(ctx: &mut GenContext, operation: &mut Operation) {
+        let existing = operation.request_body.take();
+        L::operation_input(ctx, operation);
+        let left = operation.request_body.take();
+        R::operation_input(ctx, operation);
+        let right = operation.request_body.take();
+
+        operation.request_body = [existing, left, right]
+            .into_iter()
+            .flatten()
+            .reduce(merge_request_bodies);
+    }
+}
+
+/// Merges the responses of both sides, responses with the same status share their content types
+impl<L, R> OperationOutput for AxumEither<L, R>
+where
+    L: OperationOutput,
+    R: OperationOutput,
+{
+    type Inner = Self;
+
+    fn operation_response(ctx: &mut GenContext, operation: &mut Operation) -> Option<Response> {
+        let left = L::operation_response(ctx, operation);
+        let right = R::operation_response(ctx, operation);
+        match (left, right) {
+            (Some(mut left), Some(right)) => {
+                left.content.extend(right.content);
+                Some(left)
+            }
+            (left, right) => left.or(right),
+        }
+    }
+
+    fn inferred_responses(
+        ctx: &mut GenContext,
+        operation: &mut Operation,
+    ) -> Vec<(Option<u16>, Response)> {
+        let mut responses = L::inferred_responses(ctx, operation);
+        for (status, response) in R::inferred_responses(ctx, operation) {
+            match responses
+                .iter_mut()
+                .find(|(existing, _)| *existing == status)
+            {
+                Some((_, existing)) => existing.content.extend(response.content),
+                None => responses.push((status, response)),
+            }
+        }
+        responses
+    }
+}
+
+/// Referenced request bodies can not be merged, the first one is kept
+fn merge_request_bodies(
+    first: ReferenceOr<RequestBody>,
+    second: ReferenceOr<RequestBody>,
+) -> ReferenceOr<RequestBody> {
+    match (first, second) {
+        (ReferenceOr::Item(mut first), ReferenceOr::Item(second)) => {
+            first.content.extend(second.content);
+            ReferenceOr::Item(first)
+        }
+        (first, _) => first,
+    }
+}
+
+/// The content of a body with the schema of `T`
+#[cfg(any(feature = "msgpack", feature = "cbor"))]
+fn payload_content<T: schemars::JsonSchema>(ctx: &mut GenContext) -> MediaType {
+    MediaType {
+        schema: Some(SchemaObject {
+            json_schema: ctx.schema.subschema_for::<T>(),
+            external_docs: None,
+            example: None,
+        }),
+        ..Default::default()
+    }
+}
+
+/// Describes a binary format body with the media type of its [`Format`](crate::format::Format)
+#[cfg(any(feature = "msgpack", feature = "cbor"))]
+macro_rules! format_operation {
+    ($name:ident, $format:expr) => {
+        impl<T> OperationInput for crate::$name<T>
+        where
+            T: schemars::JsonSchema,
+        {
+            fn operation_input(ctx: &mut GenContext, operation: &mut Operation) {
+                let mut body = RequestBody {
+                    required: true,
+                    ..Default::default()
+                };
+                body.content
+                    .insert($format.media_type().into(), payload_content::<T>(ctx));
+                operation.request_body = Some(ReferenceOr::Item(body));
+            }
+        }
+
+        impl<T> OperationOutput for crate::$name<T>
+        where
+            T: schemars::JsonSchema,
+        {
+            type Inner = T;
+
+            fn operation_response(
+                ctx: &mut GenContext,
+                _operation: &mut Operation,
+            ) -> Option<Response> {
+                let mut response = Response::default();
+                response
+                    .content
+                    .insert($format.media_type().into(), payload_content::<T>(ctx));
+                Some(response)
+            }
+
+            fn inferred_responses(
+                ctx: &mut GenContext,
+                operation: &mut Operation,
+            ) -> Vec<(Option<u16>, Response)> {
+                Self::operation_response(ctx, operation)
+                    .map(|response| vec![(Some(200), response)])
+                    .unwrap_or_default()
+            }
+        }
+    };
+}
+
+#[cfg(feature = "msgpack")]
+format_operation!(MsgPack, crate::format::Format::MessagePack);
+#[cfg(feature = "cbor")]
+format_operation!(Cbor, crate::format::Format::Cbor);
@@ -0,0 +1,46 @@
+//! Construct [`AxumEither`] values from fuzzer input with arbitrary.
+
+use arbitrary::{size_hint, Arbitrary, MaxRecursionReached, Result, Unstructured};
+
+use crate::AxumEither;
+
+/// Consumes a `bool` selecting the variant, followed by the value of that side.
+///
+/// # Examples
+/// ```
+/// # use axum_either::prelude::*;
+/// use arbitrary::{Arbitrary, Unstructured};
+///
+/// let mut input = Unstructured::new(&[0, 1, 7]);
+/// let either = <axum_either::one_of!(bool, u8, u16)>::arbitrary(&mut input).unwrap();
+/// assert_eq!(either, AxumEither::Right(AxumEither::Left(7)));
+/// ```
+impl<'a, L, R> Arbitrary<'a> for AxumEither<L, R>
+where
+    L: Arbitrary<'a>,
+    R: Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(if u.arbitrary()? {
+            Self::Left(u.arbitrary()?)
+        } else {
+            Self::Right(u.arbitrary()?)
+        })
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        Self::try_size_hint(depth).unwrap_or_default()
+    }
+
+    fn try_size_hint(depth: usize) -> Result<(usize, Option<usize>), MaxRecursionReached> {
+        Ok(size_hint::and(
+            <bool as Arbitrary>::try_size_hint(depth)?,
+            size_hint::try_recursion_guard(depth, |depth| {
+                Ok(size_hint::or(
+                    L::try_size_hint(depth)?,
+                    R::try_size_hint(depth)?,
+                ))
+            })?,
+        ))
+    }
+}
@@ -0,0 +1,340 @@
+//! Authenticate with a bearer token in the `Authorization` header or a session cookie.
+//!
+//! [`AuthEither`] tries the header first and the cookie second, both are read from the request
+//! parts so the body is still available to the following extractors. Implement [`AuthToken`]
+//! for other token sources and [`CookieName`] to read another cookie than `session`.
+//!
+//! # Examples
+//! ```
+//! use axum::{routing::get, Router};
+//! use axum_either::auth::AuthEither;
+//!
+//! pub async fn whoami(auth: AuthEither) -> String {
+//!     format!("token {}", auth.token())
+//! }
+//!
+//! let router: Router = Router::new().route("/whoami", get(whoami));
+//! ```
+
+use axum::{
+    extract::TypedHeader,
+    headers::{authorization::Bearer, Authorization},
+};
+use axum_core::{
+    extract::{FromRequest, RequestParts},
+    response::{IntoResponse, Response},
+};
+use axum_extra::extract::CookieJar;
+use http::{header, HeaderMap, HeaderValue, StatusCode};
+use std::{
+    fmt,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+use crate::{AttemptRef, AxumEither, AxumEitherRejection, RejectionInfo};
+
+/// An extracted credential which carries a token.
+pub trait AuthToken {
+    /// The scheme announced in the `WWW-Authenticate` header when the request is rejected.
+    ///
+    /// Only the scheme of the header side of an [`AuthEither`] is used.
+    const SCHEME: &'static str = "Bearer";
+
+    /// The token of the credential
+    fn token(&self) -> &str;
+}
+
+impl AuthToken for TypedHeader<Authorization<Bearer>> {
+    fn token(&self) -> &str {
+        self.0.token()
+    }
+}
+
+/// The name of the cookie [`SessionCookie`] reads.
+pub trait CookieName {
+    /// The name of the cookie
+    const NAME: &'static str;
+}
+
+/// The default cookie of [`SessionCookie`], named `session`
+#[derive(Debug, Clone, Copy, Hash, Default, PartialEq, PartialOrd, Eq, Ord)]
+pub struct Session;
+
+impl CookieName for Session {
+    const NAME: &'static str = "session";
+}
+
+/// Extracts the value of the cookie named [`N::NAME`](CookieName::NAME).
+///
+/// Requests without the cookie or with an empty value are rejected with [`MissingCookie`].
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct SessionCookie<N = Session> {
+    value: String,
+    name: PhantomData<fn() -> N>,
+}
+
+impl<N> SessionCookie<N> {
+    /// The value of the cookie
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// The value of the cookie
+    pub fn into_value(self) -> String {
+        self.value
+    }
+}
+
+impl<N> AuthToken for SessionCookie<N> {
+    fn token(&self) -> &str {
+        &self.value
+    }
+}
+
+#[async_trait::async_trait]
+impl<N, B> FromRequest<B> for SessionCookie<N>
+where
+    N: CookieName,
+    B: Send,
+{
+    type Rejection = MissingCookie;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        let jar = match CookieJar::from_request(req).await {
+            Ok(jar) => jar,
+            Err(infallible) => match infallible {},
+        };
+        match jar.get(N::NAME) {
+            Some(cookie) if !cookie.value().is_empty() => Ok(Self {
+                value: cookie.value().to_owned(),
+                name: PhantomData,
+            }),
+            _ => Err(MissingCookie { name: N::NAME }),
+        }
+    }
+}
+
+/// The rejection of [`SessionCookie`], it responds with `401 Unauthorized`.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct MissingCookie {
+    name: &'static str,
+}
+
+impl MissingCookie {
+    /// The name of the missing cookie
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl fmt::Display for MissingCookie {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Missing the `{}` cookie", self.name)
+    }
+}
+
+impl std::error::Error for MissingCookie {}
+
+impl IntoResponse for MissingCookie {
+    fn into_response(self) -> Response {
+        (self.status(), self.to_string()).into_response()
+    }
+}
+
+impl RejectionInfo for MissingCookie {
+    fn status(&self) -> StatusCode {
+        StatusCode::UNAUTHORIZED
+    }
+
+    fn message(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// Extracts the credential `H` or, if it is missing or invalid, the credential `C`.
+///
+/// By default this is a bearer token in the `Authorization` header or the `session` cookie. The
+/// header wins if both are present, only the header is read in that case. The token of either
+/// source is available through [`token`](Self::token).
+///
+/// If neither credential is present the request is rejected with [`AuthRejection`], which
+/// responds with `401 Unauthorized` and a `WWW-Authenticate` challenge instead of the
+/// `400 Bad Request` of a plain [`AxumEither`].
+#[derive(Debug, Clone, Copy, Hash, PartialEq, PartialOrd, Eq, Ord)]
+pub struct AuthEither<H = TypedHeader<Authorization<Bearer>>, C = SessionCookie>(
+    pub AxumEither<H, C>,
+);
+
+impl<H, C> AuthEither<H, C>
+where
+    H: AuthToken,
+    C: AuthToken,
+{
+    /// The token of the extracted credential, regardless of its source
+    pub fn token(&self) -> &str {
+        match &self.0 {
+            AxumEither::Left(header) => header.token(),
+            AxumEither::Right(cookie) => cookie.token(),
+        }
+    }
+}
+
+impl<H, C> AuthEither<H, C> {
+    /// The credential of the header, `None` if the cookie was used
+    pub fn header(&self) -> Option<&H> {
+        match &self.0 {
+            AxumEither::Left(header) => Some(header),
+            AxumEither::Right(_) => None,
+        }
+    }
+
+    /// The credential of the cookie, `None` if the header was used
+    pub fn cookie(&self) -> Option<&C> {
+        match &self.0 {
+            AxumEither::Left(_) => None,
+            AxumEither::Right(cookie) => Some(cookie),
+        }
+    }
+
+    /// The extracted credential
+    pub fn into_inner(self) -> AxumEither<H, C> {
+        self.0
+    }
+}
+
+impl<H, C> Deref for AuthEither<H, C> {
+    type Target = AxumEither<H, C>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<H, C> DerefMut for AuthEither<H, C> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[async_trait::async_trait]
+impl<H, C, B> FromRequest<B> for AuthEither<H, C>
+where
+    H: FromRequest<B> + AuthToken,
+    H::Rejection: Send,
+    C: FromRequest<B> + AuthToken,
+    B: Send,
+{
+    type Rejection = AuthRejection<H::Rejection, C::Rejection>;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        match AxumEither::<H, C>::from_request(req).await {
+            Ok(either) => Ok(Self(either)),
+            Err(rejection) => Err(AuthRejection {
+                rejection,
+                scheme: H::SCHEME,
+            }),
+        }
+    }
+}
+
+/// The rejection of [`AuthEither`], it responds with `401 Unauthorized`.
+///
+/// The `WWW-Authenticate` header challenges the client with the [scheme](AuthToken::SCHEME) of
+/// the header credential, the body contains the errors of both credentials.
+#[derive(Debug)]
+pub struct AuthRejection<HE, CE>
+where
+    HE: IntoResponse,
+    CE: IntoResponse,
+{
+    rejection: AxumEitherRejection<HE, CE>,
+    scheme: &'static str,
+}
+
+impl<HE, CE> AuthRejection<HE, CE>
+where
+    HE: IntoResponse,
+    CE: IntoResponse,
+{
+    /// The scheme of the `WWW-Authenticate` challenge
+    pub fn scheme(&self) -> &'static str {
+        self.scheme
+    }
+
+    /// The error of the header credential
+    pub fn header(&self) -> &HE {
+        self.rejection.left()
+    }
+
+    /// The error of the cookie credential
+    pub fn cookie(&self) -> &CE {
+        self.rejection.right()
+    }
+
+    /// The rejections of both credentials
+    pub fn into_inner(self) -> AxumEitherRejection<HE, CE> {
+        self.rejection
+    }
+}
+
+impl<HE, CE> fmt::Display for AuthRejection<HE, CE>
+where
+    HE: IntoResponse + fmt::Display,
+    CE: IntoResponse + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.rejection, f)
+    }
+}
+
+impl<HE, CE> std::error::Error for AuthRejection<HE, CE>
+where
+    HE: IntoResponse + std::error::Error,
+    CE: IntoResponse + std::error::Error,
+{
+}
+
+/// The body is the one of the [`AxumEitherRejection`] of both credentials
+impl<HE, CE> IntoResponse for AuthRejection<HE, CE>
+where
+    HE: IntoResponse,
+    CE: IntoResponse,
+{
+    fn into_response(self) -> Response {
+        let mut response = self.rejection.into_response();
+        *response.status_mut() = StatusCode::UNAUTHORIZED;
+        if let Ok(challenge) = HeaderValue::from_str(self.scheme) {
+            response
+                .headers_mut()
+                .insert(header::WWW_AUTHENTICATE, challenge);
+        }
+        response
+    }
+}
+
+impl<HE, CE> RejectionInfo for AuthRejection<HE, CE>
+where
+    HE: IntoResponse + RejectionInfo,
+    CE: IntoResponse + RejectionInfo,
+{
+    fn status(&self) -> StatusCode {
+        StatusCode::UNAUTHORIZED
+    }
+
+    fn message(&self) -> String {
+        self.rejection.message()
+    }
+
+    fn headers(&self) -> HeaderMap {
+        let mut headers = crate::info::plain_text_headers();
+        if let Ok(challenge) = HeaderValue::from_str(self.scheme) {
+            headers.insert(header::WWW_AUTHENTICATE, challenge);
+        }
+        headers
+    }
+
+    fn visit_attempts<'a>(&'a self, f: &mut dyn FnMut(AttemptRef<'a>)) {
+        self.rejection.visit_attempts(f)
+    }
+}
@@ -0,0 +1,140 @@
+//! A response body which is one of two bodies, for middleware branching between bodies without
+//! boxing them.
+//!
+//! # Examples
+//! ```
+//! use axum_core::body::BoxBody;
+//! use axum_either::body::EitherBody;
+//! use bytes::Bytes;
+//! use http::Response;
+//! use http_body::Full;
+//!
+//! pub fn maintenance(
+//!     response: Response<BoxBody>,
+//!     active: bool,
+//! ) -> Response<EitherBody<Full<Bytes>, BoxBody>> {
+//!     if active {
+//!         Response::new(EitherBody::left(Full::from("Down for maintenance")))
+//!     } else {
+//!         response.map(EitherBody::right)
+//!     }
+//! }
+//! ```
+
+use axum_core::{body::BoxBody, BoxError, Error};
+use bytes::Buf;
+use http::HeaderMap;
+use http_body::{Body, Full, SizeHint};
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::AxumEither;
+
+/// A [`Body`] which is either `L` or `R`.
+///
+/// Both bodies have to yield the same data, their errors are converted into an axum
+/// [`Error`].
+#[derive(Debug, Clone, Copy, Hash, PartialEq, PartialOrd, Eq, Ord)]
+pub struct EitherBody<L, R>(pub AxumEither<L, R>);
+
+impl<L, R> EitherBody<L, R> {
+    /// Create a body streaming `body` as the left variant
+    pub fn left(body: L) -> Self {
+        Self(AxumEither::Left(body))
+    }
+
+    /// Create a body streaming `body` as the right variant
+    pub fn right(body: R) -> Self {
+        Self(AxumEither::Right(body))
+    }
+
+    /// The body of the active variant
+    pub fn into_inner(self) -> AxumEither<L, R> {
+        self.0
+    }
+
+    fn project(self: Pin<&mut Self>) -> AxumEither<Pin<&mut L>, Pin<&mut R>> {
+        // SAFETY: The either is structurally pinned, it is never moved out of a pinned body.
+        unsafe { self.map_unchecked_mut(|body| &mut body.0) }.as_pin_mut()
+    }
+}
+
+impl<L, R> Body for EitherBody<L, R>
+where
+    L: Body,
+    L::Error: Into<BoxError>,
+    R: Body<Data = L::Data>,
+    R::Error: Into<BoxError>,
+{
+    type Data = L::Data;
+    type Error = Error;
+
+    fn poll_data(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Self::Data, Self::Error>>> {
+        match self.project() {
+            AxumEither::Left(l) => l
+                .poll_data(cx)
+                .map(|data| data.map(|d| d.map_err(Error::new))),
+            AxumEither::Right(r) => r
+                .poll_data(cx)
+                .map(|data| data.map(|d| d.map_err(Error::new))),
+        }
+    }
+
+    fn poll_trailers(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<Option<HeaderMap>, Self::Error>> {
+        match self.project() {
+            AxumEither::Left(l) => l.poll_trailers(cx).map_err(Error::new),
+            AxumEither::Right(r) => r.poll_trailers(cx).map_err(Error::new),
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        match &self.0 {
+            AxumEither::Left(l) => l.is_end_stream(),
+            AxumEither::Right(r) => r.is_end_stream(),
+        }
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        match &self.0 {
+            AxumEither::Left(l) => l.size_hint(),
+            AxumEither::Right(r) => r.size_hint(),
+        }
+    }
+}
+
+impl<L, R> From<AxumEither<L, R>> for EitherBody<L, R> {
+    fn from(either: AxumEither<L, R>) -> Self {
+        Self(either)
+    }
+}
+
+impl<L, R> From<EitherBody<L, R>> for AxumEither<L, R> {
+    fn from(body: EitherBody<L, R>) -> Self {
+        body.0
+    }
+}
+
+/// A buffered body is the left variant
+impl<D, R> From<Full<D>> for EitherBody<Full<D>, R>
+where
+    D: Buf,
+{
+    fn from(body: Full<D>) -> Self {
+        Self::left(body)
+    }
+}
+
+/// The boxed body of axum is the right variant
+impl<L> From<BoxBody> for EitherBody<L, BoxBody> {
+    fn from(body: BoxBody) -> Self {
+        Self::right(body)
+    }
+}
@@ -0,0 +1,208 @@
+//! Explain rejections of body extractors for requests which conventionally have no body.
+
+use axum_core::{
+    extract::{FromRequest, RequestParts},
+    response::{IntoResponse, Response},
+};
+use http::{header, HeaderMap, Method, StatusCode};
+use http_body::Body;
+use std::{
+    fmt,
+    ops::{Deref, DerefMut},
+};
+
+use crate::{AttemptRef, AxumEither, AxumEitherRejection, RejectionInfo};
+
+/// Extracts `L` or `R` like [`AxumEither`], a bodiless request is rejected with
+/// [`BodyEitherRejection::Bodyless`] instead of the errors of both sides.
+///
+/// A request is bodiless if its method is `GET`, `HEAD`, `OPTIONS` or `TRACE` and it carries no
+/// body, neither a `Content-Type` nor a non empty body. Both sides are still tried, so a side
+/// which only reads the request parts, like `Query`, extracts as usual. The dedicated rejection
+/// is only used once both sides rejected the request.
+///
+/// This is opt-in rather than built into [`AxumEither`] because the rejection of `AxumEither` is
+/// [`AxumEitherRejection`] and callers match on its `left_error` and `right_error` fields.
+/// Reporting bodiless requests there would need a new rejection type or variant, which would
+/// break every handler and formatter naming the rejection. A plain `AxumEither` still reports
+/// the errors of both sides for a bodiless request.
+///
+/// # Examples
+/// ```
+/// use axum::{extract::Query, Form, Json};
+/// use axum_either::{AxumEither, BodyEither};
+///
+/// #[derive(serde::Deserialize)]
+/// pub struct Search {
+///     q: String,
+/// }
+///
+/// /// `GET /search` without a query is rejected with "GET requests have no body"
+/// pub async fn search(BodyEither(search): BodyEither<Json<Search>, Query<Search>>) -> String {
+///     search.map_lr(|Json(s)| s.q, |Query(s)| s.q).into_inner()
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, PartialOrd, Eq, Ord)]
+pub struct BodyEither<L, R>(pub AxumEither<L, R>);
+
+impl<L, R> BodyEither<L, R> {
+    /// The extracted either
+    pub fn into_inner(self) -> AxumEither<L, R> {
+        self.0
+    }
+}
+
+impl<L, R> Deref for BodyEither<L, R> {
+    type Target = AxumEither<L, R>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<L, R> DerefMut for BodyEither<L, R> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Whether the request conventionally has no body and carries none
+fn is_bodiless<B>(req: &RequestParts<B>) -> bool
+where
+    B: Body,
+{
+    let method = req.method();
+    let bodiless_method = method == Method::GET
+        || method == Method::HEAD
+        || method == Method::OPTIONS
+        || method == Method::TRACE;
+    bodiless_method
+        && !req.headers().contains_key(header::CONTENT_TYPE)
+        && req
+            .body()
+            .is_none_or(|body| body.is_end_stream() || body.size_hint().exact() == Some(0))
+}
+
+fn bodyless_message(method: &Method) -> String {
+    format!(
+        "{} requests have no body, send the payload with another method",
+        method
+    )
+}
+
+#[async_trait::async_trait]
+impl<L, R, B> FromRequest<B> for BodyEither<L, R>
+where
+    L: FromRequest<B>,
+    L::Rejection: Send,
+    R: FromRequest<B>,
+    B: Body + Send,
+{
+    type Rejection = BodyEitherRejection<L::Rejection, R::Rejection>;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        let bodiless = is_bodiless(req);
+        match AxumEither::<L, R>::from_request(req).await {
+            Ok(either) => Ok(Self(either)),
+            Err(rejection) if bodiless => Err(BodyEitherRejection::Bodyless {
+                method: req.method().clone(),
+                rejection,
+            }),
+            Err(rejection) => Err(BodyEitherRejection::Rejected(rejection)),
+        }
+    }
+}
+
+/// The rejection of [`BodyEither`]
+#[derive(Debug)]
+pub enum BodyEitherRejection<LE, RE> {
+    /// The request has no body and a method which conventionally has none, it is answered with
+    /// `400 Bad Request` and a message naming the method
+    Bodyless {
+        /// The method of the request
+        method: Method,
+        /// The rejections of both sides
+        rejection: AxumEitherRejection<LE, RE>,
+    },
+    /// Both sides rejected a request which may have a body
+    Rejected(AxumEitherRejection<LE, RE>),
+}
+
+impl<LE, RE> BodyEitherRejection<LE, RE> {
+    /// The rejections of both sides
+    pub fn into_inner(self) -> AxumEitherRejection<LE, RE> {
+        match self {
+            Self::Bodyless { rejection, .. } | Self::Rejected(rejection) => rejection,
+        }
+    }
+}
+
+impl<LE, RE> fmt::Display for BodyEitherRejection<LE, RE>
+where
+    LE: fmt::Display,
+    RE: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bodyless { method, .. } => f.write_str(&bodyless_message(method)),
+            Self::Rejected(rejection) => fmt::Display::fmt(rejection, f),
+        }
+    }
+}
+
+impl<LE, RE> std::error::Error for BodyEitherRejection<LE, RE>
+where
+    LE: std::error::Error,
+    RE: std::error::Error,
+{
+}
+
+impl<LE, RE> IntoResponse for BodyEitherRejection<LE, RE>
+where
+    LE: IntoResponse,
+    RE: IntoResponse,
+{
+    fn into_response(self) -> Response {
+        match self {
+            Self::Bodyless { method, .. } => {
+                (StatusCode::BAD_REQUEST, bodyless_message(&method)).into_response()
+            }
+            Self::Rejected(rejection) => rejection.into_response(),
+        }
+    }
+}
+
+impl<LE, RE> RejectionInfo for BodyEitherRejection<LE, RE>
+where
+    LE: RejectionInfo,
+    RE: RejectionInfo,
+{
+    fn status(&self) -> StatusCode {
+        match self {
+            Self::Bodyless { .. } => StatusCode::BAD_REQUEST,
+            Self::Rejected(rejection) => rejection.status(),
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            Self::Bodyless { method, .. } => bodyless_message(method),
+            Self::Rejected(rejection) => rejection.message(),
+        }
+    }
+
+    fn headers(&self) -> HeaderMap {
+        match self {
+            Self::Bodyless { .. } => crate::info::plain_text_headers(),
+            Self::Rejected(rejection) => rejection.headers(),
+        }
+    }
+
+    fn visit_attempts<'a>(&'a self, f: &mut dyn FnMut(AttemptRef<'a>)) {
+        match self {
+            Self::Bodyless { rejection, .. } | Self::Rejected(rejection) => {
+                rejection.visit_attempts(f)
+            }
+        }
+    }
+}
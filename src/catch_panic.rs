@@ -0,0 +1,310 @@
+//! Turn panics of extractors into rejections so the next variant can be tried.
+
+use axum_core::{
+    extract::{FromRequest, RequestParts},
+    response::{IntoResponse, Response},
+};
+use http::{HeaderMap, StatusCode};
+use std::{
+    any::{self, Any},
+    fmt,
+    future::poll_fn,
+    ops::{Deref, DerefMut},
+    panic::{self, AssertUnwindSafe},
+    task::Poll,
+};
+
+use crate::{AttemptRef, AxumEither, AxumEitherRejection, RejectionInfo};
+
+/// Extracts `T` and rejects with [`CaughtRejection::Panicked`] if the extractor panics.
+///
+/// The panic is caught with [`catch_unwind`](std::panic::catch_unwind), the extractor is treated
+/// as unwind safe. This is only sound as far as the extractor does not leave shared state broken
+/// when it panics, data behind locks of the request extensions may be poisoned or half updated.
+/// The body is lost if the panicking extractor already took it, the following extractors are
+/// rejected in that case. The panic hook still runs, the panic is printed as usual.
+///
+/// # Examples
+/// ```
+/// use axum::Json;
+/// use axum_either::{AxumEither, CatchPanic};
+///
+/// # type Buggy = String;
+/// pub async fn handler(request: AxumEither<CatchPanic<Buggy>, CatchPanic<Json<u32>>>) {}
+/// ```
+#[derive(Debug, Clone, Copy, Hash, Default, PartialEq, PartialOrd, Eq, Ord)]
+pub struct CatchPanic<T>(pub T);
+
+impl<T> CatchPanic<T> {
+    /// The extracted value
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for CatchPanic<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for CatchPanic<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[async_trait::async_trait]
+impl<T, B> FromRequest<B> for CatchPanic<T>
+where
+    T: FromRequest<B>,
+    B: Send,
+{
+    type Rejection = CaughtRejection<T::Rejection>;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        let mut extraction = T::from_request(req);
+        let caught = poll_fn(|cx| {
+            match panic::catch_unwind(AssertUnwindSafe(|| extraction.as_mut().poll(cx))) {
+                Ok(Poll::Ready(result)) => Poll::Ready(Ok(result)),
+                Ok(Poll::Pending) => Poll::Pending,
+                Err(payload) => Poll::Ready(Err(ExtractorPanic::new::<T>(&*payload))),
+            }
+        })
+        .await;
+
+        match caught {
+            Ok(Ok(value)) => Ok(Self(value)),
+            Ok(Err(rejection)) => Err(CaughtRejection::Rejected(rejection)),
+            Err(panic) => Err(CaughtRejection::Panicked(panic)),
+        }
+    }
+}
+
+/// Extracts `L` or `R` like [`AxumEither`], a panic of either extractor rejects only its side.
+///
+/// This is `AxumEither<CatchPanic<L>, CatchPanic<R>>` without the wrappers in the extracted
+/// value, see [`CatchPanic`] for the caveats of catching panics. A nested chain on the right
+/// side is caught as a whole, wrap the extractors of the chain in [`CatchPanic`] to try the
+/// remaining extractors after a panic.
+///
+/// # Examples
+/// ```
+/// use axum::{Form, Json};
+/// use axum_either::{prelude::*, CatchPanicEither};
+///
+/// #[derive(serde::Deserialize)]
+/// pub struct Request(u32);
+///
+/// pub async fn handler(
+///     CatchPanicEither(request): CatchPanicEither<Json<Request>, Form<Request>>,
+/// ) -> String {
+///     request.map_lr(|Json(r)| r.0, |Form(r)| r.0).into_inner().to_string()
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, PartialOrd, Eq, Ord)]
+pub struct CatchPanicEither<L, R>(pub AxumEither<L, R>);
+
+impl<L, R> CatchPanicEither<L, R> {
+    /// The extracted either
+    pub fn into_inner(self) -> AxumEither<L, R> {
+        self.0
+    }
+}
+
+impl<L, R> Deref for CatchPanicEither<L, R> {
+    type Target = AxumEither<L, R>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<L, R> DerefMut for CatchPanicEither<L, R> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[async_trait::async_trait]
+impl<L, R, B> FromRequest<B> for CatchPanicEither<L, R>
+where
+    L: FromRequest<B>,
+    L::Rejection: Send,
+    R: FromRequest<B>,
+    B: Send,
+{
+    type Rejection =
+        AxumEitherRejection<CaughtRejection<L::Rejection>, CaughtRejection<R::Rejection>>;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        let either = AxumEither::<CatchPanic<L>, CatchPanic<R>>::from_request(req).await?;
+        Ok(Self(
+            either.map_lr(CatchPanic::into_inner, CatchPanic::into_inner),
+        ))
+    }
+}
+
+/// The rejection of [`CatchPanic`]
+#[derive(Debug)]
+pub enum CaughtRejection<E> {
+    /// The extractor rejected the request
+    Rejected(E),
+    /// The extractor panicked
+    Panicked(ExtractorPanic),
+}
+
+impl<E> CaughtRejection<E> {
+    /// The panic of the extractor, `None` if it rejected the request
+    pub fn panic(&self) -> Option<&ExtractorPanic> {
+        match self {
+            Self::Rejected(_) => None,
+            Self::Panicked(panic) => Some(panic),
+        }
+    }
+}
+
+impl<E> fmt::Display for CaughtRejection<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Rejected(rejection) => fmt::Display::fmt(rejection, f),
+            Self::Panicked(panic) => fmt::Display::fmt(panic, f),
+        }
+    }
+}
+
+impl<E> std::error::Error for CaughtRejection<E>
+where
+    E: std::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Rejected(rejection) => Some(rejection),
+            Self::Panicked(panic) => Some(panic),
+        }
+    }
+}
+
+impl<E> IntoResponse for CaughtRejection<E>
+where
+    E: IntoResponse,
+{
+    fn into_response(self) -> Response {
+        match self {
+            Self::Rejected(rejection) => rejection.into_response(),
+            Self::Panicked(panic) => panic.into_response(),
+        }
+    }
+}
+
+impl<E> RejectionInfo for CaughtRejection<E>
+where
+    E: RejectionInfo,
+{
+    fn status(&self) -> StatusCode {
+        match self {
+            Self::Rejected(rejection) => rejection.status(),
+            Self::Panicked(panic) => panic.status(),
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            Self::Rejected(rejection) => rejection.message(),
+            Self::Panicked(panic) => panic.message(),
+        }
+    }
+
+    fn source_error(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Rejected(rejection) => rejection.source_error(),
+            Self::Panicked(_) => None,
+        }
+    }
+
+    fn headers(&self) -> HeaderMap {
+        match self {
+            Self::Rejected(rejection) => rejection.headers(),
+            Self::Panicked(panic) => panic.headers(),
+        }
+    }
+
+    fn visit_attempts<'a>(&'a self, f: &mut dyn FnMut(AttemptRef<'a>)) {
+        match self {
+            Self::Rejected(rejection) => rejection.visit_attempts(f),
+            Self::Panicked(panic) => panic.visit_attempts(f),
+        }
+    }
+}
+
+/// A panic of an extractor caught by [`CatchPanic`], it responds with
+/// `500 Internal Server Error` and a generic body.
+///
+/// The panic message is internal to the server and never sent to the client, neither by the
+/// response nor by [`RejectionInfo::message`]. It is printed by the panic hook and available with
+/// [`panic_message`](Self::panic_message) and the [`Display`](fmt::Display) implementation for
+/// logging.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct ExtractorPanic {
+    type_name: &'static str,
+    message: Option<String>,
+}
+
+impl ExtractorPanic {
+    fn new<T>(payload: &(dyn Any + Send)) -> Self {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| (*message).to_owned())
+            .or_else(|| payload.downcast_ref::<String>().cloned());
+        Self {
+            type_name: any::type_name::<T>(),
+            message,
+        }
+    }
+
+    /// The type name of the extractor which panicked
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// The message of the panic, `None` if the panic payload is not a string
+    pub fn panic_message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+}
+
+impl fmt::Display for ExtractorPanic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The extractor {} panicked", self.type_name)?;
+        if let Some(message) = &self.message {
+            write!(f, ": {}", message)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ExtractorPanic {}
+
+impl IntoResponse for ExtractorPanic {
+    fn into_response(self) -> Response {
+        (self.status(), PANIC_RESPONSE).into_response()
+    }
+}
+
+impl RejectionInfo for ExtractorPanic {
+    fn status(&self) -> StatusCode {
+        StatusCode::INTERNAL_SERVER_ERROR
+    }
+
+    fn message(&self) -> String {
+        PANIC_RESPONSE.into()
+    }
+}
+
+/// The body of [`ExtractorPanic`], it does not reveal anything about the panic
+const PANIC_RESPONSE: &str = "Internal server error";
@@ -0,0 +1,311 @@
+//! Convert between [`one_of`](crate::one_of) chains with different types.
+//!
+//! [`AxumEither::widen`] converts a chain into a chain containing all of its types,
+//! [`AxumEither::narrow`] converts a chain into a chain containing some of its types. The
+//! position of every type is searched at the type level, the index parameters of the traits in
+//! this module are always inferred. If a type appears multiple times in a chain the search is
+//! ambiguous and the conversion does not compile.
+//!
+//! There are no `From` and `TryFrom` implementations as they would conflict with the reflexive
+//! implementations of the standard library.
+//!
+//! # Examples
+//! ```
+//! # use axum_either::prelude::*;
+//! type V1 = axum_either::one_of!(u8, u32);
+//! type V2 = axum_either::one_of!(u8, u16, u32);
+//!
+//! let either: V1 = AxumEither::Right(7);
+//! let widened: V2 = either.widen();
+//! assert_eq!(widened, AxumEither::Right(AxumEither::Right(7)));
+//!
+//! let narrowed: Result<V1, _> = widened.narrow();
+//! assert_eq!(narrowed, Ok(AxumEither::Right(7)));
+//! ```
+
+use std::{convert::Infallible, marker::PhantomData};
+
+use crate::AxumEither;
+
+/// Index of a type which is found at the current position
+#[derive(Debug, Clone, Copy, Hash, Default, PartialEq, PartialOrd, Eq, Ord)]
+pub struct Here;
+
+/// Index of a type which is found at the index `I` in the right side
+#[derive(Debug, Clone, Copy, Hash, Default, PartialEq, PartialOrd, Eq, Ord)]
+pub struct There<I>(PhantomData<I>);
+
+/// Index of a type which is not a chain itself
+#[derive(Debug, Clone, Copy, Hash, Default, PartialEq, PartialOrd, Eq, Ord)]
+pub struct Leaf<I>(PhantomData<I>);
+
+/// Construct a chain from the value of one of its types
+pub trait Inject<T, I> {
+    /// Wrap `value` in the variant holding `T`
+    fn inject(value: T) -> Self;
+}
+
+impl<T> Inject<T, Here> for T {
+    fn inject(value: T) -> Self {
+        value
+    }
+}
+
+impl<T, R> Inject<T, Here> for AxumEither<T, R> {
+    fn inject(value: T) -> Self {
+        Self::Left(value)
+    }
+}
+
+impl<T, L, R, I> Inject<T, There<I>> for AxumEither<L, R>
+where
+    R: Inject<T, I>,
+{
+    fn inject(value: T) -> Self {
+        Self::Right(R::inject(value))
+    }
+}
+
+/// Take the value of one type out of a chain
+pub trait Uninject<T, I> {
+    /// The chain without `T`, `Infallible` takes the place of the removed type
+    type Remainder;
+
+    /// The value if it has the type `T`, otherwise the remainder
+    fn uninject(self) -> Result<T, Self::Remainder>;
+}
+
+impl<T> Uninject<T, Here> for T {
+    type Remainder = Infallible;
+
+    fn uninject(self) -> Result<T, Self::Remainder> {
+        Ok(self)
+    }
+}
+
+impl<T, R> Uninject<T, Here> for AxumEither<T, R> {
+    type Remainder = R;
+
+    fn uninject(self) -> Result<T, Self::Remainder> {
+        match self {
+            Self::Left(l) => Ok(l),
+            Self::Right(r) => Err(r),
+        }
+    }
+}
+
+impl<T, L, R, I> Uninject<T, There<I>> for AxumEither<L, R>
+where
+    R: Uninject<T, I>,
+{
+    type Remainder = AxumEither<L, R::Remainder>;
+
+    fn uninject(self) -> Result<T, Self::Remainder> {
+        match self {
+            Self::Left(l) => Err(AxumEither::Left(l)),
+            Self::Right(r) => r.uninject().map_err(AxumEither::Right),
+        }
+    }
+}
+
+/// Convert a chain into a chain containing all of its types
+///
+/// The left side of every either is a single type, the chain is split until the last type. A
+/// chain of two types therefore never is ambiguous, even if it equals the end of `Target`.
+pub trait Embed<Target, I> {
+    /// Move the value into the variant of `Target` with the same type
+    fn embed(self) -> Target;
+}
+
+impl<L, R, Target, IL, IR> Embed<Target, (IL, IR)> for AxumEither<L, R>
+where
+    Target: Inject<L, IL>,
+    R: EmbedTail<Target, IR>,
+{
+    fn embed(self) -> Target {
+        match self {
+            Self::Left(l) => Target::inject(l),
+            Self::Right(r) => r.embed_tail(),
+        }
+    }
+}
+
+/// The right side of a chain for [`Embed`], the last type or the rest of the chain.
+///
+/// The rest of a chain with three or more types which equals the end of `Target` can be moved
+/// as a whole or split, name the index in that case.
+pub trait EmbedTail<Target, I> {
+    /// Move the value into the variant of `Target` with the same type
+    fn embed_tail(self) -> Target;
+}
+
+impl<T, Target, I> EmbedTail<Target, Leaf<I>> for T
+where
+    Target: Inject<T, I>,
+{
+    fn embed_tail(self) -> Target {
+        Target::inject(self)
+    }
+}
+
+impl<L, R, Target, IL, IR> EmbedTail<Target, (IL, IR)> for AxumEither<L, R>
+where
+    Self: Embed<Target, (IL, IR)>,
+{
+    fn embed_tail(self) -> Target {
+        self.embed()
+    }
+}
+
+/// Convert a chain into a chain containing some of its types
+pub trait Sculpt<Target, I> {
+    /// The chain of the types missing from `Target`
+    type Remainder;
+
+    /// The value moved into `Target` if its type is part of it, otherwise the remainder
+    fn sculpt(self) -> Result<Target, Self::Remainder>;
+}
+
+impl<S, T, I> Sculpt<T, Leaf<I>> for S
+where
+    S: Uninject<T, I>,
+{
+    type Remainder = S::Remainder;
+
+    fn sculpt(self) -> Result<T, Self::Remainder> {
+        self.uninject()
+    }
+}
+
+impl<S, T0, TRest, I0, IRest> Sculpt<AxumEither<T0, TRest>, (I0, IRest)> for S
+where
+    S: Uninject<T0, I0>,
+    S::Remainder: Sculpt<TRest, IRest>,
+{
+    type Remainder = <S::Remainder as Sculpt<TRest, IRest>>::Remainder;
+
+    fn sculpt(self) -> Result<AxumEither<T0, TRest>, Self::Remainder> {
+        match self.uninject() {
+            Ok(t0) => Ok(AxumEither::Left(t0)),
+            Err(remainder) => remainder.sculpt().map(AxumEither::Right),
+        }
+    }
+}
+
+/// Reduce a chain to a single value, every type of the chain converts into `T`
+///
+/// Generic code can accept chains of any length with `impl FoldOneOf<T, I>`.
+///
+/// A nested [`AxumEither`] is folded through its sides, so chains built by hand and with
+/// [`one_of`](crate::one_of) fold the same way. If a nested either is meant as a single value
+/// which converts into `T` as a whole, both rules apply and the index can't be inferred. Name the
+/// index then, [`Here`] converts the either with its `Into<T>` implementation.
+///
+/// # Examples
+/// ```
+/// use axum_either::{convert::FoldOneOf, prelude::*};
+///
+/// fn describe<I>(chain: impl FoldOneOf<String, I>) -> String {
+///     chain.fold_into()
+/// }
+///
+/// let chain: axum_either::one_of!(String, &str, char) = AxumEither::Right(AxumEither::Right('c'));
+/// assert_eq!(describe(chain), "c");
+/// ```
+pub trait FoldOneOf<T, I> {
+    /// Convert the value of the active variant into `T`
+    fn fold_into(self) -> T;
+}
+
+impl<T, X> FoldOneOf<T, Here> for X
+where
+    X: Into<T>,
+{
+    fn fold_into(self) -> T {
+        self.into()
+    }
+}
+
+impl<T, L, R, IL, IR> FoldOneOf<T, (IL, IR)> for AxumEither<L, R>
+where
+    L: FoldOneOf<T, IL>,
+    R: FoldOneOf<T, IR>,
+{
+    fn fold_into(self) -> T {
+        match self {
+            Self::Left(l) => l.fold_into(),
+            Self::Right(r) => r.fold_into(),
+        }
+    }
+}
+
+impl<L, R> AxumEither<L, R> {
+    /// Construct the chain from the value of one of its types, the variant is selected by the
+    /// type of `value`.
+    ///
+    /// This is the trait-level counterpart to writing the nested constructors by hand, generic
+    /// code only needs to know the chain type and the value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::prelude::*;
+    /// type Chain = axum_either::one_of!(u8, u16, u32);
+    /// assert_eq!(Chain::inject(2u16), AxumEither::Right(AxumEither::Left(2)));
+    /// ```
+    ///
+    /// Types which are not part of the chain do not compile
+    /// ```compile_fail
+    /// # use axum_either::prelude::*;
+    /// type Chain = axum_either::one_of!(u8, u16, u32);
+    /// let _ = Chain::inject(2u64);
+    /// ```
+    ///
+    /// Neither do types which appear multiple times
+    /// ```compile_fail
+    /// # use axum_either::prelude::*;
+    /// type Chain = axum_either::one_of!(u8, u16, u8);
+    /// let _ = Chain::inject(2u8);
+    /// ```
+    pub fn inject<T, I>(value: T) -> Self
+    where
+        Self: Inject<T, I>,
+    {
+        <Self as Inject<T, I>>::inject(value)
+    }
+
+    /// Convert the value of the active variant into `T`, see [`FoldOneOf`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::prelude::*;
+    /// let chain: axum_either::one_of!(u8, u16, u32) = AxumEither::Right(AxumEither::Left(2));
+    /// assert_eq!(chain.fold_into::<u64, _>(), 2);
+    /// ```
+    pub fn fold_into<T, I>(self) -> T
+    where
+        Self: FoldOneOf<T, I>,
+    {
+        <Self as FoldOneOf<T, I>>::fold_into(self)
+    }
+
+    /// Convert into a chain containing all types of this chain, see the [module](self)
+    /// documentation.
+    pub fn widen<Target, I>(self) -> Target
+    where
+        Self: Embed<Target, I>,
+    {
+        self.embed()
+    }
+
+    /// Convert into a chain containing some of the types of this chain, see the [module](self)
+    /// documentation.
+    ///
+    /// If the active variant has a type which is not part of `Target` the value is returned in
+    /// a chain of the missing types.
+    pub fn narrow<Target, I>(self) -> Result<Target, <Self as Sculpt<Target, I>>::Remainder>
+    where
+        Self: Sculpt<Target, I>,
+    {
+        self.sculpt()
+    }
+}
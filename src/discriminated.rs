@@ -0,0 +1,311 @@
+//! Choose the payload type of a json body by a discriminant instead of trying both types.
+
+use axum_core::{
+    extract::{FromRequest, RequestParts},
+    response::{IntoResponse, Response},
+    BoxError,
+};
+use http::StatusCode;
+use http_body::Body;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+use crate::{
+    json_body::{from_value, json_value, JsonBodyRejection, JsonPathError},
+    AxumEither, RejectionInfo, VariantTag,
+};
+
+/// Selects the side of a [`DiscriminatedJson`] from the parsed body.
+///
+/// # Examples
+/// ```
+/// use axum_either::{discriminated::Discriminator, VariantTag};
+/// use serde_json::Value;
+///
+/// /// Bodies with a `"legacy": true` field use the left type
+/// pub struct Legacy;
+///
+/// impl Discriminator for Legacy {
+///     fn discriminate(body: &Value) -> Option<VariantTag> {
+///         match body.get("legacy") {
+///             Some(Value::Bool(true)) => Some(VariantTag::Left),
+///             _ => Some(VariantTag::Right),
+///         }
+///     }
+/// }
+/// ```
+pub trait Discriminator {
+    /// The side the body belongs to, `None` if it can not be decided
+    fn discriminate(body: &Value) -> Option<VariantTag>;
+}
+
+/// Reads the `"version"` field of the body, `LEFT` selects the left and `RIGHT` the right type.
+///
+/// The version may be a number or a string containing a number. Other versions and bodies
+/// without the field can not be discriminated.
+#[derive(Debug, Clone, Copy, Hash, Default, PartialEq, PartialOrd, Eq, Ord)]
+pub struct Version<const LEFT: u64 = 1, const RIGHT: u64 = 2>;
+
+impl<const LEFT: u64, const RIGHT: u64> Discriminator for Version<LEFT, RIGHT> {
+    fn discriminate(body: &Value) -> Option<VariantTag> {
+        let version = match body.get("version")? {
+            Value::Number(version) => version.as_u64()?,
+            Value::String(version) => version.parse().ok()?,
+            _ => return None,
+        };
+        if version == LEFT {
+            Some(VariantTag::Left)
+        } else if version == RIGHT {
+            Some(VariantTag::Right)
+        } else {
+            None
+        }
+    }
+}
+
+/// Extracts a json body as `A` or `B`, the [`Discriminator`] `D` decides which type is used.
+///
+/// `AxumEither<Json<A>, Json<B>>` picks `A` for every body which is valid for both types. This
+/// extractor parses the body once, asks `D` for the side and only deserializes that type, so a
+/// body is never silently read as the wrong version. By default the `"version"` field selects
+/// the type, `1` for `A` and `2` for `B`.
+///
+/// # Examples
+/// ```
+/// use axum_either::{AxumEither, DiscriminatedJson};
+///
+/// #[derive(serde::Deserialize)]
+/// pub struct UserV1 {
+///     name: String,
+/// }
+///
+/// #[derive(serde::Deserialize)]
+/// pub struct UserV2 {
+///     name: String,
+///     email: Option<String>,
+/// }
+///
+/// pub async fn create(user: DiscriminatedJson<UserV1, UserV2>) -> String {
+///     match user.into_inner() {
+///         AxumEither::Left(user) => format!("v1 {}", user.name),
+///         AxumEither::Right(user) => format!("v2 {} {:?}", user.name, user.email),
+///     }
+/// }
+/// ```
+pub struct DiscriminatedJson<A, B, D = Version> {
+    either: AxumEither<A, B>,
+    discriminator: PhantomData<fn() -> D>,
+}
+
+impl<A, B, D> DiscriminatedJson<A, B, D> {
+    /// Wrap an already extracted payload
+    pub fn new(either: AxumEither<A, B>) -> Self {
+        Self {
+            either,
+            discriminator: PhantomData,
+        }
+    }
+
+    /// The extracted payload
+    pub fn into_inner(self) -> AxumEither<A, B> {
+        self.either
+    }
+}
+
+impl<A, B, D> Deref for DiscriminatedJson<A, B, D> {
+    type Target = AxumEither<A, B>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.either
+    }
+}
+
+impl<A, B, D> DerefMut for DiscriminatedJson<A, B, D> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.either
+    }
+}
+
+// The traits are implemented by hand, derives would bound the discriminator `D` as well
+
+impl<A, B, D> fmt::Debug for DiscriminatedJson<A, B, D>
+where
+    A: fmt::Debug,
+    B: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DiscriminatedJson")
+            .field(&self.either)
+            .finish()
+    }
+}
+
+impl<A, B, D> Clone for DiscriminatedJson<A, B, D>
+where
+    A: Clone,
+    B: Clone,
+{
+    fn clone(&self) -> Self {
+        Self::new(self.either.clone())
+    }
+}
+
+impl<A, B, D> Copy for DiscriminatedJson<A, B, D>
+where
+    A: Copy,
+    B: Copy,
+{
+}
+
+impl<A, B, D> Hash for DiscriminatedJson<A, B, D>
+where
+    A: Hash,
+    B: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.either.hash(state)
+    }
+}
+
+impl<A, B, D> PartialEq for DiscriminatedJson<A, B, D>
+where
+    A: PartialEq,
+    B: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.either == other.either
+    }
+}
+
+impl<A, B, D> Eq for DiscriminatedJson<A, B, D>
+where
+    A: Eq,
+    B: Eq,
+{
+}
+
+impl<A, B, D> PartialOrd for DiscriminatedJson<A, B, D>
+where
+    A: PartialOrd,
+    B: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.either.partial_cmp(&other.either)
+    }
+}
+
+impl<A, B, D> Ord for DiscriminatedJson<A, B, D>
+where
+    A: Ord,
+    B: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.either.cmp(&other.either)
+    }
+}
+
+#[async_trait::async_trait]
+impl<A, B, D, Bd> FromRequest<Bd> for DiscriminatedJson<A, B, D>
+where
+    A: DeserializeOwned,
+    B: DeserializeOwned,
+    D: Discriminator,
+    Bd: Body + Send,
+    Bd::Data: Send,
+    Bd::Error: Into<BoxError>,
+{
+    type Rejection = DiscriminatedJsonRejection;
+
+    async fn from_request(req: &mut RequestParts<Bd>) -> Result<Self, Self::Rejection> {
+        let value = json_value(req)
+            .await
+            .map_err(DiscriminatedJsonRejection::Json)?;
+        let side =
+            D::discriminate(&value).ok_or(DiscriminatedJsonRejection::MissingDiscriminant)?;
+        let either = match side {
+            VariantTag::Left => from_value(&value).map(AxumEither::Left),
+            VariantTag::Right => from_value(&value).map(AxumEither::Right),
+        };
+        either
+            .map(Self::new)
+            .map_err(|error| DiscriminatedJsonRejection::Deserialize { side, error })
+    }
+}
+
+/// The rejection of [`DiscriminatedJson`]
+#[derive(Debug)]
+pub enum DiscriminatedJsonRejection {
+    /// The body is not a json document
+    Json(JsonBodyRejection),
+    /// The discriminator could not decide the type of the body
+    MissingDiscriminant,
+    /// The body does not match the type selected by the discriminator
+    Deserialize {
+        /// The selected side
+        side: VariantTag,
+        /// The error of the deserializer, with the path of the failing field
+        error: JsonPathError,
+    },
+}
+
+impl fmt::Display for DiscriminatedJsonRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Json(rejection) => write!(f, "{}", rejection),
+            Self::MissingDiscriminant => {
+                f.write_str("The request body does not name one of the supported versions")
+            }
+            Self::Deserialize { side, error } => write!(
+                f,
+                "Failed to deserialize the {} version of the request body: {}",
+                match side {
+                    VariantTag::Left => "left",
+                    VariantTag::Right => "right",
+                },
+                error
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DiscriminatedJsonRejection {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Json(rejection) => Some(rejection),
+            Self::MissingDiscriminant => None,
+            Self::Deserialize { error, .. } => Some(error),
+        }
+    }
+}
+
+impl RejectionInfo for DiscriminatedJsonRejection {
+    fn status(&self) -> StatusCode {
+        match self {
+            Self::Json(rejection) => rejection.status(),
+            Self::MissingDiscriminant | Self::Deserialize { .. } => {
+                StatusCode::UNPROCESSABLE_ENTITY
+            }
+        }
+    }
+
+    fn message(&self) -> String {
+        self.to_string()
+    }
+
+    fn source_error(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        std::error::Error::source(self)
+    }
+}
+
+impl IntoResponse for DiscriminatedJsonRejection {
+    fn into_response(self) -> Response {
+        (self.status(), self.to_string()).into_response()
+    }
+}
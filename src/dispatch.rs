@@ -0,0 +1,256 @@
+//! Extract the variant claiming the content type of a request, without reading the body first.
+
+use axum_core::{
+    extract::{rejection::BytesRejection, FromRequest, RequestParts},
+    response::{IntoResponse, Response},
+    BoxError,
+};
+use bytes::Bytes;
+use http::{HeaderMap, StatusCode};
+use http_body::Body;
+use std::ops::{Deref, DerefMut};
+
+use crate::{media, AttemptRef, AxumEither, MediaTypeDispatch, RejectionInfo, SupportedMediaTypes};
+
+/// Extractors whose flattened variants can be extracted by index, see [`Dispatch`].
+///
+/// This is implemented for every [`SupportedMediaTypes`] extractor and for [`AxumEither`] of
+/// them.
+#[async_trait::async_trait]
+pub trait DispatchVariant<B: Send>: MediaTypeDispatch + Sized {
+    /// The rejection of a single variant, nested like the chain
+    type VariantRejection: IntoResponse + Send;
+
+    /// Extract the variant at the flattened `index`
+    async fn from_request_variant(
+        req: &mut RequestParts<B>,
+        index: usize,
+    ) -> Result<Self, Self::VariantRejection>;
+}
+
+#[async_trait::async_trait]
+impl<T, B> DispatchVariant<B> for T
+where
+    T: SupportedMediaTypes + FromRequest<B>,
+    T::Rejection: Send,
+    B: Send,
+{
+    type VariantRejection = T::Rejection;
+
+    async fn from_request_variant(
+        req: &mut RequestParts<B>,
+        _index: usize,
+    ) -> Result<Self, Self::VariantRejection> {
+        T::from_request(req).await
+    }
+}
+
+#[async_trait::async_trait]
+impl<L, R, B> DispatchVariant<B> for AxumEither<L, R>
+where
+    L: DispatchVariant<B> + Send,
+    R: DispatchVariant<B> + Send,
+    B: Send,
+{
+    type VariantRejection = AxumEither<L::VariantRejection, R::VariantRejection>;
+
+    async fn from_request_variant(
+        req: &mut RequestParts<B>,
+        index: usize,
+    ) -> Result<Self, Self::VariantRejection> {
+        if index < L::VARIANTS {
+            L::from_request_variant(req, index)
+                .await
+                .map(Self::Left)
+                .map_err(AxumEither::Left)
+        } else {
+            R::from_request_variant(req, index - L::VARIANTS)
+                .await
+                .map(Self::Right)
+                .map_err(AxumEither::Right)
+        }
+    }
+}
+
+/// Extracts the variant of `T` which claims the `Content-Type` of the request.
+///
+/// The content type is checked before the body is read:
+///
+/// - If no variant claims it the request is rejected with `415 Unsupported Media Type`, the body
+///   is never read.
+/// - If exactly one variant claims it the body is passed to that extractor without buffering.
+/// - If multiple variants claim it, or the request has no valid content type, the body is buffered
+///   once into a single [`Bytes`] and every claiming variant is tried in order with a clone of it,
+///   the payload is never copied. The last claiming variant receives the buffer itself, so a
+///   trailing [`Text`](crate::Text) turns it into its string without copying.
+///
+/// The claims are computed with [`MediaTypeDispatch`]. Dispatched extractions do not notify the
+/// observer, metrics and tracing hooks of [`AxumEither`].
+///
+/// # Examples
+/// ```
+/// use axum::{Form, Json};
+/// use axum_either::{prelude::*, Dispatch};
+///
+/// #[derive(serde::Deserialize)]
+/// pub struct Upload {
+///     name: String,
+/// }
+///
+/// pub async fn upload(
+///     Dispatch(upload): Dispatch<AxumEither<Json<Upload>, Form<Upload>>>,
+/// ) -> String {
+///     upload.map_lr(|Json(u)| u.name, |Form(u)| u.name).into_inner()
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Hash, Default, PartialEq, PartialOrd, Eq, Ord)]
+pub struct Dispatch<T>(pub T);
+
+impl<T> Dispatch<T> {
+    /// Extract the wrapped value
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Dispatch<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Dispatch<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[async_trait::async_trait]
+impl<T, B> FromRequest<B> for Dispatch<T>
+where
+    T: DispatchVariant<B> + Send,
+    B: Body + From<Bytes> + Send,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+{
+    type Rejection = DispatchRejection<T::VariantRejection>;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        let claims = match media::content_type(req.headers()) {
+            Some(content_type) => T::claims(&content_type),
+            None => (0..T::VARIANTS).collect(),
+        };
+
+        match claims.len() {
+            0 => Err(DispatchRejection::UnsupportedMediaType),
+            1 => T::from_request_variant(req, claims[0])
+                .await
+                .map(Self)
+                .map_err(|rejection| DispatchRejection::Claimed(vec![rejection])),
+            _ => {
+                let mut bytes = Bytes::from_request(req)
+                    .await
+                    .map_err(DispatchRejection::Body)?;
+                let mut rejections = Vec::with_capacity(claims.len());
+                let last = claims.len() - 1;
+                for (i, index) in claims.into_iter().enumerate() {
+                    // The last claiming variant owns the buffer, the others receive clones
+                    let body = if i < last {
+                        bytes.clone()
+                    } else {
+                        std::mem::take(&mut bytes)
+                    };
+                    *req.body_mut() = Some(B::from(body));
+                    match T::from_request_variant(req, index).await {
+                        Ok(value) => return Ok(Self(value)),
+                        Err(rejection) => rejections.push(rejection),
+                    }
+                }
+                Err(DispatchRejection::Claimed(rejections))
+            }
+        }
+    }
+}
+
+/// The rejection of [`Dispatch`]
+#[derive(Debug)]
+pub enum DispatchRejection<R> {
+    /// No variant claims the content type of the request
+    UnsupportedMediaType,
+    /// The body could not be buffered for multiple claiming variants
+    Body(BytesRejection),
+    /// Every claiming variant rejected the request, in the order they were tried. This is never
+    /// empty.
+    Claimed(Vec<R>),
+}
+
+impl<R> IntoResponse for DispatchRejection<R>
+where
+    R: IntoResponse,
+{
+    /// The response of the first claiming variant, it is the one the request is most likely
+    /// meant for
+    fn into_response(self) -> Response {
+        match self {
+            Self::UnsupportedMediaType => (
+                StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                "Expected a supported content type",
+            )
+                .into_response(),
+            Self::Body(rejection) => rejection.into_response(),
+            Self::Claimed(rejections) => match rejections.into_iter().next() {
+                Some(rejection) => rejection.into_response(),
+                None => StatusCode::BAD_REQUEST.into_response(),
+            },
+        }
+    }
+}
+
+impl<R> RejectionInfo for DispatchRejection<R>
+where
+    R: RejectionInfo,
+{
+    fn status(&self) -> StatusCode {
+        match self {
+            Self::UnsupportedMediaType => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            Self::Body(rejection) => rejection.status(),
+            Self::Claimed(rejections) => rejections
+                .first()
+                .map_or(StatusCode::BAD_REQUEST, RejectionInfo::status),
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            Self::UnsupportedMediaType => "Expected a supported content type".into(),
+            Self::Body(rejection) => rejection.message(),
+            Self::Claimed(rejections) => rejections
+                .iter()
+                .map(RejectionInfo::message)
+                .collect::<Vec<_>>()
+                .join("\n"),
+        }
+    }
+
+    fn headers(&self) -> HeaderMap {
+        match self {
+            Self::UnsupportedMediaType => crate::info::plain_text_headers(),
+            Self::Body(rejection) => rejection.headers(),
+            Self::Claimed(rejections) => rejections
+                .first()
+                .map_or_else(HeaderMap::new, RejectionInfo::headers),
+        }
+    }
+
+    fn visit_attempts<'a>(&'a self, f: &mut dyn FnMut(AttemptRef<'a>)) {
+        match self {
+            Self::UnsupportedMediaType => f(AttemptRef::new(self)),
+            Self::Body(rejection) => rejection.visit_attempts(f),
+            Self::Claimed(rejections) => rejections
+                .iter()
+                .for_each(|rejection| rejection.visit_attempts(f)),
+        }
+    }
+}
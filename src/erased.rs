@@ -0,0 +1,176 @@
+//! A rejection with the error types erased, so rejections of different routes share one type.
+
+use axum_core::response::{IntoResponse, Response};
+use http::StatusCode;
+use std::{
+    fmt::{self, Write},
+    sync::Arc,
+};
+
+use crate::{
+    rejection::{Capped, DEFAULT_MESSAGE_LIMIT},
+    AttemptRef, Attempts, AxumEitherRejection, FormattedRejection, RejectionFormatter,
+    RejectionInfo, Rejections,
+};
+
+/// An [`AxumEitherRejection`] without its error types.
+///
+/// Every route has its own `AxumEitherRejection<LE, RE>`, which makes it impossible to handle
+/// them with one function or to store them in an error type of the application. This rejection
+/// is `Send + Sync + 'static` and can be created from every `AxumEitherRejection` with
+/// [`From`], which makes it usable with `?`.
+///
+/// The status is computed with the [`RejectionFormatter`] of the original rejection when it is
+/// boxed. The original rejection is kept behind a trait object, so the messages of the failed
+/// extractions are only rendered when [`attempts`](Self::attempts) are inspected, by
+/// [`Display`](fmt::Display) and by the response, which responds with the status and the message
+/// as plain text.
+///
+/// # Examples
+/// ```
+/// use axum_either::{AxumEitherRejection, BoxedEitherRejection};
+/// use http::StatusCode;
+///
+/// type MediaRejection = AxumEitherRejection<StatusCode, (StatusCode, &'static str)>;
+///
+/// fn validate(result: Result<u32, MediaRejection>) -> Result<u32, BoxedEitherRejection> {
+///     Ok(result?)
+/// }
+///
+/// let rejection = AxumEitherRejection::new(
+///     StatusCode::UNSUPPORTED_MEDIA_TYPE,
+///     (StatusCode::BAD_REQUEST, "Invalid form"),
+/// );
+/// let boxed = validate(Err(rejection)).unwrap_err();
+/// assert_eq!(boxed.status(), StatusCode::BAD_REQUEST);
+/// assert_eq!(boxed.attempts().nth(1).unwrap().message(), "Invalid form");
+/// ```
+#[derive(Clone)]
+pub struct BoxedEitherRejection {
+    status: StatusCode,
+    rejection: Arc<dyn ErasedRejection>,
+}
+
+impl BoxedEitherRejection {
+    /// The status of the original rejection
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// The failed extractions, nested rejections are flattened. The messages are rendered when
+    /// they are requested.
+    pub fn attempts(&self) -> Attempts<'_> {
+        Attempts::from_info(self)
+    }
+
+    /// Render the failed extractions into owned [`Attempt`](crate::Attempt)s
+    pub fn into_attempts(self) -> Rejections {
+        Rejections::from_info(&self)
+    }
+}
+
+impl fmt::Debug for BoxedEitherRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BoxedEitherRejection")
+            .field("status", &self.status)
+            .field("attempts", &self.attempts().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+/// The original rejection of a [`BoxedEitherRejection`].
+///
+/// [`RejectionInfo::visit_attempts`] can not be called on trait objects, this forwards to it.
+trait ErasedRejection: Send + Sync {
+    fn visit<'a>(&'a self, f: &mut dyn FnMut(AttemptRef<'a>));
+}
+
+impl<LE, RE> ErasedRejection for AxumEitherRejection<LE, RE>
+where
+    LE: RejectionInfo + Send + Sync,
+    RE: RejectionInfo + Send + Sync,
+{
+    fn visit<'a>(&'a self, f: &mut dyn FnMut(AttemptRef<'a>)) {
+        self.visit_attempts(f)
+    }
+}
+
+impl<LE, RE> From<AxumEitherRejection<LE, RE>> for BoxedEitherRejection
+where
+    LE: RejectionInfo + Send + Sync + 'static,
+    RE: RejectionInfo + Send + Sync + 'static,
+{
+    fn from(rejection: AxumEitherRejection<LE, RE>) -> Self {
+        Self {
+            status: rejection.status(),
+            rejection: Arc::new(rejection),
+        }
+    }
+}
+
+/// The status is selected by the formatter `F`
+impl<LE, RE, F> From<FormattedRejection<LE, RE, F>> for BoxedEitherRejection
+where
+    LE: RejectionInfo + Send + Sync + 'static,
+    RE: RejectionInfo + Send + Sync + 'static,
+    F: RejectionFormatter,
+{
+    fn from(rejection: FormattedRejection<LE, RE, F>) -> Self {
+        Self {
+            status: rejection.status(),
+            rejection: Arc::new(rejection.into_inner()),
+        }
+    }
+}
+
+impl fmt::Display for BoxedEitherRejection {
+    /// The messages of all attempts, truncated to at most [`DEFAULT_MESSAGE_LIMIT`] bytes.
+    ///
+    /// Every attempt gets an equal share of the limit, the space left by short messages is
+    /// given to the following attempts.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const PREFIX: &str = "Could not parse request";
+
+        f.write_str(PREFIX)?;
+        let attempts = self.attempts();
+        let mut remaining = DEFAULT_MESSAGE_LIMIT.saturating_sub(PREFIX.len());
+        let count = attempts.len();
+        for (i, attempt) in attempts.enumerate() {
+            let mut capped = Capped::new(f, remaining / (count - i));
+            write!(
+                capped,
+                ", {} error: {}",
+                attempt.name(),
+                attempt.display_message()
+            )?;
+            remaining -= capped.written();
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for BoxedEitherRejection {}
+
+impl RejectionInfo for BoxedEitherRejection {
+    fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    fn message(&self) -> String {
+        self.to_string()
+    }
+
+    fn write_message(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        write!(out, "{}", self)
+    }
+
+    fn visit_attempts<'a>(&'a self, f: &mut dyn FnMut(AttemptRef<'a>)) {
+        self.rejection.visit(f)
+    }
+}
+
+impl IntoResponse for BoxedEitherRejection {
+    fn into_response(self) -> Response {
+        (self.status, self.to_string()).into_response()
+    }
+}
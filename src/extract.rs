@@ -0,0 +1,227 @@
+//! Extract one of multiple types from a request.
+//!
+//! [`AxumEither`] tries its sides from left to right, [`WithFormatter`] selects how its
+//! rejection is rendered. The other extractors of this crate are re-exported here.
+//!
+//! # Handling rejections inline
+//! Like every extractor, [`AxumEither`] can be wrapped in the `Option` and `Result` extractors of
+//! axum:
+//!
+//! - `Result<AxumEither<L, R>, AxumEitherRejection<L::Rejection, R::Rejection>>` gives the
+//!   combined rejection of both sides to the handler, nothing is rendered until the handler
+//!   responds with it.
+//! - `Option<AxumEither<L, R>>` is `None` if both sides were rejected, the rejections are
+//!   discarded. A missing body is not treated differently from a malformed one.
+//!
+//! ```
+//! use axum::{
+//!     extract::rejection::{FormRejection, JsonRejection},
+//!     http::StatusCode,
+//!     response::IntoResponse,
+//!     Form, Json,
+//! };
+//! use axum_either::{AxumEither, AxumEitherRejection};
+//!
+//! pub async fn inline(
+//!     request: Result<
+//!         AxumEither<Json<u32>, Form<u32>>,
+//!         AxumEitherRejection<JsonRejection, FormRejection>,
+//!     >,
+//! ) -> Result<String, StatusCode> {
+//!     match request {
+//!         Ok(either) => Ok(format!("{:?}", either.map_lr(|j| j.0, |f| f.0))),
+//!         Err(rejection) => Err(rejection.into_response().status()),
+//!     }
+//! }
+//!
+//! pub async fn optional(request: Option<AxumEither<Json<u32>, Form<u32>>>) -> &'static str {
+//!     match request {
+//!         Some(AxumEither::Left(_)) => "json",
+//!         Some(AxumEither::Right(_)) => "form",
+//!         None => "neither",
+//!     }
+//! }
+//! ```
+//!
+//! # Body types
+//! [`AxumEither<L, R>`] extracts from `RequestParts<B>` whenever both sides do, it adds no bounds
+//! on `B` besides `Send`. Extractors which only read the request parts, like `HeaderMap`,
+//! `Query` or `Extension`, are implemented for every `B: Send`, so they combine with a body
+//! extractor under any body type that extractor accepts. This includes bodies changed by
+//! middleware, e.g. `http_body::Limited<Body>`.
+//!
+//! axum-core 0.2 has a single `FromRequest<B>` trait, a parts-only extractor written against a
+//! concrete body type constrains the either to that type. Keep custom parts-only extractors
+//! generic over `B` to avoid this.
+//!
+//! ```
+//! use axum::{body::Body, extract::{FromRequest, Query}, http::HeaderMap, Json};
+//! use axum_either::AxumEither;
+//! use http_body::Limited;
+//!
+//! fn assert_extractor<T: FromRequest<B>, B>() {}
+//!
+//! type Either = AxumEither<Query<Vec<(String, String)>>, Json<u32>>;
+//! assert_extractor::<Either, Body>();
+//! assert_extractor::<Either, Limited<Body>>();
+//! assert_extractor::<AxumEither<HeaderMap, String>, Limited<Body>>();
+//! ```
+
+use axum_core::extract::{FromRequest, RequestParts};
+use std::{
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+#[cfg(feature = "track")]
+use crate::track;
+use crate::{
+    AxumEither, AxumEitherRejection, FormattedRejection, RejectionFormatter, SingleRejection,
+};
+
+#[cfg(feature = "axum-extra")]
+pub use crate::auth::AuthEither;
+#[cfg(feature = "discriminated-json")]
+pub use crate::discriminated::DiscriminatedJson;
+#[cfg(feature = "any-format")]
+pub use crate::format::AnyFormat;
+#[cfg(feature = "cbor")]
+pub use crate::format::Cbor;
+#[cfg(feature = "msgpack")]
+pub use crate::format::MsgPack;
+#[cfg(feature = "json-one-of")]
+pub use crate::json_one_of::JsonOneOf;
+#[cfg(feature = "negotiation")]
+pub use crate::negotiate::{Accept, AcceptLanguage};
+#[cfg(feature = "axum")]
+pub use crate::path::PathEither;
+#[cfg(feature = "qs-form")]
+pub use crate::qs::QsForm;
+#[cfg(feature = "validation")]
+pub use crate::validate::ValidatedEither;
+pub use crate::{
+    bodyless::BodyEither,
+    catch_panic::{CatchPanic, CatchPanicEither},
+    dispatch::{Dispatch, DispatchVariant},
+    fallback::Fallback,
+    first_ok::{Alternatives, FirstOk},
+    lazy::WithLazyRejection,
+    limit::Limited,
+    media::WithContentType,
+};
+
+#[async_trait::async_trait]
+impl<L, R, B> FromRequest<B> for AxumEither<L, R>
+where
+    L: FromRequest<B>,
+    L::Rejection: Send,
+    R: FromRequest<B>,
+    B: Send,
+{
+    type Rejection = AxumEitherRejection<L::Rejection, R::Rejection>;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        #[cfg(feature = "track")]
+        let depth = track::enter(req);
+
+        let left_error = match L::from_request(req).await {
+            Ok(l) => {
+                #[cfg(feature = "track")]
+                track::matched(req, depth, std::any::type_name::<L>());
+                return Ok(Self::Left(l));
+            }
+            Err(e) => e,
+        };
+
+        #[cfg(feature = "track")]
+        track::descend(req, depth);
+        let right = R::from_request(req).await;
+        #[cfg(feature = "track")]
+        track::ascend(req, depth);
+
+        let right_error = match right {
+            Ok(r) => {
+                #[cfg(feature = "track")]
+                track::matched(req, depth + 1, std::any::type_name::<R>());
+                return Ok(Self::Right(r));
+            }
+            Err(e) => e,
+        };
+
+        #[cfg(feature = "track")]
+        track::rejected(req, depth);
+        Err(AxumEitherRejection::new(left_error, right_error))
+    }
+}
+
+/// Extract `T` but render its rejection with the [`RejectionFormatter`] `F`.
+///
+/// # Examples
+/// ```
+/// use axum::{Json, Form};
+/// use axum_either::{AxumEither, SingleRejection, WithFormatter};
+///
+/// #[derive(serde::Deserialize)]
+/// pub struct Request(u32);
+///
+/// pub async fn form_or_json(
+///     request: WithFormatter<AxumEither<Json<Request>, Form<Request>>, SingleRejection>,
+/// ) -> String {
+///     match request.into_inner() {
+///         AxumEither::Left(Json(r)) => format!("Json {}", r.0),
+///         AxumEither::Right(Form(r)) => format!("Form {}", r.0),
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Hash, Default, PartialEq, PartialOrd, Eq, Ord)]
+pub struct WithFormatter<T, F>(pub T, pub PhantomData<F>);
+
+impl<T, F> WithFormatter<T, F> {
+    /// Wrap the given value
+    pub fn new(inner: T) -> Self {
+        Self(inner, PhantomData)
+    }
+
+    /// Extract the wrapped value
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T, F> Deref for WithFormatter<T, F> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, F> DerefMut for WithFormatter<T, F> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[async_trait::async_trait]
+impl<L, R, F, B> FromRequest<B> for WithFormatter<AxumEither<L, R>, F>
+where
+    L: FromRequest<B>,
+    L::Rejection: Send,
+    R: FromRequest<B>,
+    F: RejectionFormatter,
+    B: Send,
+{
+    type Rejection = FormattedRejection<L::Rejection, R::Rejection, F>;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        AxumEither::<L, R>::from_request(req)
+            .await
+            .map(Self::new)
+            .map_err(AxumEitherRejection::with_formatter)
+    }
+}
+
+/// Extract `T` and only respond with the error of the side which matched the request.
+///
+/// See [`SingleRejection`] for the rules used to select the reported error.
+pub type PreferLeftRejection<T> = WithFormatter<T, SingleRejection>;
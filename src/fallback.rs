@@ -0,0 +1,167 @@
+//! Substitute a default value for extractors which reject the request.
+
+use axum_core::{
+    extract::{FromRequest, RequestParts},
+    response::IntoResponse,
+};
+use http::StatusCode;
+#[cfg(feature = "axum")]
+use serde::de::DeserializeOwned;
+use std::{any, fmt::Display, marker::PhantomData};
+
+/// Extracts `T` or falls back to `T::default()` if `T` rejects the request, this never rejects.
+///
+/// Every error is ignored, including malformed payloads and failures to read the body. Only use
+/// this for payloads which are truly optional, the swallowed rejection is kept in the second
+/// field for logging.
+///
+/// By default `T` is an extractor like `Json<Filters>`. With a [`FallbackFormat`] other than
+/// [`FromExtractor`] `T` is the payload itself, for example `Fallback<Filters, FromQuery>`.
+///
+/// # Examples
+/// ```
+/// use axum::Json;
+/// use axum_either::Fallback;
+///
+/// #[derive(Default, serde::Deserialize)]
+/// pub struct Filters {
+///     limit: Option<u32>,
+/// }
+///
+/// pub async fn list(Fallback(Json(filters), rejection, _): Fallback<Json<Filters>>) -> String {
+///     match rejection {
+///         Some(rejection) => format!("Ignored the filters: {}", rejection.message),
+///         None => format!("{:?}", filters.limit),
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Hash, Default, PartialEq, Eq)]
+pub struct Fallback<T, F = FromExtractor>(
+    pub T,
+    pub Option<SwallowedRejection>,
+    pub PhantomData<F>,
+);
+
+impl<T, F> Fallback<T, F> {
+    /// The extracted or default value
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+/// How a [`Fallback`] extracts its value
+pub trait FallbackFormat<T> {
+    /// The extractor which is tried
+    type Extractor;
+
+    /// Take the value out of the extractor
+    fn into_value(extracted: Self::Extractor) -> T;
+}
+
+/// The value of the [`Fallback`] is an extractor itself
+#[derive(Debug, Clone, Copy, Hash, Default, PartialEq, PartialOrd, Eq, Ord)]
+pub struct FromExtractor;
+
+impl<T> FallbackFormat<T> for FromExtractor {
+    type Extractor = T;
+
+    fn into_value(extracted: T) -> T {
+        extracted
+    }
+}
+
+/// Implement [`FallbackFormat`] for the marker `$name` with the axum extractor `$extractor`
+#[cfg(feature = "axum")]
+macro_rules! serde_format {
+    ($($(#[$meta:meta])* $name:ident => $extractor:ident;)+) => {
+        $(
+            $(#[$meta])*
+            #[derive(Debug, Clone, Copy, Hash, Default, PartialEq, PartialOrd, Eq, Ord)]
+            pub struct $name;
+
+            impl<T: DeserializeOwned> FallbackFormat<T> for $name {
+                type Extractor = axum::extract::$extractor<T>;
+
+                fn into_value(extracted: Self::Extractor) -> T {
+                    extracted.0
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "axum")]
+serde_format! {
+    /// Deserialize the value of a [`Fallback`] from a json body.
+    ///
+    /// # Examples
+    /// ```
+    /// use axum_either::{Fallback, FromJson};
+    ///
+    /// #[derive(Default, serde::Deserialize)]
+    /// pub struct Settings {
+    ///     verbose: bool,
+    /// }
+    ///
+    /// pub async fn run(Fallback(settings, _, _): Fallback<Settings, FromJson>) -> String {
+    ///     settings.verbose.to_string()
+    /// }
+    /// ```
+    FromJson => Json;
+    /// Deserialize the value of a [`Fallback`] from a urlencoded form.
+    FromForm => Form;
+    /// Deserialize the value of a [`Fallback`] from the query string.
+    ///
+    /// # Examples
+    /// ```
+    /// use axum_either::{Fallback, FromQuery};
+    ///
+    /// #[derive(Default, serde::Deserialize)]
+    /// pub struct Filters {
+    ///     limit: Option<u32>,
+    /// }
+    ///
+    /// pub async fn list(Fallback(filters, _, _): Fallback<Filters, FromQuery>) -> String {
+    ///     format!("{:?}", filters.limit)
+    /// }
+    /// ```
+    FromQuery => Query;
+}
+
+/// A rejection replaced by the default value of a [`Fallback`]
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct SwallowedRejection {
+    /// The type name of the rejection
+    pub type_name: &'static str,
+    /// The status the rejection would have responded with
+    pub status: StatusCode,
+    /// The description of the rejection
+    pub message: String,
+}
+
+#[async_trait::async_trait]
+impl<T, F, B> FromRequest<B> for Fallback<T, F>
+where
+    T: Default + Send,
+    F: FallbackFormat<T> + Send,
+    F::Extractor: FromRequest<B>,
+    <F::Extractor as FromRequest<B>>::Rejection: Display,
+    B: Send,
+{
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        Ok(match F::Extractor::from_request(req).await {
+            Ok(extracted) => Self(F::into_value(extracted), None, PhantomData),
+            Err(rejection) => {
+                let message = rejection.to_string();
+                let swallowed = SwallowedRejection {
+                    type_name: any::type_name::<<F::Extractor as FromRequest<B>>::Rejection>(),
+                    status: rejection.into_response().status(),
+                    message,
+                };
+                Self(T::default(), Some(swallowed), PhantomData)
+            }
+        })
+    }
+}
@@ -0,0 +1,233 @@
+//! Try the extractors of a tuple in order, without nesting eithers.
+
+use axum_core::{
+    extract::{rejection::BytesRejection, FromRequest, RequestParts},
+    response::{IntoResponse, Response},
+    BoxError,
+};
+use bytes::Bytes;
+use http::{HeaderMap, StatusCode};
+use http_body::Body;
+
+use crate::{
+    AttemptRef, AxumEither, AxumEitherRejection, OneOf3, OneOf4, OneOf5, OneOf6, OneOf7, OneOf8,
+    RejectionInfo,
+};
+
+/// Tuples of extractors which can be tried by [`FirstOk`]
+pub trait Alternatives {
+    /// The extracted value, [`AxumEither`] for pairs and the [`OneOf3`] to [`OneOf8`] enums for
+    /// larger tuples
+    type Value;
+}
+
+/// Extracts the first element of the tuple `T` which accepts the request.
+///
+/// This is the tuple syntax of [`one_of`](crate::one_of), the body is buffered once and every
+/// extractor receives all of it, even if a previous extractor consumed it. If every extractor
+/// rejects the request all rejections are listed.
+///
+/// The body is buffered into a single [`Bytes`] and every extractor receives a clone of it, the
+/// payload is never copied. If the request body yields a single chunk, like the bodies of axum
+/// with a known length, that chunk is the buffer. The last extractor receives the buffer itself,
+/// so a trailing [`Text`](crate::Text) turns it into its string without copying. Extractors
+/// copying the body into owned data, like `String`, still copy it.
+///
+/// # Examples
+/// ```
+/// use axum::{extract::Query, Form, Json};
+/// use axum_either::{FirstOk, OneOf3};
+///
+/// #[derive(serde::Deserialize)]
+/// pub struct Search {
+///     term: String,
+/// }
+///
+/// pub async fn search(search: FirstOk<(Json<Search>, Form<Search>, Query<Search>)>) -> String {
+///     let term = match search.value {
+///         OneOf3::V0(Json(s)) => s.term,
+///         OneOf3::V1(Form(s)) => s.term,
+///         OneOf3::V2(Query(s)) => s.term,
+///     };
+///     format!("Found {} using extractor {}", term, search.index)
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct FirstOk<T>
+where
+    T: Alternatives,
+{
+    /// The index of the extractor which accepted the request
+    pub index: usize,
+    /// The extracted value
+    pub value: T::Value,
+}
+
+impl<T> FirstOk<T>
+where
+    T: Alternatives,
+{
+    /// The extracted value
+    pub fn into_inner(self) -> T::Value {
+        self.value
+    }
+}
+
+/// The body shared by the extractors of a [`FirstOk`]
+#[derive(Debug, Clone)]
+struct SharedBody {
+    bytes: Bytes,
+    attempts: usize,
+    /// The number of extractors, the last one receives `bytes` instead of a clone
+    extractors: usize,
+}
+
+/// Restores the shared body before extracting `T`
+struct Rebuffer<T>(T);
+
+#[async_trait::async_trait]
+impl<T, B> FromRequest<B> for Rebuffer<T>
+where
+    T: FromRequest<B>,
+    B: From<Bytes> + Send,
+{
+    type Rejection = T::Rejection;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        if let Some(shared) = req.extensions_mut().get_mut::<SharedBody>() {
+            shared.attempts += 1;
+            let bytes = if shared.attempts < shared.extractors {
+                shared.bytes.clone()
+            } else {
+                std::mem::take(&mut shared.bytes)
+            };
+            *req.body_mut() = Some(B::from(bytes));
+        }
+        T::from_request(req).await.map(Self)
+    }
+}
+
+/// The rejection of [`FirstOk`]
+#[derive(Debug)]
+pub enum FirstOkRejection<R> {
+    /// The body could not be buffered
+    Body(BytesRejection),
+    /// Every extractor rejected the request, the rejections are nested like a
+    /// [`one_of`](crate::one_of) chain
+    Attempts(R),
+}
+
+impl<R> IntoResponse for FirstOkRejection<R>
+where
+    R: IntoResponse,
+{
+    fn into_response(self) -> Response {
+        match self {
+            Self::Body(rejection) => rejection.into_response(),
+            Self::Attempts(rejection) => rejection.into_response(),
+        }
+    }
+}
+
+impl<R> RejectionInfo for FirstOkRejection<R>
+where
+    R: RejectionInfo,
+{
+    fn status(&self) -> StatusCode {
+        match self {
+            Self::Body(rejection) => rejection.status(),
+            Self::Attempts(rejection) => rejection.status(),
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            Self::Body(rejection) => rejection.message(),
+            Self::Attempts(rejection) => rejection.message(),
+        }
+    }
+
+    fn headers(&self) -> HeaderMap {
+        match self {
+            Self::Body(rejection) => rejection.headers(),
+            Self::Attempts(rejection) => rejection.headers(),
+        }
+    }
+
+    fn visit_attempts<'a>(&'a self, f: &mut dyn FnMut(AttemptRef<'a>)) {
+        match self {
+            Self::Body(rejection) => rejection.visit_attempts(f),
+            Self::Attempts(rejection) => rejection.visit_attempts(f),
+        }
+    }
+}
+
+/// The nested rejection of the extractors `$t` for the body `$b`
+macro_rules! rejections {
+    ($b:ident; $t0:ident, $t1:ident) => {
+        AxumEitherRejection<
+            <$t0 as FromRequest<$b>>::Rejection,
+            <$t1 as FromRequest<$b>>::Rejection,
+        >
+    };
+    ($b:ident; $t0:ident, $($t:ident),+) => {
+        AxumEitherRejection<<$t0 as FromRequest<$b>>::Rejection, rejections!($b; $($t),+)>
+    };
+}
+
+/// Implement [`FirstOk`] for the tuple of `$t`, the chain is converted into `$value` and its
+/// variants are unwrapped with the `$map` methods.
+macro_rules! first_ok {
+    ($value:ident { $($t:ident $map:ident),+ }) => {
+        impl<$($t),+> Alternatives for ($($t,)+) {
+            type Value = $value<$($t),+>;
+        }
+
+        #[async_trait::async_trait]
+        impl<$($t,)+ B> FromRequest<B> for FirstOk<($($t,)+)>
+        where
+            $($t: FromRequest<B>, $t::Rejection: Send,)+
+            B: Body + From<Bytes> + Send,
+            B::Data: Send,
+            B::Error: Into<BoxError>,
+        {
+            type Rejection = FirstOkRejection<rejections!(B; $($t),+)>;
+
+            async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+                let bytes = Bytes::from_request(req)
+                    .await
+                    .map_err(FirstOkRejection::Body)?;
+                req.extensions_mut().insert(SharedBody {
+                    bytes,
+                    attempts: 0,
+                    extractors: [$(stringify!($t)),+].len(),
+                });
+
+                let chain = <crate::one_of!($(Rebuffer<$t>),+)>::from_request(req).await;
+                let attempts = req
+                    .extensions_mut()
+                    .remove::<SharedBody>()
+                    .map_or(0, |shared| shared.attempts);
+
+                let value = $value::from(chain.map_err(FirstOkRejection::Attempts)?)
+                    $(.$map(|Rebuffer(v)| v))+;
+                Ok(Self {
+                    index: attempts.saturating_sub(1),
+                    value,
+                })
+            }
+        }
+    };
+}
+
+first_ok!(AxumEither { T0 map_left, T1 map_right });
+first_ok!(OneOf3 { T0 map_v0, T1 map_v1, T2 map_v2 });
+first_ok!(OneOf4 { T0 map_v0, T1 map_v1, T2 map_v2, T3 map_v3 });
+first_ok!(OneOf5 { T0 map_v0, T1 map_v1, T2 map_v2, T3 map_v3, T4 map_v4 });
+first_ok!(OneOf6 { T0 map_v0, T1 map_v1, T2 map_v2, T3 map_v3, T4 map_v4, T5 map_v5 });
+first_ok!(OneOf7 {
+    T0 map_v0, T1 map_v1, T2 map_v2, T3 map_v3, T4 map_v4, T5 map_v5, T6 map_v6
+});
+first_ok!(OneOf8 {
+    T0 map_v0, T1 map_v1, T2 map_v2, T3 map_v3, T4 map_v4, T5 map_v5, T6 map_v6, T7 map_v7
+});
@@ -0,0 +1,287 @@
+//! Flat enums equivalent to the chains built with [`one_of`](crate::one_of).
+
+use axum_core::{
+    extract::{FromRequest, RequestParts},
+    response::{IntoResponse, Response},
+};
+
+use crate::{AxumEither, AxumEitherRejection};
+
+/// Implement a `map_*` method for every variant of the enum `$name`.
+///
+/// The variants before the mapped one are accumulated in the brackets.
+macro_rules! map_variants {
+    ($name:ident [$($bv:ident($bt:ident)),*]) => {};
+    (
+        $name:ident [$($bv:ident($bt:ident)),*]
+        $v:ident($t:ident) $map:ident
+        $(, $av:ident($at:ident) $amap:ident)*
+    ) => {
+        impl<$($bt,)* $t $(, $at)*> $name<$($bt,)* $t $(, $at)*> {
+            #[doc = concat!("Maps the `", stringify!($v), "` value using the given function `f`.")]
+            pub fn $map<U>(self, f: impl FnOnce($t) -> U) -> $name<$($bt,)* U $(, $at)*> {
+                match self {
+                    $(Self::$bv(v) => $name::$bv(v),)*
+                    Self::$v(v) => $name::$v(f(v)),
+                    $(Self::$av(v) => $name::$av(v),)*
+                }
+            }
+        }
+
+        map_variants!($name [$($bv($bt),)* $v($t)] $($av($at) $amap),*);
+    };
+}
+
+/// Replace a type with `$sub`, used to repeat a type once per variant
+macro_rules! replace {
+    ($_t:ident, $sub:ty) => {
+        $sub
+    };
+}
+
+/// The rejection of the chain of the extractors `$t` for the body `$b`.
+macro_rules! chain_rejection {
+    ($b:ident; $t0:ident, $t1:ident) => {
+        AxumEitherRejection<
+            <$t0 as FromRequest<$b>>::Rejection,
+            <$t1 as FromRequest<$b>>::Rejection,
+        >
+    };
+    ($b:ident; $t0:ident, $($t:ident),+) => {
+        AxumEitherRejection<<$t0 as FromRequest<$b>>::Rejection, chain_rejection!($b; $($t),+)>
+    };
+}
+
+/// Nest the rejections `$e` the way the chain does.
+macro_rules! nest_rejections {
+    ($e0:expr, $e1:expr) => {
+        AxumEitherRejection::new($e0, $e1)
+    };
+    ($e0:expr, $($e:expr),+) => {
+        AxumEitherRejection::new($e0, nest_rejections!($($e),+))
+    };
+}
+
+/// Generate a flat enum and its conversions to the equivalent chain.
+///
+/// `$prev` is the enum of the remaining variants, the variants of the new enum except the first
+/// are mapped to the variant `$pv` of it.
+macro_rules! one_of_enum {
+    (
+        $(#[$attr:meta])*
+        $name:ident => $prev:ident {
+            $v0:ident($t0:ident) $map0:ident $f0:ident,
+            $($v:ident($t:ident) $map:ident $f:ident => $pv:ident,)+
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, Hash, PartialEq, PartialOrd, Eq, Ord)]
+        pub enum $name<$t0, $($t),+> {
+            #[doc = concat!("The variant holding `", stringify!($t0), "`, it is always tried first")]
+            $v0($t0),
+            $(
+                #[doc = concat!("The variant holding `", stringify!($t), "`")]
+                $v($t),
+            )+
+        }
+
+        map_variants!($name [] $v0($t0) $map0 $(, $v($t) $map)+);
+
+        impl<$t0, $($t),+> $name<$t0, $($t),+> {
+            /// Consume the value of the active variant with the function for that variant.
+            #[allow(clippy::too_many_arguments)]
+            pub fn fold<U>(
+                self,
+                $f0: impl FnOnce($t0) -> U,
+                $($f: impl FnOnce($t) -> U),+
+            ) -> U {
+                match self {
+                    Self::$v0(v) => $f0(v),
+                    $(Self::$v(v) => $f(v),)+
+                }
+            }
+        }
+
+        impl<T> $name<T, $(replace!($t, T)),+> {
+            /// Get the value of the active variant
+            pub fn into_inner(self) -> T {
+                match self {
+                    Self::$v0(v) $(| Self::$v(v))+ => v,
+                }
+            }
+        }
+
+        impl<$t0, $($t),+> From<$name<$t0, $($t),+>> for crate::one_of!($t0, $($t),+) {
+            fn from(value: $name<$t0, $($t),+>) -> Self {
+                match value {
+                    $name::$v0(v) => AxumEither::Left(v),
+                    $($name::$v(v) => AxumEither::Right($prev::$pv(v).into()),)+
+                }
+            }
+        }
+
+        impl<$t0, $($t),+> From<crate::one_of!($t0, $($t),+)> for $name<$t0, $($t),+> {
+            fn from(chain: crate::one_of!($t0, $($t),+)) -> Self {
+                match chain {
+                    AxumEither::Left(v) => Self::$v0(v),
+                    AxumEither::Right(rest) => match $prev::from(rest) {
+                        $($prev::$pv(v) => Self::$v(v),)+
+                    },
+                }
+            }
+        }
+
+        /// Tries the variants in order, like the equivalent chain but in a single future.
+        ///
+        /// The rejection is the one of the equivalent chain.
+        #[async_trait::async_trait]
+        impl<$t0, $($t,)+ B> FromRequest<B> for $name<$t0, $($t),+>
+        where
+            $t0: FromRequest<B>,
+            $t0::Rejection: Send,
+            $($t: FromRequest<B>, $t::Rejection: Send,)+
+            B: Send,
+        {
+            type Rejection = chain_rejection!(B; $t0, $($t),+);
+
+            async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+                #[cfg(feature = "track")]
+                let depth = crate::track::enter(req);
+
+                let $f0 = match $t0::from_request(req).await {
+                    Ok(v) => {
+                        #[cfg(feature = "track")]
+                        crate::track::matched(req, depth, std::any::type_name::<$t0>());
+                        return Ok(Self::$v0(v));
+                    }
+                    Err(e) => e,
+                };
+
+                // The index of the attempted variant, the nesting depth it has in the chain
+                #[cfg(feature = "track")]
+                let mut index = depth;
+                $(
+                    #[cfg(feature = "track")]
+                    {
+                        crate::track::descend(req, index);
+                        index += 1;
+                    }
+                    let $f = match $t::from_request(req).await {
+                        Ok(v) => {
+                            #[cfg(feature = "track")]
+                            {
+                                crate::track::ascend(req, depth);
+                                crate::track::matched(req, index, std::any::type_name::<$t>());
+                            }
+                            return Ok(Self::$v(v));
+                        }
+                        Err(e) => e,
+                    };
+                )+
+
+                #[cfg(feature = "track")]
+                {
+                    crate::track::ascend(req, depth);
+                    crate::track::rejected(req, depth);
+                }
+                Err(nest_rejections!($f0, $($f),+))
+            }
+        }
+
+        /// Responds with the active variant, like the equivalent chain.
+        impl<$t0, $($t),+> IntoResponse for $name<$t0, $($t),+>
+        where
+            crate::one_of!($t0, $($t),+): IntoResponse,
+        {
+            fn into_response(self) -> Response {
+                <crate::one_of!($t0, $($t),+)>::from(self).into_response()
+            }
+        }
+    };
+}
+
+one_of_enum! {
+    /// Extract or respond with one of three types, the flat equivalent of `one_of!(T0, T1, T2)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use axum::{Form, Json};
+    /// use axum_either::OneOf3;
+    ///
+    /// #[derive(serde::Deserialize)]
+    /// pub struct Request(u32);
+    ///
+    /// pub async fn handler(request: OneOf3<Json<Request>, Form<Request>, String>) -> String {
+    ///     request.fold(
+    ///         |Json(r)| r.0.to_string(),
+    ///         |Form(r)| r.0.to_string(),
+    ///         |s| s,
+    ///     )
+    /// }
+    /// ```
+    OneOf3 => AxumEither {
+        V0(T0) map_v0 f0,
+        V1(T1) map_v1 f1 => Left,
+        V2(T2) map_v2 f2 => Right,
+    }
+}
+
+one_of_enum! {
+    /// Extract or respond with one of four types, see [`OneOf3`].
+    OneOf4 => OneOf3 {
+        V0(T0) map_v0 f0,
+        V1(T1) map_v1 f1 => V0,
+        V2(T2) map_v2 f2 => V1,
+        V3(T3) map_v3 f3 => V2,
+    }
+}
+
+one_of_enum! {
+    /// Extract or respond with one of five types, see [`OneOf3`].
+    OneOf5 => OneOf4 {
+        V0(T0) map_v0 f0,
+        V1(T1) map_v1 f1 => V0,
+        V2(T2) map_v2 f2 => V1,
+        V3(T3) map_v3 f3 => V2,
+        V4(T4) map_v4 f4 => V3,
+    }
+}
+
+one_of_enum! {
+    /// Extract or respond with one of six types, see [`OneOf3`].
+    OneOf6 => OneOf5 {
+        V0(T0) map_v0 f0,
+        V1(T1) map_v1 f1 => V0,
+        V2(T2) map_v2 f2 => V1,
+        V3(T3) map_v3 f3 => V2,
+        V4(T4) map_v4 f4 => V3,
+        V5(T5) map_v5 f5 => V4,
+    }
+}
+
+one_of_enum! {
+    /// Extract or respond with one of seven types, see [`OneOf3`].
+    OneOf7 => OneOf6 {
+        V0(T0) map_v0 f0,
+        V1(T1) map_v1 f1 => V0,
+        V2(T2) map_v2 f2 => V1,
+        V3(T3) map_v3 f3 => V2,
+        V4(T4) map_v4 f4 => V3,
+        V5(T5) map_v5 f5 => V4,
+        V6(T6) map_v6 f6 => V5,
+    }
+}
+
+one_of_enum! {
+    /// Extract or respond with one of eight types, see [`OneOf3`].
+    OneOf8 => OneOf7 {
+        V0(T0) map_v0 f0,
+        V1(T1) map_v1 f1 => V0,
+        V2(T2) map_v2 f2 => V1,
+        V3(T3) map_v3 f3 => V2,
+        V4(T4) map_v4 f4 => V3,
+        V5(T5) map_v5 f5 => V4,
+        V6(T6) map_v6 f6 => V5,
+        V7(T7) map_v7 f7 => V6,
+    }
+}
@@ -0,0 +1,648 @@
+//! Extract one payload type from any supported wire format and respond in the same format.
+//!
+//! [`AnyFormat`] deserializes json and urlencoded forms, with the `msgpack` and `cbor`
+//! features also MessagePack and CBOR. The format is selected by the `Content-Type` of the
+//! request. [`SerializeNegotiated`] responds in the format the `Accept` header prefers instead.
+//! `MsgPack` and `Cbor` accept and respond with a single binary format, for example next to
+//! `Json` in an [`AxumEither`](crate::AxumEither).
+//!
+//! # Examples
+//! ```
+//! use axum_either::AnyFormat;
+//!
+//! #[derive(serde::Deserialize)]
+//! pub struct Request {
+//!     name: String,
+//! }
+//!
+//! #[derive(serde::Serialize)]
+//! pub struct Greeting {
+//!     msg: String,
+//! }
+//!
+//! /// Responds in the format of the request
+//! pub async fn hello(request: AnyFormat<Request>) -> AnyFormat<Greeting> {
+//!     request.map(|request| Greeting {
+//!         msg: format!("Hello {}!", request.name),
+//!     })
+//! }
+//! ```
+
+use axum_core::{
+    extract::{rejection::BytesRejection, FromRequest, RequestParts},
+    response::{IntoResponse, Response},
+    BoxError,
+};
+use bytes::Bytes;
+use http::{header, HeaderValue, StatusCode};
+use http_body::Body;
+use mime::Mime;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{fmt, sync::OnceLock};
+
+use crate::{
+    media::{self, is_json, media_type_matches},
+    negotiate::vary_on,
+    Accept, RejectionInfo, SupportedMediaTypes,
+};
+
+/// The wire formats supported by [`AnyFormat`]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, PartialOrd, Eq, Ord)]
+pub enum Format {
+    /// `application/json` or any type with the `+json` suffix
+    Json,
+    /// `application/x-www-form-urlencoded`
+    Form,
+    /// `application/msgpack` or `application/x-msgpack`
+    #[cfg(feature = "msgpack")]
+    MessagePack,
+    /// `application/cbor`
+    #[cfg(feature = "cbor")]
+    Cbor,
+}
+
+/// Every enabled format
+const FORMATS: &[Format] = &[
+    Format::Json,
+    Format::Form,
+    #[cfg(feature = "msgpack")]
+    Format::MessagePack,
+    #[cfg(feature = "cbor")]
+    Format::Cbor,
+];
+
+impl Format {
+    /// The format of a request with the given content type
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::format::Format;
+    /// let problem: mime::Mime = "application/problem+json".parse().unwrap();
+    /// assert_eq!(Format::from_content_type(&problem), Some(Format::Json));
+    /// assert_eq!(Format::from_content_type(&mime::TEXT_PLAIN), None);
+    /// ```
+    pub fn from_content_type(content_type: &Mime) -> Option<Self> {
+        if is_json(content_type) {
+            return Some(Self::Json);
+        }
+        if media_type_matches(&mime::APPLICATION_WWW_FORM_URLENCODED, content_type) {
+            return Some(Self::Form);
+        }
+        #[cfg(feature = "msgpack")]
+        if content_type.type_() == mime::APPLICATION
+            && (content_type.subtype() == "msgpack" || content_type.subtype() == "x-msgpack")
+        {
+            return Some(Self::MessagePack);
+        }
+        #[cfg(feature = "cbor")]
+        if content_type.type_() == mime::APPLICATION && content_type.subtype() == "cbor" {
+            return Some(Self::Cbor);
+        }
+        None
+    }
+
+    /// The content type of responses in this format
+    pub fn media_type(&self) -> &'static str {
+        match self {
+            Self::Json => "application/json",
+            Self::Form => "application/x-www-form-urlencoded",
+            #[cfg(feature = "msgpack")]
+            Self::MessagePack => "application/msgpack",
+            #[cfg(feature = "cbor")]
+            Self::Cbor => "application/cbor",
+        }
+    }
+
+    /// Respond with `value` serialized in this format.
+    ///
+    /// Together with the [`Format`] extractor this mirrors the format of the request without
+    /// extracting the payload through [`AnyFormat`]. Values which can not be serialized in this
+    /// format, like nested structs as a form, are answered with `415 Unsupported Media Type`.
+    ///
+    /// # Examples
+    /// ```
+    /// use axum::{body::Bytes, response::Response};
+    /// use axum_either::format::Format;
+    ///
+    /// #[derive(serde::Serialize)]
+    /// pub struct Receipt {
+    ///     size: usize,
+    /// }
+    ///
+    /// pub async fn upload(format: Format, body: Bytes) -> Response {
+    ///     format.render(Receipt { size: body.len() })
+    /// }
+    /// ```
+    pub fn render<T>(self, value: T) -> Response
+    where
+        T: Serialize,
+    {
+        match self.serialize(&value) {
+            Ok(body) => (
+                [(
+                    header::CONTENT_TYPE,
+                    HeaderValue::from_static(self.media_type()),
+                )],
+                body,
+            )
+                .into_response(),
+            Err(error) => (
+                StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                format!("Failed to render the response as {}: {}", self, error),
+            )
+                .into_response(),
+        }
+    }
+
+    fn from_headers(headers: &http::HeaderMap) -> Option<Self> {
+        media::content_type(headers).and_then(|content_type| Self::from_content_type(&content_type))
+    }
+
+    fn deserialize<T>(&self, body: &[u8]) -> Result<T, BoxError>
+    where
+        T: DeserializeOwned,
+    {
+        Ok(match self {
+            Self::Json => serde_json::from_slice(body)?,
+            Self::Form => serde_urlencoded::from_bytes(body)?,
+            #[cfg(feature = "msgpack")]
+            Self::MessagePack => rmp_serde::from_slice(body)?,
+            #[cfg(feature = "cbor")]
+            Self::Cbor => ciborium::de::from_reader(body)?,
+        })
+    }
+
+    /// The encoder of [`SerializeNegotiated`] for this format
+    fn encoder<T>(&self) -> Encoder<T>
+    where
+        T: Serialize,
+    {
+        match self {
+            Self::Json => |value| Self::Json.serialize(value),
+            Self::Form => |value| Self::Form.serialize(value),
+            #[cfg(feature = "msgpack")]
+            Self::MessagePack => |value| Self::MessagePack.serialize(value),
+            #[cfg(feature = "cbor")]
+            Self::Cbor => |value| Self::Cbor.serialize(value),
+        }
+    }
+
+    fn serialize<T>(&self, value: &T) -> Result<Vec<u8>, BoxError>
+    where
+        T: Serialize,
+    {
+        Ok(match self {
+            Self::Json => serde_json::to_vec(value)?,
+            Self::Form => serde_urlencoded::to_string(value)?.into_bytes(),
+            #[cfg(feature = "msgpack")]
+            Self::MessagePack => rmp_serde::to_vec_named(value)?,
+            #[cfg(feature = "cbor")]
+            Self::Cbor => {
+                let mut body = Vec::new();
+                ciborium::ser::into_writer(value, &mut body)?;
+                body
+            }
+        })
+    }
+}
+
+/// Extracts the format of the request from its `Content-Type`, the body is not read.
+///
+/// Requests without a supported `Content-Type` are rejected with
+/// [`AnyFormatRejection::UnsupportedMediaType`].
+#[async_trait::async_trait]
+impl<B> FromRequest<B> for Format
+where
+    B: Send,
+{
+    type Rejection = AnyFormatRejection;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        Self::from_headers(req.headers()).ok_or(AnyFormatRejection::UnsupportedMediaType)
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.media_type())
+    }
+}
+
+/// Extracts `T` from any [`Format`] and responds with `T` serialized in its format.
+///
+/// Requests without a supported `Content-Type` are rejected with
+/// `415 Unsupported Media Type`, bodies which can not be deserialized with
+/// `422 Unprocessable Entity`.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, PartialOrd, Eq, Ord)]
+pub struct AnyFormat<T> {
+    /// The payload
+    pub value: T,
+    /// The format the payload was extracted from or is rendered in
+    pub format: Format,
+}
+
+impl<T> AnyFormat<T> {
+    /// Create a payload rendered in `format`
+    pub fn new(value: T, format: Format) -> Self {
+        Self { value, format }
+    }
+
+    /// Map the payload with the given function `f`, the format is kept.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> AnyFormat<U> {
+        AnyFormat {
+            value: f(self.value),
+            format: self.format,
+        }
+    }
+
+    /// The payload
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> SupportedMediaTypes for AnyFormat<T> {
+    fn supported_media_types() -> &'static [Mime] {
+        static SUPPORTED: OnceLock<Vec<Mime>> = OnceLock::new();
+        SUPPORTED.get_or_init(|| {
+            FORMATS
+                .iter()
+                .map(|format| format.media_type().parse().unwrap())
+                .collect()
+        })
+    }
+
+    fn supports(content_type: &Mime) -> bool {
+        Format::from_content_type(content_type).is_some()
+    }
+}
+
+#[async_trait::async_trait]
+impl<T, B> FromRequest<B> for AnyFormat<T>
+where
+    T: DeserializeOwned,
+    B: Body + Send,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+{
+    type Rejection = AnyFormatRejection;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        let format = Format::from_request(req).await?;
+
+        let body = Bytes::from_request(req)
+            .await
+            .map_err(AnyFormatRejection::Body)?;
+        let value = format
+            .deserialize(&body)
+            .map_err(|error| AnyFormatRejection::Deserialize { format, error })?;
+        Ok(Self { value, format })
+    }
+}
+
+impl<T> IntoResponse for AnyFormat<T>
+where
+    T: Serialize,
+{
+    fn into_response(self) -> Response {
+        match self.format.serialize(&self.value) {
+            Ok(body) => (
+                [(
+                    header::CONTENT_TYPE,
+                    HeaderValue::from_static(self.format.media_type()),
+                )],
+                body,
+            )
+                .into_response(),
+            Err(error) => (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response(),
+        }
+    }
+}
+
+/// Implements an extractor and responder for a single [`Format`]
+#[cfg(any(feature = "msgpack", feature = "cbor"))]
+macro_rules! single_format {
+    ($(#[$meta:meta])* $name:ident, $format:expr) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, Hash, Default, PartialEq, PartialOrd, Eq, Ord)]
+        pub struct $name<T>(pub T);
+
+        impl<T> $name<T> {
+            /// The payload
+            pub fn into_inner(self) -> T {
+                self.0
+            }
+        }
+
+        impl<T> std::ops::Deref for $name<T> {
+            type Target = T;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl<T> std::ops::DerefMut for $name<T> {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+
+        impl<T> SupportedMediaTypes for $name<T> {
+            fn supported_media_types() -> &'static [Mime] {
+                static SUPPORTED: OnceLock<Vec<Mime>> = OnceLock::new();
+                SUPPORTED.get_or_init(|| vec![$format.media_type().parse().unwrap()])
+            }
+
+            fn supports(content_type: &Mime) -> bool {
+                Format::from_content_type(content_type) == Some($format)
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl<T, B> FromRequest<B> for $name<T>
+        where
+            T: DeserializeOwned,
+            B: Body + Send,
+            B::Data: Send,
+            B::Error: Into<BoxError>,
+        {
+            type Rejection = AnyFormatRejection;
+
+            async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+                if Format::from_headers(req.headers()) != Some($format) {
+                    return Err(AnyFormatRejection::UnsupportedMediaType);
+                }
+                let AnyFormat { value, .. } = AnyFormat::from_request(req).await?;
+                Ok(Self(value))
+            }
+        }
+
+        impl<T> IntoResponse for $name<T>
+        where
+            T: Serialize,
+        {
+            fn into_response(self) -> Response {
+                AnyFormat::new(self.0, $format).into_response()
+            }
+        }
+    };
+}
+
+#[cfg(feature = "msgpack")]
+single_format! {
+    /// Extracts `T` from a MessagePack body and responds with `T` as MessagePack.
+    ///
+    /// Requests need the `application/msgpack` or `application/x-msgpack` content type, responses
+    /// are sent as `application/msgpack`. Structs are encoded as maps with their field names. The
+    /// rejections are the same as for [`AnyFormat`].
+    ///
+    /// # Examples
+    /// ```
+    /// use axum::Json;
+    /// use axum_either::{format::MsgPack, AxumEither};
+    ///
+    /// #[derive(serde::Deserialize, serde::Serialize)]
+    /// pub struct Position {
+    ///     x: f32,
+    ///     y: f32,
+    /// }
+    ///
+    /// pub async fn echo(
+    ///     position: axum_either::one_of!(Json<Position>, MsgPack<Position>),
+    /// ) -> axum_either::one_of!(Json<Position>, MsgPack<Position>) {
+    ///     position
+    /// }
+    /// ```
+    MsgPack,
+    Format::MessagePack
+}
+
+#[cfg(feature = "cbor")]
+single_format! {
+    /// Extracts `T` from a CBOR body and responds with `T` as CBOR.
+    ///
+    /// Requests need the `application/cbor` content type. The rejections are the same as for
+    /// [`AnyFormat`].
+    ///
+    /// # Examples
+    /// ```
+    /// use axum::Json;
+    /// use axum_either::{format::Cbor, AxumEither};
+    ///
+    /// pub async fn echo(
+    ///     tags: AxumEither<Json<Vec<String>>, Cbor<Vec<String>>>,
+    /// ) -> AxumEither<Json<Vec<String>>, Cbor<Vec<String>>> {
+    ///     tags
+    /// }
+    /// ```
+    Cbor,
+    Format::Cbor
+}
+
+/// Encodes a value into the body of a response, see [`SerializeNegotiated::encoder`]
+pub type Encoder<T> = fn(&T) -> Result<Vec<u8>, BoxError>;
+
+/// Responds with `T` encoded in the media type the client prefers according to its `Accept`
+/// header.
+///
+/// JSON is always producible, with the `msgpack` and `cbor` features also MessagePack and CBOR.
+/// Urlencoded forms are not produced, they can't encode most types.
+/// More encoders can be registered with [`encoder`](Self::encoder). Ties are won by the encoder
+/// registered first. If no encoder is acceptable `406 Not Acceptable` is returned with the
+/// producible media types in the body. The response contains `Accept` in its `Vary` header.
+///
+/// # Examples
+/// ```
+/// use axum_either::{format::SerializeNegotiated, Accept};
+///
+/// #[derive(serde::Serialize)]
+/// pub struct User {
+///     name: String,
+/// }
+///
+/// pub async fn user(accept: Accept) -> SerializeNegotiated<User> {
+///     let user = User { name: "Reimu".into() };
+///     SerializeNegotiated::new(&accept, user)
+///         .pretty()
+///         .encoder("text/plain", |user| Ok(user.name.clone().into_bytes()))
+/// }
+/// ```
+#[derive(Clone)]
+pub struct SerializeNegotiated<T> {
+    value: T,
+    accept: Accept,
+    encoders: Vec<(&'static str, Encoder<T>)>,
+}
+
+impl<T> SerializeNegotiated<T>
+where
+    T: Serialize,
+{
+    /// Respond with `value` encoded by the built in encoders
+    pub fn new(accept: &Accept, value: T) -> Self {
+        let encoders = FORMATS
+            .iter()
+            .filter(|format| **format != Format::Form)
+            .map(|format| (format.media_type(), format.encoder()))
+            .collect();
+        Self {
+            value,
+            accept: accept.clone(),
+            encoders,
+        }
+    }
+
+    /// Encode JSON with indentation
+    pub fn pretty(self) -> Self {
+        self.encoder(Format::Json.media_type(), |value| {
+            Ok(serde_json::to_vec_pretty(value)?)
+        })
+    }
+}
+
+impl<T> SerializeNegotiated<T> {
+    /// Encode the value with `encode` if `media_type` is preferred.
+    ///
+    /// An encoder registered for the same media type before is replaced and keeps its
+    /// position.
+    ///
+    /// # Panics
+    /// If `media_type` is not a valid header value.
+    pub fn encoder(mut self, media_type: &'static str, encode: Encoder<T>) -> Self {
+        assert!(
+            HeaderValue::try_from(media_type).is_ok(),
+            "invalid media type {:?}",
+            media_type
+        );
+        match self
+            .encoders
+            .iter_mut()
+            .find(|(registered, _)| registered.eq_ignore_ascii_case(media_type))
+        {
+            Some(registered) => *registered = (media_type, encode),
+            None => self.encoders.push((media_type, encode)),
+        }
+        self
+    }
+
+    /// The media types which can be produced, in the order their encoders were registered
+    pub fn media_types(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.encoders.iter().map(|(media_type, _)| *media_type)
+    }
+
+    /// The value
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> fmt::Debug for SerializeNegotiated<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SerializeNegotiated")
+            .field("value", &self.value)
+            .field("accept", &self.accept)
+            .field("media_types", &self.media_types().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl<T> IntoResponse for SerializeNegotiated<T> {
+    fn into_response(self) -> Response {
+        let mut preferred: Option<(f32, &'static str, Encoder<T>)> = None;
+        for &(media_type, encode) in &self.encoders {
+            let quality = self.accept.quality(media_type);
+            if quality > preferred.map_or(0.0, |(quality, _, _)| quality) {
+                preferred = Some((quality, media_type, encode));
+            }
+        }
+
+        let mut response = match preferred {
+            Some((_, media_type, encode)) => match encode(&self.value) {
+                Ok(body) => (
+                    [(header::CONTENT_TYPE, HeaderValue::from_static(media_type))],
+                    body,
+                )
+                    .into_response(),
+                Err(error) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response()
+                }
+            },
+            None => (
+                StatusCode::NOT_ACCEPTABLE,
+                format!(
+                    "Expected one of: {}",
+                    self.media_types().collect::<Vec<_>>().join(", ")
+                ),
+            )
+                .into_response(),
+        };
+        vary_on(response.headers_mut(), "Accept");
+        response
+    }
+}
+
+/// The rejection of [`AnyFormat`]
+#[derive(Debug)]
+pub enum AnyFormatRejection {
+    /// The `Content-Type` is missing or not one of the supported formats
+    UnsupportedMediaType,
+    /// The body could not be buffered
+    Body(BytesRejection),
+    /// The body could not be deserialized in its format
+    Deserialize {
+        /// The format of the request
+        format: Format,
+        /// The error of the deserializer
+        error: BoxError,
+    },
+}
+
+impl fmt::Display for AnyFormatRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedMediaType => f.write_str("Expected a supported content type"),
+            Self::Body(rejection) => write!(f, "{}", rejection),
+            Self::Deserialize { format, error } => {
+                write!(f, "Failed to deserialize the {} body: {}", format, error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnyFormatRejection {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::UnsupportedMediaType => None,
+            Self::Body(rejection) => Some(rejection),
+            Self::Deserialize { error, .. } => Some(&**error),
+        }
+    }
+}
+
+impl RejectionInfo for AnyFormatRejection {
+    fn status(&self) -> StatusCode {
+        match self {
+            Self::UnsupportedMediaType => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            Self::Body(rejection) => rejection.status(),
+            Self::Deserialize { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+        }
+    }
+
+    fn message(&self) -> String {
+        self.to_string()
+    }
+
+    fn source_error(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        std::error::Error::source(self)
+    }
+}
+
+impl IntoResponse for AnyFormatRejection {
+    fn into_response(self) -> Response {
+        (self.status(), self.to_string()).into_response()
+    }
+}
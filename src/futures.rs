@@ -0,0 +1,59 @@
+//! Forward futures traits to the active side of an [`AxumEither`].
+
+use futures_sink::Sink;
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::AxumEither;
+
+/// Sends items to the active side, both sides have to accept the same items and fail with the
+/// same error.
+///
+/// # Examples
+/// ```
+/// use axum_either::prelude::*;
+/// use futures_util::SinkExt;
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let mut sink: AxumEither<Vec<u32>, Vec<u32>> = AxumEither::Right(Vec::new());
+/// sink.send(1).await.unwrap();
+/// assert_eq!(sink, AxumEither::Right(vec![1]));
+/// # });
+/// ```
+impl<Item, L, R> Sink<Item> for AxumEither<L, R>
+where
+    L: Sink<Item>,
+    R: Sink<Item, Error = L::Error>,
+{
+    type Error = L::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        match self.as_pin_mut() {
+            AxumEither::Left(l) => l.poll_ready(cx),
+            AxumEither::Right(r) => r.poll_ready(cx),
+        }
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: Item) -> Result<(), Self::Error> {
+        match self.as_pin_mut() {
+            AxumEither::Left(l) => l.start_send(item),
+            AxumEither::Right(r) => r.start_send(item),
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        match self.as_pin_mut() {
+            AxumEither::Left(l) => l.poll_flush(cx),
+            AxumEither::Right(r) => r.poll_flush(cx),
+        }
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        match self.as_pin_mut() {
+            AxumEither::Left(l) => l.poll_close(cx),
+            AxumEither::Right(r) => r.poll_close(cx),
+        }
+    }
+}
@@ -0,0 +1,57 @@
+//! Respond with HTML to browsers and with JSON to API clients.
+
+use axum::{response::Html, Json};
+use axum_core::response::{IntoResponse, Response};
+use serde::Serialize;
+
+use crate::{Accept, AxumEither, Negotiate};
+
+/// Responds with the HTML rendering of `T` or with `T` as JSON, depending on the `Accept` header.
+///
+/// JSON is used if the client accepts both equally, this includes requests without an `Accept`
+/// header. If neither is acceptable `406 Not Acceptable` is returned.
+///
+/// # Examples
+/// ```
+/// use axum_either::{Accept, HtmlOrJson};
+///
+/// #[derive(serde::Serialize)]
+/// pub struct User {
+///     name: String,
+/// }
+///
+/// pub async fn user(accept: Accept) -> HtmlOrJson<User> {
+///     let user = User { name: "Reimu".into() };
+///     HtmlOrJson::new(&accept, user, |user| format!("<h1>{}</h1>", user.name))
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct HtmlOrJson<T>(pub Negotiate<Html<String>, Json<T>>);
+
+impl<T> HtmlOrJson<T> {
+    /// Respond with `value`, `render` is only called if HTML is preferred
+    pub fn new(accept: &Accept, value: T, render: impl FnOnce(&T) -> String) -> Self {
+        Self(Negotiate(
+            accept
+                .choose("application/json", "text/html")
+                .map(|choice| match choice {
+                    AxumEither::Left(()) => AxumEither::Right(Json(value)),
+                    AxumEither::Right(()) => AxumEither::Left(Html(render(&value))),
+                }),
+        ))
+    }
+
+    /// The negotiated representation, `None` if neither was acceptable
+    pub fn into_inner(self) -> Option<AxumEither<Html<String>, Json<T>>> {
+        self.0.into_inner()
+    }
+}
+
+impl<T> IntoResponse for HtmlOrJson<T>
+where
+    T: Serialize,
+{
+    fn into_response(self) -> Response {
+        self.0.into_response()
+    }
+}
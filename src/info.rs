@@ -0,0 +1,837 @@
+//! Inspect rejections without rendering them into responses.
+
+use axum_core::extract::rejection::{BytesRejection, StringRejection};
+use http::{header, HeaderMap, HeaderValue, StatusCode};
+use std::{any, convert::Infallible, fmt};
+
+use crate::{
+    rejection::{BothMessages, DEFAULT_MESSAGE_LIMIT},
+    variant, AxumEither, AxumEitherRejection, BothRejections, FormattedRejection,
+    RejectionFormatter,
+};
+
+/// Information about a rejection which is available without rendering it.
+///
+/// This is implemented for the rejections of axum-core and, with the `axum` feature, for the
+/// rejections of axum. Implement it for your own rejections to use them with the APIs requiring
+/// it.
+///
+/// # Examples
+/// ```
+/// use axum_either::RejectionInfo;
+/// use http::StatusCode;
+///
+/// pub struct MissingToken;
+///
+/// impl RejectionInfo for MissingToken {
+///     fn status(&self) -> StatusCode {
+///         StatusCode::UNAUTHORIZED
+///     }
+///
+///     fn message(&self) -> String {
+///         "Missing token".into()
+///     }
+/// }
+/// ```
+pub trait RejectionInfo {
+    /// The status code this rejection responds with
+    fn status(&self) -> StatusCode;
+
+    /// A human readable description of this rejection
+    fn message(&self) -> String;
+
+    /// Write the [`message`](Self::message) into `out` without allocating it first.
+    ///
+    /// Rejections combining other rejections, like [`AxumEitherRejection`], write the messages
+    /// of their parts into a capped writer, so messages beyond the limit are never rendered
+    /// completely. The default implementation writes [`message`](Self::message), override it if
+    /// the message can be borrowed or written from a [`Display`](fmt::Display) implementation.
+    fn write_message(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        out.write_str(&self.message())
+    }
+
+    /// The error which caused this rejection, if there is one
+    fn source_error(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+
+    /// The headers the rendered rejection responds with, computed without rendering it.
+    ///
+    /// The default implementation returns `Content-Type: text/plain; charset=utf-8`, which is
+    /// what rejections rendering their message as plain text respond with. Override it if the
+    /// response carries other headers, [`LazyRejection`](crate::rejection::LazyRejection)
+    /// responds with these headers instead of the rendered ones.
+    fn headers(&self) -> HeaderMap {
+        plain_text_headers()
+    }
+
+    /// Call `f` for every failed extraction contained in this rejection, in the order the
+    /// extractors were tried.
+    ///
+    /// Rejections of a single extractor should keep the default implementation, which reports
+    /// the rejection itself.
+    fn visit_attempts<'a>(&'a self, f: &mut dyn FnMut(AttemptRef<'a>))
+    where
+        Self: Sized,
+    {
+        f(AttemptRef::new(self))
+    }
+}
+
+/// A borrowed failed extraction reported by [`RejectionInfo::visit_attempts`].
+///
+/// The status is computed when the attempt is created, the message is only rendered when
+/// [`message`](Self::message) is called.
+#[derive(Clone, Copy)]
+pub struct AttemptRef<'a> {
+    type_name: &'static str,
+    status: StatusCode,
+    rejection: &'a dyn RejectionInfo,
+}
+
+impl<'a> AttemptRef<'a> {
+    /// Report `rejection` itself as the failed extraction
+    pub(crate) fn new<T>(rejection: &'a T) -> Self
+    where
+        T: RejectionInfo,
+    {
+        Self {
+            type_name: any::type_name::<T>(),
+            status: rejection.status(),
+            rejection,
+        }
+    }
+
+    /// The type name of the rejection
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// The type name of the rejection with only the crate of every path, for example
+    /// `axum::JsonRejection`
+    pub fn name(&self) -> &'static str {
+        variant::name(self.type_name)
+    }
+
+    /// The status code of the rejection
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// A human readable description of the rejection, this renders the message on every call
+    pub fn message(&self) -> String {
+        self.rejection.message()
+    }
+
+    /// Displays the message with [`RejectionInfo::write_message`], without allocating it
+    pub(crate) fn display_message(&self) -> DisplayMessage<'a, dyn RejectionInfo + 'a> {
+        DisplayMessage(self.rejection)
+    }
+
+    /// The error which caused the rejection, if there is one
+    pub fn source_error(&self) -> Option<&'a (dyn std::error::Error + 'static)> {
+        self.rejection.source_error()
+    }
+
+    /// Copy the information of the rejection into an owned [`Attempt`]
+    pub fn to_attempt(&self) -> Attempt {
+        Attempt {
+            type_name: self.type_name,
+            status: self.status(),
+            message: self.message(),
+            source: self.source_error().map(ToString::to_string),
+        }
+    }
+}
+
+impl<'a> std::fmt::Debug for AttemptRef<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AttemptRef")
+            .field("type_name", &self.type_name)
+            .field("status", &self.status())
+            .finish()
+    }
+}
+
+/// An owned failed extraction, see [`AttemptRef::to_attempt`]
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct Attempt {
+    type_name: &'static str,
+    status: StatusCode,
+    message: String,
+    source: Option<String>,
+}
+
+impl Attempt {
+    /// Create an attempt of the rejection type `type_name`
+    pub fn new(type_name: &'static str, status: StatusCode, message: impl Into<String>) -> Self {
+        Self {
+            type_name,
+            status,
+            message: message.into(),
+            source: None,
+        }
+    }
+
+    /// Set the description of the error which caused the rejection
+    pub fn with_source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    /// The type name of the rejection
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// The type name of the rejection with only the crate of every path, for example
+    /// `axum::JsonRejection`
+    pub fn name(&self) -> &'static str {
+        variant::name(self.type_name)
+    }
+
+    /// The status code of the rejection
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// A human readable description of the rejection
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// A description of the error which caused the rejection, if there was one
+    pub fn source_error(&self) -> Option<&str> {
+        self.source.as_deref()
+    }
+}
+
+/// The source of an owned attempt is only kept as text, it is not reported as an error
+impl RejectionInfo for Attempt {
+    fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    fn message(&self) -> String {
+        self.message.clone()
+    }
+
+    fn write_message(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        out.write_str(&self.message)
+    }
+}
+
+/// Iterator over the borrowed failed extractions of a rejection, see
+/// [`AxumEitherRejection::collect_rejections`].
+///
+/// Nested chains are flattened in the order the extractors were tried. Creating the iterator
+/// computes the statuses, messages are only rendered by [`AttemptRef::message`].
+#[derive(Debug, Clone)]
+pub struct Attempts<'a>(std::vec::IntoIter<AttemptRef<'a>>);
+
+impl<'a> Attempts<'a> {
+    /// Collect the attempts reported by [`RejectionInfo::visit_attempts`]
+    pub fn from_info<T>(rejection: &'a T) -> Self
+    where
+        T: RejectionInfo,
+    {
+        let mut attempts = Vec::new();
+        rejection.visit_attempts(&mut |attempt| attempts.push(attempt));
+        Self(attempts.into_iter())
+    }
+}
+
+impl<'a> Iterator for Attempts<'a> {
+    type Item = AttemptRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for Attempts<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
+
+impl<'a> ExactSizeIterator for Attempts<'a> {}
+
+impl<'a> std::iter::FusedIterator for Attempts<'a> {}
+
+/// All failed extractions of a rejection, in the order the extractors were tried.
+///
+/// Nested chains are flattened, the rejection of `one_of!(A, B, C)` has three attempts.
+///
+/// # Examples
+/// ```
+/// # use axum_either::{rejection::Rejections, AxumEitherRejection};
+/// # use http::StatusCode;
+/// let rejection = AxumEitherRejection::new(
+///     StatusCode::UNSUPPORTED_MEDIA_TYPE,
+///     AxumEitherRejection::new(StatusCode::NOT_FOUND, StatusCode::BAD_REQUEST),
+/// );
+/// let rejections = Rejections::from_info(&rejection);
+/// assert_eq!(rejections.len(), 3);
+/// assert_eq!(rejections[2].status(), StatusCode::BAD_REQUEST);
+/// ```
+#[derive(Debug, Clone, Hash, Default, PartialEq, Eq)]
+pub struct Rejections(Vec<Attempt>);
+
+impl Rejections {
+    /// Collect the attempts reported by [`RejectionInfo::visit_attempts`]
+    pub fn from_info<T>(rejection: &T) -> Self
+    where
+        T: RejectionInfo,
+    {
+        let mut attempts = Vec::new();
+        rejection.visit_attempts(&mut |attempt| attempts.push(attempt.to_attempt()));
+        Self(attempts)
+    }
+
+    /// The attempt of the extractor which was tried first
+    pub fn first(&self) -> Option<&Attempt> {
+        self.0.first()
+    }
+
+    /// The attempts whose rejection has the given status
+    pub fn with_status(&self, status: StatusCode) -> impl Iterator<Item = &Attempt> {
+        self.0
+            .iter()
+            .filter(move |attempt| attempt.status == status)
+    }
+
+    /// Take the attempts out of the collection
+    pub fn into_vec(self) -> Vec<Attempt> {
+        self.0
+    }
+}
+
+impl std::ops::Deref for Rejections {
+    type Target = [Attempt];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Vec<Attempt>> for Rejections {
+    fn from(attempts: Vec<Attempt>) -> Self {
+        Self(attempts)
+    }
+}
+
+impl FromIterator<Attempt> for Rejections {
+    fn from_iter<I: IntoIterator<Item = Attempt>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for Rejections {
+    type Item = Attempt;
+    type IntoIter = std::vec::IntoIter<Attempt>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Rejections {
+    type Item = &'a Attempt;
+    type IntoIter = std::slice::Iter<'a, Attempt>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<LE, RE> RejectionInfo for AxumEitherRejection<LE, RE>
+where
+    LE: RejectionInfo,
+    RE: RejectionInfo,
+{
+    fn status(&self) -> StatusCode {
+        <BothRejections>::status(self.left_error.status(), self.right_error.status())
+    }
+
+    /// Both messages are truncated so the message is at most [`DEFAULT_MESSAGE_LIMIT`] bytes
+    /// long, see [`AxumEitherRejection::message_with_limit`]
+    fn message(&self) -> String {
+        self.message_with_limit(DEFAULT_MESSAGE_LIMIT)
+    }
+
+    fn write_message(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        write!(
+            out,
+            "{}",
+            BothMessages::message(
+                &DisplayMessage(&self.left_error),
+                &DisplayMessage(&self.right_error),
+                DEFAULT_MESSAGE_LIMIT,
+            )
+        )
+    }
+
+    fn headers(&self) -> HeaderMap {
+        <BothRejections>::headers(
+            (self.left_error.status(), self.left_error.headers()),
+            (self.right_error.status(), self.right_error.headers()),
+        )
+    }
+
+    fn visit_attempts<'a>(&'a self, f: &mut dyn FnMut(AttemptRef<'a>)) {
+        self.left_error.visit_attempts(f);
+        self.right_error.visit_attempts(f);
+    }
+}
+
+/// The status and headers are selected by the formatter `F`, everything else is the information
+/// of the wrapped rejection
+impl<LE, RE, F> RejectionInfo for FormattedRejection<LE, RE, F>
+where
+    LE: RejectionInfo,
+    RE: RejectionInfo,
+    F: RejectionFormatter,
+{
+    fn status(&self) -> StatusCode {
+        F::status(self.left_error.status(), self.right_error.status())
+    }
+
+    fn message(&self) -> String {
+        (**self).message()
+    }
+
+    fn write_message(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        (**self).write_message(out)
+    }
+
+    fn source_error(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        (**self).source_error()
+    }
+
+    fn headers(&self) -> HeaderMap {
+        F::headers(
+            (self.left_error.status(), self.left_error.headers()),
+            (self.right_error.status(), self.right_error.headers()),
+        )
+    }
+
+    fn visit_attempts<'a>(&'a self, f: &mut dyn FnMut(AttemptRef<'a>)) {
+        (**self).visit_attempts(f)
+    }
+}
+
+/// The headers of a rejection rendering its message as plain text
+pub(crate) fn plain_text_headers() -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static("text/plain; charset=utf-8"),
+    );
+    headers
+}
+
+/// Displays the message of a rejection by writing it with [`RejectionInfo::write_message`]
+pub(crate) struct DisplayMessage<'a, T: ?Sized>(pub(crate) &'a T);
+
+impl<'a, T> fmt::Display for DisplayMessage<'a, T>
+where
+    T: RejectionInfo + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.write_message(f)
+    }
+}
+
+/// The rejection of the variant which was tried, used for the rejections of single variants
+impl<L, R> RejectionInfo for AxumEither<L, R>
+where
+    L: RejectionInfo,
+    R: RejectionInfo,
+{
+    fn status(&self) -> StatusCode {
+        match self {
+            Self::Left(l) => l.status(),
+            Self::Right(r) => r.status(),
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            Self::Left(l) => l.message(),
+            Self::Right(r) => r.message(),
+        }
+    }
+
+    fn write_message(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        match self {
+            Self::Left(l) => l.write_message(out),
+            Self::Right(r) => r.write_message(out),
+        }
+    }
+
+    fn source_error(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Left(l) => l.source_error(),
+            Self::Right(r) => r.source_error(),
+        }
+    }
+
+    fn headers(&self) -> HeaderMap {
+        match self {
+            Self::Left(l) => l.headers(),
+            Self::Right(r) => r.headers(),
+        }
+    }
+
+    fn visit_attempts<'a>(&'a self, f: &mut dyn FnMut(AttemptRef<'a>)) {
+        match self {
+            Self::Left(l) => l.visit_attempts(f),
+            Self::Right(r) => r.visit_attempts(f),
+        }
+    }
+}
+
+impl RejectionInfo for Infallible {
+    fn status(&self) -> StatusCode {
+        match *self {}
+    }
+
+    fn message(&self) -> String {
+        match *self {}
+    }
+
+    fn headers(&self) -> HeaderMap {
+        match *self {}
+    }
+}
+
+impl RejectionInfo for StatusCode {
+    fn status(&self) -> StatusCode {
+        *self
+    }
+
+    fn message(&self) -> String {
+        self.canonical_reason().unwrap_or_default().into()
+    }
+
+    fn write_message(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        out.write_str(self.canonical_reason().unwrap_or_default())
+    }
+
+    /// A bare status responds with an empty body and no headers
+    fn headers(&self) -> HeaderMap {
+        HeaderMap::new()
+    }
+}
+
+impl RejectionInfo for (StatusCode, &'static str) {
+    fn status(&self) -> StatusCode {
+        self.0
+    }
+
+    fn message(&self) -> String {
+        self.1.into()
+    }
+
+    fn write_message(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        out.write_str(self.1)
+    }
+}
+
+impl RejectionInfo for (StatusCode, String) {
+    fn status(&self) -> StatusCode {
+        self.0
+    }
+
+    fn message(&self) -> String {
+        self.1.clone()
+    }
+
+    fn write_message(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        out.write_str(&self.1)
+    }
+}
+
+impl RejectionInfo for BytesRejection {
+    fn status(&self) -> StatusCode {
+        match self {
+            Self::FailedToBufferBody(_) => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn message(&self) -> String {
+        self.to_string()
+    }
+
+    fn write_message(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        write!(out, "{}", self)
+    }
+
+    fn source_error(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        // The variants of composite rejections describe the same failure as the rejection
+        // itself, the cause is their source
+        std::error::Error::source(self).and_then(std::error::Error::source)
+    }
+}
+
+impl RejectionInfo for StringRejection {
+    fn status(&self) -> StatusCode {
+        match self {
+            Self::FailedToBufferBody(_) | Self::InvalidUtf8(_) => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn message(&self) -> String {
+        self.to_string()
+    }
+
+    fn write_message(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        write!(out, "{}", self)
+    }
+
+    fn source_error(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        std::error::Error::source(self).and_then(std::error::Error::source)
+    }
+}
+
+#[cfg(feature = "axum")]
+mod axum_rejections {
+    use axum::extract::{
+        path::FailedToDeserializePathParams,
+        rejection::{FormRejection, JsonRejection, PathRejection, QueryRejection},
+    };
+    use http::StatusCode;
+    use std::fmt;
+
+    use super::RejectionInfo;
+
+    impl RejectionInfo for JsonRejection {
+        fn status(&self) -> StatusCode {
+            match self {
+                Self::JsonDataError(_) => StatusCode::UNPROCESSABLE_ENTITY,
+                Self::JsonSyntaxError(_) => StatusCode::BAD_REQUEST,
+                Self::MissingJsonContentType(_) => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                Self::BytesRejection(rejection) => rejection.status(),
+                _ => StatusCode::BAD_REQUEST,
+            }
+        }
+
+        fn message(&self) -> String {
+            let mut message = String::new();
+            let _ = self.write_message(&mut message);
+            message
+        }
+
+        fn write_message(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+            write_with_source(out, self, self.source_error())
+        }
+
+        fn source_error(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            std::error::Error::source(self).and_then(std::error::Error::source)
+        }
+    }
+
+    impl RejectionInfo for FormRejection {
+        fn status(&self) -> StatusCode {
+            match self {
+                Self::InvalidFormContentType(_) => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                Self::FailedToDeserializeQueryString(_) => StatusCode::UNPROCESSABLE_ENTITY,
+                Self::BytesRejection(rejection) => rejection.status(),
+                _ => StatusCode::BAD_REQUEST,
+            }
+        }
+
+        fn message(&self) -> String {
+            let mut message = String::new();
+            let _ = self.write_message(&mut message);
+            message
+        }
+
+        fn write_message(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+            write_with_source(out, self, self.source_error())
+        }
+
+        fn source_error(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            std::error::Error::source(self).and_then(std::error::Error::source)
+        }
+    }
+
+    /// Parameters which don't deserialize are client errors, a route which doesn't match the
+    /// extractor is a server error, like in the response of axum
+    impl RejectionInfo for PathRejection {
+        fn status(&self) -> StatusCode {
+            match self {
+                Self::FailedToDeserializePathParams(rejection) if is_route_mismatch(rejection) => {
+                    StatusCode::INTERNAL_SERVER_ERROR
+                }
+                Self::FailedToDeserializePathParams(_) => StatusCode::BAD_REQUEST,
+                _ => StatusCode::INTERNAL_SERVER_ERROR,
+            }
+        }
+
+        fn message(&self) -> String {
+            match self {
+                Self::FailedToDeserializePathParams(rejection) => rejection.to_string(),
+                _ => self.to_string(),
+            }
+        }
+    }
+
+    /// Whether the [`ErrorKind`](axum::extract::path::ErrorKind) of `rejection` means that the
+    /// route doesn't match the extractor, axum responds with a server error for those.
+    ///
+    /// axum 0.5 only hands out the kind by consuming the rejection with `into_kind`, so the
+    /// variant is read from the derived `Debug` output. Unlike the messages, the variant names are
+    /// part of the public API of axum and don't change in patch releases.
+    fn is_route_mismatch(rejection: &FailedToDeserializePathParams) -> bool {
+        let debug = format!("{:?}", rejection);
+        debug.split_once("kind: ").is_some_and(|(_, kind)| {
+            kind.starts_with("WrongNumberOfParameters ") || kind.starts_with("UnsupportedType ")
+        })
+    }
+
+    impl RejectionInfo for QueryRejection {
+        fn status(&self) -> StatusCode {
+            StatusCode::BAD_REQUEST
+        }
+
+        fn message(&self) -> String {
+            let mut message = String::new();
+            let _ = self.write_message(&mut message);
+            message
+        }
+
+        fn write_message(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+            write_with_source(out, self, self.source_error())
+        }
+
+        fn source_error(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            std::error::Error::source(self).and_then(std::error::Error::source)
+        }
+    }
+
+    /// Write `rejection` followed by the error which caused it, like the body axum responds with
+    fn write_with_source(
+        out: &mut dyn fmt::Write,
+        rejection: &dyn fmt::Display,
+        source: Option<&(dyn std::error::Error + 'static)>,
+    ) -> fmt::Result {
+        write!(out, "{}", rejection)?;
+        match source {
+            Some(source) => write!(out, ": {}", source),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "axum-extra")]
+impl RejectionInfo for axum::extract::rejection::TypedHeaderRejection {
+    fn status(&self) -> StatusCode {
+        StatusCode::BAD_REQUEST
+    }
+
+    fn message(&self) -> String {
+        self.to_string()
+    }
+
+    fn write_message(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        write!(out, "{}", self)
+    }
+}
+
+/// Serializes the rejection as a flat array of all attempts, see [`Attempt`] for the
+/// serialized fields.
+///
+/// # Examples
+/// ```
+/// # use axum_either::AxumEitherRejection;
+/// # use http::StatusCode;
+/// let rejection = AxumEitherRejection::new(
+///     (StatusCode::UNSUPPORTED_MEDIA_TYPE, "Expected json"),
+///     AxumEitherRejection::new(StatusCode::NOT_FOUND, StatusCode::BAD_REQUEST),
+/// );
+/// let value = serde_json::to_value(&rejection).unwrap();
+/// assert_eq!(value[0]["status"], 415);
+/// assert_eq!(value[0]["message"], "Expected json");
+/// assert_eq!(value[2]["status"], 400);
+/// ```
+#[cfg(feature = "serde")]
+impl<LE, RE> serde::Serialize for AxumEitherRejection<LE, RE>
+where
+    LE: RejectionInfo,
+    RE: RejectionInfo,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde::Serialize::serialize(&self.attempts(), serializer)
+    }
+}
+
+/// Serializes the attempts of the wrapped rejection
+#[cfg(feature = "serde")]
+impl<LE, RE, F> serde::Serialize for FormattedRejection<LE, RE, F>
+where
+    LE: RejectionInfo,
+    RE: RejectionInfo,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde::Serialize::serialize(&**self, serializer)
+    }
+}
+
+/// Serializes the attempts as an array
+#[cfg(feature = "serde")]
+impl serde::Serialize for Rejections {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(&self.0)
+    }
+}
+
+/// Serializes the `type_name`, the `status` as number, the `message` and, if there is one, the
+/// `source` of the attempt
+#[cfg(feature = "serde")]
+impl serde::Serialize for Attempt {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let len = if self.source.is_some() { 4 } else { 3 };
+        let mut attempt = serializer.serialize_struct("Attempt", len)?;
+        attempt.serialize_field("type_name", self.type_name)?;
+        attempt.serialize_field("status", &self.status.as_u16())?;
+        attempt.serialize_field("message", &self.message)?;
+        match &self.source {
+            Some(source) => attempt.serialize_field("source", source)?,
+            None => attempt.skip_field("source")?,
+        }
+        attempt.end()
+    }
+}
+
+/// Serializes the same fields as [`Attempt`]
+#[cfg(feature = "serde")]
+impl<'a> serde::Serialize for AttemptRef<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde::Serialize::serialize(&self.to_attempt(), serializer)
+    }
+}
@@ -0,0 +1,102 @@
+//! Buffer a json body into a [`Value`] for the extractors which deserialize it more than once.
+
+use axum_core::{
+    extract::{rejection::BytesRejection, FromRequest, RequestParts},
+    response::{IntoResponse, Response},
+    BoxError,
+};
+use bytes::Bytes;
+use http::StatusCode;
+use http_body::Body;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::fmt;
+
+use crate::{media, RejectionInfo};
+
+/// The error of deserializing a type from a buffered json body, with the path of the failing
+/// field
+pub type JsonPathError = serde_path_to_error::Error<serde_json::Error>;
+
+/// Check the `Content-Type`, buffer the body and parse it into a [`Value`]
+pub(crate) async fn json_value<B>(req: &mut RequestParts<B>) -> Result<Value, JsonBodyRejection>
+where
+    B: Body + Send,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+{
+    if !media::content_type(req.headers()).is_some_and(|content_type| media::is_json(&content_type))
+    {
+        return Err(JsonBodyRejection::MissingJsonContentType);
+    }
+
+    let body = Bytes::from_request(req)
+        .await
+        .map_err(JsonBodyRejection::Body)?;
+    serde_json::from_slice(&body).map_err(JsonBodyRejection::Syntax)
+}
+
+/// Deserialize `T` from a buffered body, the error names the path of the failing field
+pub(crate) fn from_value<T: DeserializeOwned>(value: &Value) -> Result<T, JsonPathError> {
+    serde_path_to_error::deserialize(value)
+}
+
+/// The rejection of a json body which could not be buffered into a [`Value`].
+///
+/// Shared by the rejections of [`JsonOneOf`](crate::JsonOneOf) and
+/// [`DiscriminatedJson`](crate::DiscriminatedJson).
+#[derive(Debug)]
+pub enum JsonBodyRejection {
+    /// The `Content-Type` is not json
+    MissingJsonContentType,
+    /// The body could not be buffered
+    Body(BytesRejection),
+    /// The body is not valid json
+    Syntax(serde_json::Error),
+}
+
+impl fmt::Display for JsonBodyRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingJsonContentType => {
+                f.write_str("Expected request with `Content-Type: application/json`")
+            }
+            Self::Body(rejection) => write!(f, "{}", rejection),
+            Self::Syntax(error) => write!(f, "Failed to parse the request body as JSON: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for JsonBodyRejection {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::MissingJsonContentType => None,
+            Self::Body(rejection) => Some(rejection),
+            Self::Syntax(error) => Some(error),
+        }
+    }
+}
+
+impl RejectionInfo for JsonBodyRejection {
+    fn status(&self) -> StatusCode {
+        match self {
+            Self::MissingJsonContentType => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            Self::Body(rejection) => rejection.status(),
+            Self::Syntax(_) => StatusCode::BAD_REQUEST,
+        }
+    }
+
+    fn message(&self) -> String {
+        self.to_string()
+    }
+
+    fn source_error(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        std::error::Error::source(self)
+    }
+}
+
+impl IntoResponse for JsonBodyRejection {
+    fn into_response(self) -> Response {
+        (self.status(), self.to_string()).into_response()
+    }
+}
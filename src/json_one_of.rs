@@ -0,0 +1,155 @@
+//! Extract one of two json payloads from a body which is parsed once.
+
+use axum_core::{
+    extract::{FromRequest, RequestParts},
+    response::{IntoResponse, Response},
+    BoxError,
+};
+use http::StatusCode;
+use http_body::Body;
+use serde::de::DeserializeOwned;
+use std::{
+    fmt,
+    ops::{Deref, DerefMut},
+};
+
+use crate::{
+    json_body::{from_value, json_value, JsonBodyRejection, JsonPathError},
+    AxumEither, RejectionInfo,
+};
+
+/// Extracts a json body as `A` or, if that fails, as `B`, like an untagged serde enum.
+///
+/// `AxumEither<Json<A>, Json<B>>` tokenizes the body once for every side. This extractor parses
+/// the body into a [`Value`](serde_json::Value) once and deserializes both types from it. If
+/// neither type matches the request is rejected with [`JsonOneOfRejection::Deserialize`], which
+/// contains the errors of both types together with the path of the field each type failed at.
+///
+/// # Examples
+/// ```
+/// use axum_either::{AxumEither, JsonOneOf};
+///
+/// #[derive(serde::Deserialize)]
+/// pub struct ById {
+///     id: u32,
+/// }
+///
+/// #[derive(serde::Deserialize)]
+/// pub struct ByName {
+///     name: String,
+/// }
+///
+/// pub async fn lookup(JsonOneOf(query): JsonOneOf<ById, ByName>) -> String {
+///     match query {
+///         AxumEither::Left(query) => format!("id {}", query.id),
+///         AxumEither::Right(query) => format!("name {}", query.name),
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, PartialOrd, Eq, Ord)]
+pub struct JsonOneOf<A, B>(pub AxumEither<A, B>);
+
+impl<A, B> JsonOneOf<A, B> {
+    /// The extracted payload
+    pub fn into_inner(self) -> AxumEither<A, B> {
+        self.0
+    }
+}
+
+impl<A, B> Deref for JsonOneOf<A, B> {
+    type Target = AxumEither<A, B>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<A, B> DerefMut for JsonOneOf<A, B> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[async_trait::async_trait]
+impl<A, B, Bd> FromRequest<Bd> for JsonOneOf<A, B>
+where
+    A: DeserializeOwned,
+    B: DeserializeOwned,
+    Bd: Body + Send,
+    Bd::Data: Send,
+    Bd::Error: Into<BoxError>,
+{
+    type Rejection = JsonOneOfRejection;
+
+    async fn from_request(req: &mut RequestParts<Bd>) -> Result<Self, Self::Rejection> {
+        let value = json_value(req).await.map_err(JsonOneOfRejection::Json)?;
+        let left = match from_value(&value) {
+            Ok(left) => return Ok(Self(AxumEither::Left(left))),
+            Err(left) => left,
+        };
+        match from_value(&value) {
+            Ok(right) => Ok(Self(AxumEither::Right(right))),
+            Err(right) => Err(JsonOneOfRejection::Deserialize { left, right }),
+        }
+    }
+}
+
+/// The rejection of [`JsonOneOf`]
+#[derive(Debug)]
+pub enum JsonOneOfRejection {
+    /// The body is not a json document
+    Json(JsonBodyRejection),
+    /// The body matches neither type
+    Deserialize {
+        /// The error of the left type
+        left: JsonPathError,
+        /// The error of the right type
+        right: JsonPathError,
+    },
+}
+
+impl fmt::Display for JsonOneOfRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Json(rejection) => write!(f, "{}", rejection),
+            Self::Deserialize { left, right } => write!(
+                f,
+                "Failed to deserialize the JSON body, left error: {}, right error: {}",
+                left, right
+            ),
+        }
+    }
+}
+
+/// The source of a failed deserialization is the error of the left type
+impl std::error::Error for JsonOneOfRejection {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Json(rejection) => Some(rejection),
+            Self::Deserialize { left, .. } => Some(left),
+        }
+    }
+}
+
+impl RejectionInfo for JsonOneOfRejection {
+    fn status(&self) -> StatusCode {
+        match self {
+            Self::Json(rejection) => rejection.status(),
+            Self::Deserialize { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+        }
+    }
+
+    fn message(&self) -> String {
+        self.to_string()
+    }
+
+    fn source_error(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        std::error::Error::source(self)
+    }
+}
+
+impl IntoResponse for JsonOneOfRejection {
+    fn into_response(self) -> Response {
+        (self.status(), self.to_string()).into_response()
+    }
+}
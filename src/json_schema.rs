@@ -0,0 +1,73 @@
+//! Describe [`AxumEither`] with schemars.
+
+use schemars::{
+    gen::SchemaGenerator,
+    schema::{Schema, SchemaObject, SubschemaValidation},
+    JsonSchema,
+};
+
+use crate::AxumEither;
+
+/// A `oneOf` of the schemas of both sides.
+///
+/// The schema is always inlined so nested chains built with [`one_of`](crate::one_of) are
+/// flattened into a single `oneOf`.
+///
+/// # Examples
+/// ```
+/// # use axum_either::prelude::*;
+/// let schema = schemars::schema_for!(axum_either::one_of!(String, bool, u8));
+/// let schema = serde_json::to_value(&schema).unwrap();
+/// assert_eq!(schema["title"], "String_or_Boolean_or_uint8");
+/// assert_eq!(schema["oneOf"].as_array().unwrap().len(), 3);
+/// ```
+impl<L, R> JsonSchema for AxumEither<L, R>
+where
+    L: JsonSchema,
+    R: JsonSchema,
+{
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        format!("{}_or_{}", L::schema_name(), R::schema_name())
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        let mut one_of = Vec::new();
+        push_flattened(&mut one_of, gen.subschema_for::<L>());
+        push_flattened(&mut one_of, gen.subschema_for::<R>());
+
+        SchemaObject {
+            subschemas: Some(Box::new(SubschemaValidation {
+                one_of: Some(one_of),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+/// Push `schema` to `one_of`, the entries of a schema which only consists of a `oneOf` are pushed
+/// instead
+fn push_flattened(one_of: &mut Vec<Schema>, schema: Schema) {
+    if let Schema::Object(mut object) = schema.clone() {
+        let entries = object
+            .subschemas
+            .as_mut()
+            .and_then(|subschemas| subschemas.one_of.take());
+        if let Some(entries) = entries {
+            if object.subschemas.as_deref() == Some(&SubschemaValidation::default()) {
+                object.subschemas = None;
+                if object == SchemaObject::default() {
+                    one_of.extend(entries);
+                    return;
+                }
+            }
+        }
+    }
+
+    one_of.push(schema);
+}
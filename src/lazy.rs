@@ -0,0 +1,238 @@
+//! Defer rendering rejections until their body is read.
+
+use axum_core::{
+    body::{self, BoxBody},
+    extract::{FromRequest, RequestParts},
+    response::{IntoResponse, Response},
+    Error,
+};
+use bytes::Bytes;
+use http::{HeaderMap, StatusCode};
+use http_body::{Body, SizeHint};
+use std::{
+    fmt,
+    ops::{Deref, DerefMut},
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::RejectionInfo;
+
+/// Extract `T` but only render its rejection when the body of the response is read.
+///
+/// The rejection responds with the status of [`RejectionInfo::status`] right away, see
+/// [`LazyRejection`]. This avoids building the responses of every failed extractor in services
+/// whose middleware replaces the bodies of rejections.
+///
+/// # Examples
+/// ```
+/// use axum::{Form, Json};
+/// use axum_either::{prelude::*, rejection::WithLazyRejection};
+///
+/// #[derive(serde::Deserialize)]
+/// pub struct Request(u32);
+///
+/// pub async fn form_or_json(
+///     WithLazyRejection(request): WithLazyRejection<AxumEither<Json<Request>, Form<Request>>>,
+/// ) -> String {
+///     request.map_lr(|Json(r)| r.0, |Form(r)| r.0).into_inner().to_string()
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Hash, Default, PartialEq, PartialOrd, Eq, Ord)]
+pub struct WithLazyRejection<T>(pub T);
+
+impl<T> WithLazyRejection<T> {
+    /// Extract the wrapped value
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for WithLazyRejection<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for WithLazyRejection<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[async_trait::async_trait]
+impl<T, B> FromRequest<B> for WithLazyRejection<T>
+where
+    T: FromRequest<B>,
+    T::Rejection: RejectionInfo + Send + 'static,
+    B: Send,
+{
+    type Rejection = LazyRejection<T::Rejection>;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        T::from_request(req).await.map(Self).map_err(LazyRejection)
+    }
+}
+
+/// A rejection which is only rendered when the body of its response is read.
+///
+/// The status and headers are taken from [`RejectionInfo::status`] and
+/// [`RejectionInfo::headers`] without rendering the rejection, only the body is deferred.
+/// `RejectionInfo::headers` must therefore return the headers of the rendered response: the
+/// default claims `text/plain`, so a rejection rendering JSON or any other body has to override
+/// it, or the lazy response sends its body with the wrong `Content-Type`.
+///
+/// # Examples
+/// ```
+/// # use axum_either::{rejection::LazyRejection, AxumEitherRejection};
+/// # use axum_core::response::IntoResponse;
+/// # use http::StatusCode;
+/// let rejection = LazyRejection(AxumEitherRejection::new(
+///     StatusCode::UNSUPPORTED_MEDIA_TYPE,
+///     StatusCode::NOT_FOUND,
+/// ));
+/// assert_eq!(rejection.status(), StatusCode::BAD_REQUEST);
+/// // The errors are only rendered if the body is read
+/// let response = rejection.into_response();
+/// assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+/// assert_eq!(response.headers()["content-type"], "text/plain; charset=utf-8");
+/// ```
+#[derive(Debug, Clone, Copy, Hash, Default, PartialEq, PartialOrd, Eq, Ord)]
+pub struct LazyRejection<T>(pub T);
+
+impl<T> LazyRejection<T> {
+    /// The wrapped rejection
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> LazyRejection<T>
+where
+    T: RejectionInfo,
+{
+    /// The status the rejection responds with, the rejection is not rendered
+    pub fn status(&self) -> StatusCode {
+        self.0.status()
+    }
+}
+
+impl<T> LazyRejection<T>
+where
+    T: IntoResponse + RejectionInfo + Send + 'static,
+{
+    /// The status of the rejection and a body rendering it on the first read
+    pub fn into_response_parts_lazy(self) -> (StatusCode, LazyBody) {
+        let status = self.status();
+        let rejection = self.0;
+        (
+            status,
+            LazyBody::new(move || rejection.into_response().into_body()),
+        )
+    }
+}
+
+impl<T> RejectionInfo for LazyRejection<T>
+where
+    T: RejectionInfo,
+{
+    fn status(&self) -> StatusCode {
+        self.0.status()
+    }
+
+    fn message(&self) -> String {
+        self.0.message()
+    }
+
+    fn write_message(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        self.0.write_message(out)
+    }
+
+    fn source_error(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.0.source_error()
+    }
+
+    fn headers(&self) -> HeaderMap {
+        self.0.headers()
+    }
+
+    fn visit_attempts<'a>(&'a self, f: &mut dyn FnMut(crate::AttemptRef<'a>)) {
+        self.0.visit_attempts(f)
+    }
+}
+
+impl<T> IntoResponse for LazyRejection<T>
+where
+    T: IntoResponse + RejectionInfo + Send + 'static,
+{
+    fn into_response(self) -> Response {
+        let headers = self.0.headers();
+        let (status, body) = self.into_response_parts_lazy();
+        let mut response = Response::new(body::boxed(body));
+        *response.status_mut() = status;
+        *response.headers_mut() = headers;
+        response
+    }
+}
+
+/// A [`Body`] which is built on the first read, see [`LazyRejection`]
+pub struct LazyBody {
+    render: Option<Box<dyn FnOnce() -> BoxBody + Send>>,
+    body: Option<BoxBody>,
+}
+
+impl LazyBody {
+    /// Create a body which calls `render` on the first read
+    pub fn new(render: impl FnOnce() -> BoxBody + Send + 'static) -> Self {
+        Self {
+            render: Some(Box::new(render)),
+            body: None,
+        }
+    }
+
+    fn rendered(&mut self) -> Pin<&mut BoxBody> {
+        if let Some(render) = self.render.take() {
+            self.body = Some(render());
+        }
+        Pin::new(self.body.as_mut().expect("The body is rendered"))
+    }
+}
+
+impl fmt::Debug for LazyBody {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LazyBody")
+            .field("rendered", &self.render.is_none())
+            .finish()
+    }
+}
+
+impl Body for LazyBody {
+    type Data = Bytes;
+    type Error = Error;
+
+    fn poll_data(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Self::Data, Self::Error>>> {
+        self.get_mut().rendered().poll_data(cx)
+    }
+
+    fn poll_trailers(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<Option<HeaderMap>, Self::Error>> {
+        self.get_mut().rendered().poll_trailers(cx)
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.body.as_ref().is_some_and(Body::is_end_stream)
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.body
+            .as_ref()
+            .map_or_else(SizeHint::default, Body::size_hint)
+    }
+}
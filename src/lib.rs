@@ -8,7 +8,7 @@
 //! # Example
 //! ```
 //! use axum::{Json, Form};
-//! use axum_either::AxumEither;
+//! use axum_either::prelude::*;
 //!
 //! #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
 //! pub struct Request(u32);
@@ -34,21 +34,189 @@
 //!     }
 //! }
 //! ```
+//! The commonly used types and macros can be imported with the [`prelude`]. Everything else is
+//! organized into the [`extract`], [`response`], [`rejection`] and [`macros`] modules and
+//! re-exported at the crate root.
+//!
+//! # Runtimes
+//! The core of the crate only depends on `axum-core`, `http`, `http-body`, `bytes`, `mime` and
+//! `async-trait`, none of them assume an async runtime and extraction works with any executor.
+//! The `html-or-json`, `sse`, `validation` and `router-ext` features depend on `axum`, which
+//! pulls in `tokio` and `hyper`.
+//! The helpers of the `test-util` feature are runtime agnostic.
+//!
 //! For more examples see the
 //! [examples](https://github.com/DrSloth/axum_either/tree/master/examples) directory.
 
-use axum_core::{
-    extract::{FromRequest, RequestParts},
-    response::{IntoResponse, Response},
+use axum_core::response::{IntoResponse, Response};
+use http::status::StatusCode;
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+#[cfg(feature = "negotiation")]
+pub mod accept;
+#[cfg(feature = "aide")]
+mod aide_impls;
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impls;
+#[cfg(feature = "axum-extra")]
+pub mod auth;
+#[cfg(feature = "body")]
+pub mod body;
+mod bodyless;
+mod catch_panic;
+pub mod convert;
+#[cfg(feature = "discriminated-json")]
+pub mod discriminated;
+mod dispatch;
+mod erased;
+pub mod extract;
+mod fallback;
+mod first_ok;
+mod flat;
+#[cfg(feature = "any-format")]
+pub mod format;
+#[cfg(feature = "futures")]
+mod futures;
+#[cfg(feature = "html-or-json")]
+mod html;
+mod info;
+#[cfg(any(feature = "discriminated-json", feature = "json-one-of"))]
+mod json_body;
+#[cfg(feature = "json-one-of")]
+mod json_one_of;
+#[cfg(feature = "schemars")]
+mod json_schema;
+mod lazy;
+mod limit;
+pub mod macros;
+mod map;
+pub mod media;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+#[cfg(feature = "negotiation")]
+pub mod negotiate;
+#[cfg(feature = "observer")]
+pub mod observe;
+#[cfg(feature = "utoipa")]
+pub mod openapi;
+#[cfg(feature = "axum")]
+mod path;
+pub mod prelude;
+#[cfg(feature = "problem-details")]
+pub mod problem;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "qs-form")]
+mod qs;
+mod ready;
+#[cfg(feature = "recorder")]
+pub mod recorder;
+mod redact;
+pub mod rejection;
+pub mod response;
+#[cfg(feature = "router-ext")]
+pub mod routing;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "sse")]
+pub mod sse;
+mod tag;
+#[cfg(feature = "test-util")]
+pub mod test;
+mod text;
+#[cfg(feature = "tower")]
+mod tower_impls;
+#[cfg(feature = "tracing")]
+pub mod tracing;
+#[cfg(feature = "track")]
+mod track;
+#[cfg(feature = "validation")]
+pub mod validate;
+mod variant;
+
+#[cfg(feature = "axum-extra")]
+pub use auth::AuthEither;
+#[cfg(feature = "body")]
+pub use body::EitherBody;
+pub use bodyless::BodyEither;
+pub use catch_panic::{CatchPanic, CatchPanicEither, CaughtRejection, ExtractorPanic};
+#[cfg(feature = "discriminated-json")]
+pub use discriminated::DiscriminatedJson;
+pub use dispatch::{Dispatch, DispatchRejection, DispatchVariant};
+pub use erased::BoxedEitherRejection;
+pub use extract::{PreferLeftRejection, WithFormatter};
+pub use fallback::{Fallback, FallbackFormat, FromExtractor, SwallowedRejection};
+#[cfg(feature = "axum")]
+pub use fallback::{FromForm, FromJson, FromQuery};
+pub use first_ok::{Alternatives, FirstOk, FirstOkRejection};
+pub use flat::{OneOf3, OneOf4, OneOf5, OneOf6, OneOf7, OneOf8};
+#[cfg(feature = "cbor")]
+pub use format::Cbor;
+#[cfg(feature = "msgpack")]
+pub use format::MsgPack;
+#[cfg(feature = "any-format")]
+pub use format::{AnyFormat, SerializeNegotiated};
+#[cfg(feature = "html-or-json")]
+pub use html::HtmlOrJson;
+pub use info::{Attempt, AttemptRef, Attempts, RejectionInfo, Rejections};
+#[cfg(feature = "json-one-of")]
+pub use json_one_of::JsonOneOf;
+pub use lazy::{LazyRejection, WithLazyRejection};
+pub use limit::Limited;
+pub use map::EitherMap;
+pub use media::{MediaTypeDispatch, SupportedMediaTypes, WithContentType};
+#[cfg(feature = "negotiation")]
+pub use negotiate::{Accept, AcceptLanguage, LanguageNegotiate, Negotiate};
+#[cfg(feature = "axum")]
+pub use path::PathEither;
+#[cfg(feature = "problem-details")]
+pub use problem::ProblemDetails;
+#[cfg(feature = "qs-form")]
+pub use qs::QsForm;
+pub use redact::{Redacted, DEFAULT_REDACTED_LIMIT};
+pub use rejection::{
+    AxumEitherRejection, BothRejections, EscalateServerErrors, FormattedRejection,
+    PreferClientErrors, RejectionFormatter, SingleRejection, StatusPolicy,
 };
-use http::{header, status::StatusCode, HeaderValue};
+pub use response::{WithExtension, WithStatus};
+pub use tag::{FlatIndex, Tagged, VariantTag, VARIANT_HEADER};
+pub use text::Text;
+#[cfg(feature = "validation")]
+pub use validate::ValidatedEither;
 
 /// Extract or Respond with one of the given types, this can be composed to extract more types.
 ///
 /// This implements [`IntoResponse`](axum_core::response::IntoResponse) if both L and R implement
-/// [`IntoResponse`]. If L and R implement [`FromRequest`] this type also does.
+/// [`IntoResponse`]. If L and R implement [`FromRequest`](axum_core::extract::FromRequest) this
+/// type also does.
 ///
 /// Requests are parsed from left to right, if both types collide the Left type is preferred.
+/// A `GET` or `HEAD` request without a body is rejected with the errors of both sides, use
+/// [`BodyEither`] to explain such requests with a single error instead.
+///
+/// The derived ordering sorts every left value before every right value, values of the same side
+/// are compared with the ordering of their type. This is the ordering of [`either::Either`] as
+/// well, with the `either` feature both types can be compared with each other and the result is
+/// the same as comparing after converting with [`into_either`](Self::into_either).
+///
+/// [`Infallible`](std::convert::Infallible) implements [`IntoResponse`] and can be used for a
+/// side which is never constructed, only the other side has to be a responder. Likewise an
+/// extractor rejecting with `Infallible` can be combined with any other extractor.
+///
+/// # Examples
+/// ```
+/// use axum::Json;
+/// use axum_either::prelude::*;
+/// use std::convert::Infallible;
+///
+/// pub async fn always_json() -> AxumEither<Json<u32>, Infallible> {
+///     AxumEither::Left(Json(42))
+/// }
+/// ```
 #[derive(Debug, Clone, Copy, Hash, PartialEq, PartialOrd, Eq, Ord)]
 pub enum AxumEither<L, R> {
     /// The first possibility to parse, this variant is always tried first when parsing
@@ -62,41 +230,217 @@ impl<L, R> AxumEither<L, R> {
     ///
     /// # Examples
     /// ```
-    /// # use axum_either::AxumEither;
+    /// # use axum_either::prelude::*;
     /// let l: AxumEither<i32, bool> = AxumEither::Left(10);
     /// assert_eq!(l.map_left(|i| i * 10), AxumEither::Left(100));
     /// let r: axum_either::one_of!(i32, bool) = AxumEither::Right(false);
     /// assert_eq!(r.map_left(|i| i * 100), AxumEither::Right(false));
     /// ```
     pub fn map_left<U>(self, f: impl FnOnce(L) -> U) -> AxumEither<U, R> {
-        match self {
-            Self::Left(l) => AxumEither::Left(f(l)),
-            Self::Right(r) => AxumEither::Right(r),
-        }
+        self.bimap(f, |r| r)
     }
 
     /// Maps the right value using the given function `f`.
     ///
     /// # Examples
     /// ```
-    /// # use axum_either::AxumEither;
+    /// # use axum_either::prelude::*;
     /// let l: AxumEither<i32, bool> = AxumEither::Left(10);
     /// assert_eq!(l.map_right(|b| !b), AxumEither::Left(10));
     /// let r: axum_either::one_of!(i32, bool) = AxumEither::Right(false);
     /// assert_eq!(r.map_right(|b| !b), AxumEither::Right(true));
     /// ```
     pub fn map_right<U>(self, f: impl FnOnce(R) -> U) -> AxumEither<L, U> {
+        self.bimap(|l| l, f)
+    }
+
+    /// Returns `other` if this is a left value, keeps the right value otherwise.
+    ///
+    /// Like [`Option::and`], `other` is evaluated eagerly even if it is discarded. Use
+    /// [`and_then_left`](Self::and_then_left) to only compute it for left values.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::prelude::*;
+    /// let l: AxumEither<i32, bool> = AxumEither::Left(10);
+    /// assert_eq!(l.left_and(AxumEither::<&str, bool>::Left("ten")), AxumEither::Left("ten"));
+    /// let r: AxumEither<i32, bool> = AxumEither::Right(false);
+    /// assert_eq!(r.left_and(AxumEither::<&str, bool>::Left("ten")), AxumEither::Right(false));
+    /// ```
+    pub fn left_and<U>(self, other: AxumEither<U, R>) -> AxumEither<U, R> {
+        match self {
+            Self::Left(_) => other,
+            Self::Right(r) => AxumEither::Right(r),
+        }
+    }
+
+    /// Returns `other` if this is a right value, keeps the left value otherwise.
+    ///
+    /// Like [`Option::and`], `other` is evaluated eagerly even if it is discarded. Use
+    /// [`and_then_right`](Self::and_then_right) to only compute it for right values.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::prelude::*;
+    /// let r: AxumEither<i32, bool> = AxumEither::Right(true);
+    /// assert_eq!(r.right_and(AxumEither::<i32, u8>::Right(1)), AxumEither::Right(1));
+    /// let l: AxumEither<i32, bool> = AxumEither::Left(10);
+    /// assert_eq!(l.right_and(AxumEither::<i32, u8>::Right(1)), AxumEither::Left(10));
+    /// ```
+    pub fn right_and<U>(self, other: AxumEither<L, U>) -> AxumEither<L, U> {
+        match self {
+            Self::Left(l) => AxumEither::Left(l),
+            Self::Right(_) => other,
+        }
+    }
+
+    /// Returns whichever of `self` and `other` is a left value.
+    ///
+    /// Ties are won by `self`: if both are left or both are right values `self` is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::prelude::*;
+    /// let r: AxumEither<i32, bool> = AxumEither::Right(false);
+    /// assert_eq!(r.prefer_left(AxumEither::Left(1)), AxumEither::Left(1));
+    /// assert_eq!(r.prefer_left(AxumEither::Right(true)), AxumEither::Right(false));
+    /// ```
+    pub fn prefer_left(self, other: Self) -> Self {
+        match (&self, &other) {
+            (Self::Right(_), Self::Left(_)) => other,
+            _ => self,
+        }
+    }
+
+    /// Returns the first left value of `eithers`, or the first right value if there is none.
+    ///
+    /// This stops at the first left value. Returns `None` if `eithers` is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::prelude::*;
+    /// let attempts: Vec<AxumEither<i32, &str>> =
+    ///     vec![AxumEither::Right("slow"), AxumEither::Left(1), AxumEither::Left(2)];
+    /// assert_eq!(AxumEither::collapse(attempts), Some(AxumEither::Left(1)));
+    /// ```
+    pub fn collapse(eithers: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut first_right = None;
+        for either in eithers {
+            match either {
+                Self::Left(l) => return Some(Self::Left(l)),
+                Self::Right(r) => {
+                    first_right.get_or_insert(r);
+                }
+            }
+        }
+        first_right.map(Self::Right)
+    }
+
+    /// Computes a new either from the left value with `f`, keeps the right value otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::prelude::*;
+    /// let l: AxumEither<i32, &str> = AxumEither::Left(10);
+    /// let checked = l.and_then_left(|i| match u8::try_from(i) {
+    ///     Ok(i) => AxumEither::Left(i),
+    ///     Err(_) => AxumEither::Right("out of range"),
+    /// });
+    /// assert_eq!(checked, AxumEither::Left(10u8));
+    /// ```
+    pub fn and_then_left<U>(self, f: impl FnOnce(L) -> AxumEither<U, R>) -> AxumEither<U, R> {
+        match self {
+            Self::Left(l) => f(l),
+            Self::Right(r) => AxumEither::Right(r),
+        }
+    }
+
+    /// Computes a new either from the right value with `f`, keeps the left value otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::prelude::*;
+    /// let r: AxumEither<&str, i32> = AxumEither::Right(-1);
+    /// let checked = r.and_then_right(|i| match u8::try_from(i) {
+    ///     Ok(i) => AxumEither::Right(i),
+    ///     Err(_) => AxumEither::Left("out of range"),
+    /// });
+    /// assert_eq!(checked, AxumEither::Left("out of range"));
+    /// ```
+    pub fn and_then_right<U>(self, f: impl FnOnce(R) -> AxumEither<L, U>) -> AxumEither<L, U> {
         match self {
             Self::Left(l) => AxumEither::Left(l),
-            Self::Right(r) => AxumEither::Right(f(r)),
+            Self::Right(r) => f(r),
+        }
+    }
+
+    /// Combine the values of `self` and `other` if both took the same branch.
+    ///
+    /// Left values are combined with `lf` and right values with `rf`, `None` is returned if one
+    /// is a left value and the other a right value.
+    ///
+    /// # Examples
+    /// ```
+    /// use axum::{Form, Json};
+    /// use axum_either::prelude::*;
+    ///
+    /// #[derive(serde::Deserialize)]
+    /// pub struct Meta {
+    ///     count: u32,
+    /// }
+    ///
+    /// fn total(
+    ///     first: AxumEither<Json<Meta>, Form<Meta>>,
+    ///     second: AxumEither<Json<Meta>, Form<Meta>>,
+    /// ) -> Option<AxumEither<u32, u32>> {
+    ///     first.zip_with(
+    ///         second,
+    ///         |Json(a), Json(b)| a.count + b.count,
+    ///         |Form(a), Form(b)| a.count + b.count,
+    ///     )
+    /// }
+    ///
+    /// let json = |count| AxumEither::Left(Json(Meta { count }));
+    /// let form = |count| AxumEither::Right(Form(Meta { count }));
+    /// assert_eq!(total(json(1), json(2)), Some(AxumEither::Left(3)));
+    /// assert_eq!(total(form(3), form(4)), Some(AxumEither::Right(7)));
+    /// assert_eq!(total(json(1), form(4)), None);
+    /// ```
+    pub fn zip_with<L1, R1, L2, R2>(
+        self,
+        other: AxumEither<L1, R1>,
+        lf: impl FnOnce(L, L1) -> L2,
+        rf: impl FnOnce(R, R1) -> R2,
+    ) -> Option<AxumEither<L2, R2>> {
+        match (self, other) {
+            (Self::Left(l), AxumEither::Left(l1)) => Some(AxumEither::Left(lf(l, l1))),
+            (Self::Right(r), AxumEither::Right(r1)) => Some(AxumEither::Right(rf(r, r1))),
+            _ => None,
         }
     }
 
+    /// Pair the values of `self` and `other` if both took the same branch.
+    ///
+    /// This is [`zip_with`](Self::zip_with) collecting both values into tuples.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::prelude::*;
+    /// let l: AxumEither<i32, bool> = AxumEither::Left(10);
+    /// let other: AxumEither<&str, u8> = AxumEither::Left("ten");
+    /// assert_eq!(l.zip(other), Some(AxumEither::Left((10, "ten"))));
+    /// let other: AxumEither<&str, u8> = AxumEither::Right(10);
+    /// assert_eq!(l.zip(other), None);
+    /// ```
+    pub fn zip<L1, R1>(self, other: AxumEither<L1, R1>) -> Option<AxumEither<(L, L1), (R, R1)>> {
+        self.zip_with(other, |l, l1| (l, l1), |r, r1| (r, r1))
+    }
+
     /// Map both the left and right values with the given `lf` and `rf` functions.
     ///
     /// # Examples
     /// ```
-    /// # use axum_either::AxumEither;
+    /// # use axum_either::prelude::*;
     /// let l: AxumEither<i32, bool> = AxumEither::Left(10);
     /// assert_eq!(l.map_lr(|i| i * 10, |b| !b), AxumEither::Left(100));
     /// let r: axum_either::one_of!(i32, bool) = AxumEither::Right(false);
@@ -107,7 +451,260 @@ impl<L, R> AxumEither<L, R> {
         lf: impl FnOnce(L) -> L2,
         rf: impl FnOnce(R) -> R2,
     ) -> AxumEither<L2, R2> {
-        self.map_left(lf).map_right(rf)
+        self.bimap(lf, rf)
+    }
+
+    /// Maps the left value using the fallible function `f`.
+    ///
+    /// The error of `f` is returned directly so it can be propagated with `?`, a right value is
+    /// passed through without calling `f`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::prelude::*;
+    /// let l: AxumEither<&str, bool> = AxumEither::Left("10");
+    /// assert_eq!(l.try_map_left(str::parse::<u8>), Ok(AxumEither::Left(10)));
+    /// let l: AxumEither<&str, bool> = AxumEither::Left("ten");
+    /// assert!(l.try_map_left(str::parse::<u8>).is_err());
+    /// let r: AxumEither<&str, bool> = AxumEither::Right(true);
+    /// assert_eq!(r.try_map_left(str::parse::<u8>), Ok(AxumEither::Right(true)));
+    /// ```
+    pub fn try_map_left<U, E>(
+        self,
+        f: impl FnOnce(L) -> Result<U, E>,
+    ) -> Result<AxumEither<U, R>, E> {
+        match self {
+            Self::Left(l) => f(l).map(AxumEither::Left),
+            Self::Right(r) => Ok(AxumEither::Right(r)),
+        }
+    }
+
+    /// Maps the right value using the fallible function `f`.
+    ///
+    /// The error of `f` is returned directly so it can be propagated with `?`, a left value is
+    /// passed through without calling `f`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::prelude::*;
+    /// let r: AxumEither<bool, &str> = AxumEither::Right("10");
+    /// assert_eq!(r.try_map_right(str::parse::<u8>), Ok(AxumEither::Right(10)));
+    /// let l: AxumEither<bool, &str> = AxumEither::Left(false);
+    /// assert_eq!(l.try_map_right(str::parse::<u8>), Ok(AxumEither::Left(false)));
+    /// ```
+    pub fn try_map_right<U, E>(
+        self,
+        f: impl FnOnce(R) -> Result<U, E>,
+    ) -> Result<AxumEither<L, U>, E> {
+        match self {
+            Self::Left(l) => Ok(AxumEither::Left(l)),
+            Self::Right(r) => f(r).map(AxumEither::Right),
+        }
+    }
+
+    /// Map both the left and right values with the fallible `lf` and `rf` functions.
+    ///
+    /// Only the function of the active side is called, both have to share the error type.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::prelude::*;
+    /// let l: AxumEither<&str, i64> = AxumEither::Left("10");
+    /// let mapped = l.try_map_lr(
+    ///     |s| s.parse::<u8>().map_err(|_| "not a number"),
+    ///     |n| u8::try_from(n).map_err(|_| "out of range"),
+    /// );
+    /// assert_eq!(mapped, Ok(AxumEither::Left(10)));
+    /// ```
+    pub fn try_map_lr<L2, R2, E>(
+        self,
+        lf: impl FnOnce(L) -> Result<L2, E>,
+        rf: impl FnOnce(R) -> Result<R2, E>,
+    ) -> Result<AxumEither<L2, R2>, E> {
+        match self {
+            Self::Left(l) => lf(l).map(AxumEither::Left),
+            Self::Right(r) => rf(r).map(AxumEither::Right),
+        }
+    }
+
+    /// Maps the left value using the async fallible function `f`.
+    ///
+    /// This is the async version of [`try_map_left`](Self::try_map_left), `f` is only called
+    /// and awaited for left values.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::prelude::*;
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// async fn enrich(id: u32) -> Result<String, &'static str> {
+    ///     Ok(format!("user {}", id))
+    /// }
+    ///
+    /// let l: AxumEither<u32, bool> = AxumEither::Left(1);
+    /// let l = l.try_map_left_async(enrich).await;
+    /// assert_eq!(l, Ok(AxumEither::Left("user 1".to_owned())));
+    /// # });
+    /// ```
+    pub async fn try_map_left_async<U, E, F>(
+        self,
+        f: impl FnOnce(L) -> F,
+    ) -> Result<AxumEither<U, R>, E>
+    where
+        F: Future<Output = Result<U, E>>,
+    {
+        match self {
+            Self::Left(l) => f(l).await.map(AxumEither::Left),
+            Self::Right(r) => Ok(AxumEither::Right(r)),
+        }
+    }
+
+    /// Maps the right value using the async fallible function `f`.
+    ///
+    /// This is the async version of [`try_map_right`](Self::try_map_right), `f` is only called
+    /// and awaited for right values.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::prelude::*;
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let r: AxumEither<bool, &str> = AxumEither::Right("ten");
+    /// let r = r.try_map_right_async(|s| async move { s.parse::<u8>() }).await;
+    /// assert!(r.is_err());
+    /// # });
+    /// ```
+    pub async fn try_map_right_async<U, E, F>(
+        self,
+        f: impl FnOnce(R) -> F,
+    ) -> Result<AxumEither<L, U>, E>
+    where
+        F: Future<Output = Result<U, E>>,
+    {
+        match self {
+            Self::Left(l) => Ok(AxumEither::Left(l)),
+            Self::Right(r) => f(r).await.map(AxumEither::Right),
+        }
+    }
+
+    /// Map both the left and right values with the async fallible `lf` and `rf` functions.
+    ///
+    /// Only the future of the active side is created and awaited, through the [`Future`] impl
+    /// of [`AxumEither`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::prelude::*;
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let l: AxumEither<&str, i64> = AxumEither::Left("10");
+    /// let mapped = l
+    ///     .try_map_lr_async(
+    ///         |s| async move { s.parse::<u8>().map_err(|_| "parse") },
+    ///         |i| async move { u8::try_from(i).map_err(|_| "range") },
+    ///     )
+    ///     .await;
+    /// assert_eq!(mapped, Ok(AxumEither::Left(10)));
+    /// # });
+    /// ```
+    pub async fn try_map_lr_async<L2, R2, E, LF, RF>(
+        self,
+        lf: impl FnOnce(L) -> LF,
+        rf: impl FnOnce(R) -> RF,
+    ) -> Result<AxumEither<L2, R2>, E>
+    where
+        LF: Future<Output = Result<L2, E>>,
+        RF: Future<Output = Result<R2, E>>,
+    {
+        self.map_lr(
+            |l| async move { lf(l).await.map(AxumEither::Left) },
+            |r| async move { rf(r).await.map(AxumEither::Right) },
+        )
+        .await
+    }
+
+    /// Build the response for the variant which was extracted.
+    ///
+    /// This is [`map_lr`](Self::map_lr) named for handlers which respond in the format of the
+    /// request, the response of each side can only be built from the value of that side.
+    ///
+    /// # Examples
+    /// ```
+    /// use axum::{response::Redirect, Form, Json};
+    /// use axum_either::prelude::*;
+    ///
+    /// #[derive(serde::Deserialize)]
+    /// pub struct Comment {
+    ///     text: String,
+    /// }
+    ///
+    /// pub async fn comment(
+    ///     request: AxumEither<Json<Comment>, Form<Comment>>,
+    /// ) -> AxumEither<Json<usize>, Redirect> {
+    ///     request.respond_matching(
+    ///         |Json(comment)| Json(comment.text.len()),
+    ///         |Form(_)| Redirect::to("/comments"),
+    ///     )
+    /// }
+    /// ```
+    pub fn respond_matching<LR, RR>(
+        self,
+        left: impl FnOnce(L) -> LR,
+        right: impl FnOnce(R) -> RR,
+    ) -> AxumEither<LR, RR> {
+        self.map_lr(left, right)
+    }
+
+    /// Wrap `l` into a successful left value, shorthand for `Ok(AxumEither::Left(l))`.
+    ///
+    /// # Examples
+    /// ```
+    /// use axum::{http::StatusCode, Json};
+    /// use axum_either::prelude::*;
+    ///
+    /// pub async fn user(id: u32) -> Result<AxumEither<Json<u32>, String>, StatusCode> {
+    ///     match id {
+    ///         0 => Err(StatusCode::NOT_FOUND),
+    ///         1 => AxumEither::ok_left(Json(id)),
+    ///         _ => AxumEither::ok_right(format!("user {}", id)),
+    ///     }
+    /// }
+    /// ```
+    pub fn ok_left<E>(l: L) -> Result<Self, E> {
+        Ok(Self::Left(l))
+    }
+
+    /// Wrap `r` into a successful right value, shorthand for `Ok(AxumEither::Right(r))`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::prelude::*;
+    /// let r: Result<AxumEither<u32, &str>, ()> = AxumEither::ok_right("two");
+    /// assert_eq!(r, Ok(AxumEither::Right("two")));
+    /// ```
+    pub fn ok_right<E>(r: R) -> Result<Self, E> {
+        Ok(Self::Right(r))
+    }
+
+    /// Whether this is a left value
+    ///
+    /// ```
+    /// # use axum_either::AxumEither;
+    /// let l: AxumEither<i32, bool> = AxumEither::Left(10);
+    /// assert!(l.is_left());
+    /// assert!(!l.is_right());
+    /// ```
+    pub fn is_left(&self) -> bool {
+        matches!(self, Self::Left(_))
+    }
+
+    /// Whether this is a right value
+    ///
+    /// ```
+    /// # use axum_either::AxumEither;
+    /// let r: AxumEither<i32, bool> = AxumEither::Right(false);
+    /// assert!(r.is_right());
+    /// assert!(!r.is_left());
+    /// ```
+    pub fn is_right(&self) -> bool {
+        matches!(self, Self::Right(_))
     }
 
     /// Extract the left value and discard the right value, Right maps to [`None`]
@@ -130,7 +727,7 @@ impl<L, R> AxumEither<L, R> {
     ///
     /// # Examples
     /// ```
-    /// # use axum_either::AxumEither;
+    /// # use axum_either::prelude::*;
     /// let l: AxumEither<i32, bool> = AxumEither::Left(10);
     /// assert!(l.right().is_none());
     /// let r: axum_either::one_of!(i32, bool) = AxumEither::Right(false);
@@ -147,7 +744,7 @@ impl<L, R> AxumEither<L, R> {
     ///
     /// # Examples
     /// ```
-    /// # use axum_either::AxumEither;
+    /// # use axum_either::prelude::*;
     /// let l: AxumEither<i32, bool> = AxumEither::Left(10);
     /// assert_eq!(l.into_either(), either::Either::Left(10));
     /// let r: axum_either::one_of!(i32, bool) = AxumEither::Right(false);
@@ -160,6 +757,245 @@ impl<L, R> AxumEither<L, R> {
             Self::Right(r) => either::Either::Right(r),
         }
     }
+
+    /// Move the left value to the heap, keeping a large left side from inflating the either.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::prelude::*;
+    /// let l: AxumEither<[u8; 64], bool> = AxumEither::Left([1; 64]);
+    /// assert_eq!(l.boxed_left(), AxumEither::Left(Box::new([1; 64])));
+    /// ```
+    pub fn boxed_left(self) -> AxumEither<Box<L>, R> {
+        self.map_left(Box::new)
+    }
+
+    /// Move the right value to the heap, keeping a large right side from inflating the either.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::prelude::*;
+    /// let r: AxumEither<bool, [u8; 64]> = AxumEither::Right([1; 64]);
+    /// assert_eq!(r.boxed_right(), AxumEither::Right(Box::new([1; 64])));
+    /// ```
+    pub fn boxed_right(self) -> AxumEither<L, Box<R>> {
+        self.map_right(Box::new)
+    }
+
+    /// Move the values of both sides to the heap, the either is then the size of a box.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::prelude::*;
+    /// let l: AxumEither<[u8; 64], [u16; 64]> = AxumEither::Left([1; 64]);
+    /// assert_eq!(l.boxed_lr(), AxumEither::Left(Box::new([1; 64])));
+    /// ```
+    pub fn boxed_lr(self) -> AxumEither<Box<L>, Box<R>> {
+        self.map_lr(Box::new, Box::new)
+    }
+
+    /// Borrow the values of both sides.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::prelude::*;
+    /// let l: AxumEither<String, u8> = AxumEither::Left("left".into());
+    /// assert_eq!(l.as_ref().map_lr(|s| s.len(), |_| 0), AxumEither::Left(4));
+    /// ```
+    pub fn as_ref(&self) -> AxumEither<&L, &R> {
+        match self {
+            Self::Left(l) => AxumEither::Left(l),
+            Self::Right(r) => AxumEither::Right(r),
+        }
+    }
+
+    /// Project a pinned either to its pinned side.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::prelude::*;
+    /// # use std::pin::Pin;
+    /// let either: AxumEither<u8, bool> = AxumEither::Right(true);
+    /// let pinned = Pin::new(&either).as_pin_ref();
+    /// assert!(matches!(pinned, AxumEither::Right(r) if *r));
+    /// ```
+    pub fn as_pin_ref(self: Pin<&Self>) -> AxumEither<Pin<&L>, Pin<&R>> {
+        // SAFETY: See `as_pin_mut`, a shared reference can not move the side either.
+        unsafe {
+            match self.get_ref() {
+                Self::Left(l) => AxumEither::Left(Pin::new_unchecked(l)),
+                Self::Right(r) => AxumEither::Right(Pin::new_unchecked(r)),
+            }
+        }
+    }
+
+    /// Project a pinned either to its pinned side mutably.
+    ///
+    /// This allows polling a future or stream stored in an either without unsafe code.
+    ///
+    /// # Pinning
+    /// The projection is sound because the sides are structurally pinned: `AxumEither` has no
+    /// `Drop` implementation which could move a side, it does not implement `Unpin` manually,
+    /// so it is only `Unpin` if both sides are, and no method moves a side out of a pinned
+    /// either. The enum is a plain Rust enum without `repr(packed)`, so the sides are never
+    /// moved to be aligned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::prelude::*;
+    /// # use std::pin::Pin;
+    /// let mut either: AxumEither<u8, bool> = AxumEither::Left(1);
+    /// let pinned = Pin::new(&mut either).as_pin_mut();
+    /// assert!(matches!(pinned, AxumEither::Left(_)));
+    /// ```
+    pub fn as_pin_mut(self: Pin<&mut Self>) -> AxumEither<Pin<&mut L>, Pin<&mut R>> {
+        // SAFETY: The sides are structurally pinned, see the pinning section above.
+        unsafe {
+            match self.get_unchecked_mut() {
+                Self::Left(l) => AxumEither::Left(Pin::new_unchecked(l)),
+                Self::Right(r) => AxumEither::Right(Pin::new_unchecked(r)),
+            }
+        }
+    }
+
+    /// Respond with a clone of the active variant, for example from a response cached in shared
+    /// state.
+    ///
+    /// Only the active variant is cloned. Store bodies as [`Bytes`](bytes::Bytes) to share them
+    /// between responses instead of copying them.
+    ///
+    /// # Examples
+    /// ```
+    /// use axum::{extract::Extension, response::{Html, Response}, Json};
+    /// use axum_either::prelude::*;
+    /// use bytes::Bytes;
+    /// use std::sync::Arc;
+    ///
+    /// pub async fn cached(
+    ///     Extension(page): Extension<Arc<AxumEither<Html<Bytes>, Json<u32>>>>,
+    /// ) -> Response {
+    ///     page.to_response()
+    /// }
+    /// ```
+    pub fn to_response(&self) -> Response
+    where
+        L: Clone + IntoResponse,
+        R: Clone + IntoResponse,
+    {
+        self.clone().into_response()
+    }
+
+    /// Insert `extension` into the response regardless of which variant is active, see
+    /// [`WithExtension`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::prelude::*;
+    /// # use axum_core::response::IntoResponse;
+    /// # use http::StatusCode;
+    /// #[derive(Clone, Debug, PartialEq)]
+    /// struct Cached(bool);
+    ///
+    /// let either: AxumEither<&str, StatusCode> = AxumEither::Left("hit");
+    /// let response = either.with_extension(Cached(true)).into_response();
+    /// assert_eq!(response.extensions().get(), Some(&Cached(true)));
+    /// ```
+    pub fn with_extension<T>(self, extension: T) -> WithExtension<Self, T>
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        WithExtension(self, extension)
+    }
+
+    /// Tag the response with the index of the variant which produced it, see [`Tagged`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::{AxumEither, VARIANT_HEADER};
+    /// # use axum_core::response::IntoResponse;
+    /// # use http::StatusCode;
+    /// let either: axum_either::one_of!(&str, String, StatusCode) =
+    ///     AxumEither::Right(AxumEither::Left("json".into()));
+    /// let response = either.tag_variant().into_response();
+    /// assert_eq!(response.headers()[VARIANT_HEADER], "1");
+    /// ```
+    pub fn tag_variant(self) -> Tagged<Self> {
+        Tagged(self)
+    }
+
+    /// Respond with `status` regardless of which variant is active, see [`WithStatus`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::prelude::*;
+    /// # use axum_core::response::IntoResponse;
+    /// # use http::StatusCode;
+    /// let either: AxumEither<&str, StatusCode> = AxumEither::Right(StatusCode::ACCEPTED);
+    /// let response = either.with_status(StatusCode::CREATED).into_response();
+    /// assert_eq!(response.status(), StatusCode::CREATED);
+    /// ```
+    pub fn with_status(self, status: StatusCode) -> WithStatus<Self> {
+        WithStatus(status, self)
+    }
+
+    /// Convert the active variant into a response and post-process it with `f`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::prelude::*;
+    /// # use http::{header, HeaderValue, StatusCode};
+    /// let either: AxumEither<&str, StatusCode> = AxumEither::Left("fresh");
+    /// let response = either.into_response_with(|mut response| {
+    ///     response
+    ///         .headers_mut()
+    ///         .insert(header::CACHE_CONTROL, HeaderValue::from_static("no-store"));
+    ///     response
+    /// });
+    /// assert_eq!(response.headers()[header::CACHE_CONTROL], "no-store");
+    /// ```
+    pub fn into_response_with(self, f: impl FnOnce(Response) -> Response) -> Response
+    where
+        L: IntoResponse,
+        R: IntoResponse,
+    {
+        f(self.into_response())
+    }
+
+    /// Build a rejection of both sides from the error of the active side.
+    ///
+    /// The error of the missing side is created lazily, `missing_left` is only called for a
+    /// right variant and `missing_right` only for a left variant. This is the inverse of
+    /// [`AxumEitherRejection::into_left_biased`] and
+    /// [`into_right_biased`](AxumEitherRejection::into_right_biased).
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::prelude::*;
+    /// # use http::StatusCode;
+    /// let error: AxumEither<StatusCode, StatusCode> = AxumEither::Left(StatusCode::BAD_REQUEST);
+    /// let rejection = error.into_rejection_with(
+    ///     || unreachable!(),
+    ///     || StatusCode::UNSUPPORTED_MEDIA_TYPE,
+    /// );
+    /// assert_eq!(
+    ///     rejection.into_parts(),
+    ///     (StatusCode::BAD_REQUEST, StatusCode::UNSUPPORTED_MEDIA_TYPE)
+    /// );
+    /// ```
+    pub fn into_rejection_with(
+        self,
+        missing_left: impl FnOnce() -> L,
+        missing_right: impl FnOnce() -> R,
+    ) -> AxumEitherRejection<L, R>
+    where
+        L: IntoResponse,
+        R: IntoResponse,
+    {
+        match self {
+            Self::Left(l) => AxumEitherRejection::new(l, missing_right()),
+            Self::Right(r) => AxumEitherRejection::new(missing_left(), r),
+        }
+    }
 }
 
 impl<T> AxumEither<T, T> {
@@ -167,7 +1003,7 @@ impl<T> AxumEither<T, T> {
     ///
     /// # Examples
     /// ```
-    /// # use axum_either::AxumEither;
+    /// # use axum_either::prelude::*;
     /// let l: AxumEither<i32, bool> = AxumEither::Left(10);
     /// let l = l.map_lr(|i| i * 10, |_b| unreachable!());
     /// assert_eq!(l.into_inner(), 100);
@@ -180,169 +1016,205 @@ impl<T> AxumEither<T, T> {
     }
 }
 
-#[async_trait::async_trait]
-impl<L, R, B> FromRequest<B> for AxumEither<L, R>
-where
-    L: FromRequest<B>,
-    L::Rejection: Send,
-    R: FromRequest<B>,
-    B: Send,
-{
-    type Rejection = AxumEitherRejection<L::Rejection, R::Rejection>;
+impl<L, R, E> AxumEither<Result<L, E>, Result<R, E>> {
+    /// Move the error of `result` into the left side.
+    ///
+    /// Successful values keep their side, an error becomes `Left(Err(e))`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::prelude::*;
+    /// let result: Result<AxumEither<u8, String>, &str> = Err("timeout");
+    /// let either = AxumEither::transpose_err_left(result);
+    /// assert_eq!(either, AxumEither::Left(Err("timeout")));
+    /// ```
+    pub fn transpose_err_left(result: Result<AxumEither<L, R>, E>) -> Self {
+        match result {
+            Ok(AxumEither::Left(l)) => Self::Left(Ok(l)),
+            Ok(AxumEither::Right(r)) => Self::Right(Ok(r)),
+            Err(e) => Self::Left(Err(e)),
+        }
+    }
 
-    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
-        let left_error = match L::from_request(req).await {
-            Ok(l) => return Ok(Self::Left(l)),
-            Err(e) => e,
-        };
+    /// Move the error of `result` into the right side.
+    ///
+    /// Successful values keep their side, an error becomes `Right(Err(e))`. This suits chains
+    /// where the right side is the fallback.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::prelude::*;
+    /// let result: Result<AxumEither<u8, String>, &str> = Err("timeout");
+    /// let either = AxumEither::transpose_err_right(result);
+    /// let len = axum_either::map_one_of! {either,
+    ///     l => l.map(usize::from),
+    ///     r => r.map(|s| s.len()),
+    /// };
+    /// assert_eq!(len, AxumEither::Right(Err("timeout")));
+    /// ```
+    pub fn transpose_err_right(result: Result<AxumEither<L, R>, E>) -> Self {
+        match result {
+            Ok(AxumEither::Left(l)) => Self::Left(Ok(l)),
+            Ok(AxumEither::Right(r)) => Self::Right(Ok(r)),
+            Err(e) => Self::Right(Err(e)),
+        }
+    }
+}
 
-        let right_error = match R::from_request(req).await {
-            Ok(r) => return Ok(Self::Right(r)),
-            Err(e) => e,
-        };
+impl<L, R> AxumEither<Box<L>, R> {
+    /// Move the left value out of its box, the inverse of [`boxed_left`](Self::boxed_left)
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::prelude::*;
+    /// let l: AxumEither<Box<i32>, bool> = AxumEither::Left(Box::new(10));
+    /// assert_eq!(l.unboxed_left(), AxumEither::Left(10));
+    /// ```
+    pub fn unboxed_left(self) -> AxumEither<L, R> {
+        self.map_left(|l| *l)
+    }
 
-        Err(AxumEitherRejection {
-            left_error,
-            right_error,
-        })
+    /// Maps the boxed left value using the given function `f`, the result is boxed again.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::prelude::*;
+    /// let l: AxumEither<Box<i32>, bool> = AxumEither::Left(Box::new(10));
+    /// assert_eq!(l.map_left_deref(|i| i * 10), AxumEither::Left(Box::new(100)));
+    /// ```
+    pub fn map_left_deref<U>(self, f: impl FnOnce(L) -> U) -> AxumEither<Box<U>, R> {
+        self.map_left(|l| Box::new(f(*l)))
     }
 }
 
-impl<L, R> IntoResponse for AxumEither<L, R>
+impl<L, R> AxumEither<L, Box<R>> {
+    /// Move the right value out of its box, the inverse of [`boxed_right`](Self::boxed_right)
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::prelude::*;
+    /// let r: AxumEither<i32, Box<bool>> = AxumEither::Right(Box::new(false));
+    /// assert_eq!(r.unboxed_right(), AxumEither::Right(false));
+    /// ```
+    pub fn unboxed_right(self) -> AxumEither<L, R> {
+        self.map_right(|r| *r)
+    }
+
+    /// Maps the boxed right value using the given function `f`, the result is boxed again.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::prelude::*;
+    /// let r: AxumEither<i32, Box<bool>> = AxumEither::Right(Box::new(false));
+    /// assert_eq!(r.map_right_deref(|b| !b), AxumEither::Right(Box::new(true)));
+    /// ```
+    pub fn map_right_deref<U>(self, f: impl FnOnce(R) -> U) -> AxumEither<L, Box<U>> {
+        self.map_right(|r| Box::new(f(*r)))
+    }
+}
+
+impl<L, R> AxumEither<Box<L>, Box<R>> {
+    /// Move the values of both sides out of their boxes, the inverse of
+    /// [`boxed_lr`](Self::boxed_lr)
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::prelude::*;
+    /// let l: AxumEither<Box<i32>, Box<bool>> = AxumEither::Left(Box::new(10));
+    /// assert_eq!(l.unboxed_lr(), AxumEither::Left(10));
+    /// ```
+    pub fn unboxed_lr(self) -> AxumEither<L, R> {
+        self.map_lr(|l| *l, |r| *r)
+    }
+}
+
+/// Polls the future of the active side.
+///
+/// # Examples
+/// ```
+/// # use axum_either::prelude::*;
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let future = if true {
+///     AxumEither::Left(async { 1 })
+/// } else {
+///     AxumEither::Right(async { 2 })
+/// };
+/// assert_eq!(future.await, 1);
+/// # });
+/// ```
+impl<L, R> Future for AxumEither<L, R>
 where
-    L: IntoResponse,
-    R: IntoResponse,
+    L: Future,
+    R: Future<Output = L::Output>,
 {
-    fn into_response(self) -> Response {
-        match self {
-            Self::Left(l) => l.into_response(),
-            Self::Right(r) => r.into_response(),
+    type Output = L::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match self.as_pin_mut() {
+            AxumEither::Left(l) => l.poll(cx),
+            AxumEither::Right(r) => r.poll(cx),
         }
     }
 }
 
-/// A rejection when both values of [`AxumEither`] are rejected while parsing.
-#[derive(Debug, Clone, Copy, Hash, Default, PartialEq, PartialOrd, Eq, Ord)]
-pub struct AxumEitherRejection<LE, RE>
+#[cfg(feature = "either")]
+impl<L, R> PartialEq<either::Either<L, R>> for AxumEither<L, R>
 where
-    LE: IntoResponse,
-    RE: IntoResponse,
+    L: PartialEq,
+    R: PartialEq,
 {
-    /// The error that occured while parsing the left variant
-    pub left_error: LE,
-    /// The error that occured while parsing the right variant
-    pub right_error: RE,
+    fn eq(&self, other: &either::Either<L, R>) -> bool {
+        match (self, other) {
+            (Self::Left(a), either::Either::Left(b)) => a == b,
+            (Self::Right(a), either::Either::Right(b)) => a == b,
+            _ => false,
+        }
+    }
 }
 
-impl<LE, RE> IntoResponse for AxumEitherRejection<LE, RE>
+#[cfg(feature = "either")]
+impl<L, R> PartialEq<AxumEither<L, R>> for either::Either<L, R>
 where
-    LE: IntoResponse,
-    RE: IntoResponse,
+    L: PartialEq,
+    R: PartialEq,
 {
-    fn into_response(self) -> Response {
-        let left_response = self.left_error.into_response();
-        let right_response = self.right_error.into_response();
-        let status = if left_response.status().is_server_error()
-            || right_response.status().is_server_error()
-        {
-            StatusCode::INTERNAL_SERVER_ERROR
-        } else {
-            StatusCode::BAD_REQUEST
-        };
-
-        (
-            status,
-            [(header::CONTENT_TYPE, HeaderValue::from_static("text/plain"))],
-            format!(
-                "Could not parse request\n\tleft error: {:?}\n\tright error: {:?}",
-                left_response, right_response
-            ),
-        )
-            .into_response()
+    fn eq(&self, other: &AxumEither<L, R>) -> bool {
+        other == self
     }
 }
 
-#[macro_export]
-/// Build a chain of axum eithers which may contain any of the given type.
-///
-/// # Examples
-/// ```
-/// # use axum_either::AxumEither;
-/// let val: AxumEither<i32, u32> = AxumEither::Left(0);
-/// let _val2: axum_either::one_of!(i32, u32) = val;
-/// let val: AxumEither<i32, AxumEither<u8, u32>> = AxumEither::Left(0);
-/// let _val2: axum_either::one_of!(i32, u8, u32) = val;
-/// let val: AxumEither<i32, AxumEither<u8, AxumEither<u16, u32>>> = AxumEither::Left(0);
-/// let _val2: axum_either::one_of!(i32, u8, u16, u32) = val;
-/// ```
-macro_rules! one_of {
-    ($t0:ty, $t1:ty) => {
-        AxumEither<$t0, $t1>
-    };
-    ($t0:ty, $($tleft:ty),+) => {
-        AxumEither<$t0, $crate::one_of!($($tleft),+)>
-    };
-}
-
-#[macro_export]
-/// Match a chain of [`AxumEither`]s from left to right
+/// Orders like the derived ordering, every left value is less than every right value.
 ///
 /// # Examples
 /// ```
-/// # use axum_either::AxumEither;
-/// let either: axum_either::one_of!(i32, u32, bool) = AxumEither::Right(AxumEither::Right(false));
-/// axum_either::match_one_of!{either,
-///     _val => unreachable!(),
-///     _val => unreachable!(),
-///     val => assert_eq!(val, false),
-/// };
+/// # use axum_either::prelude::*;
+/// let l: AxumEither<i32, i32> = AxumEither::Left(10);
+/// assert!(l < either::Either::Right(0));
+/// assert!(either::Either::Left(11) > l);
 /// ```
-macro_rules! match_one_of {
-    ($either:expr, $id0:pat => $expr0:expr, $id1:pat => $expr1:expr,) => {
-        match $either {
-            $crate::AxumEither::Left($id0) => $expr0,
-            $crate::AxumEither::Right($id1) => $expr1,
-        }
-    };
-    ($either:expr, $id0:pat => $expr0:expr, $($idleft:pat => $exprleft:expr,)+) => {
-        match $either {
-            $crate::AxumEither::Left($id0) => $expr0,
-            $crate::AxumEither::Right(eithers_left) => {
-                $crate::match_one_of!{eithers_left, $($idleft => $exprleft,)+}
-            }
+#[cfg(feature = "either")]
+impl<L, R> PartialOrd<either::Either<L, R>> for AxumEither<L, R>
+where
+    L: PartialOrd,
+    R: PartialOrd,
+{
+    fn partial_cmp(&self, other: &either::Either<L, R>) -> Option<std::cmp::Ordering> {
+        match (self, other) {
+            (Self::Left(a), either::Either::Left(b)) => a.partial_cmp(b),
+            (Self::Right(a), either::Either::Right(b)) => a.partial_cmp(b),
+            (Self::Left(_), either::Either::Right(_)) => Some(std::cmp::Ordering::Less),
+            (Self::Right(_), either::Either::Left(_)) => Some(std::cmp::Ordering::Greater),
         }
-    };
+    }
 }
 
-#[macro_export]
-/// Match a chain of [`AxumEither`]s from left to right and map them to an `AxumEither` directly.
-///
-/// # Examples
-/// ```
-/// # use axum_either::AxumEither;
-/// let either: axum_either::one_of!(i32, u32, u8) = AxumEither::Right(AxumEither::Right(32u8));
-/// let either = axum_either::map_one_of!{either,
-///     _val => unreachable!(),
-///     _val => unreachable!(),
-///     val => val + 100,
-/// };
-/// assert_eq!(either, AxumEither::Right(AxumEither::Right(132u8)));
-/// ```
-macro_rules! map_one_of {
-    ($either:expr, $id0:pat => $expr0:expr, $id1:pat => $expr1:expr,) => {
-        match $either {
-            $crate::AxumEither::Left($id0) => $crate::AxumEither::Left($expr0),
-            $crate::AxumEither::Right($id1) => $crate::AxumEither::Right($expr1),
-        }
-    };
-    ($either:expr, $id0:pat => $expr0:expr, $($idleft:pat => $exprleft:expr,)+) => {
-        match $either {
-            $crate::AxumEither::Left($id0) => $crate::AxumEither::Left($expr0),
-            $crate::AxumEither::Right(eithers_left) => {
-                $crate::AxumEither::Right(
-                    $crate::map_one_of!{eithers_left, $(($idleft) => $exprleft,)+}
-                )
-            }
-        }
-    };
+#[cfg(feature = "either")]
+impl<L, R> PartialOrd<AxumEither<L, R>> for either::Either<L, R>
+where
+    L: PartialOrd,
+    R: PartialOrd,
+{
+    fn partial_cmp(&self, other: &AxumEither<L, R>) -> Option<std::cmp::Ordering> {
+        other.partial_cmp(self).map(std::cmp::Ordering::reverse)
+    }
 }
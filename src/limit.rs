@@ -0,0 +1,287 @@
+//! Limit the body size per variant of an [`AxumEither`](crate::AxumEither).
+
+use axum_core::{
+    extract::{FromRequest, RequestParts},
+    response::{IntoResponse, Response},
+    BoxError,
+};
+use bytes::{BufMut, Bytes, BytesMut};
+use http::{header, HeaderMap, StatusCode};
+use http_body::{Body, LengthLimitError};
+use mime::Mime;
+use std::{
+    fmt,
+    ops::{Deref, DerefMut},
+};
+
+use crate::{media, AttemptRef, RejectionInfo, SupportedMediaTypes};
+
+/// Extracts `T` from a body of at most `BYTES` bytes.
+///
+/// The limit only applies to requests `T` is meant for: requests with a content type `T`
+/// [supports](SupportedMediaTypes::supports), or without a content type. Other requests are
+/// passed to `T` untouched, so each variant of an [`AxumEither`](crate::AxumEither) can have its
+/// own limit. A `Content-Length` above the limit is rejected without reading the body, otherwise
+/// the body is buffered up to the limit and handed to `T`.
+///
+/// Too large bodies are rejected with `413 Payload Too Large` and a message naming the content
+/// type, the body is lost in this case. Inside a plain [`AxumEither`](crate::AxumEither) this
+/// rejection is combined with the one of the other side like any other. As the body was already
+/// taken by the first side, the second side is always rejected with `BodyAlreadyExtracted`
+/// after a `413` on the first side, even if it could have parsed the body. Wrap the either in
+/// [`Dispatch`](crate::Dispatch) to only try the variant claiming the content type and respond
+/// with its `413`. Limits applied elsewhere, like axum's `ContentLengthLimit` or a
+/// `RequestBodyLimit` layer, still apply, the smaller limit wins.
+///
+/// # Examples
+/// ```
+/// use axum::{Form, Json};
+/// use axum_either::{AxumEither, Dispatch, Limited};
+///
+/// #[derive(serde::Deserialize)]
+/// pub struct Upload {
+///     data: String,
+/// }
+///
+/// /// Json up to 1 MiB, forms up to 64 KiB
+/// pub async fn upload(
+///     Dispatch(upload): Dispatch<
+///         AxumEither<Limited<Json<Upload>, 1_048_576>, Limited<Form<Upload>, 65_536>>,
+///     >,
+/// ) -> String {
+///     upload
+///         .map_lr(|Limited(Json(u))| u.data, |Limited(Form(u))| u.data)
+///         .into_inner()
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Hash, Default, PartialEq, PartialOrd, Eq, Ord)]
+pub struct Limited<T, const BYTES: usize>(pub T);
+
+impl<T, const BYTES: usize> Limited<T, BYTES> {
+    /// The limit in bytes
+    pub const LIMIT: usize = BYTES;
+
+    /// The extracted value
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T, const BYTES: usize> Deref for Limited<T, BYTES> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, const BYTES: usize> DerefMut for Limited<T, BYTES> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T, const BYTES: usize> SupportedMediaTypes for Limited<T, BYTES>
+where
+    T: SupportedMediaTypes,
+{
+    fn supported_media_types() -> &'static [Mime] {
+        T::supported_media_types()
+    }
+
+    fn supports(content_type: &Mime) -> bool {
+        T::supports(content_type)
+    }
+}
+
+#[async_trait::async_trait]
+impl<T, B, const BYTES: usize> FromRequest<B> for Limited<T, BYTES>
+where
+    T: FromRequest<B> + SupportedMediaTypes,
+    B: Body + From<Bytes> + Send,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+{
+    type Rejection = LimitedRejection<T::Rejection>;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        let content_type = media::content_type(req.headers());
+        let media_type = match &content_type {
+            Some(content_type) if !T::supports(content_type) => {
+                return T::from_request(req)
+                    .await
+                    .map(Self)
+                    .map_err(LimitedRejection::Inner);
+            }
+            Some(content_type) => Some(content_type.essence_str().to_owned()),
+            None => None,
+        };
+
+        let too_large = || LimitedRejection::PayloadTooLarge {
+            media_type: media_type.clone(),
+            limit: BYTES,
+        };
+        let content_length = req
+            .headers()
+            .get(header::CONTENT_LENGTH)
+            .and_then(|length| length.to_str().ok())
+            .and_then(|length| length.parse::<u64>().ok());
+        if content_length.is_some_and(|length| length > BYTES as u64) {
+            return Err(too_large());
+        }
+
+        if let Some(body) = req.take_body() {
+            let bytes = match buffer(body, BYTES).await {
+                Ok(bytes) => bytes,
+                Err(error) if error.is::<LengthLimitError>() => return Err(too_large()),
+                Err(error) => return Err(LimitedRejection::Body(error)),
+            };
+            *req.body_mut() = Some(B::from(bytes));
+        }
+
+        T::from_request(req)
+            .await
+            .map(Self)
+            .map_err(LimitedRejection::Inner)
+    }
+}
+
+/// Buffer `body`, failing with [`LengthLimitError`] once it is longer than `limit`
+async fn buffer<B>(body: B, limit: usize) -> Result<Bytes, BoxError>
+where
+    B: Body + Send,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+{
+    let mut body = Box::pin(http_body::Limited::new(body, limit));
+    let mut bytes = BytesMut::new();
+    while let Some(chunk) = body.data().await {
+        bytes.put(chunk?);
+    }
+    Ok(bytes.freeze())
+}
+
+/// The rejection of [`Limited`]
+#[derive(Debug)]
+pub enum LimitedRejection<E> {
+    /// The body is larger than the limit
+    PayloadTooLarge {
+        /// The essence of the content type, `None` if the request had none
+        media_type: Option<String>,
+        /// The limit in bytes
+        limit: usize,
+    },
+    /// The body could not be buffered
+    Body(BoxError),
+    /// The inner extractor rejected the request
+    Inner(E),
+}
+
+impl<E> LimitedRejection<E> {
+    /// The message of the rejections of [`Limited`] itself, `None` for inner rejections
+    fn own_message(&self) -> Option<String> {
+        match self {
+            Self::PayloadTooLarge {
+                media_type: Some(media_type),
+                limit,
+            } => Some(format!(
+                "The {} body exceeds the limit of {} bytes",
+                media_type, limit
+            )),
+            Self::PayloadTooLarge {
+                media_type: None,
+                limit,
+            } => Some(format!("The body exceeds the limit of {} bytes", limit)),
+            Self::Body(error) => Some(format!("Failed to buffer the body: {}", error)),
+            Self::Inner(_) => None,
+        }
+    }
+}
+
+impl<E> fmt::Display for LimitedRejection<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Inner(rejection) => fmt::Display::fmt(rejection, f),
+            _ => f.write_str(&self.own_message().unwrap_or_default()),
+        }
+    }
+}
+
+impl<E> std::error::Error for LimitedRejection<E>
+where
+    E: std::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::PayloadTooLarge { .. } => None,
+            Self::Body(error) => Some(&**error),
+            Self::Inner(rejection) => Some(rejection),
+        }
+    }
+}
+
+impl<E> IntoResponse for LimitedRejection<E>
+where
+    E: IntoResponse,
+{
+    fn into_response(self) -> Response {
+        match self {
+            Self::Inner(rejection) => rejection.into_response(),
+            Self::PayloadTooLarge { .. } => (
+                StatusCode::PAYLOAD_TOO_LARGE,
+                self.own_message().unwrap_or_default(),
+            )
+                .into_response(),
+            Self::Body(_) => (
+                StatusCode::BAD_REQUEST,
+                self.own_message().unwrap_or_default(),
+            )
+                .into_response(),
+        }
+    }
+}
+
+impl<E> RejectionInfo for LimitedRejection<E>
+where
+    E: RejectionInfo,
+{
+    fn status(&self) -> StatusCode {
+        match self {
+            Self::PayloadTooLarge { .. } => StatusCode::PAYLOAD_TOO_LARGE,
+            Self::Body(_) => StatusCode::BAD_REQUEST,
+            Self::Inner(rejection) => rejection.status(),
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            Self::Inner(rejection) => rejection.message(),
+            _ => self.own_message().unwrap_or_default(),
+        }
+    }
+
+    fn source_error(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::PayloadTooLarge { .. } => None,
+            Self::Body(error) => Some(&**error),
+            Self::Inner(rejection) => rejection.source_error(),
+        }
+    }
+
+    fn headers(&self) -> HeaderMap {
+        match self {
+            Self::Inner(rejection) => rejection.headers(),
+            _ => crate::info::plain_text_headers(),
+        }
+    }
+
+    fn visit_attempts<'a>(&'a self, f: &mut dyn FnMut(AttemptRef<'a>)) {
+        match self {
+            Self::Inner(rejection) => rejection.visit_attempts(f),
+            _ => f(AttemptRef::new(self)),
+        }
+    }
+}
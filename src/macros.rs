@@ -0,0 +1,373 @@
+//! Macros to build and use chains of [`AxumEither`](crate::AxumEither).
+
+#[macro_export]
+/// Build a chain of axum eithers which may contain any of the given type.
+///
+/// # Examples
+/// ```
+/// # use axum_either::prelude::*;
+/// let val: AxumEither<i32, u32> = AxumEither::Left(0);
+/// let _val2: axum_either::one_of!(i32, u32) = val;
+/// let val: AxumEither<i32, AxumEither<u8, u32>> = AxumEither::Left(0);
+/// let _val2: axum_either::one_of!(i32, u8, u32) = val;
+/// let val: AxumEither<i32, AxumEither<u8, AxumEither<u16, u32>>> = AxumEither::Left(0);
+/// let _val2: axum_either::one_of!(i32, u8, u16, u32) = val;
+/// ```
+macro_rules! one_of {
+    () => {
+        compile_error!("one_of! needs at least two types")
+    };
+    ($t0:ty $(,)?) => {
+        compile_error!("one_of! needs at least two types, use the type directly for a single one")
+    };
+    ($t0:ty, $t1:ty $(,)?) => {
+        $crate::AxumEither<$t0, $t1>
+    };
+    ($t0:ty, $($tleft:ty),+ $(,)?) => {
+        $crate::AxumEither<$t0, $crate::one_of!($($tleft),+)>
+    };
+    ($($tokens:tt)*) => {
+        compile_error!("expected `one_of!(Type, Type, ...)`, a comma separated list of types")
+    };
+}
+
+#[macro_export]
+/// Match a chain of [`AxumEither`]s from left to right
+///
+/// There has to be one arm for every type of the chain, the comma after the last arm is optional.
+/// Like in a `match`, the comma after an arm whose body is a block may be left out. Guards are
+/// not supported.
+///
+/// The chain is matched with nested `match` expressions, so `return`, `?`, `break` and
+/// `continue` in any arm behave as in a `match` written by hand.
+///
+/// # Examples
+/// ```
+/// # use axum_either::prelude::*;
+/// let either: axum_either::one_of!(i32, u32, bool) = AxumEither::Right(AxumEither::Right(false));
+/// axum_either::match_one_of!{either,
+///     _val => unreachable!(),
+///     _val => unreachable!(),
+///     val => assert_eq!(val, false),
+/// };
+/// ```
+///
+/// ```
+/// # use axum_either::prelude::*;
+/// fn parse(either: axum_either::one_of!(i32, &str, bool)) -> Result<i64, std::num::ParseIntError> {
+///     let value = axum_either::match_one_of!{either,
+///         int => i64::from(int),
+///         text => {
+///             let trimmed = text.trim();
+///             if trimmed.is_empty() {
+///                 return Ok(0);
+///             }
+///             trimmed.parse()?
+///         }
+///         flag => i64::from(flag),
+///     };
+///     Ok(value)
+/// }
+///
+/// assert_eq!(parse(AxumEither::Right(AxumEither::Left(" 12 "))), Ok(12));
+/// assert_eq!(parse(AxumEither::Right(AxumEither::Left(""))), Ok(0));
+/// assert!(parse(AxumEither::Right(AxumEither::Left("x"))).is_err());
+/// ```
+macro_rules! match_one_of {
+    (@arms $either:expr, [$($arms:tt)*]) => {
+        $crate::match_one_of!{$either, $($arms)*}
+    };
+    (@arms $either:expr, [$($arms:tt)*] $id:pat => $expr:expr, $($rest:tt)*) => {
+        $crate::match_one_of!{@arms $either, [$($arms)* $id => $expr,] $($rest)*}
+    };
+    (@arms $either:expr, [$($arms:tt)*] $id:pat => $block:block $($rest:tt)*) => {
+        $crate::match_one_of!{@arms $either, [$($arms)* $id => $block,] $($rest)*}
+    };
+    (@arms $either:expr, [$($arms:tt)*] $id:pat => $expr:expr) => {
+        $crate::match_one_of!{@arms $either, [$($arms)* $id => $expr,]}
+    };
+    ($either:expr, $id0:pat => $expr0:expr, $id1:pat => $expr1:expr,) => {
+        match $either {
+            $crate::AxumEither::Left($id0) => $expr0,
+            $crate::AxumEither::Right($id1) => $expr1,
+        }
+    };
+    ($either:expr, $id0:pat => $expr0:expr, $($idleft:pat => $exprleft:expr,)+) => {
+        match $either {
+            $crate::AxumEither::Left($id0) => $expr0,
+            $crate::AxumEither::Right(eithers_left) => {
+                $crate::match_one_of!{eithers_left, $($idleft => $exprleft,)+}
+            }
+        }
+    };
+    ($either:expr, $($id:pat => $expr:expr),+) => {
+        $crate::match_one_of!{$either, $($id => $expr,)+}
+    };
+    ($either:expr $(, $id0:pat => $expr0:expr)? $(,)?) => {
+        compile_error!("match_one_of! needs an arm for every type of the chain, at least two")
+    };
+    ($either:expr, $($arms:tt)+) => {
+        $crate::match_one_of!{@arms $either, [] $($arms)+}
+    };
+    ($($tokens:tt)*) => {
+        compile_error!(
+            "expected `match_one_of!{either, pattern => expression, ...}`, arms without a block \
+             body need a trailing comma and guards are not supported"
+        )
+    };
+}
+
+#[macro_export]
+/// Match a chain of [`AxumEither`]s from left to right and map them to an `AxumEither` directly.
+///
+/// The arms follow the same rules as in [`match_one_of`], but every arm except the last needs a
+/// trailing comma.
+///
+/// # Examples
+/// ```
+/// # use axum_either::prelude::*;
+/// let either: axum_either::one_of!(i32, u32, u8) = AxumEither::Right(AxumEither::Right(32u8));
+/// let either = axum_either::map_one_of!{either,
+///     _val => unreachable!(),
+///     _val => unreachable!(),
+///     val => val + 100,
+/// };
+/// assert_eq!(either, AxumEither::Right(AxumEither::Right(132u8)));
+/// ```
+///
+/// Every arm can name the position of its value in the output chain instead, written as
+/// `pattern => position: expression`. The output chain may then have fewer types than the input,
+/// several arms can map to the same position. The output type has to be known, positions range
+/// from 0 to 7.
+///
+/// ```
+/// # use axum_either::prelude::*;
+/// let either: axum_either::one_of!(i32, u32, &str) = AxumEither::Right(AxumEither::Left(3));
+/// let either: AxumEither<i64, String> = axum_either::map_one_of!{either,
+///     i => 0: i64::from(i),
+///     u => 0: i64::from(u),
+///     s => 1: s.to_owned(),
+/// };
+/// assert_eq!(either, AxumEither::Left(3));
+/// ```
+///
+/// Positions which are not part of the output chain do not compile
+/// ```compile_fail
+/// # use axum_either::prelude::*;
+/// let either: AxumEither<i32, u32> = AxumEither::Left(3);
+/// let either: AxumEither<i64, String> = axum_either::map_one_of!{either,
+///     i => 0: i64::from(i),
+///     u => 2: u.to_string(),
+/// };
+/// ```
+macro_rules! map_one_of {
+    ($either:expr, $($id:pat => $position:tt : $expr:expr),+ $(,)?) => {
+        $crate::match_one_of!{$either,
+            $($id => <_ as $crate::convert::Inject<_, $crate::__one_of_position!($position)>>::inject(
+                $expr
+            ),)+
+        }
+    };
+    ($either:expr, $id0:pat => $expr0:expr, $id1:pat => $expr1:expr,) => {
+        match $either {
+            $crate::AxumEither::Left($id0) => $crate::AxumEither::Left($expr0),
+            $crate::AxumEither::Right($id1) => $crate::AxumEither::Right($expr1),
+        }
+    };
+    ($either:expr, $id0:pat => $expr0:expr, $($idleft:pat => $exprleft:expr,)+) => {
+        match $either {
+            $crate::AxumEither::Left($id0) => $crate::AxumEither::Left($expr0),
+            $crate::AxumEither::Right(eithers_left) => {
+                $crate::AxumEither::Right(
+                    $crate::map_one_of!{eithers_left, $(($idleft) => $exprleft,)+}
+                )
+            }
+        }
+    };
+    ($either:expr, $($id:pat => $expr:expr),+) => {
+        $crate::map_one_of!{$either, $($id => $expr,)+}
+    };
+    ($either:expr $(, $id0:pat => $expr0:expr)? $(,)?) => {
+        compile_error!("map_one_of! needs an arm for every type of the chain, at least two")
+    };
+    ($($tokens:tt)*) => {
+        compile_error!(
+            "expected `map_one_of!{either, pattern => expression, ...}`, arms need a trailing \
+             comma and guards are not supported"
+        )
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+/// The index of the position `$position` of a chain for [`Inject`](crate::convert::Inject)
+macro_rules! __one_of_position {
+    (0) => { $crate::convert::Here };
+    (1) => { $crate::convert::There<$crate::__one_of_position!(0)> };
+    (2) => { $crate::convert::There<$crate::__one_of_position!(1)> };
+    (3) => { $crate::convert::There<$crate::__one_of_position!(2)> };
+    (4) => { $crate::convert::There<$crate::__one_of_position!(3)> };
+    (5) => { $crate::convert::There<$crate::__one_of_position!(4)> };
+    (6) => { $crate::convert::There<$crate::__one_of_position!(5)> };
+    (7) => { $crate::convert::There<$crate::__one_of_position!(6)> };
+    ($position:tt) => {
+        compile_error!(concat!(
+            "map_one_of! output positions range from 0 to 7, found ",
+            stringify!($position)
+        ))
+    };
+}
+
+#[cfg(feature = "define-one-of")]
+#[macro_export]
+/// Define a named [`one_of`] chain, optionally with a label for every type.
+///
+/// `define_one_of!(pub Body = Json<Req>, Form<Req>, String)` is the alias
+/// `pub type Body = one_of!(Json<Req>, Form<Req>, String)`. If every type has a label, as in
+/// `json: Json<Req>`, the trait `BodyExt` with accessors named after the labels is defined and
+/// implemented for the alias. For the label `json` these are
+///
+/// - `is_json(&self) -> bool`
+/// - `as_json(&self) -> Option<&Json<Req>>`
+/// - `as_json_mut(&mut self) -> Option<&mut Json<Req>>`
+/// - `into_json(self) -> Option<Json<Req>>`
+///
+/// The trait has the visibility of the alias and has to be imported to use the accessors.
+///
+/// # Examples
+/// ```
+/// use axum::{Form, Json};
+/// use axum_either::{define_one_of, AxumEither};
+///
+/// #[derive(serde::Deserialize)]
+/// pub struct Req {
+///     id: u32,
+/// }
+///
+/// define_one_of!(pub Body = json: Json<Req>, form: Form<Req>, raw: String;);
+///
+/// pub async fn create(body: Body) -> String {
+///     if let Some(Json(req)) = body.as_json() {
+///         return format!("json {}", req.id);
+///     }
+///     match body.into_raw() {
+///         Some(raw) => raw,
+///         None => "form".to_owned(),
+///     }
+/// }
+///
+/// let body: Body = AxumEither::Right(AxumEither::Right("raw".to_owned()));
+/// assert!(body.is_raw());
+/// assert!(!body.is_json());
+/// ```
+macro_rules! define_one_of {
+    (
+        $(#[$meta:meta])*
+        $vis:vis $name:ident = $($label:ident : $t:ty),+ $(,)? $(;)?
+    ) => {
+        $(#[$meta])*
+        $vis type $name = $crate::one_of!($($t),+);
+
+        $crate::macros::__paste! {
+            #[doc = concat!("The accessors of the labeled types of [`", stringify!($name), "`]")]
+            $vis trait [<$name Ext>] {
+                $(
+                    #[doc = concat!("Whether the value is a `", stringify!($label), "`")]
+                    fn [<is_ $label>](&self) -> bool;
+
+                    #[doc = concat!("Borrow the `", stringify!($label), "` value")]
+                    fn [<as_ $label>](&self) -> Option<&$t>;
+
+                    #[doc = concat!("Borrow the `", stringify!($label), "` value mutably")]
+                    fn [<as_ $label _mut>](&mut self) -> Option<&mut $t>;
+
+                    #[doc = concat!("Take the `", stringify!($label), "` value")]
+                    fn [<into_ $label>](self) -> Option<$t>;
+                )+
+            }
+
+            impl [<$name Ext>] for $name {
+                $crate::__define_one_of_accessors!{[] $($label: $t),+}
+            }
+        }
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis $name:ident = $($t:ty),+ $(,)? $(;)?
+    ) => {
+        $(#[$meta])*
+        $vis type $name = $crate::one_of!($($t),+);
+    };
+    ($($tokens:tt)*) => {
+        compile_error!(
+            "expected `define_one_of!(Name = Type, Type, ...)` or \
+             `define_one_of!(Name = label: Type, label: Type, ...)`"
+        )
+    };
+}
+
+#[cfg(feature = "define-one-of")]
+#[doc(hidden)]
+#[macro_export]
+/// The accessors of [`define_one_of`], `$path` leads to the position of the current label
+macro_rules! __define_one_of_accessors {
+    ([$($path:ident)*] $label:ident : $t:ty) => {
+        $crate::__define_one_of_accessors!{@methods [$($path)*] $label: $t}
+    };
+    ([$($path:ident)*] $label:ident : $t:ty, $($rest:tt)+) => {
+        $crate::__define_one_of_accessors!{@methods [$($path)* Left] $label: $t}
+        $crate::__define_one_of_accessors!{[$($path)* Right] $($rest)+}
+    };
+    (@methods [$($path:ident)*] $label:ident : $t:ty) => {
+        $crate::macros::__paste! {
+            fn [<is_ $label>](&self) -> bool {
+                matches!(self, $crate::__one_of_pattern!(_value; $($path)*))
+            }
+
+            fn [<as_ $label>](&self) -> Option<&$t> {
+                match self {
+                    $crate::__one_of_pattern!(value; $($path)*) => Some(value),
+                    _ => None,
+                }
+            }
+
+            fn [<as_ $label _mut>](&mut self) -> Option<&mut $t> {
+                match self {
+                    $crate::__one_of_pattern!(value; $($path)*) => Some(value),
+                    _ => None,
+                }
+            }
+
+            fn [<into_ $label>](self) -> Option<$t> {
+                match self {
+                    $crate::__one_of_pattern!(value; $($path)*) => Some(value),
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "define-one-of")]
+#[doc(hidden)]
+#[macro_export]
+/// The pattern binding `$binding` at the end of `$path`, a list of `Left` and `Right` steps
+macro_rules! __one_of_pattern {
+    ($binding:ident;) => {
+        $binding
+    };
+    ($binding:ident; Left) => {
+        $crate::AxumEither::Left($binding)
+    };
+    ($binding:ident; Right $($path:ident)*) => {
+        $crate::AxumEither::Right($crate::__one_of_pattern!($binding; $($path)*))
+    };
+}
+
+#[cfg(feature = "define-one-of")]
+#[doc(hidden)]
+pub use paste::paste as __paste;
+
+#[cfg(feature = "define-one-of")]
+pub use crate::define_one_of;
+pub use crate::{map_one_of, match_one_of, one_of};
@@ -0,0 +1,89 @@
+//! Map the sides of [`AxumEither`] and the flat enums through a common trait.
+
+use crate::{AxumEither, OneOf3, OneOf4, OneOf5, OneOf6, OneOf7, OneOf8};
+
+/// Types with a left and a right side which can be mapped independently.
+///
+/// This is implemented for [`AxumEither`] and the flat `OneOfN` enums, so generic code can be
+/// written once for both. The left side of a flat enum is its first variant and the right side
+/// is the [`one_of`](crate::one_of) chain of the remaining variants, like in the equivalent chain.
+///
+/// # Examples
+/// ```
+/// use axum_either::{prelude::*, EitherMap};
+/// use std::fmt::Debug;
+///
+/// fn describe<E>(either: E) -> String
+/// where
+///     E: EitherMap,
+///     E::Left: Debug,
+///     E::Right: Debug,
+/// {
+///     either
+///         .bimap(|l| format!("first {:?}", l), |r| format!("rest {:?}", r))
+///         .into_inner()
+/// }
+///
+/// let pair: AxumEither<u8, bool> = AxumEither::Right(true);
+/// assert_eq!(describe(pair), "rest true");
+/// let flat: OneOf3<u8, bool, char> = OneOf3::V0(3);
+/// assert_eq!(describe(flat), "first 3");
+/// ```
+pub trait EitherMap: Sized {
+    /// The type of the left side
+    type Left;
+    /// The type of the right side
+    type Right;
+
+    /// Map the active side with `lf` or `rf`, only the function of the active side is called
+    fn bimap<L2, R2>(
+        self,
+        lf: impl FnOnce(Self::Left) -> L2,
+        rf: impl FnOnce(Self::Right) -> R2,
+    ) -> AxumEither<L2, R2>;
+}
+
+impl<L, R> EitherMap for AxumEither<L, R> {
+    type Left = L;
+    type Right = R;
+
+    fn bimap<L2, R2>(
+        self,
+        lf: impl FnOnce(L) -> L2,
+        rf: impl FnOnce(R) -> R2,
+    ) -> AxumEither<L2, R2> {
+        match self {
+            Self::Left(l) => AxumEither::Left(lf(l)),
+            Self::Right(r) => AxumEither::Right(rf(r)),
+        }
+    }
+}
+
+/// Implement [`EitherMap`] for the flat enum `$name` through its equivalent chain
+macro_rules! either_map {
+    ($($name:ident<$t0:ident, $($t:ident),+>),+) => {
+        $(
+            impl<$t0, $($t),+> EitherMap for $name<$t0, $($t),+> {
+                type Left = $t0;
+                type Right = crate::one_of!($($t),+);
+
+                fn bimap<L2, R2>(
+                    self,
+                    lf: impl FnOnce(Self::Left) -> L2,
+                    rf: impl FnOnce(Self::Right) -> R2,
+                ) -> AxumEither<L2, R2> {
+                    <crate::one_of!($t0, $($t),+)>::from(self).bimap(lf, rf)
+                }
+            }
+        )+
+    };
+}
+
+either_map!(
+    OneOf3<T0, T1, T2>,
+    OneOf4<T0, T1, T2, T3>,
+    OneOf5<T0, T1, T2, T3, T4>,
+    OneOf6<T0, T1, T2, T3, T4, T5>,
+    OneOf7<T0, T1, T2, T3, T4, T5, T6>,
+    OneOf8<T0, T1, T2, T3, T4, T5, T6, T7>
+);
@@ -0,0 +1,216 @@
+//! The media types extractors accept, used to dispatch requests by their content type.
+//!
+//! Implement [`SupportedMediaTypes`] for custom extractors, every [`AxumEither`] of such
+//! extractors then implements [`MediaTypeDispatch`].
+//!
+//! # Examples
+//! ```
+//! use axum_either::{AxumEither, MediaTypeDispatch, SupportedMediaTypes};
+//! use mime::Mime;
+//!
+//! pub struct Csv(String);
+//!
+//! impl SupportedMediaTypes for Csv {
+//!     fn supported_media_types() -> &'static [Mime] {
+//!         const CSV: &[Mime] = &[mime::TEXT_CSV];
+//!         CSV
+//!     }
+//! }
+//!
+//! let claims = AxumEither::<String, Csv>::claims(&mime::TEXT_CSV);
+//! assert_eq!(claims, [1]);
+//! ```
+
+use axum_core::extract::{FromRequest, RequestParts};
+use bytes::Bytes;
+use http::{header, HeaderMap, HeaderValue};
+use mime::Mime;
+
+use crate::AxumEither;
+
+/// The media types an extractor accepts in the `Content-Type` header of a request.
+pub trait SupportedMediaTypes {
+    /// The supported media types, `*` may be used for any type or subtype
+    fn supported_media_types() -> &'static [Mime];
+
+    /// Whether `content_type` is supported, parameters like the charset are ignored.
+    ///
+    /// The default implementation compares with [`supported_media_types`](Self::supported_media_types).
+    fn supports(content_type: &Mime) -> bool {
+        Self::supported_media_types()
+            .iter()
+            .any(|supported| media_type_matches(supported, content_type))
+    }
+}
+
+/// The parsed `Content-Type` of a request, `None` if the header is missing or invalid
+pub(crate) fn content_type(headers: &HeaderMap) -> Option<Mime> {
+    headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|content_type| content_type.to_str().ok())
+        .and_then(|content_type| content_type.parse().ok())
+}
+
+pub(crate) fn media_type_matches(supported: &Mime, content_type: &Mime) -> bool {
+    (supported.type_() == mime::STAR || supported.type_() == content_type.type_())
+        && (supported.subtype() == mime::STAR || supported.subtype() == content_type.subtype())
+}
+
+/// `application/json` and every type with the `+json` suffix, like `application/problem+json`
+pub(crate) fn is_json(content_type: &Mime) -> bool {
+    content_type.type_() == mime::APPLICATION
+        && (content_type.subtype() == mime::JSON || content_type.suffix() == Some(mime::JSON))
+}
+
+impl SupportedMediaTypes for String {
+    fn supported_media_types() -> &'static [Mime] {
+        const TEXT: &[Mime] = &[mime::TEXT_PLAIN];
+        TEXT
+    }
+}
+
+/// Raw bytes accept any media type
+impl SupportedMediaTypes for Bytes {
+    fn supported_media_types() -> &'static [Mime] {
+        const ANY: &[Mime] = &[mime::STAR_STAR];
+        ANY
+    }
+}
+
+#[cfg(feature = "axum")]
+mod axum_extractors {
+    use axum::{Form, Json};
+    use mime::Mime;
+
+    use super::{is_json, SupportedMediaTypes};
+
+    /// `application/json` and every type with the `+json` suffix, like `application/problem+json`
+    impl<T> SupportedMediaTypes for Json<T> {
+        fn supported_media_types() -> &'static [Mime] {
+            const JSON: &[Mime] = &[mime::APPLICATION_JSON];
+            JSON
+        }
+
+        fn supports(content_type: &Mime) -> bool {
+            is_json(content_type)
+        }
+    }
+
+    impl<T> SupportedMediaTypes for Form<T> {
+        fn supported_media_types() -> &'static [Mime] {
+            const FORM: &[Mime] = &[mime::APPLICATION_WWW_FORM_URLENCODED];
+            FORM
+        }
+    }
+}
+
+/// Finds the variants of an extractor which accept a content type.
+///
+/// This is implemented for every [`SupportedMediaTypes`] extractor and for [`AxumEither`] of
+/// them, the variants of nested chains are flattened.
+pub trait MediaTypeDispatch {
+    /// The number of flattened variants
+    const VARIANTS: usize;
+
+    /// The flattened indices of the variants accepting `content_type`, in the order they are
+    /// tried
+    fn claims(content_type: &Mime) -> Vec<usize>;
+}
+
+impl<T> MediaTypeDispatch for T
+where
+    T: SupportedMediaTypes,
+{
+    const VARIANTS: usize = 1;
+
+    fn claims(content_type: &Mime) -> Vec<usize> {
+        if T::supports(content_type) {
+            vec![0]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+impl<L, R> MediaTypeDispatch for AxumEither<L, R>
+where
+    L: MediaTypeDispatch,
+    R: MediaTypeDispatch,
+{
+    const VARIANTS: usize = L::VARIANTS + R::VARIANTS;
+
+    fn claims(content_type: &Mime) -> Vec<usize> {
+        let mut claims = L::claims(content_type);
+        claims.extend(
+            R::claims(content_type)
+                .into_iter()
+                .map(|index| index + L::VARIANTS),
+        );
+        claims
+    }
+}
+
+/// Extracts `T` together with the `Content-Type` header the client sent.
+///
+/// The header value is kept as it was sent, including parameters like the `charset` or the
+/// `boundary`. It is captured before `T` is extracted, the rejection is the one of `T`.
+///
+/// # Examples
+/// ```
+/// use axum::{Form, Json};
+/// use axum_either::{prelude::*, WithContentType};
+///
+/// #[derive(serde::Deserialize)]
+/// pub struct Request(u32);
+///
+/// pub async fn handler(
+///     request: WithContentType<AxumEither<Json<Request>, Form<Request>>>,
+/// ) -> String {
+///     let charset = request
+///         .mime()
+///         .and_then(|mime| mime.get_param(mime::CHARSET).map(|charset| charset.to_string()));
+///     format!("charset {:?}", charset)
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WithContentType<T> {
+    /// The `Content-Type` header of the request, `None` if there was none
+    pub content_type: Option<HeaderValue>,
+    /// The extracted value
+    pub value: T,
+}
+
+impl<T> WithContentType<T> {
+    /// The content type parsed as media type, `None` if there was none or it is malformed
+    pub fn mime(&self) -> Option<Mime> {
+        self.content_type.as_ref()?.to_str().ok()?.parse().ok()
+    }
+
+    /// The content type and the extracted value
+    pub fn into_parts(self) -> (Option<HeaderValue>, T) {
+        (self.content_type, self.value)
+    }
+
+    /// The extracted value
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+#[async_trait::async_trait]
+impl<T, B> FromRequest<B> for WithContentType<T>
+where
+    T: FromRequest<B>,
+    B: Send,
+{
+    type Rejection = T::Rejection;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        let content_type = req.headers().get(header::CONTENT_TYPE).cloned();
+        let value = T::from_request(req).await?;
+        Ok(Self {
+            content_type,
+            value,
+        })
+    }
+}
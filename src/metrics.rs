@@ -0,0 +1,54 @@
+//! Report extraction outcomes to the `metrics` facade.
+//!
+//! Every extraction of an [`AxumEither`](crate::AxumEither) chain records the following metrics
+//! once, nested chains built with [`one_of`](crate::one_of) are reported by the outermost either:
+//!
+//! - [`MATCHED_TOTAL`] with the labels `variant`, the flattened index of the extracted variant,
+//!   and `type`, the type name of the extracted variant with only the crate of every path
+//! - [`REJECTED_TOTAL`] if all variants rejected the request
+//! - [`EXTRACTION_DURATION_SECONDS`] with the label `variant`, which is `rejected` if all
+//!   variants rejected the request
+//!
+//! [`Negotiate`](crate::Negotiate) responses record [`NEGOTIATED_TOTAL`] with the label
+//! `variant`, which is `none` if no representation was acceptable.
+
+use ::metrics::{histogram, increment_counter, SharedString};
+use std::time::Duration;
+
+/// Counter of extracted variants
+pub const MATCHED_TOTAL: &str = "axum_either_matched_total";
+/// Counter of extractions where every variant rejected the request
+pub const REJECTED_TOTAL: &str = "axum_either_rejected_total";
+/// Histogram of the duration of extractions in seconds
+pub const EXTRACTION_DURATION_SECONDS: &str = "axum_either_extraction_duration_seconds";
+/// Counter of the representations selected by [`Negotiate`](crate::Negotiate)
+pub const NEGOTIATED_TOTAL: &str = "axum_either_negotiated_total";
+
+/// Labels of the common indices, so they are not formatted per request
+const INDICES: [&str; 16] = [
+    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15",
+];
+
+fn index_label(index: usize) -> SharedString {
+    match INDICES.get(index) {
+        Some(label) => SharedString::const_str(label),
+        None => index.to_string().into(),
+    }
+}
+
+pub(crate) fn matched(index: usize, name: &'static str, elapsed: Duration) {
+    let variant = index_label(index);
+    increment_counter!(MATCHED_TOTAL, "variant" => variant.clone(), "type" => name);
+    histogram!(EXTRACTION_DURATION_SECONDS, elapsed.as_secs_f64(), "variant" => variant);
+}
+
+pub(crate) fn rejected(elapsed: Duration) {
+    increment_counter!(REJECTED_TOTAL);
+    histogram!(EXTRACTION_DURATION_SECONDS, elapsed.as_secs_f64(), "variant" => "rejected");
+}
+
+#[cfg(feature = "negotiation")]
+pub(crate) fn negotiated(index: Option<usize>) {
+    let variant = index.map_or(SharedString::const_str("none"), index_label);
+    increment_counter!(NEGOTIATED_TOTAL, "variant" => variant);
+}
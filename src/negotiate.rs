@@ -0,0 +1,403 @@
+//! Respond with one of two representations depending on the `Accept` header.
+//!
+//! # Examples
+//! ```
+//! use axum::{response::Html, Json};
+//! use axum_either::{Accept, Negotiate};
+//!
+//! #[derive(serde::Serialize)]
+//! pub struct User {
+//!     name: String,
+//! }
+//!
+//! pub async fn user(accept: Accept) -> Negotiate<Json<User>, Html<String>> {
+//!     let user = User { name: "Reimu".into() };
+//!     Negotiate::new(
+//!         &accept,
+//!         user,
+//!         ("application/json", Json),
+//!         ("text/html", |user: User| Html(format!("<h1>{}</h1>", user.name))),
+//!     )
+//! }
+//! ```
+
+use axum_core::{
+    extract::{FromRequest, RequestParts},
+    response::{IntoResponse, Response},
+};
+use http::{header, HeaderMap, HeaderValue, StatusCode};
+use std::convert::Infallible;
+
+use crate::{
+    accept::{parse_accept, parse_accept_language, parse_media_type},
+    AxumEither,
+};
+
+pub use crate::accept::{LanguageRange, MediaRange};
+
+/// Extracts the media ranges of the `Accept` header, this never rejects.
+///
+/// The media ranges are ordered by preference, see [`parse_accept`]. A missing header accepts
+/// every media type, malformed entries are ignored.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Accept(pub Vec<MediaRange>);
+
+impl Accept {
+    /// Parse the `Accept` headers in `headers`, see [`parse_accept`]
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        Self(parse_accept(headers))
+    }
+
+    /// The quality with which the client accepts `media_type`, 0 if it is not acceptable.
+    ///
+    /// The most specific matching range decides the quality. Parameters of a range, like the
+    /// `version` of `application/vnd.api+json;version=2`, don't match media types with a
+    /// different value for the parameter.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::Accept;
+    /// # use http::{header, HeaderMap};
+    /// let mut headers = HeaderMap::new();
+    /// headers.insert(header::ACCEPT, "text/*;q=0.3, text/html;q=0.7".parse().unwrap());
+    /// let accept = Accept::from_headers(&headers);
+    /// assert_eq!(accept.quality("text/html"), 0.7);
+    /// assert_eq!(accept.quality("text/plain"), 0.3);
+    /// assert_eq!(accept.quality("image/png"), 0.0);
+    ///
+    /// headers.insert(header::ACCEPT, "application/vnd.api+json;version=2".parse().unwrap());
+    /// let accept = Accept::from_headers(&headers);
+    /// assert_eq!(accept.quality("application/vnd.api+json;version=2"), 1.0);
+    /// assert_eq!(accept.quality("application/vnd.api+json;version=1"), 0.0);
+    /// ```
+    pub fn quality(&self, media_type: &str) -> f32 {
+        if self.0.is_empty() {
+            return 1.0;
+        }
+
+        let (ty, subtype, params) = parse_media_type(media_type);
+        self.0
+            .iter()
+            .filter_map(|range| Some((range.specificity(ty, subtype, &params)?, range.q)))
+            .max_by_key(|(specificity, _)| *specificity)
+            .map_or(0.0, |(_, q)| q)
+    }
+
+    /// Select the media type the client prefers, ties are won by `left`.
+    ///
+    /// Returns `None` if neither media type is acceptable.
+    pub fn choose(&self, left: &str, right: &str) -> Option<AxumEither<(), ()>> {
+        let left = self.quality(left);
+        let right = self.quality(right);
+        if left <= 0.0 && right <= 0.0 {
+            None
+        } else if left >= right {
+            Some(AxumEither::Left(()))
+        } else {
+            Some(AxumEither::Right(()))
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<B> FromRequest<B> for Accept
+where
+    B: Send,
+{
+    type Rejection = Infallible;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        Ok(Self::from_headers(req.headers()))
+    }
+}
+
+/// Responds with the representation the client prefers according to its `Accept` header.
+///
+/// If neither representation is acceptable `406 Not Acceptable` is returned. The response always
+/// contains `Accept` in its `Vary` header, values set by the representation are kept. Use
+/// [`content_location`](Self::content_location) to tell caches where each representation lives.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct Negotiate<L, R>(pub Option<AxumEither<L, R>>);
+
+impl<L, R> Negotiate<L, R> {
+    /// Render `value` with the function of the preferred media type.
+    ///
+    /// `left` and `right` are pairs of a media type and the function rendering it, only the
+    /// function of the preferred media type is called.
+    pub fn new<T>(
+        accept: &Accept,
+        value: T,
+        left: (&str, impl FnOnce(T) -> L),
+        right: (&str, impl FnOnce(T) -> R),
+    ) -> Self {
+        Self(accept.choose(left.0, right.0).map(|choice| match choice {
+            AxumEither::Left(()) => AxumEither::Left((left.1)(value)),
+            AxumEither::Right(()) => AxumEither::Right((right.1)(value)),
+        }))
+    }
+
+    /// The negotiated representation, `None` if neither was acceptable
+    pub fn into_inner(self) -> Option<AxumEither<L, R>> {
+        self.0
+    }
+
+    /// Set the `Content-Location` header of the response to the location of the negotiated
+    /// representation, `left` or `right`.
+    ///
+    /// No header is set if neither representation was acceptable.
+    ///
+    /// # Examples
+    /// ```
+    /// use axum::{extract::Path, response::Html, Json};
+    /// use axum_either::{negotiate::WithContentLocation, Accept, Negotiate};
+    ///
+    /// pub async fn user(
+    ///     Path(id): Path<u32>,
+    ///     accept: Accept,
+    /// ) -> WithContentLocation<Negotiate<Json<u32>, Html<String>>> {
+    ///     Negotiate::new(
+    ///         &accept,
+    ///         id,
+    ///         ("application/json", Json),
+    ///         ("text/html", |id| Html(format!("<h1>User {}</h1>", id))),
+    ///     )
+    ///     .content_location(format!("/api/users/{}", id), format!("/users/{}", id))
+    /// }
+    /// ```
+    pub fn content_location(
+        self,
+        left: impl Into<String>,
+        right: impl Into<String>,
+    ) -> WithContentLocation<Self> {
+        let location = self.0.as_ref().map(|either| match either {
+            AxumEither::Left(_) => left.into(),
+            AxumEither::Right(_) => right.into(),
+        });
+        WithContentLocation(self, location)
+    }
+}
+
+impl<L, R> IntoResponse for Negotiate<L, R>
+where
+    L: IntoResponse,
+    R: IntoResponse,
+{
+    fn into_response(self) -> Response {
+        #[cfg(feature = "metrics")]
+        crate::metrics::negotiated(self.0.as_ref().map(|either| match either {
+            AxumEither::Left(_) => 0,
+            AxumEither::Right(_) => 1,
+        }));
+
+        let mut response = match self.0 {
+            Some(either) => either.into_response(),
+            None => StatusCode::NOT_ACCEPTABLE.into_response(),
+        };
+        vary_on(response.headers_mut(), "Accept");
+        response
+    }
+}
+
+/// Extracts the language ranges of the `Accept-Language` header, this never rejects.
+///
+/// The ranges are ordered by preference, see [`parse_accept_language`]. A missing header accepts
+/// every language, malformed entries are ignored.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AcceptLanguage(pub Vec<LanguageRange>);
+
+impl AcceptLanguage {
+    /// Parse the `Accept-Language` headers in `headers`, see [`parse_accept_language`]
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        Self(parse_accept_language(headers))
+    }
+
+    /// The quality with which the client accepts `language`, 0 if it is not acceptable.
+    ///
+    /// The most specific matching range decides the quality, a range matches the languages it
+    /// is a prefix of and the languages which are a prefix of it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::negotiate::AcceptLanguage;
+    /// # use http::{header, HeaderMap};
+    /// let mut headers = HeaderMap::new();
+    /// headers.insert(header::ACCEPT_LANGUAGE, "de-AT, en;q=0.5".parse().unwrap());
+    /// let accept = AcceptLanguage::from_headers(&headers);
+    /// assert_eq!(accept.quality("de-AT"), 1.0);
+    /// assert_eq!(accept.quality("de"), 1.0);
+    /// assert_eq!(accept.quality("en-GB"), 0.5);
+    /// assert_eq!(accept.quality("fr"), 0.0);
+    /// ```
+    pub fn quality(&self, language: &str) -> f32 {
+        if self.0.is_empty() {
+            return 1.0;
+        }
+
+        self.0
+            .iter()
+            .filter_map(|range| Some((range.specificity(language)?, range.q)))
+            .max_by_key(|(specificity, _)| *specificity)
+            .map_or(0.0, |(_, q)| q)
+    }
+
+    /// Select the language the client prefers, ties and languages which are not acceptable at
+    /// all are won by `left`.
+    pub fn choose(&self, left: &str, right: &str) -> AxumEither<(), ()> {
+        if self.quality(left) >= self.quality(right) {
+            AxumEither::Left(())
+        } else {
+            AxumEither::Right(())
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<B> FromRequest<B> for AcceptLanguage
+where
+    B: Send,
+{
+    type Rejection = Infallible;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        Ok(Self::from_headers(req.headers()))
+    }
+}
+
+/// Responds with the localization the client prefers according to its `Accept-Language`
+/// header.
+///
+/// Unlike [`Negotiate`] there is no `406 Not Acceptable` response, the left localization is
+/// the default. The response contains `Accept-Language` in its `Vary` header and the chosen
+/// language in its `Content-Language` header, unless the localization sets one itself.
+///
+/// # Examples
+/// ```
+/// use axum::response::Html;
+/// use axum_either::negotiate::{AcceptLanguage, LanguageNegotiate};
+///
+/// pub async fn not_found(
+///     accept: AcceptLanguage,
+/// ) -> LanguageNegotiate<Html<&'static str>, Html<&'static str>> {
+///     LanguageNegotiate::new(
+///         &accept,
+///         (),
+///         ("en", |()| Html("<h1>Not found</h1>")),
+///         ("de", |()| Html("<h1>Nicht gefunden</h1>")),
+///     )
+/// }
+/// ```
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct LanguageNegotiate<L, R> {
+    localization: AxumEither<L, R>,
+    language: String,
+}
+
+impl<L, R> LanguageNegotiate<L, R> {
+    /// Render `value` with the function of the preferred language.
+    ///
+    /// `left` and `right` are pairs of a language tag and the function rendering it, only the
+    /// function of the preferred language is called.
+    pub fn new<T>(
+        accept: &AcceptLanguage,
+        value: T,
+        left: (&str, impl FnOnce(T) -> L),
+        right: (&str, impl FnOnce(T) -> R),
+    ) -> Self {
+        match accept.choose(left.0, right.0) {
+            AxumEither::Left(()) => Self {
+                localization: AxumEither::Left((left.1)(value)),
+                language: left.0.to_owned(),
+            },
+            AxumEither::Right(()) => Self {
+                localization: AxumEither::Right((right.1)(value)),
+                language: right.0.to_owned(),
+            },
+        }
+    }
+
+    /// The language tag of the chosen localization
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    /// The chosen localization
+    pub fn into_inner(self) -> AxumEither<L, R> {
+        self.localization
+    }
+}
+
+impl<L, R> IntoResponse for LanguageNegotiate<L, R>
+where
+    L: IntoResponse,
+    R: IntoResponse,
+{
+    fn into_response(self) -> Response {
+        let mut response = self.localization.into_response();
+        let headers = response.headers_mut();
+        if !headers.contains_key(header::CONTENT_LANGUAGE) {
+            if let Ok(language) = HeaderValue::try_from(self.language) {
+                headers.insert(header::CONTENT_LANGUAGE, language);
+            }
+        }
+        vary_on(headers, "Accept-Language");
+        response
+    }
+}
+
+/// Sets the `Content-Location` of a negotiated response, see [`Negotiate::content_location`].
+///
+/// The second field is the location of the negotiated representation.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct WithContentLocation<T>(pub T, pub Option<String>);
+
+impl<T> IntoResponse for WithContentLocation<T>
+where
+    T: IntoResponse,
+{
+    fn into_response(self) -> Response {
+        let mut response = self.0.into_response();
+        let location = self
+            .1
+            .and_then(|location| HeaderValue::try_from(location).ok());
+        if let Some(location) = location {
+            response
+                .headers_mut()
+                .insert(header::CONTENT_LOCATION, location);
+        }
+        response
+    }
+}
+
+/// Add `field` to the `Vary` header, existing values are merged into a single header
+pub(crate) fn vary_on(headers: &mut HeaderMap, field: &'static str) {
+    // Keep values which can't be merged and add a separate header
+    if headers
+        .get_all(header::VARY)
+        .iter()
+        .any(|value| value.to_str().is_err())
+    {
+        headers.append(header::VARY, HeaderValue::from_static(field));
+        return;
+    }
+
+    let mut values: Vec<&str> = headers
+        .get_all(header::VARY)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .collect();
+    if values
+        .iter()
+        .any(|value| *value == "*" || value.eq_ignore_ascii_case(field))
+    {
+        return;
+    }
+    values.push(field);
+    let vary = values.join(", ");
+    // The values were valid header values before, joining them keeps them valid
+    if let Ok(vary) = HeaderValue::try_from(vary) {
+        headers.insert(header::VARY, vary);
+    }
+}
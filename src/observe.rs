@@ -0,0 +1,80 @@
+//! Observe which variant of an [`AxumEither`](crate::AxumEither) was extracted.
+//!
+//! Insert an [`Observer`] into the request extensions, for example with the `Extension` layer of
+//! axum, and it will be notified by every [`AxumEither`](crate::AxumEither) extracted from the
+//! request.
+//!
+//! # Examples
+//! ```
+//! use axum::{routing::post, Extension, Form, Json, Router};
+//! use axum_either::{
+//!     observe::{EitherEvent, EitherObserver, Observer},
+//!     AxumEither,
+//! };
+//! use std::sync::atomic::{AtomicU64, Ordering};
+//!
+//! #[derive(Default)]
+//! pub struct FormCounter(AtomicU64);
+//!
+//! impl EitherObserver for FormCounter {
+//!     fn observe(&self, event: EitherEvent) {
+//!         if event == EitherEvent::Matched(1) {
+//!             self.0.fetch_add(1, Ordering::Relaxed);
+//!         }
+//!     }
+//! }
+//!
+//! async fn handler(_request: AxumEither<Json<String>, Form<Vec<(String, String)>>>) {}
+//!
+//! let router: Router = Router::new()
+//!     .route("/", post(handler))
+//!     .layer(Extension(Observer::new(FormCounter::default())));
+//! ```
+
+use axum_core::extract::RequestParts;
+use std::{
+    fmt::{self, Debug, Formatter},
+    sync::Arc,
+};
+
+/// Gets notified about the outcome of every [`AxumEither`](crate::AxumEither) extraction.
+pub trait EitherObserver: Send + Sync + 'static {
+    /// Called once per extraction of a (possibly nested) [`AxumEither`](crate::AxumEither)
+    fn observe(&self, event: EitherEvent);
+}
+
+/// The outcome of an extraction reported to an [`EitherObserver`]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, PartialOrd, Eq, Ord)]
+pub enum EitherEvent {
+    /// The variant at the given index was extracted.
+    ///
+    /// The index is flattened for chains built with [`one_of`](crate::one_of), the third type of
+    /// `one_of!(A, B, C)` has the index 2.
+    Matched(usize),
+    /// All variants rejected the request
+    RejectedAll,
+}
+
+/// The request extension holding the [`EitherObserver`] to notify.
+#[derive(Clone)]
+pub struct Observer(Arc<dyn EitherObserver>);
+
+impl Observer {
+    /// Create an extension notifying the given `observer`
+    pub fn new(observer: impl EitherObserver) -> Self {
+        Self(Arc::new(observer))
+    }
+}
+
+impl Debug for Observer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Observer").finish()
+    }
+}
+
+/// Notify the [`Observer`] of the request, if there is one
+pub(crate) fn notify<B>(req: &RequestParts<B>, event: EitherEvent) {
+    if let Some(observer) = req.extensions().get::<Observer>() {
+        observer.0.observe(event);
+    }
+}
@@ -0,0 +1,283 @@
+//! Describe [`AxumEither`](crate::AxumEither) in OpenAPI documents generated with utoipa.
+//!
+//! [`ToSchema`] is implemented as a `oneOf` of the schemas of all sides, nested chains built with
+//! [`one_of`](crate::one_of) are flattened into a single `oneOf`.
+//!
+//! Extractors and responses implementing [`OpenApiBody`] are described by their media types, an
+//! [`AxumEither`](crate::AxumEither) of them implements [`IntoResponses`] and provides a request
+//! body with [`AxumEither::openapi_request_body`](crate::AxumEither::openapi_request_body).
+//!
+//! # Examples
+#![cfg_attr(feature = "axum", doc = "```")]
+#![cfg_attr(not(feature = "axum"), doc = "```ignore")]
+//! use axum_either::prelude::*;
+//! use utoipa::{openapi::ComponentsBuilder, ToSchema};
+//!
+//! #[derive(ToSchema)]
+//! pub struct Cat {
+//!     lives: u8,
+//! }
+//!
+//! #[derive(ToSchema)]
+//! pub struct Dog {
+//!     name: String,
+//! }
+//!
+//! let components = ComponentsBuilder::new()
+//!     .schema_from::<AxumEither<Cat, Dog>>()
+//!     .build();
+//! assert!(components.schemas.contains_key("CatOrDog"));
+//! ```
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Mutex,
+};
+use utoipa::{
+    openapi::{
+        request_body::{RequestBody, RequestBodyBuilder},
+        schema::{ObjectBuilder, OneOfBuilder, Schema, SchemaType},
+        Content, RefOr, Required, Response, ResponseBuilder,
+    },
+    IntoResponses, ToSchema,
+};
+
+use crate::AxumEither;
+
+impl<'s, L, R> ToSchema<'s> for AxumEither<L, R>
+where
+    L: ToSchema<'s>,
+    R: ToSchema<'s>,
+{
+    fn schema() -> (&'s str, RefOr<Schema>) {
+        let (left_name, left) = L::schema();
+        let (right_name, right) = R::schema();
+
+        let mut items = Vec::new();
+        push_flattened(&mut items, left);
+        push_flattened(&mut items, right);
+        let schema = items
+            .into_iter()
+            .fold(OneOfBuilder::new(), |one_of, item| one_of.item(item))
+            .build();
+
+        (
+            intern(format!("{}Or{}", left_name, right_name)),
+            RefOr::T(Schema::OneOf(schema)),
+        )
+    }
+}
+
+/// Push `schema` to `items`, the items of a `oneOf` are pushed instead of the `oneOf` itself
+fn push_flattened(items: &mut Vec<RefOr<Schema>>, schema: RefOr<Schema>) {
+    match schema {
+        RefOr::T(Schema::OneOf(one_of)) => items.extend(one_of.items),
+        schema => items.push(schema),
+    }
+}
+
+/// Schema names are borrowed, every distinct name is leaked once
+fn intern(name: String) -> &'static str {
+    static NAMES: Mutex<BTreeSet<&'static str>> = Mutex::new(BTreeSet::new());
+
+    let mut names = NAMES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    match names.get(name.as_str()) {
+        Some(name) => name,
+        None => {
+            let name = Box::leak(name.into_boxed_str());
+            names.insert(name);
+            name
+        }
+    }
+}
+
+/// Describes the body an extractor accepts or a response renders by its media types.
+///
+/// This is implemented for `String` and, with the `axum` feature, for `Json`, `Form` and `Html`.
+///
+/// # Examples
+/// ```
+/// use axum_either::openapi::OpenApiBody;
+/// use utoipa::openapi::{schema::Schema, Content, ObjectBuilder, RefOr, SchemaType};
+///
+/// pub struct Csv(String);
+///
+/// impl OpenApiBody for Csv {
+///     fn contents(contents: &mut Vec<(&'static str, Content)>) {
+///         let schema = ObjectBuilder::new().schema_type(SchemaType::String).build();
+///         contents.push(("text/csv", Content::new(RefOr::T(Schema::Object(schema)))));
+///     }
+/// }
+/// ```
+pub trait OpenApiBody {
+    /// Push the media types of this body and their content to `contents`
+    fn contents(contents: &mut Vec<(&'static str, Content)>);
+}
+
+impl<L, R> OpenApiBody for AxumEither<L, R>
+where
+    L: OpenApiBody,
+    R: OpenApiBody,
+{
+    fn contents(contents: &mut Vec<(&'static str, Content)>) {
+        L::contents(contents);
+        R::contents(contents);
+    }
+}
+
+impl OpenApiBody for String {
+    fn contents(contents: &mut Vec<(&'static str, Content)>) {
+        contents.push(("text/plain; charset=utf-8", string_content()));
+    }
+}
+
+#[cfg(feature = "axum")]
+mod axum_bodies {
+    use axum::{response::Html, Form, Json};
+    use utoipa::{openapi::Content, ToSchema};
+
+    use super::{string_content, OpenApiBody};
+
+    impl<T> OpenApiBody for Json<T>
+    where
+        T: for<'s> ToSchema<'s>,
+    {
+        fn contents(contents: &mut Vec<(&'static str, Content)>) {
+            contents.push(("application/json", Content::new(T::schema().1)));
+        }
+    }
+
+    impl<T> OpenApiBody for Form<T>
+    where
+        T: for<'s> ToSchema<'s>,
+    {
+        fn contents(contents: &mut Vec<(&'static str, Content)>) {
+            contents.push((
+                "application/x-www-form-urlencoded",
+                Content::new(T::schema().1),
+            ));
+        }
+    }
+
+    impl<T> OpenApiBody for Html<T> {
+        fn contents(contents: &mut Vec<(&'static str, Content)>) {
+            contents.push(("text/html; charset=utf-8", string_content()));
+        }
+    }
+}
+
+#[cfg(feature = "msgpack")]
+impl<T> OpenApiBody for crate::MsgPack<T>
+where
+    T: for<'s> utoipa::ToSchema<'s>,
+{
+    fn contents(contents: &mut Vec<(&'static str, Content)>) {
+        contents.push(("application/msgpack", Content::new(T::schema().1)));
+    }
+}
+
+#[cfg(feature = "cbor")]
+impl<T> OpenApiBody for crate::Cbor<T>
+where
+    T: for<'s> utoipa::ToSchema<'s>,
+{
+    fn contents(contents: &mut Vec<(&'static str, Content)>) {
+        contents.push(("application/cbor", Content::new(T::schema().1)));
+    }
+}
+
+fn string_content() -> Content {
+    let schema = ObjectBuilder::new().schema_type(SchemaType::String).build();
+    Content::new(RefOr::T(Schema::Object(schema)))
+}
+
+/// Describes a `200 OK` response with the media types of all sides.
+///
+/// # Examples
+#[cfg_attr(feature = "axum", doc = "```")]
+#[cfg_attr(not(feature = "axum"), doc = "```ignore")]
+/// use axum::{Form, Json};
+/// use axum_either::prelude::*;
+/// use utoipa::{OpenApi, ToSchema};
+///
+/// #[derive(serde::Serialize, serde::Deserialize, ToSchema)]
+/// pub struct HelloRequest {
+///     pub name: String,
+/// }
+///
+/// #[derive(serde::Serialize, ToSchema)]
+/// pub struct HelloResponse {
+///     pub msg: String,
+///     pub name: String,
+/// }
+///
+/// /// A route which accepts both a form and json and gives an appropriate response
+/// #[utoipa::path(post, path = "/hello", responses(AxumEither<Json<HelloResponse>, String>))]
+/// pub async fn hello(
+///     request: AxumEither<Json<HelloRequest>, Form<HelloRequest>>,
+/// ) -> AxumEither<Json<HelloResponse>, String> {
+///     match request {
+///         AxumEither::Left(Json(req)) => AxumEither::Left(Json(HelloResponse {
+///             msg: format!("Hello {}!", req.name),
+///             name: req.name,
+///         })),
+///         AxumEither::Right(Form(req)) => AxumEither::Right(format!("Hi {}!", req.name)),
+///     }
+/// }
+///
+/// #[derive(OpenApi)]
+/// #[openapi(paths(hello))]
+/// struct ApiDoc;
+///
+/// let mut openapi = ApiDoc::openapi();
+/// let hello = openapi.paths.paths.get_mut("/hello").unwrap();
+/// for operation in hello.operations.values_mut() {
+///     operation.request_body = Some(
+///         AxumEither::<Json<HelloRequest>, Form<HelloRequest>>::openapi_request_body(),
+///     );
+/// }
+///
+/// let spec = openapi.to_json().unwrap();
+/// assert!(spec.contains("application/x-www-form-urlencoded"));
+/// assert!(spec.contains("text/plain; charset=utf-8"));
+/// ```
+impl<L, R> IntoResponses for AxumEither<L, R>
+where
+    L: OpenApiBody,
+    R: OpenApiBody,
+{
+    fn responses() -> BTreeMap<String, RefOr<Response>> {
+        let mut contents = Vec::new();
+        Self::contents(&mut contents);
+        let response = contents
+            .into_iter()
+            .fold(
+                ResponseBuilder::new().description("One of the response formats"),
+                |response, (content_type, content)| response.content(content_type, content),
+            )
+            .build();
+
+        BTreeMap::from([("200".into(), RefOr::T(response))])
+    }
+}
+
+impl<L, R> AxumEither<L, R>
+where
+    L: OpenApiBody,
+    R: OpenApiBody,
+{
+    /// A required request body accepting the media types of all sides
+    pub fn openapi_request_body() -> RequestBody {
+        let mut contents = Vec::new();
+        Self::contents(&mut contents);
+        contents
+            .into_iter()
+            .fold(
+                RequestBodyBuilder::new().required(Some(Required::True)),
+                |body, (content_type, content)| body.content(content_type, content),
+            )
+            .build()
+    }
+}
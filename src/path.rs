@@ -0,0 +1,73 @@
+//! Extract a path segment which has one of two shapes.
+
+use axum::extract::{rejection::PathRejection, Path};
+use axum_core::extract::{FromRequest, RequestParts};
+use serde::de::DeserializeOwned;
+use std::ops::{Deref, DerefMut};
+
+use crate::{AxumEither, AxumEitherRejection};
+
+/// Extracts the path parameters as `A` or, if they don't deserialize as `A`, as `B`.
+///
+/// This is `AxumEither<Path<A>, Path<B>>` without the `Path` wrappers. Only the parameters
+/// captured by the router are read, the body of the request is never touched. If neither
+/// interpretation deserializes, the rejection contains both errors, for example
+/// ``Cannot parse `abc` to a `u64` `` for the left side.
+///
+/// The left side wins whenever it deserializes, so the more specific type belongs there.
+/// The parsing rules of `A` decide the split: `Path<u64>` accepts `00123` as `123`. It does not
+/// accept `-1`, `1.5`, `123abc` or numbers above `u64::MAX`, so those become slugs.
+/// Routes don't match empty segments, `/items/` is not found before anything is extracted.
+///
+/// # Examples
+/// ```
+/// use axum::{routing::get, Router};
+/// use axum_either::{prelude::*, PathEither};
+///
+/// pub async fn item(id: PathEither<u64, String>) -> String {
+///     match id.into_inner() {
+///         AxumEither::Left(id) => format!("item {}", id),
+///         AxumEither::Right(slug) => format!("item {:?}", slug),
+///     }
+/// }
+///
+/// let router: Router = Router::new().route("/items/:id", get(item));
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, PartialOrd, Eq, Ord)]
+pub struct PathEither<A, B>(pub AxumEither<A, B>);
+
+impl<A, B> PathEither<A, B> {
+    /// The extracted parameters
+    pub fn into_inner(self) -> AxumEither<A, B> {
+        self.0
+    }
+}
+
+impl<A, B> Deref for PathEither<A, B> {
+    type Target = AxumEither<A, B>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<A, B> DerefMut for PathEither<A, B> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[async_trait::async_trait]
+impl<A, B, Body> FromRequest<Body> for PathEither<A, B>
+where
+    A: DeserializeOwned + Send,
+    B: DeserializeOwned + Send,
+    Body: Send,
+{
+    type Rejection = AxumEitherRejection<PathRejection, PathRejection>;
+
+    async fn from_request(req: &mut RequestParts<Body>) -> Result<Self, Self::Rejection> {
+        let either = AxumEither::<Path<A>, Path<B>>::from_request(req).await?;
+        Ok(Self(either.map_lr(|Path(a)| a, |Path(b)| b)))
+    }
+}
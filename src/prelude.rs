@@ -0,0 +1,37 @@
+//! Import the commonly used types and macros at once.
+//!
+//! # Examples
+//! ```
+//! use axum::{Form, Json};
+//! use axum_either::prelude::*;
+//!
+//! pub async fn echo(request: one_of!(Json<String>, Form<String>, String)) -> String {
+//!     match_one_of! {request,
+//!         Json(s) => s,
+//!         Form(s) => s,
+//!         s => s,
+//!     }
+//! }
+//! ```
+
+#[cfg(feature = "define-one-of")]
+pub use crate::define_one_of;
+#[cfg(feature = "router-ext")]
+pub use crate::routing::RouterExt;
+#[cfg(feature = "any-format")]
+pub use crate::AnyFormat;
+#[cfg(feature = "body")]
+pub use crate::EitherBody;
+#[cfg(feature = "html-or-json")]
+pub use crate::HtmlOrJson;
+#[cfg(feature = "problem-details")]
+pub use crate::ProblemDetails;
+#[cfg(feature = "validation")]
+pub use crate::ValidatedEither;
+pub use crate::{
+    map_one_of, match_one_of, one_of, AxumEither, AxumEitherRejection, Fallback, FirstOk, OneOf3,
+    OneOf4, OneOf5, OneOf6, OneOf7, OneOf8, PreferLeftRejection, RejectionInfo, Tagged,
+    WithExtension, WithFormatter, WithStatus,
+};
+#[cfg(feature = "negotiation")]
+pub use crate::{Accept, Negotiate};
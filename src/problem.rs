@@ -0,0 +1,169 @@
+//! Render rejections as [RFC 7807](https://www.rfc-editor.org/rfc/rfc7807) problem details.
+//!
+//! # Examples
+//! ```
+//! use axum::{Json, Form};
+//! use axum_either::{AxumEither, ProblemDetails, WithFormatter};
+//!
+//! #[derive(serde::Deserialize)]
+//! pub struct Request(u32);
+//!
+//! pub async fn form_or_json(
+//!     request: WithFormatter<AxumEither<Json<Request>, Form<Request>>, ProblemDetails>,
+//! ) -> String {
+//!     match request.into_inner() {
+//!         AxumEither::Left(Json(r)) => format!("Json {}", r.0),
+//!         AxumEither::Right(Form(r)) => format!("Form {}", r.0),
+//!     }
+//! }
+//! ```
+
+use axum_core::response::{IntoResponse, Response};
+use http::{header, HeaderMap, HeaderValue, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+use crate::{
+    ready::describe,
+    rejection::{forward_headers, RenderedAttempts},
+    EscalateServerErrors, RejectionFormatter, StatusPolicy,
+};
+
+/// The content type of problem detail responses
+pub const PROBLEM_JSON: &str = "application/problem+json";
+
+/// Provides the `type` and `title` members of a problem details response.
+///
+/// # Examples
+/// ```
+/// use axum_either::problem::{ProblemDetails, ProblemType};
+///
+/// pub struct InvalidRequest;
+///
+/// impl ProblemType for InvalidRequest {
+///     const TYPE: &'static str = "https://example.com/problems/invalid-request";
+/// }
+///
+/// pub type InvalidRequestProblem = ProblemDetails<InvalidRequest>;
+/// ```
+pub trait ProblemType {
+    /// The URI identifying the problem type
+    const TYPE: &'static str;
+    /// A short summary of the problem type
+    const TITLE: &'static str = "Could not parse request";
+}
+
+/// The default [`ProblemType`] of `about:blank`
+#[derive(Debug, Clone, Copy, Hash, Default, PartialEq, PartialOrd, Eq, Ord)]
+pub struct AboutBlank;
+
+impl ProblemType for AboutBlank {
+    const TYPE: &'static str = "about:blank";
+}
+
+/// A [`RejectionFormatter`] rendering both errors as a `application/problem+json` [`Problem`].
+///
+/// The status is selected by the [`StatusPolicy`] `P`, the `type` and `title` are provided by the
+/// [`ProblemType`] `T`. Like [`BothRejections`](crate::BothRejections), the headers of the side
+/// which decided the status are forwarded except `Content-Type` and `Content-Length`.
+#[derive(Debug, Clone, Copy, Hash, Default, PartialEq, PartialOrd, Eq, Ord)]
+pub struct ProblemDetails<T = AboutBlank, P = EscalateServerErrors>(PhantomData<(T, P)>);
+
+impl<T, P> RejectionFormatter for ProblemDetails<T, P>
+where
+    T: ProblemType,
+    P: StatusPolicy,
+{
+    fn format(left: Response, right: Response) -> Response {
+        let status = P::status(left.status(), right.status());
+        let (mut left, left_body) = left.into_parts();
+        let (mut right, right_body) = right.into_parts();
+        let left_message = describe(left.status, left_body);
+        let right_message = describe(right.status, right_body);
+        let mut attempts = RenderedAttempts::default();
+        attempts.push(&mut left, left_message);
+        attempts.push(&mut right, right_message);
+        let forwarded = if P::prefer_left(left.status, right.status) {
+            left.headers
+        } else {
+            right.headers
+        };
+
+        let problem = Problem {
+            problem_type: T::TYPE.into(),
+            title: T::TITLE.into(),
+            status: status.as_u16(),
+            detail: "None of the accepted formats could be parsed from the request".into(),
+            attempts: attempts
+                .0
+                .iter()
+                .map(|(status, detail)| ProblemAttempt {
+                    status: status.as_u16(),
+                    detail: detail.clone(),
+                })
+                .collect(),
+        };
+
+        let body = match serde_json::to_vec(&problem) {
+            Ok(body) => body,
+            Err(_) => return status.into_response(),
+        };
+
+        let mut response = (
+            status,
+            [(header::CONTENT_TYPE, HeaderValue::from_static(PROBLEM_JSON))],
+            body,
+        )
+            .into_response();
+        forward_headers(&forwarded, response.headers_mut());
+        response.extensions_mut().insert(attempts);
+        response
+    }
+
+    fn status(left: StatusCode, right: StatusCode) -> StatusCode {
+        P::status(left, right)
+    }
+
+    fn prefer_left(left: StatusCode, right: StatusCode) -> bool {
+        P::prefer_left(left, right)
+    }
+
+    fn headers(left: (StatusCode, HeaderMap), right: (StatusCode, HeaderMap)) -> HeaderMap {
+        let forwarded = if P::prefer_left(left.0, right.0) {
+            left.1
+        } else {
+            right.1
+        };
+        let mut headers = HeaderMap::new();
+        headers.insert(header::CONTENT_TYPE, HeaderValue::from_static(PROBLEM_JSON));
+        forward_headers(&forwarded, &mut headers);
+        headers
+    }
+}
+
+/// The problem details body rendered by [`ProblemDetails`]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Problem {
+    /// The URI identifying the problem type
+    #[serde(rename = "type")]
+    pub problem_type: String,
+    /// A short summary of the problem type
+    pub title: String,
+    /// The status code of the response
+    pub status: u16,
+    /// A human readable explanation of this problem
+    pub detail: String,
+    /// The failures of the individual extractors, in the order they were tried. The failures of
+    /// nested chains are flattened into this list.
+    pub attempts: Vec<ProblemAttempt>,
+}
+
+/// The failure of a single extractor in a [`Problem`]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProblemAttempt {
+    /// The status code the extractor rejected with
+    pub status: u16,
+    /// A description of the rejection, the text of its body or the reason phrase of its status
+    /// if the body is empty, binary or still has to be produced
+    pub detail: String,
+}
@@ -0,0 +1,106 @@
+//! Generate [`AxumEither`] values with proptest.
+//!
+//! # Examples
+//! ```
+//! use axum_either::prelude::*;
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     fn into_inner_keeps_value(either in any::<AxumEither<u8, u8>>()) {
+//!         let expected = either.map_lr(|l| l, |r| r);
+//!         prop_assert_eq!(either.into_inner(), expected.into_inner());
+//!     }
+//! }
+//! # into_inner_keeps_value();
+//! ```
+
+use proptest::{
+    arbitrary::{any_with, Arbitrary},
+    strategy::{BoxedStrategy, Strategy, Union},
+};
+
+use crate::AxumEither;
+
+/// Generate `Left` values from `left` and `Right` values from `right`.
+///
+/// `left_weight` is the percentage of generated values which are `Left`, it must not exceed 100.
+/// With a weight of 0 only `Right` values and with a weight of 100 only `Left` values are
+/// generated.
+///
+/// # Examples
+/// ```
+/// use axum_either::proptest::strategy;
+/// use proptest::prelude::*;
+///
+/// proptest! {
+///     fn mostly_left(either in strategy(any::<u8>(), "[a-z]+", 90)) {
+///         prop_assert!(either.right().map_or(true, |s| !s.is_empty()));
+///     }
+/// }
+/// # mostly_left();
+/// ```
+pub fn strategy<L, R>(
+    left: L,
+    right: R,
+    left_weight: u32,
+) -> impl Strategy<Value = AxumEither<L::Value, R::Value>>
+where
+    L: Strategy + 'static,
+    R: Strategy + 'static,
+{
+    assert!(left_weight <= 100, "left_weight is a percentage");
+    // A union panics on options with a weight of 0, those sides are never generated
+    let options = vec![
+        (left_weight, left.prop_map(AxumEither::Left).boxed()),
+        (100 - left_weight, right.prop_map(AxumEither::Right).boxed()),
+    ];
+    Union::new_weighted(
+        options
+            .into_iter()
+            .filter(|(weight, _)| *weight > 0)
+            .collect(),
+    )
+}
+
+/// The [`Arbitrary`] parameters of [`AxumEither`]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct EitherParameters<L, R> {
+    /// The percentage of generated values which are `Left`, 50 by default
+    pub left_weight: u32,
+    /// The parameters of the left side
+    pub left: L,
+    /// The parameters of the right side
+    pub right: R,
+}
+
+impl<L, R> Default for EitherParameters<L, R>
+where
+    L: Default,
+    R: Default,
+{
+    fn default() -> Self {
+        Self {
+            left_weight: 50,
+            left: L::default(),
+            right: R::default(),
+        }
+    }
+}
+
+impl<L, R> Arbitrary for AxumEither<L, R>
+where
+    L: Arbitrary + 'static,
+    R: Arbitrary + 'static,
+{
+    type Parameters = EitherParameters<L::Parameters, R::Parameters>;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(parameters: Self::Parameters) -> Self::Strategy {
+        strategy(
+            any_with::<L>(parameters.left),
+            any_with::<R>(parameters.right),
+            parameters.left_weight,
+        )
+        .boxed()
+    }
+}
@@ -0,0 +1,176 @@
+//! Extract urlencoded forms with nested structures and arrays.
+
+use axum_core::{
+    extract::{rejection::BytesRejection, FromRequest, RequestParts},
+    response::{IntoResponse, Response},
+    BoxError,
+};
+use bytes::Bytes;
+use http::{Method, StatusCode};
+use http_body::Body;
+use mime::Mime;
+use serde::de::DeserializeOwned;
+use std::{
+    fmt,
+    ops::{Deref, DerefMut},
+};
+
+use crate::{
+    media::{self, media_type_matches},
+    RejectionInfo, SupportedMediaTypes,
+};
+
+/// The nesting depth [`QsForm`] deserializes
+const MAX_DEPTH: usize = 5;
+
+/// Extracts an urlencoded form with the bracket syntax of `serde_qs`, like `items[0][id]=3`.
+///
+/// This is a drop-in replacement for axum's `Form` in an [`AxumEither`](crate::AxumEither):
+/// `GET` and `HEAD` requests are read from the query string, other requests need the
+/// `application/x-www-form-urlencoded` content type. Percent encoded brackets, as sent by
+/// browsers, are decoded. Structures may be nested up to 5 levels deep.
+///
+/// # Examples
+/// ```
+/// use axum::Json;
+/// use axum_either::{AxumEither, QsForm};
+///
+/// #[derive(serde::Deserialize)]
+/// pub struct Item {
+///     id: u32,
+/// }
+///
+/// #[derive(serde::Deserialize)]
+/// pub struct Order {
+///     items: Vec<Item>,
+/// }
+///
+/// pub async fn order(order: AxumEither<Json<Order>, QsForm<Order>>) -> String {
+///     let order = order.map_lr(|Json(order)| order, QsForm::into_inner).into_inner();
+///     format!("{} items", order.items.len())
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Hash, Default, PartialEq, PartialOrd, Eq, Ord)]
+pub struct QsForm<T>(pub T);
+
+impl<T> QsForm<T> {
+    /// The deserialized form
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for QsForm<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for QsForm<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> SupportedMediaTypes for QsForm<T> {
+    fn supported_media_types() -> &'static [Mime] {
+        const FORM: &[Mime] = &[mime::APPLICATION_WWW_FORM_URLENCODED];
+        FORM
+    }
+}
+
+#[async_trait::async_trait]
+impl<T, B> FromRequest<B> for QsForm<T>
+where
+    T: DeserializeOwned,
+    B: Body + Send,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+{
+    type Rejection = QsFormRejection;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        let config = serde_qs::Config::new(MAX_DEPTH, false);
+        if req.method() == Method::GET || req.method() == Method::HEAD {
+            let query = req.uri().query().unwrap_or_default();
+            return config
+                .deserialize_str(query)
+                .map(Self)
+                .map_err(QsFormRejection::Deserialize);
+        }
+
+        let is_form = media::content_type(req.headers()).is_some_and(|content_type| {
+            media_type_matches(&mime::APPLICATION_WWW_FORM_URLENCODED, &content_type)
+        });
+        if !is_form {
+            return Err(QsFormRejection::InvalidContentType);
+        }
+
+        let body = Bytes::from_request(req)
+            .await
+            .map_err(QsFormRejection::Body)?;
+        config
+            .deserialize_bytes(&body)
+            .map(Self)
+            .map_err(QsFormRejection::Deserialize)
+    }
+}
+
+/// The rejection of [`QsForm`]
+#[derive(Debug)]
+pub enum QsFormRejection {
+    /// The `Content-Type` is not `application/x-www-form-urlencoded`
+    InvalidContentType,
+    /// The body could not be buffered
+    Body(BytesRejection),
+    /// The form could not be deserialized
+    Deserialize(serde_qs::Error),
+}
+
+impl fmt::Display for QsFormRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidContentType => f.write_str(
+                "Form requests must have `Content-Type: application/x-www-form-urlencoded`",
+            ),
+            Self::Body(rejection) => write!(f, "{}", rejection),
+            Self::Deserialize(error) => write!(f, "Failed to deserialize form: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for QsFormRejection {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidContentType => None,
+            Self::Body(rejection) => Some(rejection),
+            Self::Deserialize(error) => Some(error),
+        }
+    }
+}
+
+impl RejectionInfo for QsFormRejection {
+    fn status(&self) -> StatusCode {
+        match self {
+            Self::InvalidContentType => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            Self::Body(rejection) => rejection.status(),
+            Self::Deserialize(_) => StatusCode::UNPROCESSABLE_ENTITY,
+        }
+    }
+
+    fn message(&self) -> String {
+        self.to_string()
+    }
+
+    fn source_error(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        std::error::Error::source(self)
+    }
+}
+
+impl IntoResponse for QsFormRejection {
+    fn into_response(self) -> Response {
+        (self.status(), self.to_string()).into_response()
+    }
+}
@@ -0,0 +1,63 @@
+//! Read the bodies of rendered rejections without an async runtime.
+
+use axum_core::body::BoxBody;
+use http::StatusCode;
+use http_body::Body;
+use std::{
+    fmt::Write,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll, Wake, Waker},
+};
+
+use crate::rejection::{Capped, DEFAULT_MESSAGE_LIMIT};
+
+/// The text of a body which is available without waiting, capped at [`DEFAULT_MESSAGE_LIMIT`].
+///
+/// Rejections are rendered into memory, so their bodies are ready on the first poll.
+pub(crate) fn ready_text(mut body: BoxBody) -> Option<String> {
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let mut cx = Context::from_waker(&waker);
+
+    let mut bytes = Vec::new();
+    while bytes.len() <= DEFAULT_MESSAGE_LIMIT {
+        match Pin::new(&mut body).poll_data(&mut cx) {
+            Poll::Ready(Some(Ok(chunk))) => bytes.extend_from_slice(&chunk),
+            Poll::Ready(None) => break,
+            Poll::Ready(Some(Err(_))) | Poll::Pending => return None,
+        }
+    }
+
+    // A capped body may end inside of a character, only that character is dropped
+    let text = match std::str::from_utf8(&bytes) {
+        Ok(text) => text,
+        Err(error) if error.error_len().is_none() => {
+            std::str::from_utf8(&bytes[..error.valid_up_to()]).ok()?
+        }
+        Err(_) => return None,
+    };
+    let mut detail = String::new();
+    Capped::new(&mut detail, DEFAULT_MESSAGE_LIMIT)
+        .write_str(text)
+        .ok()?;
+    Some(detail)
+}
+
+/// Describe a rendered rejection by the text of its body, or by the reason phrase of its status
+/// if the body is empty, binary or still has to be produced
+pub(crate) fn describe(status: StatusCode, body: BoxBody) -> String {
+    ready_text(body)
+        .filter(|text| !text.is_empty())
+        .unwrap_or_else(|| {
+            status
+                .canonical_reason()
+                .unwrap_or("Unknown error")
+                .to_owned()
+        })
+}
+
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
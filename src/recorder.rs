@@ -0,0 +1,181 @@
+//! Count which variants requests are extracted as, without a metrics stack.
+//!
+//! [`VariantRecorderLayer`] counts the type names, with only the crate of every path, of the
+//! variants every [`AxumEither`](crate::AxumEither) chain extracted while the inner service
+//! handled the request.
+//! The counts are read through the shared [`RecorderHandle`].
+//!
+//! # Examples
+//! ```
+//! use axum::{extract::Extension, routing::{get, post}, Form, Json, Router};
+//! use axum_either::{prelude::*, recorder::{RecorderHandle, VariantRecorderLayer}};
+//! use std::{collections::HashMap, sync::Arc};
+//!
+//! async fn submit(_request: AxumEither<Json<String>, Form<Vec<(String, String)>>>) {}
+//!
+//! async fn stats(
+//!     Extension(handle): Extension<Arc<RecorderHandle>>,
+//! ) -> Json<HashMap<&'static str, u64>> {
+//!     Json(handle.counts())
+//! }
+//!
+//! let recorder = VariantRecorderLayer::new();
+//! let router: Router = Router::new()
+//!     .route("/submit", post(submit))
+//!     .route("/admin/variants", get(stats))
+//!     .layer(Extension(recorder.handle()))
+//!     .layer(recorder);
+//! ```
+
+use http::Request;
+use pin_project_lite::pin_project;
+use std::{
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+use tower_layer::Layer;
+use tower_service::Service;
+
+/// The counts of the extracted variants by their type name
+#[derive(Debug, Default)]
+pub struct RecorderHandle {
+    counts: Mutex<HashMap<&'static str, u64>>,
+}
+
+impl RecorderHandle {
+    /// A snapshot of the counts of the extracted variants by their type name
+    pub fn counts(&self) -> HashMap<&'static str, u64> {
+        self.lock().clone()
+    }
+
+    /// Reset all counts to zero
+    pub fn reset(&self) {
+        self.lock().clear();
+    }
+
+    fn record(&self, names: Vec<&'static str>) {
+        let mut counts = self.lock();
+        for name in names {
+            *counts.entry(name).or_default() += 1;
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<&'static str, u64>> {
+        self.counts
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// The request extension the extracted variants are collected in
+#[derive(Debug, Clone, Default)]
+pub(crate) struct MatchedVariants(Arc<Mutex<Vec<&'static str>>>);
+
+impl MatchedVariants {
+    pub(crate) fn push(&self, name: &'static str) {
+        self.0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .push(name);
+    }
+
+    fn take(&self) -> Vec<&'static str> {
+        std::mem::take(
+            &mut *self
+                .0
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner()),
+        )
+    }
+}
+
+/// A [`Layer`] counting the variants extracted by the wrapped service.
+#[derive(Debug, Clone, Default)]
+pub struct VariantRecorderLayer {
+    handle: Arc<RecorderHandle>,
+}
+
+impl VariantRecorderLayer {
+    /// Create a layer with empty counts
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The handle to read the counts of this layer
+    pub fn handle(&self) -> Arc<RecorderHandle> {
+        Arc::clone(&self.handle)
+    }
+}
+
+impl<S> Layer<S> for VariantRecorderLayer {
+    type Service = VariantRecorder<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        VariantRecorder {
+            inner,
+            handle: self.handle(),
+        }
+    }
+}
+
+/// The service of [`VariantRecorderLayer`]
+#[derive(Debug, Clone)]
+pub struct VariantRecorder<S> {
+    inner: S,
+    handle: Arc<RecorderHandle>,
+}
+
+impl<S, B> Service<Request<B>> for VariantRecorder<S>
+where
+    S: Service<Request<B>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = RecordVariants<S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: Request<B>) -> Self::Future {
+        let matched = MatchedVariants::default();
+        req.extensions_mut().insert(matched.clone());
+        RecordVariants {
+            inner: self.inner.call(req),
+            matched,
+            handle: Arc::clone(&self.handle),
+        }
+    }
+}
+
+pin_project! {
+    /// The future of [`VariantRecorder`], records the extracted variants once the response is ready
+    #[derive(Debug)]
+    pub struct RecordVariants<F> {
+        #[pin]
+        inner: F,
+        matched: MatchedVariants,
+        handle: Arc<RecorderHandle>,
+    }
+}
+
+impl<F> Future for RecordVariants<F>
+where
+    F: Future,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        match this.inner.poll(cx) {
+            Poll::Ready(output) => {
+                this.handle.record(this.matched.take());
+                Poll::Ready(output)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
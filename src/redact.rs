@@ -0,0 +1,211 @@
+//! Redaction of request derived content from rejections.
+
+use axum_core::{body, response::Response};
+use bytes::Bytes;
+use http::{header, HeaderMap, HeaderValue, StatusCode};
+use http_body::{Body, Full};
+use std::{
+    marker::PhantomData,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+
+use crate::{media, BothRejections, RejectionFormatter};
+
+/// The default body limit of [`Redacted`] in bytes
+pub const DEFAULT_REDACTED_LIMIT: usize = 4096;
+
+/// A [`RejectionFormatter`] which removes request derived content from the inner rejections.
+///
+/// The bodies of the inner rejections often echo parts of the request, for example the values of
+/// fields which could not be deserialized. Before the rejections are rendered by `F` their bodies
+/// are replaced with a generic message for the kind of the rejection, for example
+/// "JSON deserialization failed" for a `JsonRejection`. Rejections of an unknown kind, as well as
+/// oversized bodies and unsupported content types, are described by their status:
+///
+/// - `413 Payload Too Large`: "The request body is too large"
+/// - `415 Unsupported Media Type`: "The request content type is not supported"
+/// - other client errors: "The request could not be parsed"
+/// - server errors: "Internal server error"
+///
+/// Status codes and headers of the inner rejections are preserved. Json and text rejections keep
+/// their `Content-Type`, json messages are rendered as `{"message": "..."}`. Regardless of the
+/// output of `F` the response body is truncated to at most `LIMIT` bytes, without splitting UTF-8
+/// encoded characters. Json bodies which might be truncated are sent as `text/plain`, a cut off
+/// json document would not be valid json.
+///
+/// # Examples
+/// ```
+/// # use axum_either::{AxumEitherRejection, Redacted, SingleRejection};
+/// # use axum_core::response::IntoResponse;
+/// # use http::StatusCode;
+/// let rejection = AxumEitherRejection::new(
+///     (StatusCode::UNPROCESSABLE_ENTITY, "invalid value: \"secret\""),
+///     StatusCode::UNSUPPORTED_MEDIA_TYPE,
+/// );
+/// let response = rejection.with_formatter::<Redacted<SingleRejection>>().into_response();
+/// assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+/// ```
+#[derive(Debug, Clone, Copy, Hash, Default, PartialEq, PartialOrd, Eq, Ord)]
+pub struct Redacted<F = BothRejections, const LIMIT: usize = DEFAULT_REDACTED_LIMIT>(
+    PhantomData<F>,
+);
+
+impl<F, const LIMIT: usize> RejectionFormatter for Redacted<F, LIMIT>
+where
+    F: RejectionFormatter,
+{
+    fn format(left: Response, right: Response) -> Response {
+        let response = F::format(redact(left, None), redact(right, None));
+        truncate(response, LIMIT)
+    }
+
+    fn format_typed(left: (&'static str, Response), right: (&'static str, Response)) -> Response {
+        let response = F::format_typed(
+            (left.0, redact(left.1, Some(left.0))),
+            (right.0, redact(right.1, Some(right.0))),
+        );
+        truncate(response, LIMIT)
+    }
+
+    fn status(left: StatusCode, right: StatusCode) -> StatusCode {
+        F::status(left, right)
+    }
+
+    fn prefer_left(left: StatusCode, right: StatusCode) -> bool {
+        F::prefer_left(left, right)
+    }
+
+    fn headers(left: (StatusCode, HeaderMap), right: (StatusCode, HeaderMap)) -> HeaderMap {
+        F::headers(left, right)
+    }
+}
+
+/// Replace the body of `response` with a generic message for the rejection called `type_name`
+fn redact(response: Response, type_name: Option<&str>) -> Response {
+    let (mut parts, _) = response.into_parts();
+    let message = message(parts.status, type_name);
+    parts.headers.remove(header::CONTENT_LENGTH);
+
+    let content_type = media::content_type(&parts.headers);
+    let body = match content_type {
+        // The messages contain no characters which would have to be escaped
+        Some(content_type) if media::is_json(&content_type) => {
+            body::boxed(Full::from(format!(r#"{{"message":"{}"}}"#, message)))
+        }
+        Some(content_type) if content_type.type_() == mime::TEXT => {
+            body::boxed(Full::from(message))
+        }
+        _ => {
+            parts.headers.insert(
+                header::CONTENT_TYPE,
+                HeaderValue::from_static("text/plain; charset=utf-8"),
+            );
+            body::boxed(Full::from(message))
+        }
+    };
+    Response::from_parts(parts, body)
+}
+
+/// The generic message for a rejection, by its kind if the type name is known
+fn message(status: StatusCode, type_name: Option<&str>) -> &'static str {
+    let kind = type_name.map(|type_name| {
+        let outer = type_name.split('<').next().unwrap_or(type_name);
+        outer.rsplit("::").next().unwrap_or(outer)
+    });
+
+    match (status.as_u16(), kind) {
+        (413, _) => "The request body is too large",
+        (415, _) => "The request content type is not supported",
+        (400..=499, Some(kind)) if kind.contains("Json") => "JSON deserialization failed",
+        (400..=499, Some(kind)) if kind.contains("Form") => "Form deserialization failed",
+        (400..=499, Some(kind)) if kind.contains("Query") => "Query string deserialization failed",
+        (400..=499, Some(kind)) if kind.contains("Path") => "Path deserialization failed",
+        (400..=499, _) => "The request could not be parsed",
+        _ => "Internal server error",
+    }
+}
+
+/// Limit the body of `response` to `limit` bytes
+fn truncate(response: Response, limit: usize) -> Response {
+    let (mut parts, inner) = response.into_parts();
+    parts.headers.remove(header::CONTENT_LENGTH);
+
+    let fits = inner
+        .size_hint()
+        .upper()
+        .is_some_and(|upper| upper <= limit as u64);
+    let json = media::content_type(&parts.headers)
+        .is_some_and(|content_type| media::is_json(&content_type));
+    if json && !fits {
+        parts.headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("text/plain; charset=utf-8"),
+        );
+    }
+
+    Response::from_parts(
+        parts,
+        body::boxed(Truncated {
+            inner,
+            remaining: limit,
+        }),
+    )
+}
+
+/// A body which ends after `remaining` bytes
+struct Truncated<B> {
+    inner: B,
+    remaining: usize,
+}
+
+impl<B> Body for Truncated<B>
+where
+    B: Body<Data = Bytes> + Unpin,
+{
+    type Data = Bytes;
+    type Error = B::Error;
+
+    fn poll_data(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Self::Data, Self::Error>>> {
+        if self.remaining == 0 {
+            return Poll::Ready(None);
+        }
+
+        match ready!(Pin::new(&mut self.inner).poll_data(cx)) {
+            Some(Ok(mut data)) if data.len() > self.remaining => {
+                // Never end the body inside of a UTF-8 encoded character
+                let mut end = self.remaining;
+                while end > 0 && is_continuation_byte(data[end]) {
+                    end -= 1;
+                }
+                data.truncate(end);
+                self.remaining = 0;
+                Poll::Ready(Some(Ok(data)))
+            }
+            Some(Ok(data)) => {
+                self.remaining -= data.len();
+                Poll::Ready(Some(Ok(data)))
+            }
+            other => Poll::Ready(other),
+        }
+    }
+
+    fn poll_trailers(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<Option<HeaderMap>, Self::Error>> {
+        Pin::new(&mut self.inner).poll_trailers(cx)
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.remaining == 0 || self.inner.is_end_stream()
+    }
+}
+
+/// Whether `byte` continues a UTF-8 encoded character instead of starting one
+fn is_continuation_byte(byte: u8) -> bool {
+    byte & 0b1100_0000 == 0b1000_0000
+}
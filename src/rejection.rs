@@ -0,0 +1,1078 @@
+//! The rejections of the extractors and how they are rendered.
+//!
+//! [`AxumEitherRejection`] holds the errors of both sides, its response is rendered by a
+//! [`RejectionFormatter`]. The other rejections of this crate are re-exported here.
+
+use axum_core::response::{IntoResponse, Response};
+use http::{header, response, HeaderMap, HeaderValue, StatusCode};
+use std::{
+    any, fmt,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+#[cfg(feature = "axum-extra")]
+pub use crate::auth::{AuthRejection, MissingCookie};
+#[cfg(feature = "discriminated-json")]
+pub use crate::discriminated::DiscriminatedJsonRejection;
+#[cfg(feature = "any-format")]
+pub use crate::format::AnyFormatRejection;
+#[cfg(any(feature = "discriminated-json", feature = "json-one-of"))]
+pub use crate::json_body::{JsonBodyRejection, JsonPathError};
+#[cfg(feature = "json-one-of")]
+pub use crate::json_one_of::JsonOneOfRejection;
+#[cfg(feature = "problem-details")]
+pub use crate::problem::ProblemDetails;
+#[cfg(feature = "qs-form")]
+pub use crate::qs::QsFormRejection;
+#[cfg(feature = "validation")]
+pub use crate::validate::ValidatedEitherRejection;
+#[cfg(doc)]
+use crate::WithFormatter;
+pub use crate::{
+    bodyless::BodyEitherRejection,
+    catch_panic::{CaughtRejection, ExtractorPanic},
+    dispatch::DispatchRejection,
+    erased::BoxedEitherRejection,
+    fallback::SwallowedRejection,
+    first_ok::FirstOkRejection,
+    info::{Attempt, AttemptRef, Attempts, RejectionInfo, Rejections},
+    lazy::{LazyBody, LazyRejection, WithLazyRejection},
+    limit::LimitedRejection,
+    redact::{Redacted, DEFAULT_REDACTED_LIMIT},
+    text::TextRejection,
+};
+use crate::{info::DisplayMessage, ready::describe, AxumEither};
+
+/// A rejection when both values of [`AxumEither`] are rejected while parsing.
+///
+/// The response reports both errors with [`BothRejections`], use
+/// [`with_formatter`](Self::with_formatter) or the [`WithFormatter`] extractor to render it with
+/// another [`RejectionFormatter`].
+///
+/// Use [`attempts`](Self::attempts) to inspect the errors of a whole chain as a flat
+/// [`Rejections`] collection, and the accessors [`left`](Self::left), [`right`](Self::right) and
+/// [`into_parts`](Self::into_parts) for the typed errors of both sides. The public fields are only
+/// kept for compatibility and will become private in the next breaking release.
+#[derive(Debug, Clone, Copy, Hash, Default, PartialEq, PartialOrd, Eq, Ord)]
+pub struct AxumEitherRejection<LE, RE> {
+    /// The error that occured while parsing the left variant
+    pub left_error: LE,
+    /// The error that occured while parsing the right variant
+    pub right_error: RE,
+}
+
+impl<LE, RE> AxumEitherRejection<LE, RE> {
+    /// Create a new rejection from the errors of both sides, rendered by [`BothRejections`]
+    pub fn new(left_error: LE, right_error: RE) -> Self {
+        Self {
+            left_error,
+            right_error,
+        }
+    }
+
+    /// Render this rejection with another [`RejectionFormatter`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::{AxumEitherRejection, SingleRejection};
+    /// # use axum_core::response::IntoResponse;
+    /// # use http::StatusCode;
+    /// let rejection = AxumEitherRejection::new(StatusCode::UNSUPPORTED_MEDIA_TYPE, StatusCode::NOT_FOUND);
+    /// let response = rejection.with_formatter::<SingleRejection>().into_response();
+    /// assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    /// ```
+    pub fn with_formatter<F>(self) -> FormattedRejection<LE, RE, F> {
+        FormattedRejection {
+            rejection: self,
+            formatter: PhantomData,
+        }
+    }
+
+    /// Map the left error using the given function `f`.
+    ///
+    /// This can be used to replace the error of a single extractor before the rejection is
+    /// rendered. Extract a [`Result`] to adapt the rejection in a handler.
+    ///
+    /// # Examples
+    /// ```
+    /// use axum::{
+    ///     extract::rejection::{FormRejection, JsonRejection},
+    ///     http::StatusCode,
+    ///     Form, Json,
+    /// };
+    /// use axum_either::{AxumEither, AxumEitherRejection};
+    ///
+    /// #[derive(serde::Deserialize)]
+    /// pub struct Request(u32);
+    ///
+    /// pub async fn form_or_json(
+    ///     request: Result<
+    ///         AxumEither<Json<Request>, Form<Request>>,
+    ///         AxumEitherRejection<JsonRejection, FormRejection>,
+    ///     >,
+    /// ) -> Result<String, AxumEitherRejection<(StatusCode, &'static str), FormRejection>> {
+    ///     let request = request.map_err(|rejection| {
+    ///         rejection.map_left_error(|_| (StatusCode::BAD_REQUEST, "Invalid json"))
+    ///     })?;
+    ///     let id = request.map_lr(|Json(r)| r.0, |Form(r)| r.0).into_inner();
+    ///     Ok(id.to_string())
+    /// }
+    /// ```
+    pub fn map_left_error<U>(self, f: impl FnOnce(LE) -> U) -> AxumEitherRejection<U, RE> {
+        AxumEitherRejection {
+            left_error: f(self.left_error),
+            right_error: self.right_error,
+        }
+    }
+
+    /// Map the right error using the given function `f`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::AxumEitherRejection;
+    /// # use http::StatusCode;
+    /// let rejection = AxumEitherRejection::new(StatusCode::BAD_REQUEST, StatusCode::NOT_FOUND);
+    /// let rejection = rejection.map_right_error(|_| StatusCode::UNPROCESSABLE_ENTITY);
+    /// assert_eq!(rejection.right_error, StatusCode::UNPROCESSABLE_ENTITY);
+    /// ```
+    pub fn map_right_error<U>(self, f: impl FnOnce(RE) -> U) -> AxumEitherRejection<LE, U> {
+        AxumEitherRejection {
+            left_error: self.left_error,
+            right_error: f(self.right_error),
+        }
+    }
+
+    /// Map both the left and right errors with the given `lf` and `rf` functions.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::AxumEitherRejection;
+    /// # use http::StatusCode;
+    /// let rejection = AxumEitherRejection::new(StatusCode::BAD_REQUEST, StatusCode::NOT_FOUND);
+    /// let rejection = rejection.map_errors(|l| l.as_u16().to_string(), |r| r.to_string());
+    /// assert_eq!(rejection.left_error, "400");
+    /// ```
+    pub fn map_errors<U, V>(
+        self,
+        lf: impl FnOnce(LE) -> U,
+        rf: impl FnOnce(RE) -> V,
+    ) -> AxumEitherRejection<U, V> {
+        self.map_left_error(lf).map_right_error(rf)
+    }
+
+    /// Take both errors out of the rejection.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::AxumEitherRejection;
+    /// # use http::StatusCode;
+    /// let rejection = AxumEitherRejection::new(StatusCode::BAD_REQUEST, StatusCode::NOT_FOUND);
+    /// assert_eq!(rejection.into_errors(), (StatusCode::BAD_REQUEST, StatusCode::NOT_FOUND));
+    /// ```
+    pub fn into_errors(self) -> (LE, RE) {
+        (self.left_error, self.right_error)
+    }
+
+    /// The error that occured while parsing the left variant
+    pub fn left(&self) -> &LE {
+        &self.left_error
+    }
+
+    /// The error that occured while parsing the right variant
+    pub fn right(&self) -> &RE {
+        &self.right_error
+    }
+
+    /// Take both errors out of the rejection, this is the same as
+    /// [`into_errors`](Self::into_errors).
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::AxumEitherRejection;
+    /// # use http::StatusCode;
+    /// let rejection = AxumEitherRejection::new(StatusCode::BAD_REQUEST, StatusCode::NOT_FOUND);
+    /// let (left, right) = rejection.into_parts();
+    /// assert_eq!(left, StatusCode::BAD_REQUEST);
+    /// assert_eq!(right, StatusCode::NOT_FOUND);
+    /// ```
+    pub fn into_parts(self) -> (LE, RE) {
+        self.into_errors()
+    }
+
+    /// Keep only one of the errors, the left error is kept if `f` returns `true` and the right
+    /// error otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::{AxumEither, AxumEitherRejection};
+    /// # use http::StatusCode;
+    /// let rejection = AxumEitherRejection::new(StatusCode::BAD_REQUEST, StatusCode::NOT_FOUND);
+    /// let error = rejection.into_either_by(|l, _r| *l != StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    /// assert_eq!(error, AxumEither::Left(StatusCode::BAD_REQUEST));
+    /// ```
+    pub fn into_either_by(self, f: impl FnOnce(&LE, &RE) -> bool) -> AxumEither<LE, RE> {
+        if f(&self.left_error, &self.right_error) {
+            AxumEither::Left(self.left_error)
+        } else {
+            AxumEither::Right(self.right_error)
+        }
+    }
+
+    /// Keep only the left error as [`AxumEither::Left`], the right error is dropped.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::{AxumEither, AxumEitherRejection};
+    /// # use http::StatusCode;
+    /// let rejection = AxumEitherRejection::new(StatusCode::BAD_REQUEST, StatusCode::NOT_FOUND);
+    /// assert_eq!(rejection.into_left_biased(), AxumEither::Left(StatusCode::BAD_REQUEST));
+    /// ```
+    pub fn into_left_biased(self) -> AxumEither<LE, RE> {
+        AxumEither::Left(self.left_error)
+    }
+
+    /// Keep only the right error as [`AxumEither::Right`], the left error is dropped.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::{AxumEither, AxumEitherRejection};
+    /// # use http::StatusCode;
+    /// let rejection = AxumEitherRejection::new(StatusCode::BAD_REQUEST, StatusCode::NOT_FOUND);
+    /// assert_eq!(rejection.into_right_biased(), AxumEither::Right(StatusCode::NOT_FOUND));
+    /// ```
+    pub fn into_right_biased(self) -> AxumEither<LE, RE> {
+        AxumEither::Right(self.right_error)
+    }
+}
+
+impl<LE, RE> AxumEitherRejection<LE, RE>
+where
+    LE: RejectionInfo,
+    RE: RejectionInfo,
+{
+    /// All failed extractions of the chain, nested rejections are flattened.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::AxumEitherRejection;
+    /// # use http::StatusCode;
+    /// let rejection = AxumEitherRejection::new(
+    ///     (StatusCode::UNSUPPORTED_MEDIA_TYPE, "Expected json"),
+    ///     StatusCode::BAD_REQUEST,
+    /// );
+    /// let attempts = rejection.attempts();
+    /// assert_eq!(attempts[0].message(), "Expected json");
+    /// assert_eq!(attempts[1].status(), StatusCode::BAD_REQUEST);
+    /// ```
+    pub fn attempts(&self) -> Rejections {
+        Rejections::from_info(self)
+    }
+
+    /// Iterate over the failed extractions of the chain without copying them.
+    ///
+    /// Unlike [`attempts`](Self::attempts) no messages are rendered up front, only the statuses
+    /// are computed. No responses are rendered, [`AttemptRef::message`] only uses
+    /// [`RejectionInfo::message`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::AxumEitherRejection;
+    /// # use http::StatusCode;
+    /// let rejection = AxumEitherRejection::new(
+    ///     StatusCode::UNSUPPORTED_MEDIA_TYPE,
+    ///     AxumEitherRejection::new(StatusCode::NOT_FOUND, StatusCode::BAD_REQUEST),
+    /// );
+    /// let statuses: Vec<_> = rejection
+    ///     .collect_rejections()
+    ///     .map(|attempt| attempt.status())
+    ///     .collect();
+    /// assert_eq!(
+    ///     statuses,
+    ///     [
+    ///         StatusCode::UNSUPPORTED_MEDIA_TYPE,
+    ///         StatusCode::NOT_FOUND,
+    ///         StatusCode::BAD_REQUEST
+    ///     ]
+    /// );
+    /// ```
+    pub fn collect_rejections(&self) -> Attempts<'_> {
+        Attempts::from_info(self)
+    }
+
+    /// The error most worth reporting, selected with the same rules the response is rendered with.
+    ///
+    /// This only inspects the statuses of the errors through [`RejectionInfo`], no responses are
+    /// rendered to decide.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::{AxumEither, AxumEitherRejection};
+    /// # use http::StatusCode;
+    /// let rejection = AxumEitherRejection::new(StatusCode::NOT_FOUND, StatusCode::BAD_GATEWAY);
+    /// assert_eq!(rejection.preferred(), AxumEither::Right(&StatusCode::BAD_GATEWAY));
+    /// ```
+    pub fn preferred(&self) -> AxumEither<&LE, &RE> {
+        self.preferred_by::<BothRejections>()
+    }
+
+    /// Take the error most worth reporting out of the rejection, see
+    /// [`preferred`](Self::preferred).
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::{AxumEither, AxumEitherRejection, SingleRejection};
+    /// # use http::StatusCode;
+    /// let rejection = AxumEitherRejection::new(StatusCode::UNSUPPORTED_MEDIA_TYPE, StatusCode::NOT_FOUND);
+    /// let rejection = rejection.with_formatter::<SingleRejection>();
+    /// assert_eq!(rejection.into_preferred(), AxumEither::Right(StatusCode::NOT_FOUND));
+    /// ```
+    pub fn into_preferred(self) -> AxumEither<LE, RE> {
+        self.into_preferred_by::<BothRejections>()
+    }
+
+    /// The error the formatter `F` prefers, see [`RejectionFormatter::prefer_left`]
+    fn preferred_by<F: RejectionFormatter>(&self) -> AxumEither<&LE, &RE> {
+        if F::prefer_left(self.left_error.status(), self.right_error.status()) {
+            AxumEither::Left(&self.left_error)
+        } else {
+            AxumEither::Right(&self.right_error)
+        }
+    }
+
+    /// Take the error the formatter `F` prefers out of the rejection
+    fn into_preferred_by<F: RejectionFormatter>(self) -> AxumEither<LE, RE> {
+        if F::prefer_left(self.left_error.status(), self.right_error.status()) {
+            AxumEither::Left(self.left_error)
+        } else {
+            AxumEither::Right(self.right_error)
+        }
+    }
+}
+
+impl<LE, RE> AxumEitherRejection<LE, RE>
+where
+    LE: IntoResponse,
+    RE: IntoResponse,
+{
+    /// Render the rejection and post-process the response with `f`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::AxumEitherRejection;
+    /// # use http::{header, HeaderValue, StatusCode};
+    /// let rejection = AxumEitherRejection::new(StatusCode::NOT_FOUND, StatusCode::BAD_REQUEST);
+    /// let response = rejection.into_response_with(|mut response| {
+    ///     response
+    ///         .headers_mut()
+    ///         .insert(header::CACHE_CONTROL, HeaderValue::from_static("no-store"));
+    ///     response
+    /// });
+    /// assert_eq!(response.headers()[header::CACHE_CONTROL], "no-store");
+    /// ```
+    pub fn into_response_with(self, f: impl FnOnce(Response) -> Response) -> Response {
+        f(self.into_response())
+    }
+
+    /// Render both errors and combine them with the formatter `F`
+    fn render<F: RejectionFormatter>(self) -> Response {
+        F::format_typed(
+            (any::type_name::<LE>(), self.left_error.into_response()),
+            (any::type_name::<RE>(), self.right_error.into_response()),
+        )
+    }
+}
+
+impl<LE, RE> AxumEitherRejection<LE, RE>
+where
+    LE: fmt::Display,
+    RE: fmt::Display,
+{
+    /// The message of both errors as an owned string, see the [`Display`](fmt::Display)
+    /// implementation.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::AxumEitherRejection;
+    /// let rejection = AxumEitherRejection::new("Expected json", "Expected a form");
+    /// assert_eq!(
+    ///     rejection.to_owned_message(),
+    ///     "Could not parse request, left error: Expected json, right error: Expected a form"
+    /// );
+    /// ```
+    pub fn to_owned_message(&self) -> String {
+        self.to_string()
+    }
+
+    /// The message of both errors, each error is truncated so the message is at most `limit`
+    /// bytes long. The [`Display`](fmt::Display) implementation uses
+    /// [`DEFAULT_MESSAGE_LIMIT`].
+    ///
+    /// The errors are written straight into the output, they are never buffered.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::{rejection::TRUNCATION_MARKER, AxumEitherRejection};
+    /// let rejection = AxumEitherRejection::new("x".repeat(1000), "Expected a form");
+    /// let message = rejection.display_with_limit(128).to_string();
+    /// assert!(message.len() <= 128);
+    /// assert!(message.contains(TRUNCATION_MARKER));
+    /// assert!(message.ends_with("right error: Expected a form"));
+    /// ```
+    pub fn display_with_limit(&self, limit: usize) -> impl fmt::Display + '_ {
+        BothMessages::display(&self.left_error, &self.right_error, limit)
+    }
+}
+
+impl<LE, RE> AxumEitherRejection<LE, RE>
+where
+    LE: RejectionInfo,
+    RE: RejectionInfo,
+{
+    /// The [`RejectionInfo::message`] of both errors, each error is truncated so the message is
+    /// at most `limit` bytes long. [`RejectionInfo::message`] uses [`DEFAULT_MESSAGE_LIMIT`].
+    ///
+    /// The errors are written with [`RejectionInfo::write_message`], so the part of a message
+    /// beyond the limit is not buffered.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::{rejection::TRUNCATION_MARKER, AxumEitherRejection};
+    /// # use http::StatusCode;
+    /// let long = "x".repeat(1000);
+    /// let rejection = AxumEitherRejection::new(
+    ///     (StatusCode::BAD_REQUEST, long),
+    ///     (StatusCode::BAD_REQUEST, "Expected a form"),
+    /// );
+    /// let message = rejection.message_with_limit(128);
+    /// assert!(message.len() <= 128);
+    /// assert!(message.contains(TRUNCATION_MARKER));
+    /// assert!(message.ends_with("right error: Expected a form"));
+    /// ```
+    pub fn message_with_limit(&self, limit: usize) -> String {
+        BothMessages::message(
+            &DisplayMessage(&self.left_error),
+            &DisplayMessage(&self.right_error),
+            limit,
+        )
+        .to_string()
+    }
+}
+
+impl<LE, RE> fmt::Display for AxumEitherRejection<LE, RE>
+where
+    LE: fmt::Display,
+    RE: fmt::Display,
+{
+    /// Both errors are truncated so the message is at most [`DEFAULT_MESSAGE_LIMIT`] bytes long,
+    /// use [`display_with_limit`](AxumEitherRejection::display_with_limit) for another limit.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.display_with_limit(DEFAULT_MESSAGE_LIMIT), f)
+    }
+}
+
+/// The default limit of rejection messages in bytes
+pub const DEFAULT_MESSAGE_LIMIT: usize = 4096;
+
+/// Appended to the errors which were truncated to fit into the message limit
+pub const TRUNCATION_MARKER: &str = "... (truncated)";
+
+/// The message of [`AxumEitherRejection`], the errors are trait objects so it is only compiled
+/// once.
+///
+/// Both errors are written straight into a [`Capped`] writer, the left error gets half of the
+/// limit and the right error the rest.
+pub(crate) struct BothMessages<'a> {
+    prefix: &'static str,
+    separator: &'static str,
+    left: &'a dyn fmt::Display,
+    right: &'a dyn fmt::Display,
+    limit: usize,
+}
+
+impl<'a> BothMessages<'a> {
+    /// The format of the [`Display`](fmt::Display) implementation of [`AxumEitherRejection`]
+    pub(crate) fn display(
+        left: &'a dyn fmt::Display,
+        right: &'a dyn fmt::Display,
+        limit: usize,
+    ) -> Self {
+        Self {
+            prefix: "Could not parse request, left error: ",
+            separator: ", right error: ",
+            left,
+            right,
+            limit,
+        }
+    }
+
+    /// The format of [`RejectionInfo::message`](crate::RejectionInfo::message), which matches
+    /// the body rendered by [`BothRejections`]
+    pub(crate) fn message(
+        left: &'a dyn fmt::Display,
+        right: &'a dyn fmt::Display,
+        limit: usize,
+    ) -> Self {
+        Self {
+            prefix: "Could not parse request\n\tleft error: ",
+            separator: "\n\tright error: ",
+            left,
+            right,
+            limit,
+        }
+    }
+}
+
+impl<'a> fmt::Display for BothMessages<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let budget = self
+            .limit
+            .saturating_sub(self.prefix.len() + self.separator.len());
+        let left_budget = budget / 2;
+
+        f.write_str(self.prefix)?;
+        let mut left = Capped::new(f, left_budget);
+        fmt::write(&mut left, format_args!("{}", self.left))?;
+        let right_budget = budget - left.written();
+        f.write_str(self.separator)?;
+        fmt::write(
+            &mut Capped::new(f, right_budget),
+            format_args!("{}", self.right),
+        )
+    }
+}
+
+/// Writes at most `limit` bytes, [`TRUNCATION_MARKER`] included, into the inner writer
+pub(crate) struct Capped<'a, W: ?Sized> {
+    inner: &'a mut W,
+    limit: usize,
+    written: usize,
+    truncated: bool,
+}
+
+impl<'a, W> Capped<'a, W>
+where
+    W: fmt::Write + ?Sized,
+{
+    pub(crate) fn new(inner: &'a mut W, limit: usize) -> Self {
+        Self {
+            inner,
+            limit,
+            written: 0,
+            truncated: false,
+        }
+    }
+
+    /// The number of bytes written into the inner writer
+    pub(crate) fn written(&self) -> usize {
+        self.written
+    }
+}
+
+impl<'a, W> fmt::Write for Capped<'a, W>
+where
+    W: fmt::Write + ?Sized,
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.truncated {
+            return Ok(());
+        }
+
+        if self.written + s.len() <= self.limit {
+            self.written += s.len();
+            return self.inner.write_str(s);
+        }
+
+        // Keep room for the marker, the marker alone is dropped if it does not fit
+        let room = self
+            .limit
+            .saturating_sub(self.written + TRUNCATION_MARKER.len());
+        let mut end = room.min(s.len());
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        self.truncated = true;
+        self.inner.write_str(&s[..end])?;
+        self.written += end;
+        if self.written + TRUNCATION_MARKER.len() <= self.limit {
+            self.written += TRUNCATION_MARKER.len();
+            self.inner.write_str(TRUNCATION_MARKER)?;
+        }
+        Ok(())
+    }
+}
+
+/// The source of the rejection is the left error, the right error is only part of the message.
+/// Use [`sources`](AxumEitherRejection::sources) to walk the errors of both sides.
+///
+/// # Examples
+/// ```
+/// use axum::extract::rejection::{FormRejection, JsonRejection};
+/// use axum_either::AxumEitherRejection;
+///
+/// #[derive(Debug, thiserror::Error)]
+/// pub enum AppError {
+///     #[error("invalid payload")]
+///     Payload(#[from] AxumEitherRejection<JsonRejection, FormRejection>),
+/// }
+/// ```
+impl<LE, RE> std::error::Error for AxumEitherRejection<LE, RE>
+where
+    LE: std::error::Error + 'static,
+    RE: std::error::Error,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.left_error)
+    }
+}
+
+impl<LE, RE> AxumEitherRejection<LE, RE>
+where
+    LE: std::error::Error + 'static,
+    RE: std::error::Error + 'static,
+{
+    /// The errors of both sides, the left error first.
+    ///
+    /// [`Error::source`](std::error::Error::source) can only return one error, so it only
+    /// returns the left error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use axum_either::AxumEitherRejection;
+    /// # use std::fmt;
+    /// #[derive(Debug)]
+    /// struct Invalid(&'static str);
+    ///
+    /// impl fmt::Display for Invalid {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         f.write_str(self.0)
+    ///     }
+    /// }
+    ///
+    /// impl std::error::Error for Invalid {}
+    ///
+    /// let rejection = AxumEitherRejection::new(Invalid("Expected json"), Invalid("Expected a form"));
+    /// let messages: Vec<_> = rejection.sources().map(|error| error.to_string()).collect();
+    /// assert_eq!(messages, ["Expected json", "Expected a form"]);
+    /// ```
+    pub fn sources(&self) -> impl Iterator<Item = &(dyn std::error::Error + 'static)> + '_ {
+        let left: &(dyn std::error::Error + 'static) = &self.left_error;
+        let right: &(dyn std::error::Error + 'static) = &self.right_error;
+        [left, right].into_iter()
+    }
+}
+
+impl<LE, RE> IntoResponse for AxumEitherRejection<LE, RE>
+where
+    LE: IntoResponse,
+    RE: IntoResponse,
+{
+    fn into_response(self) -> Response {
+        self.render::<BothRejections>()
+    }
+}
+
+/// An [`AxumEitherRejection`] rendered by the [`RejectionFormatter`] `F`.
+///
+/// Created by [`AxumEitherRejection::with_formatter`] and the [`WithFormatter`] extractor. The
+/// formatter decides the response, the status, the headers and the
+/// [`preferred`](Self::preferred) error. Everything else is available on the wrapped
+/// rejection, which this type dereferences to.
+#[derive(Debug, Clone, Copy, Hash, Default, PartialEq, PartialOrd, Eq, Ord)]
+pub struct FormattedRejection<LE, RE, F> {
+    rejection: AxumEitherRejection<LE, RE>,
+    formatter: PhantomData<fn() -> F>,
+}
+
+impl<LE, RE, F> FormattedRejection<LE, RE, F> {
+    /// The wrapped rejection, which is rendered with [`BothRejections`] again
+    pub fn into_inner(self) -> AxumEitherRejection<LE, RE> {
+        self.rejection
+    }
+
+    /// Render the rejection with another [`RejectionFormatter`]
+    pub fn with_formatter<F2>(self) -> FormattedRejection<LE, RE, F2> {
+        self.rejection.with_formatter()
+    }
+
+    /// Map the left error using the given function `f`, see
+    /// [`AxumEitherRejection::map_left_error`]
+    pub fn map_left_error<U>(self, f: impl FnOnce(LE) -> U) -> FormattedRejection<U, RE, F> {
+        self.rejection.map_left_error(f).with_formatter()
+    }
+
+    /// Map the right error using the given function `f`, see
+    /// [`AxumEitherRejection::map_right_error`]
+    pub fn map_right_error<U>(self, f: impl FnOnce(RE) -> U) -> FormattedRejection<LE, U, F> {
+        self.rejection.map_right_error(f).with_formatter()
+    }
+
+    /// Map both the left and right errors with the given `lf` and `rf` functions
+    pub fn map_errors<U, V>(
+        self,
+        lf: impl FnOnce(LE) -> U,
+        rf: impl FnOnce(RE) -> V,
+    ) -> FormattedRejection<U, V, F> {
+        self.rejection.map_errors(lf, rf).with_formatter()
+    }
+}
+
+impl<LE, RE, F> FormattedRejection<LE, RE, F>
+where
+    LE: RejectionInfo,
+    RE: RejectionInfo,
+    F: RejectionFormatter,
+{
+    /// The error most worth reporting according to the formatter `F`
+    pub fn preferred(&self) -> AxumEither<&LE, &RE> {
+        self.rejection.preferred_by::<F>()
+    }
+
+    /// Take the error most worth reporting according to the formatter `F` out of the rejection
+    pub fn into_preferred(self) -> AxumEither<LE, RE> {
+        self.rejection.into_preferred_by::<F>()
+    }
+}
+
+impl<LE, RE, F> FormattedRejection<LE, RE, F>
+where
+    LE: IntoResponse,
+    RE: IntoResponse,
+    F: RejectionFormatter,
+{
+    /// Render the rejection with the formatter `F` and post-process the response with `f`
+    pub fn into_response_with(self, f: impl FnOnce(Response) -> Response) -> Response {
+        f(self.into_response())
+    }
+}
+
+impl<LE, RE, F> Deref for FormattedRejection<LE, RE, F> {
+    type Target = AxumEitherRejection<LE, RE>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.rejection
+    }
+}
+
+impl<LE, RE, F> DerefMut for FormattedRejection<LE, RE, F> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.rejection
+    }
+}
+
+impl<LE, RE, F> fmt::Display for FormattedRejection<LE, RE, F>
+where
+    LE: fmt::Display,
+    RE: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.rejection, f)
+    }
+}
+
+impl<LE, RE, F> std::error::Error for FormattedRejection<LE, RE, F>
+where
+    LE: std::error::Error + 'static,
+    RE: std::error::Error,
+    F: fmt::Debug,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        std::error::Error::source(&self.rejection)
+    }
+}
+
+impl<LE, RE, F> IntoResponse for FormattedRejection<LE, RE, F>
+where
+    LE: IntoResponse,
+    RE: IntoResponse,
+    F: RejectionFormatter,
+{
+    fn into_response(self) -> Response {
+        self.rejection.render::<F>()
+    }
+}
+
+/// Renders the rejections of both sides of an [`AxumEither`] into a single response.
+///
+/// Formatters are selected at the type level with [`WithFormatter`] or
+/// [`AxumEitherRejection::with_formatter`].
+pub trait RejectionFormatter {
+    /// Combine the already rendered left and right rejections into the final response.
+    fn format(left: Response, right: Response) -> Response;
+
+    /// Combine the rendered rejections like [`format`](Self::format), with the
+    /// [type names](std::any::type_name) of the rejections they were rendered from.
+    ///
+    /// [`AxumEitherRejection`] is rendered with this method. The default implementation ignores
+    /// the type names and calls [`format`](Self::format).
+    fn format_typed(left: (&'static str, Response), right: (&'static str, Response)) -> Response {
+        Self::format(left.1, right.1)
+    }
+
+    /// The status [`format`](Self::format) responds with for rejections with the given statuses.
+    ///
+    /// This is used to classify rejections without rendering them, the default implementation
+    /// applies [`EscalateServerErrors`].
+    fn status(left: StatusCode, right: StatusCode) -> StatusCode {
+        EscalateServerErrors::status(left, right)
+    }
+
+    /// Whether the left rejection is the one most worth reporting for rejections with the given
+    /// statuses, this is the side which decides the status in [`format`](Self::format).
+    ///
+    /// The default implementation applies [`EscalateServerErrors`].
+    fn prefer_left(left: StatusCode, right: StatusCode) -> bool {
+        EscalateServerErrors::prefer_left(left, right)
+    }
+
+    /// The headers [`format`](Self::format) responds with for rejections with the given statuses
+    /// and headers, see [`RejectionInfo::headers`].
+    ///
+    /// This is used by [`LazyRejection`](crate::LazyRejection) to respond without rendering the
+    /// body. The default implementation forwards the headers of the side selected by
+    /// [`prefer_left`](Self::prefer_left) except `Content-Type` and `Content-Length`, and sets a
+    /// UTF-8 encoded plain text content type.
+    fn headers(left: (StatusCode, HeaderMap), right: (StatusCode, HeaderMap)) -> HeaderMap {
+        let forwarded = if Self::prefer_left(left.0, right.0) {
+            left.1
+        } else {
+            right.1
+        };
+        let mut headers = crate::info::plain_text_headers();
+        forward_headers(&forwarded, &mut headers);
+        headers
+    }
+}
+
+/// The default [`RejectionFormatter`], reports both errors in a UTF-8 encoded plain text body.
+///
+/// The status is selected by the [`StatusPolicy`] `P`, by default a server error on either side
+/// results in `500 Internal Server Error` and `400 Bad Request` is used otherwise.
+///
+/// The headers of the side which decided the status are forwarded to the combined response.
+/// `Content-Type` and `Content-Length` are not forwarded as they describe the original body.
+#[derive(Debug, Clone, Copy, Hash, Default, PartialEq, PartialOrd, Eq, Ord)]
+pub struct BothRejections<P = EscalateServerErrors>(PhantomData<P>);
+
+impl<P> BothRejections<P> {
+    /// The content type of the rendered rejection
+    pub const CONTENT_TYPE: &'static str = "text/plain; charset=utf-8";
+}
+
+impl<P> RejectionFormatter for BothRejections<P>
+where
+    P: StatusPolicy,
+{
+    fn format(left: Response, right: Response) -> Response {
+        let status = P::status(left.status(), right.status());
+        let prefer_left = P::prefer_left(left.status(), right.status());
+        render_both(status, Self::CONTENT_TYPE, prefer_left, left, right)
+    }
+
+    fn status(left: StatusCode, right: StatusCode) -> StatusCode {
+        P::status(left, right)
+    }
+
+    fn prefer_left(left: StatusCode, right: StatusCode) -> bool {
+        P::prefer_left(left, right)
+    }
+}
+
+/// Render the response of [`BothRejections`] with the given content type, this is not generic so
+/// it is only compiled once for all policies.
+///
+/// Both rejections are described by the text of their bodies, the combined message is capped at
+/// [`DEFAULT_MESSAGE_LIMIT`] like [`RejectionInfo::message`].
+fn render_both(
+    status: StatusCode,
+    content_type: &'static str,
+    prefer_left: bool,
+    left: Response,
+    right: Response,
+) -> Response {
+    let (mut left, left_body) = left.into_parts();
+    let (mut right, right_body) = right.into_parts();
+    let left_message = describe(left.status, left_body);
+    let right_message = describe(right.status, right_body);
+    let body =
+        BothMessages::message(&left_message, &right_message, DEFAULT_MESSAGE_LIMIT).to_string();
+    let mut attempts = RenderedAttempts::default();
+    attempts.push(&mut left, left_message);
+    attempts.push(&mut right, right_message);
+    let forwarded = if prefer_left {
+        left.headers
+    } else {
+        right.headers
+    };
+
+    let mut response = (
+        status,
+        [(header::CONTENT_TYPE, HeaderValue::from_static(content_type))],
+        body,
+    )
+        .into_response();
+    forward_headers(&forwarded, response.headers_mut());
+    response.extensions_mut().insert(attempts);
+    response
+}
+
+/// The failed extractions of a rejection rendered by [`BothRejections`] or
+/// [`ProblemDetails`](crate::ProblemDetails), as their statuses and descriptions.
+///
+/// They are attached to the extensions of the rendered response, so the formatter of an
+/// enclosing either reports the failures of a nested chain one by one instead of as a single
+/// failure. Formatters only get the rendered responses of both sides, this is the part of
+/// [`RejectionInfo::visit_attempts`] which survives rendering.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RenderedAttempts(pub(crate) Vec<(StatusCode, String)>);
+
+impl RenderedAttempts {
+    /// Add the failures of a rendered side, the flattened failures if it is a rendered chain,
+    /// otherwise the side itself described by `message`
+    pub(crate) fn push(&mut self, side: &mut response::Parts, message: String) {
+        match side.extensions.remove::<Self>() {
+            Some(nested) => self.0.extend(nested.0),
+            None => self.0.push((side.status, message)),
+        }
+    }
+}
+
+/// Decides the status of a rejection combining the errors of multiple extractors.
+pub trait StatusPolicy {
+    /// Whether the left error decides the combined status, otherwise the right error does.
+    fn prefer_left(left: StatusCode, right: StatusCode) -> bool;
+
+    /// The status of the combined rejection
+    fn status(left: StatusCode, right: StatusCode) -> StatusCode;
+}
+
+/// The default [`StatusPolicy`], a server error on either side results in
+/// `500 Internal Server Error`, otherwise `400 Bad Request` is used.
+///
+/// This is conservative, a failure inside of the server is never hidden behind a client error.
+/// The downside is that a request which was correctly rejected by one extractor is reported as a
+/// server error if another extractor failed with a server error, for example because of a
+/// transient backend failure. Use [`PreferClientErrors`] if client errors should be reported
+/// instead.
+///
+/// The left side decides the status unless only the right side failed with a server error.
+#[derive(Debug, Clone, Copy, Hash, Default, PartialEq, PartialOrd, Eq, Ord)]
+pub struct EscalateServerErrors;
+
+impl StatusPolicy for EscalateServerErrors {
+    fn prefer_left(left: StatusCode, right: StatusCode) -> bool {
+        left.is_server_error() || !right.is_server_error()
+    }
+
+    fn status(left: StatusCode, right: StatusCode) -> StatusCode {
+        if left.is_server_error() || right.is_server_error() {
+            StatusCode::INTERNAL_SERVER_ERROR
+        } else {
+            StatusCode::BAD_REQUEST
+        }
+    }
+}
+
+/// A [`StatusPolicy`] which prefers client errors, only if both sides failed with a server
+/// error `500 Internal Server Error` is used, otherwise `400 Bad Request`.
+///
+/// When exactly one side failed with a server error, the client error of the other side is
+/// reported and decides the status. This keeps legitimately rejected requests out of server error
+/// dashboards but may hide a server failure from the client if the failing extractor was the one
+/// meant to handle the request.
+///
+/// # Examples
+/// ```
+/// # use axum_either::{AxumEitherRejection, BothRejections, PreferClientErrors};
+/// # use axum_core::response::IntoResponse;
+/// # use http::StatusCode;
+/// let rejection = AxumEitherRejection::new(StatusCode::SERVICE_UNAVAILABLE, StatusCode::NOT_FOUND);
+/// let response = rejection
+///     .with_formatter::<BothRejections<PreferClientErrors>>()
+///     .into_response();
+/// assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+/// ```
+#[derive(Debug, Clone, Copy, Hash, Default, PartialEq, PartialOrd, Eq, Ord)]
+pub struct PreferClientErrors;
+
+impl StatusPolicy for PreferClientErrors {
+    fn prefer_left(left: StatusCode, right: StatusCode) -> bool {
+        !left.is_server_error() || right.is_server_error()
+    }
+
+    fn status(left: StatusCode, right: StatusCode) -> StatusCode {
+        if left.is_server_error() && right.is_server_error() {
+            StatusCode::INTERNAL_SERVER_ERROR
+        } else {
+            StatusCode::BAD_REQUEST
+        }
+    }
+}
+
+/// Append all headers except `Content-Type` and `Content-Length` from `from` to `to`
+pub(crate) fn forward_headers(from: &HeaderMap, to: &mut HeaderMap) {
+    for (name, value) in from {
+        if *name != header::CONTENT_TYPE && *name != header::CONTENT_LENGTH {
+            to.append(name.clone(), value.clone());
+        }
+    }
+}
+
+/// A [`RejectionFormatter`] which only responds with the error of a single side.
+///
+/// This does not reveal that the endpoint also accepts other formats. The reported side is
+/// selected as follows:
+///
+/// - If the left side rejected with `415 Unsupported Media Type` and the right side did not, the
+///   request content type matched the right extractor and the right error is reported.
+/// - Otherwise the left error is reported.
+///
+/// The selected response is passed through unchanged. The error of the other side is only dropped
+/// from the response, it is still available on the [`AxumEitherRejection`] for server side logging.
+///
+/// Logging the dropped error requires the `tracing` feature, it is then logged as a `DEBUG` event
+/// with its type, status and message when the response is rendered. Without `tracing` the dropped
+/// error is not reported anywhere, neither the `observer` hooks nor the `metrics` feature
+/// see it since formatters run without the request. Log both errors of the rejection before it is
+/// rendered if they are needed, for example with [`sources`](AxumEitherRejection::sources).
+#[derive(Debug, Clone, Copy, Hash, Default, PartialEq, PartialOrd, Eq, Ord)]
+pub struct SingleRejection;
+
+impl RejectionFormatter for SingleRejection {
+    fn format(left: Response, right: Response) -> Response {
+        Self::format_typed(("left", left), ("right", right))
+    }
+
+    fn format_typed(left: (&'static str, Response), right: (&'static str, Response)) -> Response {
+        let (reported, discarded) = if Self::prefer_left(left.1.status(), right.1.status()) {
+            (left, right)
+        } else {
+            (right, left)
+        };
+        #[cfg(feature = "tracing")]
+        crate::tracing::discarded(discarded.0, discarded.1);
+        #[cfg(not(feature = "tracing"))]
+        drop(discarded);
+        reported.1
+    }
+
+    fn status(left: StatusCode, right: StatusCode) -> StatusCode {
+        if Self::prefer_left(left, right) {
+            left
+        } else {
+            right
+        }
+    }
+
+    fn prefer_left(left: StatusCode, right: StatusCode) -> bool {
+        left != StatusCode::UNSUPPORTED_MEDIA_TYPE || right == StatusCode::UNSUPPORTED_MEDIA_TYPE
+    }
+
+    fn headers(left: (StatusCode, HeaderMap), right: (StatusCode, HeaderMap)) -> HeaderMap {
+        if Self::prefer_left(left.0, right.0) {
+            left.1
+        } else {
+            right.1
+        }
+    }
+}
@@ -0,0 +1,247 @@
+//! Respond with one of multiple types.
+//!
+//! [`AxumEither`] responds with its active side, the wrappers of this module adapt the response.
+//! The other responders of this crate are re-exported here.
+
+use axum_core::response::{IntoResponse, IntoResponseParts, Response, ResponseParts};
+use http::StatusCode;
+
+use crate::{tag, AxumEither};
+
+#[cfg(feature = "body")]
+pub use crate::body::EitherBody;
+#[cfg(feature = "any-format")]
+pub use crate::format::SerializeNegotiated;
+#[cfg(feature = "html-or-json")]
+pub use crate::html::HtmlOrJson;
+#[cfg(feature = "negotiation")]
+pub use crate::negotiate::{LanguageNegotiate, Negotiate};
+pub use crate::tag::{Tagged, VARIANT_HEADER};
+
+impl<L, R> IntoResponse for AxumEither<L, R>
+where
+    L: IntoResponse,
+    R: IntoResponse,
+{
+    fn into_response(self) -> Response {
+        if !tag::is_recording() {
+            return match self {
+                Self::Left(l) => l.into_response(),
+                Self::Right(r) => r.into_response(),
+            };
+        }
+
+        let (index, mut response) = match self {
+            Self::Left(l) => (0, l.into_response()),
+            Self::Right(r) => {
+                let response = r.into_response();
+                let index = tag::variant_index(&response).map_or(1, |index| index + 1);
+                (index, response)
+            }
+        };
+        tag::set_variant_index(&mut response, index);
+        response
+    }
+}
+
+/// Use an [`AxumEither`] of response parts in a response tuple.
+///
+/// # Examples
+/// ```
+/// use axum::{http::header, response::{AppendHeaders, IntoResponse}, Json};
+/// use axum_either::prelude::*;
+///
+/// pub async fn handler(cached: bool) -> impl IntoResponse {
+///     let parts = if cached {
+///         AxumEither::Left([(header::CACHE_CONTROL, "max-age=3600")])
+///     } else {
+///         AxumEither::Right(AppendHeaders([(header::CACHE_CONTROL, "no-store")]))
+///     };
+///     (parts, Json(cached))
+/// }
+/// ```
+impl<L, R> IntoResponseParts for AxumEither<L, R>
+where
+    L: IntoResponseParts,
+    R: IntoResponseParts,
+{
+    type Error = AxumEither<L::Error, R::Error>;
+
+    fn into_response_parts(self, res: ResponseParts) -> Result<ResponseParts, Self::Error> {
+        match self {
+            Self::Left(l) => l.into_response_parts(res).map_err(AxumEither::Left),
+            Self::Right(r) => r.into_response_parts(res).map_err(AxumEither::Right),
+        }
+    }
+}
+
+/// Overrides the status of the response of `T`, its headers and body are kept.
+///
+/// # Examples
+/// ```
+/// use axum::{http::StatusCode, Json};
+/// use axum_either::{AxumEither, WithStatus};
+///
+/// pub async fn create(
+///     request: AxumEither<Json<String>, String>,
+/// ) -> WithStatus<AxumEither<Json<String>, String>> {
+///     request.with_status(StatusCode::CREATED)
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, PartialOrd, Eq, Ord)]
+pub struct WithStatus<T>(pub StatusCode, pub T);
+
+impl<L, R> AxumEither<WithStatus<L>, R> {
+    /// Respond with `l` and `status` on the left side, the status of the right side is kept.
+    ///
+    /// This is the same as a `(StatusCode, L)` tuple on the left side. An outer status, like
+    /// [`AxumEither::with_status`] or a `(StatusCode, AxumEither<L, R>)` tuple, still overrides it.
+    ///
+    /// # Examples
+    /// ```
+    /// use axum::{http::StatusCode, response::Html, Json};
+    /// use axum_either::{AxumEither, WithStatus};
+    ///
+    /// pub async fn upsert(created: bool) -> AxumEither<WithStatus<Json<u32>>, Html<&'static str>> {
+    ///     if created {
+    ///         AxumEither::left_with_status(StatusCode::CREATED, Json(1))
+    ///     } else {
+    ///         AxumEither::Right(Html("<p>unchanged</p>"))
+    ///     }
+    /// }
+    /// ```
+    pub fn left_with_status(status: StatusCode, l: L) -> Self {
+        Self::Left(WithStatus(status, l))
+    }
+}
+
+impl<L, R> AxumEither<L, WithStatus<R>> {
+    /// Respond with `r` and `status` on the right side, the status of the left side is kept.
+    ///
+    /// See [`left_with_status`](AxumEither::left_with_status).
+    pub fn right_with_status(status: StatusCode, r: R) -> Self {
+        Self::Right(WithStatus(status, r))
+    }
+}
+
+impl<T> IntoResponse for WithStatus<T>
+where
+    T: IntoResponse,
+{
+    fn into_response(self) -> Response {
+        let mut response = self.1.into_response();
+        *response.status_mut() = self.0;
+        response
+    }
+}
+
+/// Inserts the extension `E` into the response of `T`, for example for middleware inspecting
+/// responses.
+///
+/// # Examples
+/// ```
+/// use axum::Json;
+/// use axum_either::{AxumEither, WithExtension};
+///
+/// #[derive(Clone)]
+/// pub struct Format(&'static str);
+///
+/// pub async fn echo(
+///     request: AxumEither<Json<String>, String>,
+/// ) -> WithExtension<AxumEither<Json<String>, String>, Format> {
+///     let format = request.as_ref().map_lr(|_| Format("json"), |_| Format("text"));
+///     request.with_extension(format.into_inner())
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, PartialOrd, Eq, Ord)]
+pub struct WithExtension<T, E>(pub T, pub E);
+
+impl<T, E> IntoResponse for WithExtension<T, E>
+where
+    T: IntoResponse,
+    E: Clone + Send + Sync + 'static,
+{
+    fn into_response(self) -> Response {
+        let mut response = self.0.into_response();
+        response.extensions_mut().insert(self.1);
+        response
+    }
+}
+
+/// Constructors for the responders of axum handlers return most often.
+#[cfg(feature = "axum")]
+mod axum_constructors {
+    use axum::{
+        response::{Html, Redirect},
+        Json,
+    };
+
+    use crate::AxumEither;
+
+    impl<T, R> AxumEither<Json<T>, R> {
+        /// Respond with `value` as json on the left side.
+        ///
+        /// # Examples
+        /// ```
+        /// use axum::{http::StatusCode, response::Html, Json};
+        /// use axum_either::prelude::*;
+        ///
+        /// pub async fn user(id: u32) -> Result<AxumEither<Json<u32>, Html<String>>, StatusCode> {
+        ///     if id == 0 {
+        ///         return Err(StatusCode::NOT_FOUND);
+        ///     }
+        ///     Ok(AxumEither::json_left(id))
+        /// }
+        /// ```
+        pub fn json_left(value: T) -> Self {
+            Self::Left(Json(value))
+        }
+    }
+
+    impl<L, T> AxumEither<L, Json<T>> {
+        /// Respond with `value` as json on the right side.
+        pub fn json_right(value: T) -> Self {
+            Self::Right(Json(value))
+        }
+    }
+
+    impl<T, R> AxumEither<Html<T>, R> {
+        /// Respond with `html` on the left side.
+        pub fn html_left(html: T) -> Self {
+            Self::Left(Html(html))
+        }
+    }
+
+    impl<L, T> AxumEither<L, Html<T>> {
+        /// Respond with `html` on the right side.
+        ///
+        /// # Examples
+        /// ```
+        /// use axum::{response::Html, Json};
+        /// use axum_either::prelude::*;
+        ///
+        /// pub async fn page() -> AxumEither<Json<u32>, Html<&'static str>> {
+        ///     AxumEither::html_right("<h1>Hello</h1>")
+        /// }
+        /// ```
+        pub fn html_right(html: T) -> Self {
+            Self::Right(Html(html))
+        }
+    }
+
+    impl<R> AxumEither<Redirect, R> {
+        /// Respond with a `303 See Other` redirect to `uri` on the left side, see
+        /// [`Redirect::to`].
+        pub fn redirect_left(uri: &str) -> Self {
+            Self::Left(Redirect::to(uri))
+        }
+    }
+
+    impl<L> AxumEither<L, Redirect> {
+        /// Respond with a `303 See Other` redirect to `uri` on the right side, see
+        /// [`Redirect::to`].
+        pub fn redirect_right(uri: &str) -> Self {
+            Self::Right(Redirect::to(uri))
+        }
+    }
+}
@@ -0,0 +1,150 @@
+//! Register a handler per request format on a single route.
+//!
+//! # Examples
+//! ```
+//! use axum::{Form, Json, Router};
+//! use axum_either::routing::RouterExt;
+//!
+//! #[derive(serde::Deserialize)]
+//! pub struct Hello {
+//!     name: String,
+//! }
+//!
+//! async fn json_hello(Json(hello): Json<Hello>) -> String {
+//!     format!("Hello {} from json", hello.name)
+//! }
+//!
+//! async fn form_hello(Form(hello): Form<Hello>) -> String {
+//!     format!("Hello {} from a form", hello.name)
+//! }
+//!
+//! let router: Router = Router::new().route_negotiated("/hello", json_hello, form_hello);
+//! ```
+//!
+//! The dispatch only looks at the last argument of a handler, which has to extract the body.
+//! Other extractors may come before it as long as they do not read the body:
+//! ```
+//! use axum::{extract::Path, Form, Json, Router};
+//! use axum_either::routing::RouterExt;
+//!
+//! async fn json_rename(Path(id): Path<u32>, Json(name): Json<String>) -> String {
+//!     format!("Renamed {} to {}", id, name)
+//! }
+//!
+//! async fn form_rename(Path(id): Path<u32>, Form(name): Form<Vec<(String, String)>>) -> String {
+//!     format!("Renamed {} to {:?}", id, name)
+//! }
+//!
+//! let router: Router = Router::new().route_negotiated("/users/:id", json_rename, form_rename);
+//! ```
+
+use axum::{
+    handler::Handler,
+    http::{Request, StatusCode},
+    response::{IntoResponse, Response},
+    routing::any,
+    Router,
+};
+use http_body::Body;
+
+use crate::{media, AxumEither, MediaTypeDispatch};
+
+/// The extractor arguments of a handler, the last of them extracts the body
+///
+/// Only the last argument is inspected. The other arguments, like `Path` or `Query`, are not
+/// checked for a [`MediaTypeDispatch`] implementation and must not consume the body, otherwise
+/// the dispatch decides on an extractor which never sees the body.
+pub trait BodyExtractor {
+    /// The extractor of the body
+    type Body;
+}
+
+macro_rules! body_extractor {
+    ($($t:ident),* ; $last:ident) => {
+        impl<$($t,)* $last> BodyExtractor for ($($t,)* $last,) {
+            type Body = $last;
+        }
+    };
+}
+
+body_extractor!(; T1);
+body_extractor!(T1; T2);
+body_extractor!(T1, T2; T3);
+body_extractor!(T1, T2, T3; T4);
+body_extractor!(T1, T2, T3, T4; T5);
+body_extractor!(T1, T2, T3, T4, T5; T6);
+body_extractor!(T1, T2, T3, T4, T5, T6; T7);
+body_extractor!(T1, T2, T3, T4, T5, T6, T7; T8);
+body_extractor!(T1, T2, T3, T4, T5, T6, T7, T8; T9);
+body_extractor!(T1, T2, T3, T4, T5, T6, T7, T8, T9; T10);
+body_extractor!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10; T11);
+body_extractor!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11; T12);
+body_extractor!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12; T13);
+body_extractor!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13; T14);
+body_extractor!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14; T15);
+body_extractor!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15; T16);
+
+/// Adds routes dispatching to one of two handlers by the `Content-Type` of the request.
+pub trait RouterExt<B> {
+    /// Route `path` to `left` or `right`, whichever has a body extractor supporting the content
+    /// type of the request.
+    ///
+    /// The media types are taken from the [`SupportedMediaTypes`](crate::SupportedMediaTypes) of
+    /// the last extractor of each handler, `left` wins if both support the content type. Requests
+    /// neither supports are rejected with `415 Unsupported Media Type`.
+    ///
+    /// Only the last argument of each handler is checked, so the body extractor has to come last
+    /// and the arguments before it must leave the body alone, see [`BodyExtractor`].
+    fn route_negotiated<L, TL, R, TR>(self, path: &str, left: L, right: R) -> Self
+    where
+        L: Handler<TL, B>,
+        TL: BodyExtractor + 'static,
+        TL::Body: MediaTypeDispatch,
+        R: Handler<TR, B>,
+        TR: BodyExtractor + 'static,
+        TR::Body: MediaTypeDispatch;
+}
+
+impl<B> RouterExt<B> for Router<B>
+where
+    B: Body + Send + 'static,
+{
+    fn route_negotiated<L, TL, R, TR>(self, path: &str, left: L, right: R) -> Self
+    where
+        L: Handler<TL, B>,
+        TL: BodyExtractor + 'static,
+        TL::Body: MediaTypeDispatch,
+        R: Handler<TR, B>,
+        TR: BodyExtractor + 'static,
+        TR::Body: MediaTypeDispatch,
+    {
+        self.route(
+            path,
+            any(move |req: Request<B>| {
+                dispatch::<_, TL, _, TR, _>(left.clone(), right.clone(), req)
+            }),
+        )
+    }
+}
+
+async fn dispatch<L, TL, R, TR, B>(left: L, right: R, req: Request<B>) -> Response
+where
+    L: Handler<TL, B>,
+    TL: BodyExtractor,
+    TL::Body: MediaTypeDispatch,
+    R: Handler<TR, B>,
+    TR: BodyExtractor,
+    TR::Body: MediaTypeDispatch,
+{
+    let claim = media::content_type(req.headers()).and_then(|content_type| {
+        AxumEither::<TL::Body, TR::Body>::claims(&content_type)
+            .first()
+            .copied()
+    });
+
+    match claim {
+        Some(0) => left.call(req).await,
+        Some(_) => right.call(req).await,
+        None => StatusCode::UNSUPPORTED_MEDIA_TYPE.into_response(),
+    }
+}
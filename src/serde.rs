@@ -0,0 +1,574 @@
+//! Serialize and deserialize [`AxumEither`] with serde.
+//!
+//! [`AxumEither`] is untagged, the active variant is serialized transparently. Deserialization
+//! tries `L` first and only falls back to `R` if `L` fails, like extraction does. If both sides
+//! accept the same data the variant is lost in a round trip, a serialized `Right` is deserialized
+//! as `Left`.
+//!
+//! The input is buffered before it is deserialized into the sides, types borrowing from the input
+//! like `&str` can not be deserialized.
+//!
+//! # Examples
+//! ```
+//! # use axum_either::prelude::*;
+//! let either: AxumEither<u32, String> = serde_json::from_str("\"text\"").unwrap();
+//! assert_eq!(either, AxumEither::Right("text".to_owned()));
+//!
+//! // Both sides accept numbers, left wins
+//! let either: AxumEither<u32, u64> = serde_json::from_str("5").unwrap();
+//! assert_eq!(either, AxumEither::Left(5));
+//! ```
+
+use ::serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_value::Value;
+
+use crate::AxumEither;
+
+impl<L, R> Serialize for AxumEither<L, R>
+where
+    L: Serialize,
+    R: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Left(l) => l.serialize(serializer),
+            Self::Right(r) => r.serialize(serializer),
+        }
+    }
+}
+
+impl<'de, L, R> Deserialize<'de> for AxumEither<L, R>
+where
+    L: Deserialize<'de>,
+    R: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        let left_error = match value.clone().deserialize_into() {
+            Ok(l) => return Ok(Self::Left(l)),
+            Err(e) => e,
+        };
+        let right_error = match value.deserialize_into() {
+            Ok(r) => return Ok(Self::Right(r)),
+            Err(e) => e,
+        };
+
+        Err(de::Error::custom(format_args!(
+            "data matched neither side of AxumEither, left error: {}, right error: {}",
+            left_error, right_error
+        )))
+    }
+}
+
+/// Serialize an [`AxumEither`] externally tagged as `{"left": ...}` or `{"right": ...}`.
+///
+/// Use this with `#[serde(with = "axum_either::serde::tagged")]` if both sides can have the same
+/// representation.
+///
+/// # Examples
+/// ```
+/// use axum_either::prelude::*;
+///
+/// #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+/// struct Session {
+///     #[serde(with = "axum_either::serde::tagged")]
+///     user: AxumEither<String, String>,
+/// }
+///
+/// let session = Session { user: AxumEither::Right("guest".into()) };
+/// let json = serde_json::to_string(&session).unwrap();
+/// assert_eq!(json, r#"{"user":{"right":"guest"}}"#);
+/// assert_eq!(serde_json::from_str::<Session>(&json).unwrap(), session);
+/// ```
+pub mod tagged {
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::AxumEither;
+
+    #[derive(Serialize)]
+    #[serde(rename = "AxumEither", rename_all = "lowercase")]
+    enum TaggedRef<'a, L, R> {
+        Left(&'a L),
+        Right(&'a R),
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename = "AxumEither", rename_all = "lowercase")]
+    enum Tagged<L, R> {
+        Left(L),
+        Right(R),
+    }
+
+    /// Serialize `either` as `{"left": ...}` or `{"right": ...}`
+    pub fn serialize<L, R, S>(either: &AxumEither<L, R>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        L: Serialize,
+        R: Serialize,
+        S: Serializer,
+    {
+        match either {
+            AxumEither::Left(l) => TaggedRef::<L, R>::Left(l),
+            AxumEither::Right(r) => TaggedRef::Right(r),
+        }
+        .serialize(serializer)
+    }
+
+    /// Deserialize `{"left": ...}` or `{"right": ...}`
+    pub fn deserialize<'de, L, R, D>(deserializer: D) -> Result<AxumEither<L, R>, D::Error>
+    where
+        L: Deserialize<'de>,
+        R: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        Ok(match Tagged::deserialize(deserializer)? {
+            Tagged::Left(l) => AxumEither::Left(l),
+            Tagged::Right(r) => AxumEither::Right(r),
+        })
+    }
+}
+
+/// Serialize an [`AxumEither`] adjacently tagged as `{"tag": "left", "content": ...}`.
+///
+/// Use this with `#[serde(with = "axum_either::serde::adjacently_tagged")]`. Other keys are
+/// configured by implementing [`Keys`](adjacently_tagged::Keys) and using
+/// [`serialize_with_keys`](adjacently_tagged::serialize_with_keys) and
+/// [`deserialize_with_keys`](adjacently_tagged::deserialize_with_keys).
+///
+/// # Examples
+/// ```
+/// use axum_either::{serde::adjacently_tagged::Keys, AxumEither};
+///
+/// struct KindValue;
+///
+/// impl Keys for KindValue {
+///     const TAG: &'static str = "kind";
+///     const CONTENT: &'static str = "value";
+/// }
+///
+/// #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+/// struct Job {
+///     #[serde(
+///         serialize_with = "axum_either::serde::adjacently_tagged::serialize_with_keys::<KindValue, _, _, _>",
+///         deserialize_with = "axum_either::serde::adjacently_tagged::deserialize_with_keys::<KindValue, _, _, _>"
+///     )]
+///     payload: AxumEither<u32, String>,
+/// }
+///
+/// let job = Job { payload: AxumEither::Left(3) };
+/// let json = serde_json::to_string(&job).unwrap();
+/// assert_eq!(json, r#"{"payload":{"kind":"left","value":3}}"#);
+/// assert_eq!(serde_json::from_str::<Job>(&json).unwrap(), job);
+/// ```
+pub mod adjacently_tagged {
+    use ::serde::{
+        de::{self, MapAccess, Visitor},
+        ser::SerializeStruct,
+        Deserialize, Deserializer, Serialize, Serializer,
+    };
+    use serde_value::Value;
+    use std::{
+        fmt::{self, Formatter},
+        marker::PhantomData,
+    };
+
+    use crate::AxumEither;
+
+    /// The keys of the tag and the content
+    pub trait Keys {
+        /// The key of the tag, its value is `"left"` or `"right"`
+        const TAG: &'static str;
+        /// The key of the value of the active variant
+        const CONTENT: &'static str;
+    }
+
+    /// The keys `tag` and `content`
+    #[derive(Debug, Clone, Copy, Hash, Default, PartialEq, PartialOrd, Eq, Ord)]
+    pub struct DefaultKeys;
+
+    impl Keys for DefaultKeys {
+        const TAG: &'static str = "tag";
+        const CONTENT: &'static str = "content";
+    }
+
+    /// Serialize `either` with the [`DefaultKeys`]
+    pub fn serialize<L, R, S>(either: &AxumEither<L, R>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        L: Serialize,
+        R: Serialize,
+        S: Serializer,
+    {
+        serialize_with_keys::<DefaultKeys, L, R, S>(either, serializer)
+    }
+
+    /// Deserialize with the [`DefaultKeys`]
+    pub fn deserialize<'de, L, R, D>(deserializer: D) -> Result<AxumEither<L, R>, D::Error>
+    where
+        L: Deserialize<'de>,
+        R: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        deserialize_with_keys::<DefaultKeys, L, R, D>(deserializer)
+    }
+
+    /// Serialize `either` with the keys `K`
+    pub fn serialize_with_keys<K, L, R, S>(
+        either: &AxumEither<L, R>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        K: Keys,
+        L: Serialize,
+        R: Serialize,
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("AxumEither", 2)?;
+        match either {
+            AxumEither::Left(l) => {
+                state.serialize_field(K::TAG, "left")?;
+                state.serialize_field(K::CONTENT, l)?;
+            }
+            AxumEither::Right(r) => {
+                state.serialize_field(K::TAG, "right")?;
+                state.serialize_field(K::CONTENT, r)?;
+            }
+        }
+        state.end()
+    }
+
+    /// Deserialize with the keys `K`, the keys may appear in any order
+    pub fn deserialize_with_keys<'de, K, L, R, D>(
+        deserializer: D,
+    ) -> Result<AxumEither<L, R>, D::Error>
+    where
+        K: Keys,
+        L: Deserialize<'de>,
+        R: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            "AxumEither",
+            &[K::TAG, K::CONTENT],
+            AdjacentlyTaggedVisitor::<K, L, R>(PhantomData, PhantomData),
+        )
+    }
+
+    struct AdjacentlyTaggedVisitor<K, L, R>(PhantomData<fn() -> K>, PhantomData<fn() -> (L, R)>);
+
+    impl<'de, K, L, R> Visitor<'de> for AdjacentlyTaggedVisitor<K, L, R>
+    where
+        K: Keys,
+        L: Deserialize<'de>,
+        R: Deserialize<'de>,
+    {
+        type Value = AxumEither<L, R>;
+
+        fn expecting(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+            write!(
+                formatter,
+                "a map with the keys \"{}\" and \"{}\"",
+                K::TAG,
+                K::CONTENT
+            )
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut left = None;
+            let mut content = None;
+            while let Some(key) = map.next_key::<String>()? {
+                if key == K::TAG {
+                    left = Some(match map.next_value::<String>()?.as_str() {
+                        "left" => true,
+                        "right" => false,
+                        other => return Err(de::Error::unknown_variant(other, &["left", "right"])),
+                    });
+                } else if key == K::CONTENT {
+                    content = Some(map.next_value::<Value>()?);
+                } else {
+                    map.next_value::<de::IgnoredAny>()?;
+                }
+            }
+
+            let left = left.ok_or_else(|| de::Error::missing_field(K::TAG))?;
+            let content = content.ok_or_else(|| de::Error::missing_field(K::CONTENT))?;
+            if left {
+                content.deserialize_into().map(AxumEither::Left)
+            } else {
+                content.deserialize_into().map(AxumEither::Right)
+            }
+            .map_err(de::Error::custom)
+        }
+    }
+}
+
+/// Serialize a chain or a flat `OneOfN` as `{"variant": 2, "value": ...}` with the flattened
+/// index of the active variant.
+///
+/// A chain built with [`one_of`](crate::one_of) and the flat enum of the same types share the
+/// format, stored data stays readable when a field migrates between them.
+///
+/// The flat enums and [`AxumEither`]s whose right side is not a chain work with
+/// `#[serde(with = "axum_either::serde::flat_tagged")]`. A nested chain also is an
+/// [`AxumEither`] of two values, so its number of variants has to be named with
+/// [`serialize`](flat_tagged::serialize) and [`deserialize`](flat_tagged::deserialize).
+///
+/// # Examples
+/// ```
+/// use axum_either::{prelude::*, OneOf3};
+///
+/// #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+/// struct Flat {
+///     #[serde(with = "axum_either::serde::flat_tagged")]
+///     id: OneOf3<u32, u32, String>,
+/// }
+///
+/// #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+/// struct Nested {
+///     #[serde(
+///         serialize_with = "axum_either::serde::flat_tagged::serialize::<3, _, _>",
+///         deserialize_with = "axum_either::serde::flat_tagged::deserialize::<3, _, _>"
+///     )]
+///     id: one_of!(u32, u32, String),
+/// }
+///
+/// let flat = Flat { id: OneOf3::V1(7) };
+/// let json = serde_json::to_string(&flat).unwrap();
+/// assert_eq!(json, r#"{"id":{"variant":1,"value":7}}"#);
+///
+/// let nested: Nested = serde_json::from_str(&json).unwrap();
+/// assert_eq!(nested.id, AxumEither::Right(AxumEither::Left(7)));
+/// assert_eq!(serde_json::to_string(&nested).unwrap(), json);
+/// ```
+pub mod flat_tagged {
+    use ::serde::{
+        de::{self, DeserializeOwned, MapAccess, Unexpected, Visitor},
+        ser::SerializeStruct,
+        Deserializer, Serialize, Serializer,
+    };
+    use serde_value::{Value, ValueDeserializer};
+    use std::{
+        fmt::{self, Formatter},
+        marker::PhantomData,
+    };
+
+    use crate::{AxumEither, FlatIndex, OneOf3, OneOf4, OneOf5, OneOf6, OneOf7, OneOf8};
+
+    const VARIANT: &str = "variant";
+    const VALUE: &str = "value";
+
+    /// A chain or flat enum with `N` variants which are serialized with their flattened index
+    pub trait FlatTagged<const N: usize>: FlatIndex<N> + Sized {
+        /// Serialize the value of the active variant
+        fn serialize_value<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer;
+
+        /// Deserialize the value of the variant with the flattened index `variant`
+        fn deserialize_value<'de, D>(variant: usize, deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>;
+    }
+
+    /// The error for a variant index which is out of range
+    fn invalid_variant<E: de::Error>(variant: usize, variants: usize) -> E {
+        E::invalid_value(
+            Unexpected::Unsigned(variant as u64),
+            &format!("a variant index below {}", variants).as_str(),
+        )
+    }
+
+    impl<L, R> FlatTagged<2> for AxumEither<L, R>
+    where
+        L: Serialize + DeserializeOwned,
+        R: Serialize + DeserializeOwned,
+    {
+        fn serialize_value<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match self {
+                Self::Left(l) => l.serialize(serializer),
+                Self::Right(r) => r.serialize(serializer),
+            }
+        }
+
+        fn deserialize_value<'de, D>(variant: usize, deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            match variant {
+                0 => L::deserialize(deserializer).map(Self::Left),
+                1 => R::deserialize(deserializer).map(Self::Right),
+                _ => Err(invalid_variant(variant, 2)),
+            }
+        }
+    }
+
+    /// Implement [`FlatTagged`] for chains of `$n` types whose right side is a chain of `$rest`
+    macro_rules! nested_flat_tagged {
+        ($($n:literal => $rest:literal),+) => {
+            $(
+                impl<L, R> FlatTagged<$n> for AxumEither<L, R>
+                where
+                    L: Serialize + DeserializeOwned,
+                    R: FlatTagged<$rest>,
+                {
+                    fn serialize_value<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                    where
+                        S: Serializer,
+                    {
+                        match self {
+                            Self::Left(l) => l.serialize(serializer),
+                            Self::Right(r) => r.serialize_value(serializer),
+                        }
+                    }
+
+                    fn deserialize_value<'de, D>(
+                        variant: usize,
+                        deserializer: D,
+                    ) -> Result<Self, D::Error>
+                    where
+                        D: Deserializer<'de>,
+                    {
+                        match variant {
+                            0 => L::deserialize(deserializer).map(Self::Left),
+                            _ if variant >= $n => Err(invalid_variant(variant, $n)),
+                            _ => R::deserialize_value(variant - 1, deserializer).map(Self::Right),
+                        }
+                    }
+                }
+            )+
+        };
+    }
+
+    nested_flat_tagged!(3 => 2, 4 => 3, 5 => 4, 6 => 5, 7 => 6, 8 => 7);
+
+    /// Implement [`FlatTagged`] for the flat enum `$name`
+    macro_rules! flat_flat_tagged {
+        ($($name:ident $n:literal { $($v:ident($t:ident) $index:literal),+ }),+) => {
+            $(
+                impl<$($t),+> FlatTagged<$n> for $name<$($t),+>
+                where
+                    $($t: Serialize + DeserializeOwned,)+
+                {
+                    fn serialize_value<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                    where
+                        S: Serializer,
+                    {
+                        match self {
+                            $(Self::$v(v) => v.serialize(serializer),)+
+                        }
+                    }
+
+                    fn deserialize_value<'de, D>(
+                        variant: usize,
+                        deserializer: D,
+                    ) -> Result<Self, D::Error>
+                    where
+                        D: Deserializer<'de>,
+                    {
+                        match variant {
+                            $($index => $t::deserialize(deserializer).map(Self::$v),)+
+                            _ => Err(invalid_variant(variant, $n)),
+                        }
+                    }
+                }
+            )+
+        };
+    }
+
+    flat_flat_tagged!(
+        OneOf3 3 { V0(T0) 0, V1(T1) 1, V2(T2) 2 },
+        OneOf4 4 { V0(T0) 0, V1(T1) 1, V2(T2) 2, V3(T3) 3 },
+        OneOf5 5 { V0(T0) 0, V1(T1) 1, V2(T2) 2, V3(T3) 3, V4(T4) 4 },
+        OneOf6 6 { V0(T0) 0, V1(T1) 1, V2(T2) 2, V3(T3) 3, V4(T4) 4, V5(T5) 5 },
+        OneOf7 7 { V0(T0) 0, V1(T1) 1, V2(T2) 2, V3(T3) 3, V4(T4) 4, V5(T5) 5, V6(T6) 6 },
+        OneOf8 8 { V0(T0) 0, V1(T1) 1, V2(T2) 2, V3(T3) 3, V4(T4) 4, V5(T5) 5, V6(T6) 6, V7(T7) 7 }
+    );
+
+    /// Serializes the value of the active variant of `T`
+    struct ValueRef<'a, T, const N: usize>(&'a T);
+
+    impl<T, const N: usize> Serialize for ValueRef<'_, T, N>
+    where
+        T: FlatTagged<N>,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            self.0.serialize_value(serializer)
+        }
+    }
+
+    /// Serialize `value` as `{"variant": index, "value": ...}`
+    pub fn serialize<const N: usize, T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: FlatTagged<N>,
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("AxumEither", 2)?;
+        state.serialize_field(VARIANT, &value.flat_index())?;
+        state.serialize_field(VALUE, &ValueRef::<T, N>(value))?;
+        state.end()
+    }
+
+    /// Deserialize `{"variant": index, "value": ...}`, the keys may appear in any order
+    pub fn deserialize<'de, const N: usize, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: FlatTagged<N>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            "AxumEither",
+            &[VARIANT, VALUE],
+            FlatTaggedVisitor::<T, N>(PhantomData),
+        )
+    }
+
+    struct FlatTaggedVisitor<T, const N: usize>(PhantomData<fn() -> T>);
+
+    impl<'de, T, const N: usize> Visitor<'de> for FlatTaggedVisitor<T, N>
+    where
+        T: FlatTagged<N>,
+    {
+        type Value = T;
+
+        fn expecting(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+            write!(
+                formatter,
+                "a map with the keys \"{}\" and \"{}\"",
+                VARIANT, VALUE
+            )
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut variant = None;
+            let mut value = None;
+            while let Some(key) = map.next_key::<String>()? {
+                if key == VARIANT {
+                    variant = Some(map.next_value::<usize>()?);
+                } else if key == VALUE {
+                    value = Some(map.next_value::<Value>()?);
+                } else {
+                    map.next_value::<de::IgnoredAny>()?;
+                }
+            }
+
+            let variant = variant.ok_or_else(|| de::Error::missing_field(VARIANT))?;
+            let value = value.ok_or_else(|| de::Error::missing_field(VALUE))?;
+            T::deserialize_value(variant, ValueDeserializer::<A::Error>::new(value))
+        }
+    }
+}
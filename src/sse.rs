@@ -0,0 +1,53 @@
+//! Respond with a server-sent event stream or another response.
+//!
+//! Stream types are rarely nameable, [`BoxEventStream`] erases them so they can be used in the
+//! return type of a handler.
+//!
+//! # Examples
+//! ```
+//! use axum::{response::sse::Event, Json};
+//! use axum_either::{sse::SseOr, AxumEither};
+//! use futures_util::{stream, StreamExt};
+//! use std::convert::Infallible;
+//!
+//! pub async fn updates(live: bool) -> SseOr<Json<u32>> {
+//!     if live {
+//!         let events = stream::iter([1, 2, 3])
+//!             .map(|n| Ok::<_, Infallible>(Event::default().data(n.to_string())));
+//!         AxumEither::left_sse(events)
+//!     } else {
+//!         AxumEither::Right(Json(3))
+//!     }
+//! }
+//! ```
+
+use axum::{
+    response::sse::{Event, Sse},
+    BoxError,
+};
+use futures_core::Stream;
+use std::{convert::Infallible, pin::Pin};
+
+use crate::AxumEither;
+
+/// A boxed stream of server-sent events with the error type `E`
+pub type BoxEventStream<E = Infallible> = Pin<Box<dyn Stream<Item = Result<Event, E>> + Send>>;
+
+/// Responds with a boxed server-sent event stream or with `R`
+pub type SseOr<R, E = Infallible> = AxumEither<Sse<BoxEventStream<E>>, R>;
+
+impl<R, E> AxumEither<Sse<BoxEventStream<E>>, R>
+where
+    E: Into<BoxError> + 'static,
+{
+    /// Respond with the server-sent events of `stream`, the type of the stream is erased.
+    ///
+    /// Configure the stream with [`Sse`] directly and box it with [`Box::pin`] if for example a
+    /// keep alive is required.
+    pub fn left_sse<S>(stream: S) -> Self
+    where
+        S: Stream<Item = Result<Event, E>> + Send + 'static,
+    {
+        Self::Left(Sse::new(Box::pin(stream)))
+    }
+}
@@ -0,0 +1,272 @@
+//! Tag responses with the variant which produced them.
+
+use axum_core::response::{IntoResponse, Response};
+use http::{header::HeaderName, HeaderValue};
+use std::cell::Cell;
+
+use crate::{AxumEither, OneOf3, OneOf4, OneOf5, OneOf6, OneOf7, OneOf8};
+
+/// The header [`Tagged`] responses carry the variant index in
+pub const VARIANT_HEADER: &str = "x-axum-either-variant";
+
+/// Records the flattened variant index of the [`AxumEither`](crate::AxumEither) which produced a
+/// response.
+#[derive(Debug, Clone, Copy)]
+struct ResponseVariant(usize);
+
+thread_local! {
+    /// The number of [`record`] scopes rendering on this thread
+    static RECORDING: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Render `f` with variant indices recorded into the responses of the rendered
+/// [`AxumEither`](crate::AxumEither)s, outside of a scope nothing is recorded
+pub(crate) fn record<T>(f: impl FnOnce() -> T) -> T {
+    /// Leaves the scope even if rendering panics
+    struct Scope;
+
+    impl Drop for Scope {
+        fn drop(&mut self) {
+            RECORDING.with(|recording| recording.set(recording.get() - 1));
+        }
+    }
+
+    RECORDING.with(|recording| recording.set(recording.get() + 1));
+    let _scope = Scope;
+    f()
+}
+
+/// Poll `future` inside of a [`record`] scope
+#[cfg(feature = "test-util")]
+pub(crate) async fn record_future<F: std::future::Future>(future: F) -> F::Output {
+    let mut future = std::pin::pin!(future);
+    std::future::poll_fn(|cx| record(|| future.as_mut().poll(cx))).await
+}
+
+pub(crate) fn is_recording() -> bool {
+    RECORDING.with(|recording| recording.get() > 0)
+}
+
+pub(crate) fn variant_index<B>(response: &http::Response<B>) -> Option<usize> {
+    response
+        .extensions()
+        .get::<ResponseVariant>()
+        .map(|variant| variant.0)
+}
+
+pub(crate) fn set_variant_index(response: &mut Response, index: usize) {
+    response.extensions_mut().insert(ResponseVariant(index));
+}
+
+/// Adds the [`VARIANT_HEADER`] to the response of the wrapped [`AxumEither`](crate::AxumEither).
+///
+/// The header contains the flattened index of the variant which produced the response, the third
+/// type of `one_of!(A, B, C)` has the index 2. Chains nest on the right side like the ones built
+/// by `one_of!`, [`Negotiate`](crate::Negotiate) chains are flattened the same way. Responses are
+/// only tagged when wrapped in this type so debugging information is never sent by accident,
+/// untagged responses do not even record the index.
+///
+/// # Examples
+/// ```
+/// use axum::{Form, Json};
+/// use axum_either::{AxumEither, Tagged};
+///
+/// pub async fn echo(
+///     request: AxumEither<Json<String>, Form<Vec<(String, String)>>>,
+/// ) -> Tagged<AxumEither<String, String>> {
+///     Tagged(request.map_lr(|Json(s)| s, |Form(f)| format!("{:?}", f)))
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Hash, Default, PartialEq, PartialOrd, Eq, Ord)]
+pub struct Tagged<T>(pub T);
+
+impl<T> IntoResponse for Tagged<T>
+where
+    T: IntoResponse,
+{
+    fn into_response(self) -> Response {
+        let mut response = record(|| self.0.into_response());
+        if let Some(index) = variant_index(&response) {
+            response.headers_mut().insert(
+                HeaderName::from_static(VARIANT_HEADER),
+                HeaderValue::from(index),
+            );
+        }
+        response
+    }
+}
+
+/// The side of an [`AxumEither`] without its value.
+///
+/// Storage code which keeps values in a type erased form can store the tag next to them and
+/// branch on it cheaply, see [`AxumEither::into_tagged`].
+#[derive(Debug, Clone, Copy, Hash, PartialEq, PartialOrd, Eq, Ord)]
+pub enum VariantTag {
+    /// The value is a left value
+    Left,
+    /// The value is a right value
+    Right,
+}
+
+impl VariantTag {
+    /// The index of the side, 0 for left and 1 for right
+    pub fn index(self) -> usize {
+        match self {
+            Self::Left => 0,
+            Self::Right => 1,
+        }
+    }
+}
+
+impl<L, R> AxumEither<L, R> {
+    /// The side of this value
+    ///
+    /// # Examples
+    /// ```
+    /// use axum_either::{AxumEither, VariantTag};
+    ///
+    /// let r: AxumEither<i32, bool> = AxumEither::Right(false);
+    /// assert_eq!(r.tag(), VariantTag::Right);
+    /// ```
+    pub fn tag(&self) -> VariantTag {
+        match self {
+            Self::Left(_) => VariantTag::Left,
+            Self::Right(_) => VariantTag::Right,
+        }
+    }
+
+    /// Erase the type of the value into `T` with the function of its side and return it with its
+    /// tag, [`from_tagged`](Self::from_tagged) reverses this.
+    ///
+    /// # Examples
+    /// ```
+    /// use axum_either::{AxumEither, VariantTag};
+    ///
+    /// let l: AxumEither<i32, bool> = AxumEither::Left(10);
+    /// let (tag, value) = l.into_tagged(|i| i.to_string(), |b| b.to_string());
+    /// assert_eq!((tag, value.as_str()), (VariantTag::Left, "10"));
+    ///
+    /// let restored = AxumEither::from_tagged(tag, value, |s| s.parse().unwrap(), |s| s == "true");
+    /// assert_eq!(restored, l);
+    /// ```
+    pub fn into_tagged<T>(
+        self,
+        left: impl FnOnce(L) -> T,
+        right: impl FnOnce(R) -> T,
+    ) -> (VariantTag, T) {
+        match self {
+            Self::Left(l) => (VariantTag::Left, left(l)),
+            Self::Right(r) => (VariantTag::Right, right(r)),
+        }
+    }
+
+    /// Rebuild a value from its tag and its type erased form, only the builder of the side `tag`
+    /// is called.
+    pub fn from_tagged<T>(
+        tag: VariantTag,
+        value: T,
+        left: impl FnOnce(T) -> L,
+        right: impl FnOnce(T) -> R,
+    ) -> Self {
+        match tag {
+            VariantTag::Left => Self::Left(left(value)),
+            VariantTag::Right => Self::Right(right(value)),
+        }
+    }
+
+    /// Rebuild a value from its tag and its type erased form with fallible builders, only the
+    /// builder of the side `tag` is called.
+    ///
+    /// # Examples
+    /// ```
+    /// use axum_either::{AxumEither, VariantTag};
+    ///
+    /// let restored: Result<AxumEither<i32, bool>, String> = AxumEither::try_from_tagged(
+    ///     VariantTag::Right,
+    ///     "true",
+    ///     |s| s.parse().map_err(|e: std::num::ParseIntError| e.to_string()),
+    ///     |s| s.parse().map_err(|e: std::str::ParseBoolError| e.to_string()),
+    /// );
+    /// assert_eq!(restored, Ok(AxumEither::Right(true)));
+    /// ```
+    pub fn try_from_tagged<T, E>(
+        tag: VariantTag,
+        value: T,
+        left: impl FnOnce(T) -> Result<L, E>,
+        right: impl FnOnce(T) -> Result<R, E>,
+    ) -> Result<Self, E> {
+        match tag {
+            VariantTag::Left => left(value).map(Self::Left),
+            VariantTag::Right => right(value).map(Self::Right),
+        }
+    }
+}
+
+/// The flattened index of the active variant of a chain or flat enum with `N` variants.
+///
+/// A nested chain is also an [`AxumEither`] of two values, name `N` if it is not inferred.
+///
+/// # Examples
+/// ```
+/// use axum_either::{prelude::*, FlatIndex};
+///
+/// let chain: one_of!(u8, u16, u32) = AxumEither::Right(AxumEither::Right(3));
+/// assert_eq!(FlatIndex::<3>::flat_index(&chain), 2);
+/// assert_eq!(FlatIndex::<2>::flat_index(&chain), 1);
+/// assert_eq!(OneOf3::<u8, u16, u32>::from(chain).flat_index(), 2);
+/// ```
+pub trait FlatIndex<const N: usize> {
+    /// The flattened index of the active variant, below `N`
+    fn flat_index(&self) -> usize;
+}
+
+impl<L, R> FlatIndex<2> for AxumEither<L, R> {
+    fn flat_index(&self) -> usize {
+        self.tag().index()
+    }
+}
+
+/// Implement [`FlatIndex`] for chains of `$n` types whose right side is a chain of `$rest`
+macro_rules! nested_flat_index {
+    ($($n:literal => $rest:literal),+) => {
+        $(
+            impl<L, R> FlatIndex<$n> for AxumEither<L, R>
+            where
+                R: FlatIndex<$rest>,
+            {
+                fn flat_index(&self) -> usize {
+                    match self {
+                        Self::Left(_) => 0,
+                        Self::Right(r) => 1 + r.flat_index(),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+nested_flat_index!(3 => 2, 4 => 3, 5 => 4, 6 => 5, 7 => 6, 8 => 7);
+
+/// Implement [`FlatIndex`] for the flat enum `$name`
+macro_rules! flat_index {
+    ($($name:ident $n:literal { $($v:ident($t:ident) $index:literal),+ }),+) => {
+        $(
+            impl<$($t),+> FlatIndex<$n> for $name<$($t),+> {
+                fn flat_index(&self) -> usize {
+                    match self {
+                        $(Self::$v(_) => $index,)+
+                    }
+                }
+            }
+        )+
+    };
+}
+
+flat_index!(
+    OneOf3 3 { V0(T0) 0, V1(T1) 1, V2(T2) 2 },
+    OneOf4 4 { V0(T0) 0, V1(T1) 1, V2(T2) 2, V3(T3) 3 },
+    OneOf5 5 { V0(T0) 0, V1(T1) 1, V2(T2) 2, V3(T3) 3, V4(T4) 4 },
+    OneOf6 6 { V0(T0) 0, V1(T1) 1, V2(T2) 2, V3(T3) 3, V4(T4) 4, V5(T5) 5 },
+    OneOf7 7 { V0(T0) 0, V1(T1) 1, V2(T2) 2, V3(T3) 3, V4(T4) 4, V5(T5) 5, V6(T6) 6 },
+    OneOf8 8 { V0(T0) 0, V1(T1) 1, V2(T2) 2, V3(T3) 3, V4(T4) 4, V5(T5) 5, V6(T6) 6, V7(T7) 7 }
+);
@@ -0,0 +1,395 @@
+//! Run extractors against requests without a server, for unit tests of handlers and extractors.
+//!
+//! # Examples
+//! ```
+//! use axum::{Form, Json};
+//! use axum_either::{prelude::*, test};
+//! use std::collections::HashMap;
+//!
+//! # tokio::runtime::Runtime::new().unwrap().block_on(async {
+//! let request = test::form_request(&[("name", "Reimu")]);
+//! let either = test::extract::<AxumEither<Json<String>, Form<HashMap<String, String>>>, _>(request)
+//!     .await
+//!     .unwrap();
+//! assert_eq!(either.right().unwrap().0["name"], "Reimu");
+//! # });
+//! ```
+//!
+//! The mock extractors [`AlwaysOk`] and [`AlwaysReject`] don't look at the request at all, they
+//! allow testing how rejections are combined and in which order variants are tried.
+//!
+//! ```
+//! use axum_either::{
+//!     prelude::*,
+//!     test::{self, AlwaysOk, AlwaysReject, MockRejection},
+//! };
+//! use http::StatusCode;
+//!
+//! # tokio::runtime::Runtime::new().unwrap().block_on(async {
+//! type Chain = one_of!(AlwaysReject, AlwaysOk<u8>, AlwaysOk<u16>);
+//! let either = test::extract::<Chain, _>(test::empty_request()).await.unwrap();
+//! assert!(matches!(either, AxumEither::Right(AxumEither::Left(_))));
+//!
+//! type Rejected = AxumEither<AlwaysReject, AlwaysReject<MockRejection<503>>>;
+//! let rejection = test::extract::<Rejected, _>(test::empty_request())
+//!     .await
+//!     .unwrap_err();
+//! assert_eq!(rejection.status(), StatusCode::INTERNAL_SERVER_ERROR);
+//! # });
+//! ```
+//!
+//! Whole routes are tested with the [`Harness`], which calls a service like an axum `Router`
+//! directly and reports which variant produced the response.
+//!
+//! ```
+//! use axum::{routing::post, Form, Json, Router};
+//! use axum_either::{prelude::*, test::Harness, Tagged};
+//!
+//! async fn echo(
+//!     request: AxumEither<Json<String>, Form<Vec<(String, String)>>>,
+//! ) -> Tagged<AxumEither<Json<String>, String>> {
+//!     Tagged(request.map_lr(|Json(s)| Json(s), |Form(f)| format!("{:?}", f)))
+//! }
+//!
+//! # tokio::runtime::Runtime::new().unwrap().block_on(async {
+//! let harness: Harness<Router> = Harness::new(Router::new().route("/", post(echo)));
+//! let response = harness.post("/").json(&"Reimu").send().await;
+//! assert_eq!(response.variant(), Some(0));
+//! assert_eq!(response.json::<String>(), "Reimu");
+//! # });
+//! ```
+
+use axum_core::{
+    extract::{FromRequest, RequestParts},
+    response::{IntoResponse, Response},
+};
+use bytes::{Buf, Bytes, BytesMut};
+use http::{header, HeaderMap, HeaderValue, Method, Request, StatusCode};
+use http_body::{Body as HttpBody, Full};
+use serde::{de::DeserializeOwned, Serialize};
+use std::{fmt, future::poll_fn, marker::PhantomData};
+use tower_service::Service;
+
+use crate::{tag, RejectionInfo, VARIANT_HEADER};
+
+/// The body of the requests built by this module
+pub type TestBody = Full<Bytes>;
+
+/// Run the extractor `E` against `request`, like axum does before calling a handler
+pub async fn extract<E, B>(request: Request<B>) -> Result<E, E::Rejection>
+where
+    E: FromRequest<B>,
+{
+    E::from_request(&mut RequestParts::new(request)).await
+}
+
+/// Run the extractor `E` against `request` with `state` inserted as a request extension, where
+/// extractors like `axum::Extension` find the shared state of the application
+pub async fn extract_with_state<E, S, B>(
+    mut request: Request<B>,
+    state: S,
+) -> Result<E, E::Rejection>
+where
+    E: FromRequest<B>,
+    S: Clone + Send + Sync + 'static,
+{
+    request.extensions_mut().insert(state);
+    extract(request).await
+}
+
+/// A `POST /` request with the given `Content-Type` and body
+pub fn raw_request(content_type: &str, body: impl Into<Bytes>) -> Request<TestBody> {
+    Request::builder()
+        .method(Method::POST)
+        .uri("/")
+        .header(header::CONTENT_TYPE, content_type)
+        .body(Full::new(body.into()))
+        .expect("Invalid content type")
+}
+
+/// A `POST /` request with `value` serialized as json
+pub fn json_request(value: &impl Serialize) -> Request<TestBody> {
+    let body = serde_json::to_vec(value).expect("Failed to serialize json");
+    raw_request("application/json", body)
+}
+
+/// A `POST /` request with `value` serialized as urlencoded form
+pub fn form_request(value: &impl Serialize) -> Request<TestBody> {
+    let body = serde_urlencoded::to_string(value).expect("Failed to serialize form");
+    raw_request("application/x-www-form-urlencoded", body)
+}
+
+/// A `GET /` request without `Content-Type` and with an empty body
+pub fn empty_request() -> Request<TestBody> {
+    Request::builder()
+        .method(Method::GET)
+        .uri("/")
+        .body(Full::new(Bytes::new()))
+        .expect("Failed to build request")
+}
+
+/// An extractor which always succeeds with the default value of `T`
+#[derive(Debug, Clone, Copy, Hash, Default, PartialEq, PartialOrd, Eq, Ord)]
+pub struct AlwaysOk<T = ()>(pub T);
+
+impl<T> AlwaysOk<T> {
+    /// Extract the wrapped value
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+#[async_trait::async_trait]
+impl<T, B> FromRequest<B> for AlwaysOk<T>
+where
+    T: Default,
+    B: Send,
+{
+    type Rejection = MockRejection;
+
+    async fn from_request(_req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        Ok(Self(T::default()))
+    }
+}
+
+/// An extractor which always rejects with the default value of `E`
+///
+/// # Examples
+/// ```
+/// use axum_either::test::{self, AlwaysReject};
+/// use http::StatusCode;
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let rejection = test::extract::<AlwaysReject<(StatusCode, &str)>, _>(test::empty_request())
+///     .await
+///     .unwrap_err();
+/// assert_eq!(rejection, (StatusCode::OK, ""));
+/// # });
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, PartialOrd, Eq, Ord)]
+pub struct AlwaysReject<E = MockRejection>(PhantomData<E>);
+
+#[async_trait::async_trait]
+impl<E, B> FromRequest<B> for AlwaysReject<E>
+where
+    E: Default + IntoResponse,
+    B: Send,
+{
+    type Rejection = E;
+
+    async fn from_request(_req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        Err(E::default())
+    }
+}
+
+/// A rejection responding with the status `STATUS` and a message naming it
+///
+/// # Panics
+/// Rendering panics if `STATUS` is not a valid status code.
+#[derive(Debug, Clone, Copy, Hash, Default, PartialEq, PartialOrd, Eq, Ord)]
+pub struct MockRejection<const STATUS: u16 = 400>;
+
+impl<const STATUS: u16> fmt::Display for MockRejection<STATUS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Mock rejection with status {}", STATUS)
+    }
+}
+
+impl<const STATUS: u16> std::error::Error for MockRejection<STATUS> {}
+
+impl<const STATUS: u16> IntoResponse for MockRejection<STATUS> {
+    fn into_response(self) -> Response {
+        (RejectionInfo::status(&self), self.to_string()).into_response()
+    }
+}
+
+impl<const STATUS: u16> RejectionInfo for MockRejection<STATUS> {
+    fn status(&self) -> StatusCode {
+        StatusCode::from_u16(STATUS).expect("Invalid mock rejection status")
+    }
+
+    fn message(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// Sends requests to a service, usually an axum `Router`, without binding a socket
+///
+/// Every request is sent to a clone of the service, so a harness can be shared between the cases
+/// of a test.
+#[derive(Debug, Clone)]
+pub struct Harness<S> {
+    service: S,
+}
+
+impl<S> Harness<S> {
+    /// Send requests to `service`
+    pub fn new(service: S) -> Self {
+        Self { service }
+    }
+
+    /// Start building a request with the given method to `uri`
+    pub fn request(&self, method: Method, uri: &str) -> TestRequest<'_, S> {
+        TestRequest {
+            harness: self,
+            builder: Request::builder().method(method).uri(uri),
+            body: Bytes::new(),
+        }
+    }
+
+    /// Start building a `GET` request to `uri`
+    pub fn get(&self, uri: &str) -> TestRequest<'_, S> {
+        self.request(Method::GET, uri)
+    }
+
+    /// Start building a `POST` request to `uri`
+    pub fn post(&self, uri: &str) -> TestRequest<'_, S> {
+        self.request(Method::POST, uri)
+    }
+}
+
+/// A request built by [`Harness`], sent with [`send`](Self::send)
+#[derive(Debug)]
+pub struct TestRequest<'a, S> {
+    harness: &'a Harness<S>,
+    builder: http::request::Builder,
+    body: Bytes,
+}
+
+impl<S> TestRequest<'_, S> {
+    /// Add a header to the request
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.builder = self.builder.header(name, value);
+        self
+    }
+
+    /// Set the `Content-Type` of the request
+    pub fn content_type(self, content_type: &str) -> Self {
+        self.header(header::CONTENT_TYPE.as_str(), content_type)
+    }
+
+    /// Set the `Accept` header of the request
+    pub fn accept(self, accept: &str) -> Self {
+        self.header(header::ACCEPT.as_str(), accept)
+    }
+
+    /// Set the body of the request, without changing its `Content-Type`
+    pub fn body(mut self, body: impl Into<Bytes>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Send `value` serialized as json
+    pub fn json(self, value: &impl Serialize) -> Self {
+        let body = serde_json::to_vec(value).expect("Failed to serialize json");
+        self.content_type("application/json").body(body)
+    }
+
+    /// Send `value` serialized as urlencoded form
+    pub fn form(self, value: &impl Serialize) -> Self {
+        let body = serde_urlencoded::to_string(value).expect("Failed to serialize form");
+        self.content_type("application/x-www-form-urlencoded")
+            .body(body)
+    }
+
+    /// Send the request and collect the response
+    ///
+    /// # Panics
+    /// Panics if the request is invalid, the service fails or the response body can't be read.
+    pub async fn send<B, RB>(self) -> TestResponse
+    where
+        S: Service<Request<B>, Response = http::Response<RB>> + Clone,
+        S::Error: fmt::Debug,
+        B: From<Bytes>,
+        RB: HttpBody,
+        RB::Error: fmt::Debug,
+    {
+        let request = self
+            .builder
+            .body(B::from(self.body))
+            .expect("Invalid test request");
+
+        let mut service = self.harness.service.clone();
+        poll_fn(|cx| service.poll_ready(cx))
+            .await
+            .expect("Service is not ready");
+        let response = tag::record_future(service.call(request))
+            .await
+            .expect("Service failed");
+
+        let variant = tag::variant_index(&response);
+        let (parts, body) = response.into_parts();
+        let mut body = Box::pin(body);
+        let mut bytes = BytesMut::new();
+        while let Some(chunk) = poll_fn(|cx| body.as_mut().poll_data(cx)).await {
+            bytes.extend_from_slice(chunk.expect("Failed to read response body").chunk());
+        }
+
+        TestResponse {
+            status: parts.status,
+            headers: parts.headers,
+            variant,
+            body: bytes.freeze(),
+        }
+    }
+}
+
+/// A response collected by [`TestRequest::send`]
+#[derive(Debug, Clone)]
+pub struct TestResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    variant: Option<usize>,
+    body: Bytes,
+}
+
+impl TestResponse {
+    /// The status of the response
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// The headers of the response
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// The value of a header, if it is present
+    pub fn header(&self, name: &str) -> Option<&HeaderValue> {
+        self.headers.get(name)
+    }
+
+    /// The flattened index of the variant which produced the response
+    ///
+    /// This is read from the [`VARIANT_HEADER`] of [`Tagged`](crate::Tagged) responses. Without
+    /// the header the index recorded by the responding [`AxumEither`](crate::AxumEither) is
+    /// used, which is only available when no middleware replaced the response.
+    pub fn variant(&self) -> Option<usize> {
+        self.headers
+            .get(VARIANT_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse().ok())
+            .or(self.variant)
+    }
+
+    /// The raw body of the response
+    pub fn bytes(&self) -> &Bytes {
+        &self.body
+    }
+
+    /// The body of the response as text
+    ///
+    /// # Panics
+    /// Panics if the body is not valid UTF-8.
+    pub fn text(&self) -> &str {
+        std::str::from_utf8(&self.body).expect("Response body is not UTF-8")
+    }
+
+    /// The body of the response deserialized from json
+    ///
+    /// # Panics
+    /// Panics if the body is not valid json for `T`.
+    pub fn json<T: DeserializeOwned>(&self) -> T {
+        serde_json::from_slice(&self.body).expect("Response body is not valid json")
+    }
+}
@@ -0,0 +1,144 @@
+//! Extract a UTF-8 body as a `String` without copying the buffered body.
+
+use axum_core::{
+    extract::{rejection::BytesRejection, FromRequest, RequestParts},
+    response::{IntoResponse, Response},
+    BoxError,
+};
+use bytes::Bytes;
+use http::StatusCode;
+use http_body::Body;
+use mime::Mime;
+use std::{
+    fmt,
+    ops::{Deref, DerefMut},
+    string::FromUtf8Error,
+};
+
+use crate::{RejectionInfo, SupportedMediaTypes};
+
+/// Extracts the body as a `String`, reusing the allocation of the body if possible.
+///
+/// The `String` extractor of axum copies the body into a new allocation. `Text` converts the
+/// [`Bytes`] of the body into the string instead, which reuses their allocation if the extractor
+/// is their only owner. [`FirstOk`](crate::FirstOk) and [`Dispatch`](crate::Dispatch) move the
+/// buffered body into the last extractor they try, so a trailing `Text` takes over the buffer
+/// of the retried body without copying it.
+///
+/// # Examples
+/// ```
+/// use axum::Json;
+/// use axum_either::{AxumEither, FirstOk, Text};
+///
+/// pub async fn echo(body: FirstOk<(Json<Vec<u32>>, Text)>) -> String {
+///     match body.into_inner() {
+///         AxumEither::Left(Json(numbers)) => format!("{} numbers", numbers.len()),
+///         AxumEither::Right(Text(text)) => text,
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Hash, Default, PartialEq, PartialOrd, Eq, Ord)]
+pub struct Text(pub String);
+
+impl Text {
+    /// The extracted string
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl Deref for Text {
+    type Target = String;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Text {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl SupportedMediaTypes for Text {
+    fn supported_media_types() -> &'static [Mime] {
+        const TEXT: &[Mime] = &[mime::TEXT_PLAIN];
+        TEXT
+    }
+}
+
+#[async_trait::async_trait]
+impl<B> FromRequest<B> for Text
+where
+    B: Body + Send,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+{
+    type Rejection = TextRejection;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        let bytes = Bytes::from_request(req)
+            .await
+            .map_err(TextRejection::Body)?;
+        String::from_utf8(Vec::from(bytes))
+            .map(Self)
+            .map_err(TextRejection::InvalidUtf8)
+    }
+}
+
+/// The rejection of [`Text`]
+#[derive(Debug)]
+pub enum TextRejection {
+    /// The body could not be buffered
+    Body(BytesRejection),
+    /// The body is not valid UTF-8
+    InvalidUtf8(FromUtf8Error),
+}
+
+impl fmt::Display for TextRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Body(rejection) => write!(f, "{}", rejection),
+            Self::InvalidUtf8(error) => {
+                write!(f, "Request body didn't contain valid UTF-8: {}", error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for TextRejection {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Body(rejection) => Some(rejection),
+            Self::InvalidUtf8(error) => Some(error),
+        }
+    }
+}
+
+impl RejectionInfo for TextRejection {
+    fn status(&self) -> StatusCode {
+        match self {
+            Self::Body(rejection) => rejection.status(),
+            Self::InvalidUtf8(_) => StatusCode::BAD_REQUEST,
+        }
+    }
+
+    fn message(&self) -> String {
+        self.to_string()
+    }
+
+    fn write_message(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        write!(out, "{}", self)
+    }
+
+    fn source_error(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        std::error::Error::source(self)
+    }
+}
+
+impl IntoResponse for TextRejection {
+    fn into_response(self) -> Response {
+        (self.status(), self.to_string()).into_response()
+    }
+}
@@ -0,0 +1,63 @@
+//! Forward tower traits to the active side of an [`AxumEither`].
+
+use std::task::{Context, Poll};
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::AxumEither;
+
+/// Calls the active service, both services have to respond with the same response and error.
+impl<Request, L, R> Service<Request> for AxumEither<L, R>
+where
+    L: Service<Request>,
+    R: Service<Request, Response = L::Response, Error = L::Error>,
+{
+    type Response = L::Response;
+    type Error = L::Error;
+    type Future = AxumEither<L::Future, R::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        match self {
+            Self::Left(l) => l.poll_ready(cx),
+            Self::Right(r) => r.poll_ready(cx),
+        }
+    }
+
+    fn call(&mut self, req: Request) -> Self::Future {
+        match self {
+            Self::Left(l) => AxumEither::Left(l.call(req)),
+            Self::Right(r) => AxumEither::Right(r.call(req)),
+        }
+    }
+}
+
+/// Wraps the service with the active layer, to branch between middleware configurations.
+///
+/// # Examples
+/// ```
+/// use axum::{routing::get, Extension, Router};
+/// use axum_either::prelude::*;
+/// use tower::layer::util::Identity;
+///
+/// let local = std::env::var("LOCAL").is_ok();
+/// let layer = if local {
+///     AxumEither::Right(Identity::new())
+/// } else {
+///     AxumEither::Left(Extension("production"))
+/// };
+/// let router: Router = Router::new().route("/", get(|| async { "hello" })).layer(layer);
+/// ```
+impl<S, L, R> Layer<S> for AxumEither<L, R>
+where
+    L: Layer<S>,
+    R: Layer<S>,
+{
+    type Service = AxumEither<L::Service, R::Service>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        match self {
+            Self::Left(l) => AxumEither::Left(l.layer(inner)),
+            Self::Right(r) => AxumEither::Right(r.layer(inner)),
+        }
+    }
+}
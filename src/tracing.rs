@@ -0,0 +1,74 @@
+//! Record extraction outcomes as fields of the current `tracing` span.
+//!
+//! Every extraction of an [`AxumEither`](crate::AxumEither) chain records the following fields
+//! once, nested chains built with [`one_of`](crate::one_of) are reported by the outermost either:
+//!
+//! - [`VARIANT_INDEX`], the flattened index of the extracted variant
+//! - [`VARIANT_TYPE`], the type name of the extracted variant with only the crate of every path,
+//!   for example `axum::Json<my_crate::HelloRequest>`
+//! - [`ATTEMPTS`], the number of extractors which were tried
+//!
+//! If all variants rejected the request only [`ATTEMPTS`] is recorded.
+//!
+//! [`SingleRejection`](crate::SingleRejection) logs the error it drops from the response as a
+//! `DEBUG` event when the response is rendered. This is the only place the dropped error is
+//! logged, without this feature it is discarded.
+//!
+//! `tracing` only records fields which were declared when the span was created, declare them
+//! as empty in the span of the request, for example in the `make_span_with` of the `TraceLayer`
+//! of tower-http.
+//!
+//! # Examples
+//! ```
+//! use axum_either::tracing::{ATTEMPTS, VARIANT_INDEX, VARIANT_TYPE};
+//! use http::Request;
+//! use tracing::field::Empty;
+//!
+//! pub fn make_span<B>(request: &Request<B>) -> tracing::Span {
+//!     tracing::info_span!(
+//!         "request",
+//!         uri = %request.uri(),
+//!         axum_either.variant_index = Empty,
+//!         axum_either.variant_type = Empty,
+//!         axum_either.attempts = Empty,
+//!     )
+//! }
+//! # assert_eq!(VARIANT_INDEX, "axum_either.variant_index");
+//! # assert_eq!(VARIANT_TYPE, "axum_either.variant_type");
+//! # assert_eq!(ATTEMPTS, "axum_either.attempts");
+//! ```
+
+use ::tracing::Span;
+use axum_core::response::Response;
+
+use crate::ready::ready_text;
+
+/// Field of the flattened index of the extracted variant
+pub const VARIANT_INDEX: &str = "axum_either.variant_index";
+/// Field of the type name of the extracted variant with only the crate of every path
+pub const VARIANT_TYPE: &str = "axum_either.variant_type";
+/// Field of the number of extractors which were tried
+pub const ATTEMPTS: &str = "axum_either.attempts";
+
+pub(crate) fn matched(index: usize, name: &str) {
+    let span = Span::current();
+    span.record(VARIANT_INDEX, index);
+    span.record(VARIANT_TYPE, name);
+    span.record(ATTEMPTS, index + 1);
+}
+
+pub(crate) fn rejected(attempts: usize) {
+    Span::current().record(ATTEMPTS, attempts);
+}
+
+/// Log the rejection [`SingleRejection`](crate::SingleRejection) dropped from the response
+pub(crate) fn discarded(type_name: &'static str, response: Response) {
+    let status = response.status().as_u16();
+    let message = ready_text(response.into_body());
+    ::tracing::debug!(
+        rejection = type_name,
+        status,
+        error = message.as_deref().unwrap_or_default(),
+        "Discarded the rejection of the other side",
+    );
+}
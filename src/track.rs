@@ -0,0 +1,103 @@
+//! Track the extraction of nested eithers so only a single outcome is reported per chain.
+
+use axum_core::extract::RequestParts;
+#[cfg(feature = "metrics")]
+use std::time::Instant;
+
+#[cfg(feature = "observer")]
+use crate::observe::{self, EitherEvent};
+
+/// The nesting depth of the currently extracted right side
+#[derive(Debug, Clone, Copy)]
+struct Depth(usize);
+
+/// Marks that the current extraction was already reported by a nested either
+#[derive(Debug, Clone, Copy)]
+struct Reported;
+
+/// The number of extractors tried by the current extraction
+#[cfg(feature = "tracing")]
+#[derive(Debug, Clone, Copy)]
+struct Attempts(usize);
+
+/// When the outermost either started extracting
+#[cfg(feature = "metrics")]
+#[derive(Debug, Clone, Copy)]
+struct Start(Instant);
+
+/// Start an extraction and return the nesting depth of it
+pub(crate) fn enter<B>(req: &mut RequestParts<B>) -> usize {
+    let depth = req.extensions().get::<Depth>().map_or(0, |depth| depth.0);
+    if depth == 0 {
+        req.extensions_mut().remove::<Reported>();
+        #[cfg(feature = "metrics")]
+        req.extensions_mut().insert(Start(Instant::now()));
+    }
+    depth
+}
+
+/// Called before the right side of the either at `depth` is extracted
+pub(crate) fn descend<B>(req: &mut RequestParts<B>, depth: usize) {
+    req.extensions_mut().insert(Depth(depth + 1));
+    #[cfg(feature = "tracing")]
+    req.extensions_mut().insert(Attempts(depth + 2));
+}
+
+/// Called after the right side of the either at `depth` was extracted
+pub(crate) fn ascend<B>(req: &mut RequestParts<B>, depth: usize) {
+    if depth == 0 {
+        req.extensions_mut().remove::<Depth>();
+    } else {
+        req.extensions_mut().insert(Depth(depth));
+    }
+}
+
+/// Report a match of the type `type_name` at `index` unless a nested either already did
+#[cfg_attr(
+    not(all(
+        any(feature = "observer", feature = "metrics", feature = "tracing"),
+        any(feature = "metrics", feature = "tracing", feature = "recorder"),
+    )),
+    allow(unused_variables)
+)]
+pub(crate) fn matched<B>(req: &mut RequestParts<B>, index: usize, type_name: &'static str) {
+    if req.extensions().get::<Reported>().is_some() {
+        return;
+    }
+
+    req.extensions_mut().insert(Reported);
+    #[cfg(any(feature = "metrics", feature = "tracing", feature = "recorder"))]
+    let name = crate::variant::name(type_name);
+    #[cfg(feature = "observer")]
+    observe::notify(req, EitherEvent::Matched(index));
+    #[cfg(feature = "metrics")]
+    if let Some(start) = req.extensions().get::<Start>() {
+        crate::metrics::matched(index, name, start.0.elapsed());
+    }
+    #[cfg(feature = "tracing")]
+    crate::tracing::matched(index, name);
+    #[cfg(feature = "recorder")]
+    if let Some(matched) = req.extensions().get::<crate::recorder::MatchedVariants>() {
+        matched.push(name);
+    }
+}
+
+/// Report a rejection of the either at `depth`, only the outermost either reports rejections
+#[cfg_attr(
+    not(any(feature = "observer", feature = "metrics", feature = "tracing")),
+    allow(unused_variables)
+)]
+pub(crate) fn rejected<B>(req: &mut RequestParts<B>, depth: usize) {
+    if depth == 0 {
+        #[cfg(feature = "observer")]
+        observe::notify(req, EitherEvent::RejectedAll);
+        #[cfg(feature = "metrics")]
+        if let Some(start) = req.extensions().get::<Start>() {
+            crate::metrics::rejected(start.0.elapsed());
+        }
+        #[cfg(feature = "tracing")]
+        if let Some(attempts) = req.extensions().get::<Attempts>() {
+            crate::tracing::rejected(attempts.0);
+        }
+    }
+}
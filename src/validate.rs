@@ -0,0 +1,135 @@
+//! Validate the payload of an [`AxumEither`] with validator.
+//!
+//! # Examples
+//! ```
+//! use axum_either::ValidatedEither;
+//! use validator::Validate;
+//!
+//! #[derive(serde::Deserialize, Validate)]
+//! pub struct SignUp {
+//!     #[validate(email)]
+//!     email: String,
+//! }
+//!
+//! /// Accepts json and forms, invalid emails are rejected with `422 Unprocessable Entity`
+//! pub async fn sign_up(ValidatedEither(sign_up, _): ValidatedEither<SignUp>) -> String {
+//!     format!("Welcome {}", sign_up.email)
+//! }
+//! ```
+
+use async_trait::async_trait;
+use axum::{Form, Json};
+use axum_core::{
+    extract::{FromRequest, RequestParts},
+    response::{IntoResponse, Response},
+};
+use http::{header, HeaderValue, StatusCode};
+use std::marker::PhantomData;
+use validator::{Validate, ValidationErrors};
+
+use crate::{AxumEither, AxumEitherRejection};
+
+/// Converts an extracted value into the payload it carries
+pub trait IntoPayload<T> {
+    /// Unwrap the payload
+    fn into_payload(self) -> T;
+}
+
+impl<T> IntoPayload<T> for Json<T> {
+    fn into_payload(self) -> T {
+        self.0
+    }
+}
+
+impl<T> IntoPayload<T> for Form<T> {
+    fn into_payload(self) -> T {
+        self.0
+    }
+}
+
+impl<T, L, R> IntoPayload<T> for AxumEither<L, R>
+where
+    L: IntoPayload<T>,
+    R: IntoPayload<T>,
+{
+    fn into_payload(self) -> T {
+        match self {
+            Self::Left(l) => l.into_payload(),
+            Self::Right(r) => r.into_payload(),
+        }
+    }
+}
+
+/// Extracts `L` or `R`, collapses them into the payload `T` and validates it.
+///
+/// By default the payload is extracted from json or a form.
+#[derive(Debug, Clone, Copy, Hash, Default, PartialEq, PartialOrd, Eq, Ord)]
+pub struct ValidatedEither<T, L = Json<T>, R = Form<T>>(pub T, pub PhantomData<fn() -> (L, R)>);
+
+impl<T, L, R> ValidatedEither<T, L, R> {
+    /// The validated payload
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+#[async_trait]
+impl<T, L, R, B> FromRequest<B> for ValidatedEither<T, L, R>
+where
+    T: Validate,
+    L: FromRequest<B> + IntoPayload<T>,
+    L::Rejection: Send,
+    R: FromRequest<B> + IntoPayload<T>,
+    B: Send,
+{
+    type Rejection = ValidatedEitherRejection<L::Rejection, R::Rejection>;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        let payload = AxumEither::<L, R>::from_request(req)
+            .await
+            .map_err(ValidatedEitherRejection::Extract)?
+            .into_payload();
+        payload
+            .validate()
+            .map_err(ValidatedEitherRejection::Invalid)?;
+        Ok(Self(payload, PhantomData))
+    }
+}
+
+/// The rejection of [`ValidatedEither`]
+#[derive(Debug)]
+pub enum ValidatedEitherRejection<LE, RE>
+where
+    LE: IntoResponse,
+    RE: IntoResponse,
+{
+    /// Neither side could be extracted
+    Extract(AxumEitherRejection<LE, RE>),
+    /// The payload was extracted but is invalid
+    Invalid(ValidationErrors),
+}
+
+/// Invalid payloads are rejected with `422 Unprocessable Entity` and the field errors as json
+impl<LE, RE> IntoResponse for ValidatedEitherRejection<LE, RE>
+where
+    LE: IntoResponse,
+    RE: IntoResponse,
+{
+    fn into_response(self) -> Response {
+        match self {
+            Self::Extract(rejection) => rejection.into_response(),
+            Self::Invalid(errors) => match serde_json::to_vec(&errors) {
+                Ok(body) => (
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    [(
+                        header::CONTENT_TYPE,
+                        HeaderValue::from_static("application/json"),
+                    )],
+                    body,
+                )
+                    .into_response(),
+                Err(_) => StatusCode::UNPROCESSABLE_ENTITY.into_response(),
+            },
+        }
+    }
+}
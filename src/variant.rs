@@ -0,0 +1,73 @@
+//! Readable names of variant types, shared by everything reporting which variant was extracted.
+
+use std::{
+    collections::HashMap,
+    sync::{OnceLock, PoisonError, RwLock},
+};
+
+/// The crates whose paths are removed entirely, their types are known without the crate name
+const PRELUDE_CRATES: &[&str] = &["core", "alloc", "std"];
+
+/// The readable name of the type called `type_name`, as returned by [`std::any::type_name`].
+///
+/// Every path is shortened to its crate and the name of the type, so
+/// `axum::json::Json<alloc::vec::Vec<my_crate::api::HelloRequest>>` becomes
+/// `axum::Json<Vec<my_crate::HelloRequest>>`. Keeping the crate tells apart equally named types
+/// of different crates, like `axum::Json` and `axum_extra::Json`. Only the paths of the standard
+/// library are removed completely, as well as the erased lifetimes `'_` of generic arguments.
+///
+/// Statics of generic functions are shared by all instantiations, so the names are cached by the
+/// full type name. Every type name is only shortened once, later lookups only take a read lock.
+pub(crate) fn name(type_name: &'static str) -> &'static str {
+    static NAMES: OnceLock<RwLock<HashMap<&'static str, &'static str>>> = OnceLock::new();
+    let names = NAMES.get_or_init(Default::default);
+
+    let cached = names
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(type_name)
+        .copied();
+    cached.unwrap_or_else(|| {
+        *names
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(type_name)
+            .or_insert_with(|| Box::leak(shorten(type_name).into_boxed_str()))
+    })
+}
+
+/// Shorten every path in `type_name` and remove the erased lifetimes
+fn shorten(type_name: &str) -> String {
+    let mut name = String::with_capacity(type_name.len());
+    let mut rest = type_name;
+    while let Some(start) = rest.find(is_path_char) {
+        name.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let end = rest.find(|c| !is_path_char(c)).unwrap_or(rest.len());
+        push_short_path(&mut name, &rest[..end]);
+        rest = &rest[end..];
+    }
+    name.push_str(rest);
+
+    if name.contains("'_") {
+        name = name.replace("<'_>", "").replace("'_, ", "");
+    }
+    name
+}
+
+/// Push `path` without the modules between its crate and its last segment
+fn push_short_path(name: &mut String, path: &str) {
+    match (path.split_once("::"), path.rsplit("::").next()) {
+        (Some((krate, _)), Some(last)) if !PRELUDE_CRATES.contains(&krate) => {
+            name.push_str(krate);
+            name.push_str("::");
+            name.push_str(last);
+        }
+        (Some(_), Some(last)) => name.push_str(last),
+        _ => name.push_str(path),
+    }
+}
+
+fn is_path_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == ':'
+}
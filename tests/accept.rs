@@ -0,0 +1,151 @@
+#![cfg(feature = "negotiation")]
+
+use axum::http::{header, HeaderMap};
+use axum_either::accept::{parse_accept, MediaRange};
+
+fn parse(values: &[&str]) -> Vec<(String, f32)> {
+    let mut headers = HeaderMap::new();
+    for value in values {
+        headers.append(header::ACCEPT, value.parse().unwrap());
+    }
+    parse_accept(&headers)
+        .into_iter()
+        .map(|range| (format!("{}/{}", range.ty, range.subtype), range.q))
+        .collect()
+}
+
+fn ranges(ranges: &[(&str, f32)]) -> Vec<(String, f32)> {
+    ranges.iter().map(|(r, q)| (r.to_string(), *q)).collect()
+}
+
+#[test]
+fn rfc_7231_quality_ordering() {
+    assert_eq!(
+        parse(&["text/*;q=0.3, text/html;q=0.7, */*;q=0.5"]),
+        ranges(&[("text/html", 0.7), ("*/*", 0.5), ("text/*", 0.3)])
+    );
+    assert_eq!(
+        parse(&["audio/*; q=0.2, audio/basic"]),
+        ranges(&[("audio/basic", 1.0), ("audio/*", 0.2)])
+    );
+    assert_eq!(
+        parse(&[
+            "text/*;q=0.3, text/html;q=0.7, text/html;level=1, text/html;level=2;q=0.4, */*;q=0.5"
+        ]),
+        ranges(&[
+            ("text/html", 1.0),
+            ("text/html", 0.7),
+            ("*/*", 0.5),
+            ("text/html", 0.4),
+            ("text/*", 0.3)
+        ])
+    );
+}
+
+#[test]
+fn specificity_breaks_ties() {
+    assert_eq!(
+        parse(&["*/*, text/*, text/html"]),
+        ranges(&[("text/html", 1.0), ("text/*", 1.0), ("*/*", 1.0)])
+    );
+}
+
+#[test]
+fn header_order_breaks_remaining_ties() {
+    assert_eq!(
+        parse(&["application/json, text/html", "image/png"]),
+        ranges(&[
+            ("application/json", 1.0),
+            ("text/html", 1.0),
+            ("image/png", 1.0)
+        ])
+    );
+}
+
+#[test]
+fn malformed_ranges_are_skipped() {
+    assert_eq!(
+        parse(&["text/html;q=2, , json, */html, image/png;q=x, application/json;q=0.9"]),
+        ranges(&[("application/json", 0.9)])
+    );
+    assert_eq!(MediaRange::parse("text/"), None);
+    assert!(parse(&[]).is_empty());
+}
+
+#[test]
+fn accept_language_ordering_and_malformed_ranges() {
+    use axum_either::accept::{parse_accept_language, LanguageRange};
+
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        header::ACCEPT_LANGUAGE,
+        "en;q=0.5, de-AT, de;q=0.8, not a tag, fr;q=2, *;q=0.1"
+            .parse()
+            .unwrap(),
+    );
+    let ranges: Vec<_> = parse_accept_language(&headers)
+        .into_iter()
+        .map(|range| (range.tag, range.q))
+        .collect();
+    assert_eq!(
+        ranges,
+        [
+            ("de-at".to_owned(), 1.0),
+            ("de".to_owned(), 0.8),
+            ("en".to_owned(), 0.5),
+            ("*".to_owned(), 0.1)
+        ]
+    );
+    assert_eq!(
+        LanguageRange::parse("zh-Hant-TW;q=0.3").unwrap().tag,
+        "zh-hant-tw"
+    );
+    assert!(LanguageRange::parse("").is_none());
+}
+
+#[test]
+fn media_type_parameters_are_preserved() {
+    let range =
+        MediaRange::parse(r#"application/vnd.api+json; Version=2; charset="UTF-8"; q=0.8; ext=1"#)
+            .unwrap();
+    assert_eq!(range.q, 0.8);
+    assert_eq!(
+        range.params,
+        [
+            ("version".to_owned(), "2".to_owned()),
+            ("charset".to_owned(), "UTF-8".to_owned())
+        ]
+    );
+    assert_eq!(range.param("VERSION"), Some("2"));
+    assert_eq!(range.param("ext"), None);
+
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        header::ACCEPT,
+        "text/html, text/html;level=1".parse().unwrap(),
+    );
+    let levels: Vec<_> = parse_accept(&headers)
+        .iter()
+        .map(|range| range.param("level").map(str::to_owned))
+        .collect();
+    assert_eq!(levels, [Some("1".to_owned()), None]);
+}
+
+#[test]
+fn parameters_select_the_quality() {
+    use axum_either::Accept;
+
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        header::ACCEPT,
+        "text/html;level=1;q=0.2, text/html;q=0.7, application/json;charset=utf-8"
+            .parse()
+            .unwrap(),
+    );
+    let accept = Accept::from_headers(&headers);
+    assert_eq!(accept.quality("text/html;level=1"), 0.2);
+    assert_eq!(accept.quality("text/html;level=2"), 0.7);
+    assert_eq!(accept.quality("text/html"), 0.7);
+    assert_eq!(accept.quality("application/json"), 1.0);
+    assert_eq!(accept.quality("application/json; charset=latin1"), 0.0);
+}
@@ -0,0 +1,125 @@
+#![cfg(feature = "aide")]
+
+use aide::{
+    gen::{self, GenContext},
+    openapi::{MediaType, Operation, ReferenceOr, RequestBody, Response},
+    OperationInput, OperationOutput,
+};
+use axum_either::AxumEither;
+
+/// An extractor or response with the given content type
+struct Body<const JSON: bool, const FORM: bool>;
+
+fn content_type(json: bool, form: bool) -> &'static str {
+    match (json, form) {
+        (true, _) => "application/json",
+        (false, true) => "application/x-www-form-urlencoded",
+        (false, false) => "text/plain",
+    }
+}
+
+impl<const JSON: bool, const FORM: bool> OperationInput for Body<JSON, FORM> {
+    fn operation_input(_ctx: &mut GenContext, operation: &mut Operation) {
+        let mut body = RequestBody {
+            required: true,
+            ..Default::default()
+        };
+        body.content
+            .insert(content_type(JSON, FORM).into(), MediaType::default());
+        operation.request_body = Some(ReferenceOr::Item(body));
+    }
+}
+
+impl<const JSON: bool, const FORM: bool> OperationOutput for Body<JSON, FORM> {
+    type Inner = Self;
+
+    fn operation_response(_ctx: &mut GenContext, _operation: &mut Operation) -> Option<Response> {
+        let mut response = Response::default();
+        response
+            .content
+            .insert(content_type(JSON, FORM).into(), MediaType::default());
+        Some(response)
+    }
+
+    fn inferred_responses(
+        ctx: &mut GenContext,
+        operation: &mut Operation,
+    ) -> Vec<(Option<u16>, Response)> {
+        Self::operation_response(ctx, operation)
+            .map(|response| vec![(Some(200), response)])
+            .unwrap_or_default()
+    }
+}
+
+type Json = Body<true, false>;
+type Form = Body<false, true>;
+type Text = Body<false, false>;
+
+#[test]
+fn request_bodies_are_merged() {
+    let mut operation = Operation::default();
+    gen::in_context(|ctx| {
+        <axum_either::one_of!(Json, Form, Text)>::operation_input(ctx, &mut operation)
+    });
+
+    let body = match operation.request_body.unwrap() {
+        ReferenceOr::Item(body) => body,
+        _ => panic!("expected an inline request body"),
+    };
+    let content_types: Vec<_> = body.content.keys().map(String::as_str).collect();
+    assert_eq!(
+        content_types,
+        [
+            "application/json",
+            "application/x-www-form-urlencoded",
+            "text/plain"
+        ]
+    );
+}
+
+#[test]
+fn responses_are_merged() {
+    let mut operation = Operation::default();
+    let responses =
+        gen::in_context(|ctx| AxumEither::<Json, Text>::inferred_responses(ctx, &mut operation));
+
+    assert_eq!(responses.len(), 1);
+    let (status, response) = &responses[0];
+    assert_eq!(*status, Some(200));
+    assert!(response.content.contains_key("application/json"));
+    assert!(response.content.contains_key("text/plain"));
+}
+
+#[cfg(all(feature = "msgpack", feature = "cbor"))]
+#[test]
+fn binary_formats_are_described() {
+    use axum_either::{Cbor, MsgPack};
+
+    let mut operation = Operation::default();
+    gen::in_context(|ctx| {
+        <axum_either::one_of!(Json, MsgPack<String>, Cbor<String>)>::operation_input(
+            ctx,
+            &mut operation,
+        )
+    });
+    let body = match operation.request_body.unwrap() {
+        ReferenceOr::Item(body) => body,
+        _ => panic!("expected an inline request body"),
+    };
+    let content_types: Vec<_> = body.content.keys().map(String::as_str).collect();
+    assert_eq!(
+        content_types,
+        [
+            "application/json",
+            "application/msgpack",
+            "application/cbor"
+        ]
+    );
+    assert!(body.content["application/msgpack"].schema.is_some());
+
+    let responses = gen::in_context(|ctx| {
+        AxumEither::<MsgPack<String>, Cbor<String>>::inferred_responses(ctx, &mut operation)
+    });
+    assert_eq!(responses.len(), 1);
+    assert!(responses[0].1.content.contains_key("application/cbor"));
+}
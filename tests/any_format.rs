@@ -0,0 +1,178 @@
+#![cfg(feature = "any-format")]
+
+use axum::{
+    body::Body,
+    extract::FromRequest,
+    http::{header, StatusCode},
+    response::IntoResponse,
+};
+use axum_either::{
+    format::{AnyFormatRejection, Format},
+    AnyFormat, RejectionInfo,
+};
+use serde::{Deserialize, Serialize};
+
+mod common;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+struct User {
+    name: String,
+    age: u32,
+}
+
+fn user() -> User {
+    User {
+        name: "Reimu".into(),
+        age: 17,
+    }
+}
+
+async fn extract(
+    content_type: &str,
+    body: impl Into<Body>,
+) -> Result<AnyFormat<User>, AnyFormatRejection> {
+    let mut req = common::post(content_type, body);
+    AnyFormat::from_request(&mut req).await
+}
+
+/// Respond with the user in `format` and extract the response again
+async fn round_trip(format: Format) {
+    let response = AnyFormat::new(user(), format).into_response();
+    assert_eq!(response.status(), StatusCode::OK);
+    let content_type = response.headers()[header::CONTENT_TYPE]
+        .to_str()
+        .unwrap()
+        .to_owned();
+    assert_eq!(content_type, format.media_type());
+
+    let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+    let extracted = extract(&content_type, body).await.unwrap();
+    assert_eq!(extracted, AnyFormat::new(user(), format));
+}
+
+#[tokio::test]
+async fn round_trips_json() {
+    round_trip(Format::Json).await;
+
+    let extracted = extract("application/json", r#"{"name":"Reimu","age":17}"#)
+        .await
+        .unwrap();
+    assert_eq!(extracted.value, user());
+}
+
+#[tokio::test]
+async fn round_trips_form() {
+    round_trip(Format::Form).await;
+
+    let extracted = extract("application/x-www-form-urlencoded", "name=Reimu&age=17")
+        .await
+        .unwrap();
+    assert_eq!(extracted.format, Format::Form);
+}
+
+#[cfg(feature = "msgpack")]
+#[tokio::test]
+async fn round_trips_msgpack() {
+    round_trip(Format::MessagePack).await;
+}
+
+#[cfg(feature = "cbor")]
+#[tokio::test]
+async fn round_trips_cbor() {
+    round_trip(Format::Cbor).await;
+}
+
+#[tokio::test]
+async fn unsupported_media_type() {
+    let rejection = extract("text/plain", "Reimu").await.unwrap_err();
+    assert!(matches!(
+        rejection,
+        AnyFormatRejection::UnsupportedMediaType
+    ));
+    assert_eq!(
+        rejection.into_response().status(),
+        StatusCode::UNSUPPORTED_MEDIA_TYPE
+    );
+}
+
+#[tokio::test]
+async fn invalid_payload() {
+    let rejection = extract("application/json", r#"{"name":"Reimu"}"#)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        rejection,
+        AnyFormatRejection::Deserialize {
+            format: Format::Json,
+            ..
+        }
+    ));
+    assert_eq!(rejection.status(), StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+/// Extract the format of a request with `content_type` and render the user in it
+async fn mirror(content_type: &str) -> Result<axum::response::Response, AnyFormatRejection> {
+    let mut req = common::post(content_type, Body::empty());
+    let format = Format::from_request(&mut req).await?;
+    Ok(format.render(user()))
+}
+
+async fn assert_mirrors(content_type: &str, format: Format) {
+    let response = mirror(content_type).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        format.media_type()
+    );
+
+    let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+    let extracted = extract(format.media_type(), body).await.unwrap();
+    assert_eq!(extracted, AnyFormat::new(user(), format));
+}
+
+#[tokio::test]
+async fn mirrors_json() {
+    assert_mirrors("application/json", Format::Json).await;
+    assert_mirrors("application/vnd.api+json; charset=utf-8", Format::Json).await;
+}
+
+#[tokio::test]
+async fn mirrors_form() {
+    assert_mirrors("application/x-www-form-urlencoded", Format::Form).await;
+}
+
+#[cfg(feature = "msgpack")]
+#[tokio::test]
+async fn mirrors_msgpack() {
+    assert_mirrors("application/x-msgpack", Format::MessagePack).await;
+}
+
+#[cfg(feature = "cbor")]
+#[tokio::test]
+async fn mirrors_cbor() {
+    assert_mirrors("application/cbor", Format::Cbor).await;
+}
+
+#[tokio::test]
+async fn format_extractor_rejects_unknown_content_types() {
+    let rejection = mirror("text/plain").await.unwrap_err();
+    assert_eq!(rejection.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+}
+
+#[tokio::test]
+async fn render_rejects_values_the_format_can_not_encode() {
+    #[derive(Serialize)]
+    struct Team {
+        members: Vec<User>,
+    }
+
+    let response = Format::Form.render(Team {
+        members: vec![user()],
+    });
+    assert_eq!(response.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+
+    let response = Format::Json.render(Team {
+        members: vec![user()],
+    });
+    assert_eq!(response.status(), StatusCode::OK);
+}
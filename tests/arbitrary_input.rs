@@ -0,0 +1,39 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use axum_either::AxumEither;
+
+type Chain = axum_either::one_of!(u8, u16, bool, u32);
+
+#[test]
+fn builds_nested_chains_from_bytes() {
+    let inputs: [(&[u8], Chain); 4] = [
+        (&[1, 5], AxumEither::Left(5)),
+        (&[0, 1, 7, 7], AxumEither::Right(AxumEither::Left(0x0707))),
+        (
+            &[0, 0, 1, 1],
+            AxumEither::Right(AxumEither::Right(AxumEither::Left(true))),
+        ),
+        (
+            &[0, 0, 0, 1, 1, 1, 1],
+            AxumEither::Right(AxumEither::Right(AxumEither::Right(0x01010101))),
+        ),
+    ];
+
+    for (bytes, expected) in inputs {
+        let mut input = Unstructured::new(bytes);
+        assert_eq!(Chain::arbitrary(&mut input).unwrap(), expected);
+    }
+}
+
+#[test]
+fn size_hint_of_nested_chain() {
+    assert_eq!(AxumEither::<u8, u32>::size_hint(0), (2, Some(5)));
+    assert_eq!(Chain::size_hint(0), (2, Some(7)));
+
+    type Deep =
+        axum_either::one_of!(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8);
+    let (lower, upper) = Deep::size_hint(0);
+    assert_eq!(lower, 2);
+    assert!(upper.is_some());
+}
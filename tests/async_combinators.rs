@@ -0,0 +1,88 @@
+use axum_either::AxumEither;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[derive(Debug, PartialEq)]
+enum AppError {
+    NotFound(u32),
+}
+
+async fn enrich(id: u32) -> Result<String, AppError> {
+    match id {
+        1 => Ok("admin".to_owned()),
+        id => Err(AppError::NotFound(id)),
+    }
+}
+
+async fn handler(request: AxumEither<u32, bool>) -> Result<AxumEither<String, bool>, AppError> {
+    let enriched = request.try_map_left_async(enrich).await?;
+    Ok(enriched)
+}
+
+#[tokio::test]
+async fn try_map_left_async_propagates_with_question_mark() {
+    assert_eq!(
+        handler(AxumEither::Left(1)).await,
+        Ok(AxumEither::Left("admin".to_owned()))
+    );
+    assert_eq!(
+        handler(AxumEither::Left(2)).await,
+        Err(AppError::NotFound(2))
+    );
+    assert_eq!(
+        handler(AxumEither::Right(true)).await,
+        Ok(AxumEither::Right(true))
+    );
+}
+
+#[tokio::test]
+async fn inactive_side_future_is_never_created() {
+    let created = AtomicUsize::new(0);
+
+    let right: AxumEither<u32, u32> = AxumEither::Right(2);
+    let mapped = right
+        .try_map_left_async(|_| {
+            created.fetch_add(1, Ordering::SeqCst);
+            async { Ok::<_, AppError>(()) }
+        })
+        .await;
+    assert_eq!(mapped, Ok(AxumEither::Right(2)));
+
+    let left: AxumEither<u32, u32> = AxumEither::Left(1);
+    let mapped = left
+        .try_map_right_async(|_| {
+            created.fetch_add(1, Ordering::SeqCst);
+            async { Ok::<_, AppError>(()) }
+        })
+        .await;
+    assert_eq!(mapped, Ok(AxumEither::Left(1)));
+
+    assert_eq!(created.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn try_map_lr_async_short_circuits_on_the_active_error() {
+    let created = AtomicUsize::new(0);
+
+    let left: AxumEither<u32, u32> = AxumEither::Left(5);
+    let mapped = left
+        .try_map_lr_async(enrich, |id| {
+            created.fetch_add(1, Ordering::SeqCst);
+            enrich(id)
+        })
+        .await;
+    assert_eq!(mapped, Err(AppError::NotFound(5)));
+    assert_eq!(created.load(Ordering::SeqCst), 0);
+
+    let right: AxumEither<u32, u32> = AxumEither::Right(1);
+    let mapped = right
+        .try_map_lr_async(
+            |id| {
+                created.fetch_add(1, Ordering::SeqCst);
+                enrich(id)
+            },
+            enrich,
+        )
+        .await;
+    assert_eq!(mapped, Ok(AxumEither::Right("admin".to_owned())));
+    assert_eq!(created.load(Ordering::SeqCst), 0);
+}
@@ -0,0 +1,152 @@
+use axum::{extract::FromRequest, http::StatusCode, Form, Json};
+use axum_either::{
+    rejection::{Attempt, Rejections},
+    AxumEither, AxumEitherRejection,
+};
+use serde::Deserialize;
+
+mod common;
+
+#[derive(Deserialize, Debug)]
+pub struct Payload {
+    pub id: u32,
+}
+
+#[tokio::test]
+async fn attempts_flatten_chain() {
+    let mut req = common::post("text/plain", "id=3");
+    let rejection =
+        <axum_either::one_of!(Json<Payload>, Form<Payload>, Json<u32>)>::from_request(&mut req)
+            .await
+            .unwrap_err();
+
+    let attempts = rejection.attempts();
+    assert_eq!(attempts.len(), 3);
+    assert!(attempts
+        .iter()
+        .all(|attempt| attempt.status() == StatusCode::UNSUPPORTED_MEDIA_TYPE));
+    assert_eq!(
+        attempts.first().unwrap().type_name(),
+        "axum::extract::rejection::JsonRejection"
+    );
+    assert_eq!(attempts[0].source_error(), None);
+}
+
+#[tokio::test]
+async fn attempts_keep_source_error() {
+    let mut req = common::post("application/json", r#"{"id": "three"}"#);
+    let rejection = AxumEither::<Json<Payload>, Form<Payload>>::from_request(&mut req)
+        .await
+        .unwrap_err();
+
+    let attempts = rejection.attempts();
+    assert_eq!(attempts[0].status(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(attempts[0]
+        .source_error()
+        .expect("the json data error has a source")
+        .contains("invalid type"));
+    assert_eq!(
+        attempts
+            .with_status(StatusCode::UNSUPPORTED_MEDIA_TYPE)
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn rejections_collect_attempts() {
+    let rejections: Rejections = [
+        Attempt::new("Left", StatusCode::NOT_FOUND, "left"),
+        Attempt::new("Right", StatusCode::BAD_REQUEST, "right").with_source("cause"),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(rejections.len(), 2);
+    assert_eq!(rejections[1].source_error(), Some("cause"));
+    let messages: Vec<_> = rejections.iter().map(Attempt::message).collect();
+    assert_eq!(messages, ["left", "right"]);
+}
+
+#[test]
+fn legacy_field_access_still_works() {
+    let mut rejection = AxumEitherRejection::new(StatusCode::NOT_FOUND, StatusCode::BAD_REQUEST);
+    assert_eq!(rejection.left_error, StatusCode::NOT_FOUND);
+    assert_eq!(rejection.right_error, StatusCode::BAD_REQUEST);
+
+    rejection.right_error = StatusCode::UNPROCESSABLE_ENTITY;
+    assert_eq!(rejection.right(), &StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(
+        rejection.attempts()[1].status(),
+        StatusCode::UNPROCESSABLE_ENTITY
+    );
+
+    let AxumEitherRejection {
+        left_error,
+        right_error,
+        ..
+    } = rejection;
+    assert_eq!(left_error, StatusCode::NOT_FOUND);
+    assert_eq!(right_error, StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+/// A rejection counting how often its message was rendered
+struct Counted<'a> {
+    status: StatusCode,
+    rendered: &'a std::cell::Cell<usize>,
+}
+
+impl axum_either::RejectionInfo for Counted<'_> {
+    fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    fn message(&self) -> String {
+        self.rendered.set(self.rendered.get() + 1);
+        self.status.to_string()
+    }
+}
+
+#[test]
+fn collect_rejections_iterates_depth_four_in_order_and_lazily() {
+    let rendered = std::cell::Cell::new(0);
+    let counted = |status| Counted {
+        status,
+        rendered: &rendered,
+    };
+    let rejection = AxumEitherRejection::new(
+        counted(StatusCode::UNSUPPORTED_MEDIA_TYPE),
+        AxumEitherRejection::new(
+            counted(StatusCode::BAD_REQUEST),
+            AxumEitherRejection::new(
+                counted(StatusCode::UNPROCESSABLE_ENTITY),
+                counted(StatusCode::NOT_FOUND),
+            ),
+        ),
+    );
+
+    let mut attempts = rejection.collect_rejections();
+    assert_eq!(attempts.len(), 4);
+    let statuses: Vec<_> = attempts.clone().map(|attempt| attempt.status()).collect();
+    assert_eq!(
+        statuses,
+        [
+            StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            StatusCode::BAD_REQUEST,
+            StatusCode::UNPROCESSABLE_ENTITY,
+            StatusCode::NOT_FOUND,
+        ]
+    );
+    assert!(attempts
+        .clone()
+        .all(|attempt| attempt.name() == "attempts::Counted"));
+    assert_eq!(rendered.get(), 0);
+
+    let third = attempts.clone().nth(2).unwrap();
+    assert_eq!(third.message(), "422 Unprocessable Entity");
+    assert_eq!(rendered.get(), 1);
+    assert_eq!(
+        attempts.next_back().unwrap().status(),
+        StatusCode::NOT_FOUND
+    );
+    assert_eq!(rendered.get(), 1);
+}
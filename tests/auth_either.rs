@@ -0,0 +1,124 @@
+#![cfg(feature = "axum-extra")]
+
+use axum::{
+    body::Body,
+    extract::{FromRequest, RequestParts},
+    http::{header, Request, StatusCode},
+    response::IntoResponse,
+};
+use axum_either::{
+    auth::{AuthEither, AuthToken, CookieName, SessionCookie},
+    Attempts, RejectionInfo,
+};
+
+fn request(authorization: Option<&str>, cookie: Option<&str>) -> RequestParts<Body> {
+    let mut request = Request::get("/");
+    if let Some(authorization) = authorization {
+        request = request.header(header::AUTHORIZATION, authorization);
+    }
+    if let Some(cookie) = cookie {
+        request = request.header(header::COOKIE, cookie);
+    }
+    RequestParts::new(request.body(Body::empty()).unwrap())
+}
+
+#[tokio::test]
+async fn header_only() {
+    let mut req = request(Some("Bearer header-token"), None);
+    let auth = <AuthEither>::from_request(&mut req).await.unwrap();
+
+    assert_eq!(auth.token(), "header-token");
+    assert!(auth.header().is_some());
+    assert!(auth.cookie().is_none());
+}
+
+#[tokio::test]
+async fn cookie_only() {
+    let mut req = request(None, Some("theme=dark; session=cookie-token"));
+    let auth = <AuthEither>::from_request(&mut req).await.unwrap();
+
+    assert_eq!(auth.token(), "cookie-token");
+    assert!(auth.header().is_none());
+    assert_eq!(auth.cookie().unwrap().value(), "cookie-token");
+}
+
+#[tokio::test]
+async fn header_wins_over_cookie() {
+    let mut req = request(Some("Bearer header-token"), Some("session=cookie-token"));
+    let auth = <AuthEither>::from_request(&mut req).await.unwrap();
+
+    assert_eq!(auth.token(), "header-token");
+    assert!(auth.is_left());
+}
+
+#[tokio::test]
+async fn malformed_header_falls_back_to_cookie() {
+    let mut req = request(Some("Basic dXNlcjpwYXNz"), Some("session=cookie-token"));
+    let auth = <AuthEither>::from_request(&mut req).await.unwrap();
+
+    assert_eq!(auth.token(), "cookie-token");
+}
+
+#[tokio::test]
+async fn neither_is_unauthorized() {
+    let mut req = request(None, Some("session="));
+    let rejection = <AuthEither>::from_request(&mut req).await.unwrap_err();
+
+    assert_eq!(rejection.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(rejection.cookie().name(), "session");
+    assert_eq!(Attempts::from_info(&rejection).len(), 2);
+
+    let response = rejection.into_response();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(response.headers()[header::WWW_AUTHENTICATE], "Bearer");
+    let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+    let body = String::from_utf8(body.to_vec()).unwrap();
+    assert!(body.contains("authorization"), "{}", body);
+    assert!(body.contains("`session` cookie"), "{}", body);
+}
+
+#[derive(Debug)]
+pub struct Sid;
+
+impl CookieName for Sid {
+    const NAME: &'static str = "sid";
+}
+
+#[derive(Debug)]
+pub struct ApiKey(String);
+
+impl AuthToken for ApiKey {
+    const SCHEME: &'static str = "ApiKey";
+
+    fn token(&self) -> &str {
+        &self.0
+    }
+}
+
+#[axum::async_trait]
+impl<B: Send> FromRequest<B> for ApiKey {
+    type Rejection = StatusCode;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        req.headers()
+            .get("x-api-key")
+            .and_then(|key| key.to_str().ok())
+            .map(|key| Self(key.to_owned()))
+            .ok_or(StatusCode::UNAUTHORIZED)
+    }
+}
+
+#[tokio::test]
+async fn custom_sources() {
+    let mut req = request(None, Some("sid=custom"));
+    let auth = AuthEither::<ApiKey, SessionCookie<Sid>>::from_request(&mut req)
+        .await
+        .unwrap();
+    assert_eq!(auth.token(), "custom");
+
+    let mut req = request(None, Some("session=ignored"));
+    let rejection = AuthEither::<ApiKey, SessionCookie<Sid>>::from_request(&mut req)
+        .await
+        .unwrap_err();
+    assert_eq!(rejection.scheme(), "ApiKey");
+}
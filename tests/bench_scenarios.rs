@@ -0,0 +1,40 @@
+#![cfg(feature = "test-util")]
+
+//! Runs every benchmark scenario once, so the benchmarks are checked by `cargo test`.
+
+#[path = "../benches/scenarios/mod.rs"]
+mod scenarios;
+
+use scenarios::Bodies;
+
+#[tokio::test]
+async fn scenarios_take_the_benchmarked_paths() {
+    for (label, len) in scenarios::SIZES {
+        let bodies = Bodies::new(len);
+        assert!(
+            scenarios::direct_json(bodies.json_request()).await,
+            "{}",
+            label
+        );
+        assert!(
+            scenarios::either_left(bodies.json_request()).await,
+            "{}",
+            label
+        );
+        assert!(
+            scenarios::last_of_five(bodies.text_request()).await,
+            "{}",
+            label
+        );
+        assert!(
+            scenarios::flat_last_of_five(bodies.text_request()).await,
+            "{}",
+            label
+        );
+        assert!(
+            scenarios::double_rejection(bodies.text_request()).await,
+            "{}",
+            label
+        );
+    }
+}
@@ -0,0 +1,163 @@
+#![cfg(any(feature = "msgpack", feature = "cbor"))]
+
+use axum::{
+    body::Body,
+    extract::{FromRequest, RequestParts},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use axum_either::{AxumEither, MediaTypeDispatch, RejectionInfo};
+use serde::{Deserialize, Serialize};
+
+mod common;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Position {
+    pub name: String,
+    pub x: i32,
+    pub y: i32,
+}
+
+fn position() -> Position {
+    Position {
+        name: "home".into(),
+        x: 3,
+        y: -4,
+    }
+}
+
+async fn into_request(response: Response) -> RequestParts<Body> {
+    assert_eq!(response.status(), StatusCode::OK);
+    let content_type = response.headers()[header::CONTENT_TYPE].clone();
+    let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+    common::post(content_type.to_str().unwrap(), body)
+}
+
+/// Respond with `either`, extract the response as the same chain and check the active side
+async fn round_trip<T>(either: T) -> T
+where
+    T: IntoResponse + FromRequest<Body>,
+    T::Rejection: std::fmt::Debug,
+{
+    let mut req = into_request(either.into_response()).await;
+    T::from_request(&mut req).await.unwrap()
+}
+
+async fn assert_json_side<T>()
+where
+    T: FromRequest<Body>,
+    T::Rejection: std::fmt::Debug,
+{
+    let body = serde_json::to_vec(&position()).unwrap();
+    T::from_request(&mut common::post("application/json", body))
+        .await
+        .unwrap();
+}
+
+#[cfg(feature = "msgpack")]
+mod msgpack {
+    use super::*;
+    use axum_either::format::MsgPack;
+
+    type Either = axum_either::one_of!(Json<Position>, MsgPack<Position>);
+
+    #[tokio::test]
+    async fn round_trips_through_one_of() {
+        match round_trip::<Either>(AxumEither::Right(MsgPack(position()))).await {
+            AxumEither::Right(MsgPack(value)) => assert_eq!(value, position()),
+            AxumEither::Left(_) => panic!("msgpack was extracted as json"),
+        }
+        match round_trip::<Either>(AxumEither::Left(Json(position()))).await {
+            AxumEither::Left(Json(value)) => assert_eq!(value, position()),
+            AxumEither::Right(_) => panic!("json was extracted as msgpack"),
+        }
+        assert_json_side::<Either>().await;
+    }
+
+    #[tokio::test]
+    async fn accepts_both_media_types() {
+        let body = rmp_serde::to_vec_named(&position()).unwrap();
+        for content_type in ["application/msgpack", "application/x-msgpack"] {
+            let MsgPack(value) =
+                MsgPack::<Position>::from_request(&mut common::post(content_type, body.clone()))
+                    .await
+                    .unwrap();
+            assert_eq!(value, position());
+        }
+    }
+
+    #[tokio::test]
+    async fn responds_as_msgpack() {
+        let response = MsgPack(position()).into_response();
+        assert_eq!(
+            response.headers()[header::CONTENT_TYPE],
+            "application/msgpack"
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_other_formats() {
+        let rejection =
+            MsgPack::<Position>::from_request(&mut common::post("application/json", "{}"))
+                .await
+                .unwrap_err();
+        assert_eq!(rejection.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+
+        let rejection =
+            MsgPack::<Position>::from_request(&mut common::post("application/msgpack", "nope"))
+                .await
+                .unwrap_err();
+        assert_eq!(rejection.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[test]
+    fn dispatches_by_media_type() {
+        assert_eq!(
+            Either::claims(&"application/x-msgpack".parse().unwrap()),
+            [1]
+        );
+        assert_eq!(Either::claims(&mime::APPLICATION_JSON), [0]);
+    }
+}
+
+#[cfg(feature = "cbor")]
+mod cbor {
+    use super::*;
+    use axum_either::format::Cbor;
+
+    type Either = axum_either::one_of!(Json<Position>, Cbor<Position>);
+
+    #[tokio::test]
+    async fn round_trips_through_one_of() {
+        match round_trip::<Either>(AxumEither::Right(Cbor(position()))).await {
+            AxumEither::Right(Cbor(value)) => assert_eq!(value, position()),
+            AxumEither::Left(_) => panic!("cbor was extracted as json"),
+        }
+        match round_trip::<Either>(AxumEither::Left(Json(position()))).await {
+            AxumEither::Left(Json(value)) => assert_eq!(value, position()),
+            AxumEither::Right(_) => panic!("json was extracted as cbor"),
+        }
+        assert_json_side::<Either>().await;
+    }
+
+    #[tokio::test]
+    async fn responds_as_cbor() {
+        let response = Cbor(position()).into_response();
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/cbor");
+    }
+
+    #[tokio::test]
+    async fn rejects_other_formats() {
+        let rejection =
+            Cbor::<Position>::from_request(&mut common::post("application/msgpack", ""))
+                .await
+                .unwrap_err();
+        assert_eq!(rejection.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    }
+
+    #[test]
+    fn dispatches_by_media_type() {
+        assert_eq!(Either::claims(&"application/cbor".parse().unwrap()), [1]);
+    }
+}
@@ -0,0 +1,98 @@
+#![cfg(feature = "body")]
+
+use axum_either::{body::EitherBody, AxumEither};
+use bytes::{Bytes, BytesMut};
+use futures_util::future::poll_fn;
+use http::{HeaderMap, HeaderValue};
+use http_body::{Body, Full};
+use std::pin::Pin;
+
+/// Polls the body to its end like hyper does, returning the data and trailers
+async fn collect<B>(mut body: B) -> (Bytes, Option<HeaderMap>)
+where
+    B: Body<Data = Bytes> + Unpin,
+    B::Error: std::fmt::Debug,
+{
+    let mut data = BytesMut::new();
+    while let Some(chunk) = poll_fn(|cx| Pin::new(&mut body).poll_data(cx)).await {
+        data.extend_from_slice(&chunk.unwrap());
+    }
+    let trailers = poll_fn(|cx| Pin::new(&mut body).poll_trailers(cx))
+        .await
+        .unwrap();
+    (data.freeze(), trailers)
+}
+
+fn trailers() -> HeaderMap {
+    let mut trailers = HeaderMap::new();
+    trailers.insert("x-checksum", HeaderValue::from_static("abc"));
+    trailers
+}
+
+#[tokio::test]
+async fn left_body() {
+    let body: EitherBody<Full<Bytes>, hyper::Body> = EitherBody::left(Full::from("left"));
+    assert_eq!(body.size_hint().exact(), Some(4));
+
+    let (data, trailers) = collect(body).await;
+    assert_eq!(data, "left");
+    assert_eq!(trailers, None);
+}
+
+#[tokio::test]
+async fn right_body_with_trailers() {
+    let (mut sender, body) = hyper::Body::channel();
+    let body: EitherBody<Full<Bytes>, hyper::Body> = EitherBody::right(body);
+
+    tokio::spawn(async move {
+        sender.send_data(Bytes::from("ri")).await.unwrap();
+        sender.send_data(Bytes::from("ght")).await.unwrap();
+        sender.send_trailers(trailers()).await.unwrap();
+    });
+
+    let (data, received) = collect(body).await;
+    assert_eq!(data, "right");
+    assert_eq!(received, Some(trailers()));
+}
+
+#[tokio::test]
+async fn left_body_with_trailers() {
+    let (mut sender, body) = hyper::Body::channel();
+    let body: EitherBody<hyper::Body, Full<Bytes>> = AxumEither::Left(body).into();
+
+    tokio::spawn(async move {
+        sender.send_data(Bytes::from("left")).await.unwrap();
+        sender.send_trailers(trailers()).await.unwrap();
+    });
+
+    let (data, received) = collect(body).await;
+    assert_eq!(data, "left");
+    assert_eq!(received, Some(trailers()));
+}
+
+#[tokio::test]
+async fn errors_are_converted() {
+    let (sender, body) = hyper::Body::channel();
+    let mut body: EitherBody<Full<Bytes>, hyper::Body> = EitherBody::right(body);
+    sender.abort();
+
+    let chunk = poll_fn(|cx| Pin::new(&mut body).poll_data(cx)).await;
+    assert!(matches!(chunk, Some(Err(_))));
+}
+
+#[tokio::test]
+async fn converts_from_common_bodies() {
+    let body: EitherBody<Full<Bytes>, axum::body::BoxBody> = Full::from("full").into();
+    assert!(matches!(body.0, AxumEither::Left(_)));
+
+    let body: EitherBody<Full<Bytes>, axum::body::BoxBody> =
+        axum::body::boxed(Full::from("boxed")).into();
+    let (data, _) = collect(body).await;
+    assert_eq!(data, "boxed");
+
+    let response = hyper::Response::new(EitherBody::<Full<Bytes>, Full<Bytes>>::left(Full::from(
+        "hyper",
+    )));
+    let data = hyper::body::to_bytes(response.into_body()).await.unwrap();
+    assert_eq!(data, "hyper");
+}
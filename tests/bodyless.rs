@@ -0,0 +1,103 @@
+use axum::{
+    body::Body,
+    extract::{FromRequest, Query, RequestParts},
+    http::{header, Method, Request, StatusCode},
+    response::IntoResponse,
+    Form, Json,
+};
+use axum_either::{rejection::BodyEitherRejection, AxumEither, BodyEither, RejectionInfo};
+use serde::Deserialize;
+
+mod common;
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct Search {
+    pub q: String,
+}
+
+type BodyOnly = BodyEither<Json<Search>, Form<Search>>;
+type Mixed = BodyEither<Json<Search>, Query<Search>>;
+
+fn request(method: Method, uri: &str) -> RequestParts<Body> {
+    RequestParts::new(
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .body(Body::empty())
+            .unwrap(),
+    )
+}
+
+#[tokio::test]
+async fn bodiless_methods_get_a_dedicated_rejection() {
+    for method in [Method::GET, Method::HEAD] {
+        let rejection = BodyOnly::from_request(&mut request(method.clone(), "/"))
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(&rejection, BodyEitherRejection::Bodyless { method: m, .. } if *m == method)
+        );
+        assert_eq!(rejection.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            rejection.message(),
+            format!(
+                "{} requests have no body, send the payload with another method",
+                method
+            )
+        );
+        // The rejections of both sides are still available
+        assert_eq!(axum_either::Attempts::from_info(&rejection).len(), 2);
+
+        let response = rejection.into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
+
+#[tokio::test]
+async fn query_side_still_extracts() {
+    for method in [Method::GET, Method::HEAD] {
+        let BodyEither(either) = Mixed::from_request(&mut request(method, "/?q=rust"))
+            .await
+            .unwrap();
+        match either {
+            AxumEither::Right(Query(search)) => assert_eq!(search.q, "rust"),
+            AxumEither::Left(_) => panic!("json extracted from a bodiless request"),
+        }
+    }
+}
+
+#[tokio::test]
+async fn mixed_either_without_query_is_bodiless() {
+    let rejection = Mixed::from_request(&mut request(Method::GET, "/"))
+        .await
+        .unwrap_err();
+    assert!(matches!(rejection, BodyEitherRejection::Bodyless { .. }));
+}
+
+#[tokio::test]
+async fn requests_with_a_body_keep_the_combined_rejection() {
+    let mut req = common::post("text/plain", "q=rust");
+    let rejection = BodyOnly::from_request(&mut req).await.unwrap_err();
+    assert!(matches!(rejection, BodyEitherRejection::Rejected(_)));
+
+    let mut req = RequestParts::new(
+        Request::get("/")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from("{}"))
+            .unwrap(),
+    );
+    let rejection = BodyOnly::from_request(&mut req).await.unwrap_err();
+    assert!(matches!(rejection, BodyEitherRejection::Rejected(_)));
+}
+
+#[tokio::test]
+async fn get_with_a_json_body_extracts() {
+    let mut req = RequestParts::new(
+        Request::get("/")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(r#"{"q":"rust"}"#))
+            .unwrap(),
+    );
+    let BodyEither(either) = BodyOnly::from_request(&mut req).await.unwrap();
+    assert!(either.is_left());
+}
@@ -0,0 +1,42 @@
+use axum_either::prelude::*;
+use std::mem::size_of;
+
+type Large = [u64; 128];
+
+const _: () = assert!(size_of::<AxumEither<Box<Large>, u8>>() < size_of::<AxumEither<Large, u8>>());
+const _: () = assert!(size_of::<AxumEither<Box<Large>, u8>>() <= 2 * size_of::<usize>());
+const _: () = assert!(size_of::<AxumEither<Box<Large>, Box<Large>>>() <= 2 * size_of::<usize>());
+
+#[test]
+fn boxing_roundtrips() {
+    let l: AxumEither<Large, u8> = AxumEither::Left([7; 128]);
+    assert_eq!(l.boxed_left().unboxed_left(), l);
+    let r: AxumEither<u8, Large> = AxumEither::Right([7; 128]);
+    assert_eq!(r.boxed_right().unboxed_right(), r);
+    let lr: AxumEither<Large, u8> = AxumEither::Right(3);
+    assert_eq!(lr.boxed_lr().unboxed_lr(), lr);
+}
+
+#[test]
+fn boxing_keeps_the_other_side() {
+    let r: AxumEither<Large, u8> = AxumEither::Right(3);
+    assert_eq!(r.boxed_left(), AxumEither::Right(3));
+    let l: AxumEither<u8, Large> = AxumEither::Left(3);
+    assert_eq!(l.boxed_right(), AxumEither::Left(3));
+}
+
+#[test]
+fn map_deref_maps_through_the_box() {
+    let l: AxumEither<Large, u8> = AxumEither::Left([1; 128]);
+    let summed = l
+        .boxed_left()
+        .map_left_deref(|large| large.iter().sum::<u64>());
+    assert_eq!(summed, AxumEither::Left(Box::new(128)));
+
+    let r: AxumEither<u8, Large> = AxumEither::Right([2; 128]);
+    let summed = r
+        .boxed_right()
+        .map_right_deref(|large| large.iter().sum::<u64>())
+        .unboxed_right();
+    assert_eq!(summed, AxumEither::Right(256));
+}
@@ -0,0 +1,172 @@
+#![cfg(all(feature = "test-util", feature = "axum"))]
+
+use axum::{
+    extract::{
+        rejection::{FormRejection, JsonRejection, QueryRejection},
+        Query,
+    },
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::post,
+    Form, Json, Router,
+};
+use axum_either::{
+    test::Harness, AxumEither, AxumEitherRejection, BoxedEitherRejection, RejectionInfo, Rejections,
+};
+use serde::Deserialize;
+use std::{
+    fmt,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+#[derive(Deserialize, Debug)]
+pub struct Item {
+    pub id: u32,
+}
+
+type Create = AxumEither<Json<Item>, Form<Item>>;
+type CreateRejection = AxumEitherRejection<JsonRejection, FormRejection>;
+
+type Update = AxumEither<Json<Item>, AxumEither<Form<Item>, Query<Item>>>;
+type UpdateRejection =
+    AxumEitherRejection<JsonRejection, AxumEitherRejection<FormRejection, QueryRejection>>;
+
+/// The one error handler shared by all routes
+fn handle_rejection(rejection: BoxedEitherRejection) -> Response {
+    let attempts = rejection.attempts().len().to_string();
+    (
+        rejection.status(),
+        [("x-attempts", attempts)],
+        rejection.to_string(),
+    )
+        .into_response()
+}
+
+async fn create(request: Result<Create, CreateRejection>) -> Result<String, Response> {
+    let item = request
+        .map_err(BoxedEitherRejection::from)
+        .map_err(handle_rejection)?;
+    Ok(format!("{:?}", item.map_lr(|Json(i)| i, |Form(i)| i)))
+}
+
+async fn update(request: Result<Update, UpdateRejection>) -> Result<String, Response> {
+    let item = request
+        .map_err(BoxedEitherRejection::from)
+        .map_err(handle_rejection)?;
+    Ok(format!("{:?}", item.is_left()))
+}
+
+fn router() -> Router {
+    Router::new()
+        .route("/create", post(create))
+        .route("/update", post(update))
+}
+
+#[tokio::test]
+async fn rejections_of_different_routes_share_one_handler() {
+    let harness = Harness::new(router());
+
+    let created = harness
+        .post("/create")
+        .json(&serde_json::json!({"id": 3}))
+        .send()
+        .await;
+    assert_eq!(created.status(), StatusCode::OK);
+
+    let create = harness
+        .post("/create")
+        .content_type("text/plain")
+        .body("3")
+        .send()
+        .await;
+    assert_eq!(create.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(create.header("x-attempts").unwrap(), "2");
+    assert!(create
+        .text()
+        .starts_with("Could not parse request, axum::JsonRejection error: "));
+    assert!(create.text().contains("FormRejection error: "));
+
+    let update = harness
+        .post("/update")
+        .content_type("text/plain")
+        .body("3")
+        .send()
+        .await;
+    assert_eq!(update.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(update.header("x-attempts").unwrap(), "3");
+    assert!(update.text().contains("QueryRejection error: "));
+}
+
+#[test]
+fn keeps_status_and_attempts() {
+    let rejection = AxumEitherRejection::new(
+        (StatusCode::UNSUPPORTED_MEDIA_TYPE, "Expected json"),
+        AxumEitherRejection::new(StatusCode::NOT_FOUND, StatusCode::INTERNAL_SERVER_ERROR),
+    );
+    let status = rejection.status();
+    let attempts = rejection.attempts();
+
+    let boxed = BoxedEitherRejection::from(rejection);
+    assert_eq!(boxed.status(), status);
+    assert_eq!(boxed.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(boxed.clone().into_attempts(), attempts);
+    assert_eq!(boxed.attempts().next().unwrap().message(), "Expected json");
+
+    // The attempts are reported with the names of the original rejections
+    let reported = Rejections::from_info(&boxed);
+    assert_eq!(reported, attempts);
+    assert_eq!(
+        boxed.into_response().status(),
+        StatusCode::INTERNAL_SERVER_ERROR
+    );
+}
+
+#[derive(Clone, Default)]
+struct CountingRejection(Arc<AtomicUsize>);
+
+impl RejectionInfo for CountingRejection {
+    fn status(&self) -> StatusCode {
+        StatusCode::BAD_REQUEST
+    }
+
+    fn message(&self) -> String {
+        self.0.fetch_add(1, Ordering::SeqCst);
+        "Counted".to_owned()
+    }
+
+    fn write_message(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        self.0.fetch_add(1, Ordering::SeqCst);
+        out.write_str("Counted")
+    }
+}
+
+#[test]
+fn renders_messages_on_demand() {
+    let left = CountingRejection::default();
+    let rendered = left.0.clone();
+
+    let boxed = BoxedEitherRejection::from(AxumEitherRejection::new(
+        left,
+        StatusCode::UNSUPPORTED_MEDIA_TYPE,
+    ));
+    assert_eq!(boxed.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(boxed.attempts().len(), 2);
+    assert_eq!(rendered.load(Ordering::SeqCst), 0);
+
+    assert!(boxed.to_string().contains("Counted"));
+    assert_eq!(rendered.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn is_a_send_sync_error() {
+    fn assert_error<E: std::error::Error + Send + Sync + 'static>() {}
+    assert_error::<BoxedEitherRejection>();
+
+    let error: Box<dyn std::error::Error + Send + Sync> = Box::new(BoxedEitherRejection::from(
+        AxumEitherRejection::new(StatusCode::BAD_REQUEST, StatusCode::NOT_FOUND),
+    ));
+    assert!(error.to_string().starts_with("Could not parse request"));
+}
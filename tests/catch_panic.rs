@@ -0,0 +1,109 @@
+use axum::{
+    body::Body,
+    extract::{FromRequest, RequestParts},
+    http::{header, Request, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use axum_either::{AxumEither, CatchPanic, CatchPanicEither, CaughtRejection, RejectionInfo};
+
+/// An extractor with a bug
+#[derive(Debug)]
+struct Buggy;
+
+#[async_trait::async_trait]
+impl<B: Send> FromRequest<B> for Buggy {
+    type Rejection = StatusCode;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        if req.headers().contains_key("x-buggy") {
+            panic!("index out of bounds");
+        }
+        Err(StatusCode::BAD_REQUEST)
+    }
+}
+
+fn request(buggy: bool, body: &'static str) -> RequestParts<Body> {
+    let mut request = Request::post("/").header(header::CONTENT_TYPE, "application/json");
+    if buggy {
+        request = request.header("x-buggy", "1");
+    }
+    RequestParts::new(request.body(Body::from(body)).unwrap())
+}
+
+#[tokio::test]
+async fn right_answers_when_left_panics() {
+    let mut req = request(true, "7");
+    let CatchPanicEither(either) = CatchPanicEither::<Buggy, Json<u32>>::from_request(&mut req)
+        .await
+        .unwrap();
+    assert!(matches!(either, AxumEither::Right(Json(7))));
+}
+
+#[tokio::test]
+async fn panic_is_reported_in_the_rejection() {
+    let mut req = request(true, "not json");
+    let rejection = CatchPanicEither::<Buggy, Json<u32>>::from_request(&mut req)
+        .await
+        .unwrap_err();
+
+    let (left, right) = rejection.into_parts();
+    let panic = left.panic().expect("the left extractor panicked");
+    assert!(panic.type_name().ends_with("Buggy"));
+    assert_eq!(panic.panic_message(), Some("index out of bounds"));
+    assert_eq!(panic.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(left.to_string().contains("index out of bounds"));
+    assert!(matches!(right, CaughtRejection::Rejected(_)));
+}
+
+#[tokio::test]
+async fn panic_message_is_not_sent() {
+    let mut req = request(true, "7");
+    let rejection = CatchPanic::<Buggy>::from_request(&mut req)
+        .await
+        .unwrap_err();
+    assert!(!rejection.message().contains("index out of bounds"));
+
+    let response = rejection.into_response();
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+    assert_eq!(&body[..], b"Internal server error");
+}
+
+#[tokio::test]
+async fn panics_show_up_in_the_attempts() {
+    let mut req = request(true, "not json");
+    let rejection = CatchPanicEither::<Buggy, Json<u32>>::from_request(&mut req)
+        .await
+        .unwrap_err();
+    let names: Vec<_> = rejection
+        .collect_rejections()
+        .map(|attempt| attempt.name())
+        .collect();
+    assert_eq!(
+        names,
+        ["axum_either::ExtractorPanic", "axum::JsonRejection"]
+    );
+    assert_eq!(
+        rejection.into_response().status(),
+        StatusCode::INTERNAL_SERVER_ERROR
+    );
+}
+
+#[tokio::test]
+async fn rejections_pass_through() {
+    let mut req = request(false, "7");
+    let rejection = CatchPanic::<Buggy>::from_request(&mut req)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        rejection,
+        CaughtRejection::Rejected(StatusCode::BAD_REQUEST)
+    ));
+
+    let mut req = request(false, "7");
+    let either = AxumEither::<CatchPanic<Buggy>, CatchPanic<Json<u32>>>::from_request(&mut req)
+        .await
+        .unwrap();
+    assert!(matches!(either, AxumEither::Right(CatchPanic(Json(7)))));
+}
@@ -0,0 +1,213 @@
+use axum_either::AxumEither;
+
+type Either = AxumEither<u8, &'static str>;
+
+#[test]
+fn left_and_all_combinations() {
+    let left: Either = AxumEither::Left(1);
+    let right: Either = AxumEither::Right("first");
+    let other_left: AxumEither<bool, &str> = AxumEither::Left(true);
+    let other_right: AxumEither<bool, &str> = AxumEither::Right("second");
+
+    assert_eq!(left.left_and(other_left), AxumEither::Left(true));
+    assert_eq!(left.left_and(other_right), AxumEither::Right("second"));
+    assert_eq!(right.left_and(other_left), AxumEither::Right("first"));
+    assert_eq!(right.left_and(other_right), AxumEither::Right("first"));
+}
+
+#[test]
+fn right_and_all_combinations() {
+    let left: Either = AxumEither::Left(1);
+    let right: Either = AxumEither::Right("first");
+    let other_left: AxumEither<u8, bool> = AxumEither::Left(2);
+    let other_right: AxumEither<u8, bool> = AxumEither::Right(true);
+
+    assert_eq!(left.right_and(other_left), AxumEither::Left(1));
+    assert_eq!(left.right_and(other_right), AxumEither::Left(1));
+    assert_eq!(right.right_and(other_left), AxumEither::Left(2));
+    assert_eq!(right.right_and(other_right), AxumEither::Right(true));
+}
+
+#[test]
+fn and_then_is_lazy() {
+    let right: Either = AxumEither::Right("first");
+    assert_eq!(
+        right.and_then_left(|_| -> AxumEither<bool, &str> { unreachable!() }),
+        AxumEither::Right("first")
+    );
+
+    let left: Either = AxumEither::Left(1);
+    assert_eq!(
+        left.and_then_right(|_| -> AxumEither<u8, bool> { unreachable!() }),
+        AxumEither::Left(1)
+    );
+    assert_eq!(
+        left.and_then_left(|l| AxumEither::<bool, &str>::Left(l == 1)),
+        AxumEither::Left(true)
+    );
+}
+
+#[test]
+fn transpose_err_all_combinations() {
+    type Transposed = AxumEither<Result<u8, bool>, Result<&'static str, bool>>;
+    let inputs: [Result<Either, bool>; 3] = [
+        Ok(AxumEither::Left(1)),
+        Ok(AxumEither::Right("right")),
+        Err(false),
+    ];
+
+    let left = inputs.map(Transposed::transpose_err_left);
+    assert_eq!(
+        left,
+        [
+            AxumEither::Left(Ok(1)),
+            AxumEither::Right(Ok("right")),
+            AxumEither::Left(Err(false)),
+        ]
+    );
+
+    let right = inputs.map(Transposed::transpose_err_right);
+    assert_eq!(
+        right,
+        [
+            AxumEither::Left(Ok(1)),
+            AxumEither::Right(Ok("right")),
+            AxumEither::Right(Err(false)),
+        ]
+    );
+}
+
+#[test]
+fn prefer_left_breaks_ties_with_self() {
+    let left: Either = AxumEither::Left(1);
+    let other_left: Either = AxumEither::Left(2);
+    let right: Either = AxumEither::Right("first");
+    let other_right: Either = AxumEither::Right("second");
+
+    assert_eq!(left.prefer_left(other_left), left);
+    assert_eq!(left.prefer_left(right), left);
+    assert_eq!(right.prefer_left(left), left);
+    assert_eq!(right.prefer_left(other_right), right);
+}
+
+#[test]
+fn collapse_empty() {
+    assert_eq!(Either::collapse(Vec::new()), None);
+}
+
+#[test]
+fn collapse_all_right_returns_first() {
+    let eithers: [Either; 3] = [
+        AxumEither::Right("first"),
+        AxumEither::Right("second"),
+        AxumEither::Right("third"),
+    ];
+    assert_eq!(Either::collapse(eithers), Some(AxumEither::Right("first")));
+}
+
+#[test]
+fn collapse_mixed_returns_first_left() {
+    let eithers: [Either; 4] = [
+        AxumEither::Right("first"),
+        AxumEither::Left(1),
+        AxumEither::Right("second"),
+        AxumEither::Left(2),
+    ];
+    assert_eq!(Either::collapse(eithers), Some(AxumEither::Left(1)));
+
+    let mut consumed = 0;
+    let eithers = [AxumEither::Left(1), AxumEither::Left(2)]
+        .into_iter()
+        .inspect(|_| consumed += 1);
+    assert_eq!(Either::collapse(eithers), Some(AxumEither::Left(1)));
+    assert_eq!(consumed, 1);
+}
+
+#[derive(Debug, PartialEq)]
+enum AppError {
+    Parse,
+    Lookup(u8),
+}
+
+fn parse(input: &'static str) -> Result<u8, AppError> {
+    input.parse().map_err(|_| AppError::Parse)
+}
+
+fn lookup(id: u8) -> Result<&'static str, AppError> {
+    match id {
+        1 => Ok("one"),
+        id => Err(AppError::Lookup(id)),
+    }
+}
+
+fn handler(
+    request: AxumEither<&'static str, u8>,
+) -> Result<AxumEither<u8, &'static str>, AppError> {
+    let parsed = request.try_map_left(parse)?;
+    parsed.try_map_right(lookup)
+}
+
+#[test]
+fn try_map_propagates_errors_of_the_active_side() {
+    assert_eq!(handler(AxumEither::Left("7")), Ok(AxumEither::Left(7)));
+    assert_eq!(handler(AxumEither::Left("seven")), Err(AppError::Parse));
+    assert_eq!(handler(AxumEither::Right(1)), Ok(AxumEither::Right("one")));
+    assert_eq!(handler(AxumEither::Right(2)), Err(AppError::Lookup(2)));
+}
+
+#[test]
+fn try_map_passes_the_inactive_side_through() {
+    let left: Either = AxumEither::Left(1);
+    let right: Either = AxumEither::Right("first");
+
+    let mapped: Result<_, AppError> = right.try_map_left(|_| -> Result<u8, _> { unreachable!() });
+    assert_eq!(mapped, Ok(AxumEither::Right("first")));
+    let mapped: Result<_, AppError> = left.try_map_right(|_| -> Result<u8, _> { unreachable!() });
+    assert_eq!(mapped, Ok(AxumEither::Left(1)));
+}
+
+#[test]
+fn try_map_lr_only_calls_the_active_side() {
+    let right: AxumEither<&'static str, u8> = AxumEither::Right(3);
+    let mapped = right.try_map_lr(|_| -> Result<u8, AppError> { unreachable!() }, lookup);
+    assert_eq!(mapped, Err(AppError::Lookup(3)));
+    let left: AxumEither<&'static str, u8> = AxumEither::Left("x");
+    assert_eq!(left.try_map_lr(parse, lookup), Err(AppError::Parse));
+}
+
+#[test]
+fn zip_with_all_combinations() {
+    let left: Either = AxumEither::Left(1);
+    let right: Either = AxumEither::Right("first");
+    let other_left: AxumEither<u8, &str> = AxumEither::Left(2);
+    let other_right: AxumEither<u8, &str> = AxumEither::Right("second");
+    let add = |a: u8, b: u8| a + b;
+    let concat = |a: &str, b: &str| format!("{} {}", a, b);
+
+    assert_eq!(
+        left.zip_with(other_left, add, concat),
+        Some(AxumEither::Left(3))
+    );
+    assert_eq!(left.zip_with(other_right, add, concat), None);
+    assert_eq!(right.zip_with(other_left, add, concat), None);
+    assert_eq!(
+        right.zip_with(other_right, add, concat),
+        Some(AxumEither::Right("first second".to_owned()))
+    );
+}
+
+#[test]
+fn zip_all_combinations() {
+    let left: Either = AxumEither::Left(1);
+    let right: Either = AxumEither::Right("first");
+    let other_left: AxumEither<bool, char> = AxumEither::Left(true);
+    let other_right: AxumEither<bool, char> = AxumEither::Right('c');
+
+    assert_eq!(left.zip(other_left), Some(AxumEither::Left((1, true))));
+    assert_eq!(left.zip(other_right), None);
+    assert_eq!(right.zip(other_left), None);
+    assert_eq!(
+        right.zip(other_right),
+        Some(AxumEither::Right(("first", 'c')))
+    );
+}
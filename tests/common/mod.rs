@@ -0,0 +1,23 @@
+//! Requests shared by the integration tests.
+#![allow(dead_code)]
+
+use axum::{
+    body::Body,
+    extract::RequestParts,
+    http::{header, Request},
+};
+
+/// A `POST /` request with the given `Content-Type` and body
+pub fn post(content_type: &str, body: impl Into<Body>) -> RequestParts<Body> {
+    RequestParts::new(
+        Request::post("/")
+            .header(header::CONTENT_TYPE, content_type)
+            .body(body.into())
+            .unwrap(),
+    )
+}
+
+/// A `POST /` request with the given body and without `Content-Type`
+pub fn post_without_content_type(body: impl Into<Body>) -> RequestParts<Body> {
+    RequestParts::new(Request::post("/").body(body.into()).unwrap())
+}
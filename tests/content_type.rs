@@ -0,0 +1,81 @@
+use axum::{
+    body::Body,
+    extract::{FromRequest, RequestParts},
+    http::{header, HeaderValue, Request},
+    Form, Json,
+};
+use axum_either::{AxumEither, WithContentType};
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct Payload {
+    pub id: u32,
+}
+
+type Either = AxumEither<Json<Payload>, Form<Payload>>;
+
+fn request(content_type: Option<&str>, body: &'static str) -> RequestParts<Body> {
+    let mut request = Request::post("/");
+    if let Some(content_type) = content_type {
+        request = request.header(header::CONTENT_TYPE, content_type);
+    }
+    RequestParts::new(request.body(Body::from(body)).unwrap())
+}
+
+#[tokio::test]
+async fn keeps_parameters() {
+    let mut req = request(Some("application/json; charset=utf-8"), r#"{"id": 1}"#);
+    let extracted = WithContentType::<Either>::from_request(&mut req)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        extracted.content_type,
+        Some(HeaderValue::from_static("application/json; charset=utf-8"))
+    );
+    let mime = extracted.mime().unwrap();
+    assert_eq!(mime.essence_str(), "application/json");
+    assert_eq!(mime.get_param(mime::CHARSET).unwrap(), "utf-8");
+    assert!(matches!(
+        extracted.value,
+        AxumEither::Left(Json(Payload { id: 1 }))
+    ));
+}
+
+#[tokio::test]
+async fn keeps_the_right_variant_content_type() {
+    let mut req = request(
+        Some("application/x-www-form-urlencoded;charset=UTF-8"),
+        "id=2",
+    );
+    let (content_type, value) = WithContentType::<Either>::from_request(&mut req)
+        .await
+        .unwrap()
+        .into_parts();
+
+    assert_eq!(
+        content_type.unwrap(),
+        "application/x-www-form-urlencoded;charset=UTF-8"
+    );
+    assert!(matches!(value, AxumEither::Right(Form(Payload { id: 2 }))));
+}
+
+#[tokio::test]
+async fn none_without_header() {
+    let mut req = request(None, "plain");
+    let extracted = WithContentType::<String>::from_request(&mut req)
+        .await
+        .unwrap();
+    assert_eq!(extracted.content_type, None);
+    assert_eq!(extracted.mime(), None);
+    assert_eq!(extracted.into_inner(), "plain");
+}
+
+#[tokio::test]
+async fn rejection_is_the_one_of_the_value() {
+    let mut req = request(Some("text/plain"), "id=3");
+    let rejection = WithContentType::<Either>::from_request(&mut req)
+        .await
+        .unwrap_err();
+    assert_eq!(rejection.attempts().len(), 2);
+}
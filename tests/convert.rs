@@ -0,0 +1,190 @@
+use axum_either::{
+    convert::{FoldOneOf, Here, Inject},
+    AxumEither,
+};
+use std::convert::Infallible;
+
+type Abc = axum_either::one_of!(u8, u16, u32);
+
+#[test]
+fn widen_inserting_in_the_middle() {
+    let either: axum_either::one_of!(u8, u32) = AxumEither::Left(1);
+    let widened: Abc = either.widen();
+    assert_eq!(widened, AxumEither::Left(1));
+
+    let either: axum_either::one_of!(u8, u32) = AxumEither::Right(3);
+    let widened: Abc = either.widen();
+    assert_eq!(widened, AxumEither::Right(AxumEither::Right(3)));
+}
+
+#[test]
+fn widen_inserting_at_the_front_and_end() {
+    let either: axum_either::one_of!(u16, u32) = AxumEither::Left(2);
+    let widened: Abc = either.widen();
+    assert_eq!(widened, AxumEither::Right(AxumEither::Left(2)));
+
+    let either: axum_either::one_of!(u8, u16) = AxumEither::Right(2);
+    let widened: Abc = either.widen();
+    assert_eq!(widened, AxumEither::Right(AxumEither::Left(2)));
+}
+
+#[test]
+fn widen_reordered() {
+    let either: axum_either::one_of!(u32, u8) = AxumEither::Left(3);
+    let widened: Abc = either.widen();
+    assert_eq!(widened, AxumEither::Right(AxumEither::Right(3)));
+}
+
+#[test]
+fn narrow_keeps_contained_types() {
+    let either: Abc = AxumEither::Right(AxumEither::Right(3));
+    let narrowed: Result<axum_either::one_of!(u8, u32), _> = either.narrow();
+    assert_eq!(narrowed, Ok(AxumEither::Right(3)));
+
+    let either: Abc = AxumEither::Left(1);
+    let narrowed: Result<axum_either::one_of!(u8, u32), _> = either.narrow();
+    assert_eq!(narrowed, Ok(AxumEither::Left(1)));
+}
+
+#[test]
+fn narrow_fails_for_dropped_type() {
+    let either: Abc = AxumEither::Right(AxumEither::Left(2));
+    let narrowed: Result<axum_either::one_of!(u8, u32), AxumEither<u16, Infallible>> =
+        either.narrow();
+    assert_eq!(narrowed, Err(AxumEither::Left(2)));
+}
+
+type Four = axum_either::one_of!(u8, u16, u32, String);
+
+#[test]
+fn inject_every_type_of_four_chain() {
+    assert_eq!(Four::inject(1u8), AxumEither::Left(1));
+    assert_eq!(Four::inject(2u16), AxumEither::Right(AxumEither::Left(2)));
+    assert_eq!(
+        Four::inject(3u32),
+        AxumEither::Right(AxumEither::Right(AxumEither::Left(3)))
+    );
+    assert_eq!(
+        Four::inject(String::from("four")),
+        AxumEither::Right(AxumEither::Right(AxumEither::Right("four".into())))
+    );
+}
+
+fn generic_inject<C, T, I>(value: T) -> C
+where
+    C: Inject<T, I>,
+{
+    C::inject(value)
+}
+
+#[test]
+fn inject_through_trait_bound() {
+    let either: Four = generic_inject(3u32);
+    assert_eq!(
+        either,
+        AxumEither::Right(AxumEither::Right(AxumEither::Left(3)))
+    );
+}
+
+#[derive(Debug, PartialEq)]
+enum Payload {
+    Json(String),
+    Form(u32),
+    Text(String),
+    Binary(Vec<u8>),
+}
+
+struct JsonBody(String);
+struct FormBody(u32);
+struct TextBody(&'static str);
+struct BinaryBody(Vec<u8>);
+
+impl From<JsonBody> for Payload {
+    fn from(body: JsonBody) -> Self {
+        Self::Json(body.0)
+    }
+}
+
+impl From<FormBody> for Payload {
+    fn from(body: FormBody) -> Self {
+        Self::Form(body.0)
+    }
+}
+
+impl From<TextBody> for Payload {
+    fn from(body: TextBody) -> Self {
+        Self::Text(body.0.into())
+    }
+}
+
+impl From<BinaryBody> for Payload {
+    fn from(body: BinaryBody) -> Self {
+        Self::Binary(body.0)
+    }
+}
+
+fn fold_payload<I>(chain: impl FoldOneOf<Payload, I>) -> Payload {
+    chain.fold_into()
+}
+
+#[test]
+fn fold_depth_two() {
+    type Chain = axum_either::one_of!(JsonBody, FormBody);
+    let chain: Chain = AxumEither::Left(JsonBody("{}".into()));
+    assert_eq!(fold_payload(chain), Payload::Json("{}".into()));
+    let chain: Chain = AxumEither::Right(FormBody(3));
+    assert_eq!(chain.fold_into::<Payload, _>(), Payload::Form(3));
+}
+
+#[test]
+fn fold_depth_four() {
+    type Chain = axum_either::one_of!(JsonBody, FormBody, TextBody, BinaryBody);
+    let text: Chain = AxumEither::Right(AxumEither::Right(AxumEither::Left(TextBody("hi"))));
+    assert_eq!(fold_payload(text), Payload::Text("hi".into()));
+    let binary: Chain =
+        AxumEither::Right(AxumEither::Right(AxumEither::Right(BinaryBody(vec![1, 2]))));
+    assert_eq!(fold_payload(binary), Payload::Binary(vec![1, 2]));
+}
+
+struct TextUpload(&'static str);
+struct BinaryUpload(Vec<u8>);
+
+impl From<TextUpload> for Payload {
+    fn from(upload: TextUpload) -> Self {
+        Self::Text(upload.0.into())
+    }
+}
+
+impl From<BinaryUpload> for Payload {
+    fn from(upload: BinaryUpload) -> Self {
+        Self::Binary(upload.0)
+    }
+}
+
+/// An either which is converted as a whole rather than folded through its sides, its sides are
+/// not used in the other chains so their folds stay unambiguous
+type Upload = AxumEither<TextUpload, BinaryUpload>;
+
+impl From<Upload> for Payload {
+    fn from(upload: Upload) -> Self {
+        match upload {
+            AxumEither::Left(text) => Self::Binary(text.0.as_bytes().to_vec()),
+            AxumEither::Right(binary) => binary.into(),
+        }
+    }
+}
+
+#[test]
+fn fold_either_leaf_with_named_index() {
+    let chain: AxumEither<JsonBody, Upload> = AxumEither::Right(AxumEither::Left(TextUpload("hi")));
+    assert_eq!(
+        chain.fold_into::<Payload, (Here, Here)>(),
+        Payload::Binary(b"hi".to_vec())
+    );
+
+    let chain: AxumEither<JsonBody, Upload> = AxumEither::Right(AxumEither::Left(TextUpload("hi")));
+    assert_eq!(
+        chain.fold_into::<Payload, (Here, (Here, Here))>(),
+        Payload::Text("hi".into())
+    );
+}
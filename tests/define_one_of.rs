@@ -0,0 +1,100 @@
+#![cfg(feature = "define-one-of")]
+
+use axum::{Form, Json};
+use axum_either::{define_one_of, AxumEither};
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct Req {
+    pub id: u32,
+}
+
+define_one_of!(pub Body = json: Json<Req>, form: Form<Req>, raw: String;);
+
+define_one_of!(
+    /// A chain without labels is only an alias
+    pub Plain = u8, u16, u32
+);
+
+fn json() -> Body {
+    AxumEither::Left(Json(Req { id: 1 }))
+}
+
+fn form() -> Body {
+    AxumEither::Right(AxumEither::Left(Form(Req { id: 2 })))
+}
+
+fn raw() -> Body {
+    AxumEither::Right(AxumEither::Right("raw".to_owned()))
+}
+
+#[test]
+fn predicates() {
+    assert!(json().is_json());
+    assert!(!json().is_form());
+    assert!(!json().is_raw());
+
+    assert!(!form().is_json());
+    assert!(form().is_form());
+    assert!(!form().is_raw());
+
+    assert!(!raw().is_json());
+    assert!(!raw().is_form());
+    assert!(raw().is_raw());
+}
+
+#[test]
+fn borrowing_accessors() {
+    assert_eq!(json().as_json().map(|json| json.id), Some(1));
+    assert!(json().as_form().is_none());
+    assert!(json().as_raw().is_none());
+
+    assert_eq!(form().as_form().map(|form| form.id), Some(2));
+    assert!(form().as_json().is_none());
+    assert!(form().as_raw().is_none());
+
+    assert_eq!(raw().as_raw().map(String::as_str), Some("raw"));
+    assert!(raw().as_json().is_none());
+    assert!(raw().as_form().is_none());
+}
+
+#[test]
+fn mutable_accessors() {
+    let mut body = json();
+    body.as_json_mut().unwrap().id = 10;
+    assert!(body.as_form_mut().is_none());
+    assert!(body.as_raw_mut().is_none());
+    assert_eq!(body.into_json().map(|Json(req)| req), Some(Req { id: 10 }));
+
+    let mut body = form();
+    body.as_form_mut().unwrap().0.id = 20;
+    assert!(body.as_json_mut().is_none());
+    assert_eq!(body.into_form().map(|Form(req)| req), Some(Req { id: 20 }));
+
+    let mut body = raw();
+    body.as_raw_mut().unwrap().push('!');
+    assert!(body.as_json_mut().is_none());
+    assert_eq!(body.into_raw().as_deref(), Some("raw!"));
+}
+
+#[test]
+fn owning_accessors() {
+    assert_eq!(json().into_json().map(|Json(req)| req), Some(Req { id: 1 }));
+    assert!(json().into_form().is_none());
+    assert!(json().into_raw().is_none());
+
+    assert_eq!(form().into_form().map(|Form(req)| req), Some(Req { id: 2 }));
+    assert!(form().into_json().is_none());
+    assert!(form().into_raw().is_none());
+
+    assert_eq!(raw().into_raw().as_deref(), Some("raw"));
+    assert!(raw().into_json().is_none());
+    assert!(raw().into_form().is_none());
+}
+
+#[test]
+fn unlabeled_alias() {
+    let plain: Plain = AxumEither::Right(AxumEither::Left(3));
+    let chain: AxumEither<u8, AxumEither<u16, u32>> = plain;
+    assert_eq!(chain, AxumEither::Right(AxumEither::Left(3)));
+}
@@ -0,0 +1,162 @@
+#![cfg(feature = "discriminated-json")]
+
+use axum::{extract::FromRequest, http::StatusCode};
+use axum_either::{
+    discriminated::Discriminator,
+    rejection::{DiscriminatedJsonRejection, JsonBodyRejection},
+    AxumEither, DiscriminatedJson, RejectionInfo, VariantTag,
+};
+use serde::Deserialize;
+use serde_json::Value;
+
+mod common;
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct UserV1 {
+    pub name: String,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct UserV2 {
+    pub name: String,
+    #[serde(default)]
+    pub email: Option<String>,
+}
+
+pub struct Kind;
+
+impl Discriminator for Kind {
+    fn discriminate(body: &Value) -> Option<VariantTag> {
+        match body.get("kind")?.as_str()? {
+            "old" => Some(VariantTag::Left),
+            "new" => Some(VariantTag::Right),
+            _ => None,
+        }
+    }
+}
+
+async fn extract<D: Discriminator>(
+    content_type: &str,
+    body: &'static str,
+) -> Result<DiscriminatedJson<UserV1, UserV2, D>, DiscriminatedJsonRejection> {
+    let mut req = common::post(content_type, body);
+    DiscriminatedJson::from_request(&mut req).await
+}
+
+#[tokio::test]
+async fn version_selects_left() {
+    let user = extract::<axum_either::discriminated::Version>(
+        "application/json",
+        r#"{"version": 1, "name": "Reimu"}"#,
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        user.into_inner(),
+        AxumEither::Left(UserV1 {
+            name: "Reimu".into()
+        })
+    );
+}
+
+#[tokio::test]
+async fn version_selects_right() {
+    let user = extract::<axum_either::discriminated::Version>(
+        "application/json",
+        r#"{"version": "2", "name": "Reimu"}"#,
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        user.into_inner(),
+        AxumEither::Right(UserV2 {
+            name: "Reimu".into(),
+            email: None,
+        })
+    );
+}
+
+#[tokio::test]
+async fn body_valid_for_both_uses_the_discriminant() {
+    // Without the discriminant a plain either would pick the left type for this body
+    let user = extract::<Kind>("application/json", r#"{"kind": "new", "name": "Marisa"}"#)
+        .await
+        .unwrap();
+    assert!(user.is_right());
+
+    let user = extract::<Kind>("application/json", r#"{"kind": "old", "name": "Marisa"}"#)
+        .await
+        .unwrap();
+    assert!(user.is_left());
+}
+
+#[tokio::test]
+async fn missing_discriminant() {
+    let rejection =
+        extract::<axum_either::discriminated::Version>("application/json", r#"{"name": "Reimu"}"#)
+            .await
+            .unwrap_err();
+    assert!(matches!(
+        rejection,
+        DiscriminatedJsonRejection::MissingDiscriminant
+    ));
+    assert_eq!(rejection.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let rejection = extract::<axum_either::discriminated::Version>(
+        "application/json",
+        r#"{"version": 3, "name": "Reimu"}"#,
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(
+        rejection,
+        DiscriminatedJsonRejection::MissingDiscriminant
+    ));
+}
+
+#[tokio::test]
+async fn selected_type_does_not_match() {
+    let rejection = extract::<Kind>("application/json", r#"{"kind": "new", "email": "a@b.c"}"#)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        rejection,
+        DiscriminatedJsonRejection::Deserialize {
+            side: VariantTag::Right,
+            ..
+        }
+    ));
+    assert_eq!(rejection.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let rejection = extract::<Kind>(
+        "application/json",
+        r#"{"kind": "new", "name": "Reimu", "email": 3}"#,
+    )
+    .await
+    .unwrap_err();
+    match rejection {
+        DiscriminatedJsonRejection::Deserialize { error, .. } => {
+            assert_eq!(error.path().to_string(), "email");
+        }
+        rejection => panic!("unexpected rejection {:?}", rejection),
+    }
+}
+
+#[tokio::test]
+async fn invalid_json_and_content_type() {
+    let rejection = extract::<Kind>("application/json", "{").await.unwrap_err();
+    assert!(matches!(
+        rejection,
+        DiscriminatedJsonRejection::Json(JsonBodyRejection::Syntax(_))
+    ));
+    assert_eq!(rejection.status(), StatusCode::BAD_REQUEST);
+
+    let rejection = extract::<Kind>("text/plain", r#"{"kind": "old", "name": "Marisa"}"#)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        rejection,
+        DiscriminatedJsonRejection::Json(JsonBodyRejection::MissingJsonContentType)
+    ));
+    assert_eq!(rejection.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+}
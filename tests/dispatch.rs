@@ -0,0 +1,129 @@
+#![cfg(feature = "axum")]
+
+use axum::{
+    extract::{FromRequest, RequestParts},
+    http::{header, Request, StatusCode},
+    response::IntoResponse,
+    Form, Json,
+};
+use axum_either::{AxumEither, Dispatch, DispatchRejection};
+use bytes::Bytes;
+use http_body::Body;
+use serde::Deserialize;
+use std::{
+    convert::Infallible,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+};
+
+/// A body counting how often its data was read
+struct CountingBody {
+    data: Option<Bytes>,
+    reads: Arc<AtomicUsize>,
+}
+
+impl From<Bytes> for CountingBody {
+    fn from(data: Bytes) -> Self {
+        Self {
+            data: Some(data),
+            reads: Arc::default(),
+        }
+    }
+}
+
+impl Body for CountingBody {
+    type Data = Bytes;
+    type Error = Infallible;
+
+    fn poll_data(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Self::Data, Self::Error>>> {
+        let data = self.data.take();
+        if data.is_some() {
+            self.reads.fetch_add(1, Ordering::SeqCst);
+        }
+        Poll::Ready(data.map(Ok))
+    }
+
+    fn poll_trailers(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+    ) -> Poll<Result<Option<http::HeaderMap>, Self::Error>> {
+        Poll::Ready(Ok(None))
+    }
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct Payload {
+    pub id: u32,
+}
+
+fn request(
+    content_type: &str,
+    body: &'static str,
+) -> (RequestParts<CountingBody>, Arc<AtomicUsize>) {
+    let body = CountingBody::from(Bytes::from_static(body.as_bytes()));
+    let reads = Arc::clone(&body.reads);
+    let req = Request::post("/")
+        .header(header::CONTENT_TYPE, content_type)
+        .body(body)
+        .unwrap();
+    (RequestParts::new(req), reads)
+}
+
+type JsonOrForm = AxumEither<Json<Payload>, Form<Payload>>;
+
+#[tokio::test]
+async fn unclaimed_content_type_never_reads_body() {
+    let (mut req, reads) = request("text/csv", "id\n1");
+    let rejection = Dispatch::<JsonOrForm>::from_request(&mut req)
+        .await
+        .unwrap_err();
+    assert!(matches!(rejection, DispatchRejection::UnsupportedMediaType));
+    assert_eq!(reads.load(Ordering::SeqCst), 0);
+    assert_eq!(
+        rejection.into_response().status(),
+        StatusCode::UNSUPPORTED_MEDIA_TYPE
+    );
+}
+
+#[tokio::test]
+async fn single_claim_reads_body_once() {
+    let (mut req, reads) = request("application/x-www-form-urlencoded", "id=2");
+    let Dispatch(either) = Dispatch::<JsonOrForm>::from_request(&mut req)
+        .await
+        .unwrap();
+    assert!(matches!(either, AxumEither::Right(Form(Payload { id: 2 }))));
+    assert_eq!(reads.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn single_claim_reports_only_its_rejection() {
+    let (mut req, reads) = request("application/json", r#"{"id": "one"}"#);
+    let rejection = Dispatch::<JsonOrForm>::from_request(&mut req)
+        .await
+        .unwrap_err();
+    assert_eq!(reads.load(Ordering::SeqCst), 1);
+    match rejection {
+        DispatchRejection::Claimed(rejections) => {
+            assert_eq!(rejections.len(), 1);
+            assert!(matches!(rejections[0], AxumEither::Left(_)));
+        }
+        rejection => panic!("unexpected rejection {:?}", rejection),
+    }
+}
+
+#[tokio::test]
+async fn multiple_claims_buffer_once() {
+    let (mut req, reads) = request("application/json", r#"[1, 2]"#);
+    let Dispatch(either) = Dispatch::<AxumEither<Json<Payload>, Bytes>>::from_request(&mut req)
+        .await
+        .unwrap();
+    assert_eq!(either.right().unwrap(), Bytes::from_static(b"[1, 2]"));
+    assert_eq!(reads.load(Ordering::SeqCst), 1);
+}
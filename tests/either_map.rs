@@ -0,0 +1,73 @@
+use axum_either::{AxumEither, EitherMap, OneOf3, OneOf4};
+
+/// Count the length of the left side and negate the right side
+fn measure<E>(either: E) -> AxumEither<usize, bool>
+where
+    E: EitherMap,
+    E::Left: AsRef<str>,
+    E::Right: Into<bool>,
+{
+    either.bimap(|l| l.as_ref().len(), |r| !r.into())
+}
+
+#[test]
+fn generic_over_pair() {
+    let left: AxumEither<&str, bool> = AxumEither::Left("four");
+    let right: AxumEither<&str, bool> = AxumEither::Right(true);
+    assert_eq!(measure(left), AxumEither::Left(4));
+    assert_eq!(measure(right), AxumEither::Right(false));
+}
+
+/// Flattened variants of the right side of a flat enum
+struct Rest(AxumEither<bool, u8>);
+
+impl From<Rest> for bool {
+    fn from(rest: Rest) -> Self {
+        rest.0.map_right(|u| u != 0).into_inner()
+    }
+}
+
+#[test]
+fn generic_over_one_of3() {
+    let values: [OneOf3<String, bool, u8>; 3] =
+        [OneOf3::V0("three".into()), OneOf3::V1(true), OneOf3::V2(0)];
+    let measured: Vec<_> = values
+        .into_iter()
+        .map(|value| measure(value.bimap(|l| l, Rest).map_right(bool::from)))
+        .collect();
+    assert_eq!(
+        measured,
+        [
+            AxumEither::Left(5),
+            AxumEither::Right(false),
+            AxumEither::Right(true)
+        ]
+    );
+}
+
+#[test]
+fn flat_right_side_is_the_remaining_chain() {
+    let value: OneOf4<u8, u16, u32, u64> = OneOf4::V2(2);
+    let mapped = value.bimap(
+        |_| unreachable!(),
+        |rest: axum_either::one_of!(u16, u32, u64)| rest,
+    );
+    assert_eq!(
+        mapped,
+        AxumEither::<(), _>::Right(AxumEither::Right(AxumEither::Left(2)))
+    );
+}
+
+#[test]
+fn only_the_active_function_is_called() {
+    let left: AxumEither<u8, u8> = AxumEither::Left(1);
+    assert_eq!(
+        left.bimap(|l| l + 1, |_| -> u8 { unreachable!() }),
+        AxumEither::Left(2)
+    );
+    let right: OneOf3<u8, u8, u8> = OneOf3::V1(1);
+    assert_eq!(
+        right.bimap(|_| -> u8 { unreachable!() }, |r| r.into_inner()),
+        AxumEither::Right(1)
+    );
+}
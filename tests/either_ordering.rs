@@ -0,0 +1,53 @@
+#![cfg(all(feature = "proptest", feature = "either"))]
+
+use axum_either::AxumEither;
+use proptest::prelude::*;
+use std::cmp::Ordering;
+
+proptest! {
+    #[test]
+    fn ord_matches_either(a in any::<AxumEither<i8, u8>>(), b in any::<AxumEither<i8, u8>>()) {
+        prop_assert_eq!(a.cmp(&b), a.into_either().cmp(&b.into_either()));
+    }
+
+    #[test]
+    fn partial_ord_matches_either(a in any::<AxumEither<f32, u8>>(), b in any::<AxumEither<f32, u8>>()) {
+        prop_assert_eq!(a.partial_cmp(&b), a.into_either().partial_cmp(&b.into_either()));
+    }
+
+    #[test]
+    fn mixed_comparison_matches_converting_first(
+        a in any::<AxumEither<i8, u8>>(),
+        b in any::<AxumEither<i8, u8>>(),
+    ) {
+        let converted = b.into_either();
+        prop_assert_eq!(a.partial_cmp(&converted), Some(a.cmp(&b)));
+        prop_assert_eq!(converted.partial_cmp(&a), Some(b.cmp(&a)));
+        prop_assert_eq!(a == converted, a == b);
+        prop_assert_eq!(converted == a, a == b);
+    }
+}
+
+#[test]
+fn left_sorts_before_right() {
+    let mut keys = vec![
+        AxumEither::Right(0u8),
+        AxumEither::Left(5i8),
+        AxumEither::Right(1),
+        AxumEither::Left(-3),
+    ];
+    keys.sort();
+    assert_eq!(
+        keys,
+        [
+            AxumEither::Left(-3),
+            AxumEither::Left(5),
+            AxumEither::Right(0),
+            AxumEither::Right(1),
+        ]
+    );
+    assert_eq!(
+        AxumEither::<i8, u8>::Left(i8::MAX).partial_cmp(&either::Either::Right(0)),
+        Some(Ordering::Less)
+    );
+}
@@ -0,0 +1,89 @@
+use axum::{
+    extract::{
+        rejection::{FormRejection, JsonRejection},
+        FromRequest,
+    },
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Form, Json,
+};
+use axum_either::{AxumEither, AxumEitherRejection};
+use serde::Deserialize;
+
+mod common;
+
+#[derive(Deserialize, Debug)]
+struct Payload {
+    id: u32,
+}
+
+type PayloadRejection = AxumEitherRejection<JsonRejection, FormRejection>;
+
+#[derive(Debug, thiserror::Error)]
+enum AppError {
+    #[error("invalid payload: {0}")]
+    Payload(#[from] PayloadRejection),
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        (StatusCode::BAD_REQUEST, self.to_string()).into_response()
+    }
+}
+
+async fn handler(
+    payload: Result<AxumEither<Json<Payload>, Form<Payload>>, PayloadRejection>,
+) -> Result<String, AppError> {
+    let id = payload?.map_lr(|Json(p)| p.id, |Form(p)| p.id).into_inner();
+    Ok(id.to_string())
+}
+
+async fn call(content_type: &str, body: &'static str) -> (StatusCode, String) {
+    let mut req = common::post(content_type, body);
+    let payload = FromRequest::from_request(&mut req).await.unwrap();
+    let response = handler(payload).await.into_response();
+    let status = response.status();
+    let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+#[tokio::test]
+async fn thiserror_from_in_handler() {
+    assert_eq!(
+        call("application/json", r#"{"id":1}"#).await,
+        (StatusCode::OK, "1".to_owned())
+    );
+
+    let (status, body) = call("text/plain", "id=1").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(body.starts_with("invalid payload: Could not parse request, left error: "));
+}
+
+#[tokio::test]
+async fn anyhow_conversion() {
+    let mut req = common::post_without_content_type("");
+    let rejection = AxumEither::<Json<Payload>, Form<Payload>>::from_request(&mut req)
+        .await
+        .unwrap_err();
+    let message = rejection.to_owned_message();
+
+    let error = anyhow::Error::from(rejection);
+    assert_eq!(error.to_string(), message);
+    assert!(error.source().is_some());
+}
+
+#[tokio::test]
+async fn sources_report_both_errors() {
+    let mut req = common::post("text/plain", "id=1");
+    let rejection = AxumEither::<Json<Payload>, Form<Payload>>::from_request(&mut req)
+        .await
+        .unwrap_err();
+    let sources: Vec<_> = rejection.sources().map(ToString::to_string).collect();
+    assert_eq!(
+        sources,
+        [
+            rejection.left_error.to_string(),
+            rejection.right_error.to_string()
+        ]
+    );
+}
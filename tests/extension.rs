@@ -0,0 +1,72 @@
+use axum::{
+    body::Body,
+    http::{HeaderValue, Request},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::post,
+    Json, Router,
+};
+use axum_either::{AxumEither, WithExtension};
+use std::net::{SocketAddr, TcpListener};
+
+#[derive(Clone)]
+struct Format(&'static str);
+
+async fn echo(
+    request: AxumEither<Json<String>, String>,
+) -> WithExtension<AxumEither<Json<String>, String>, Format> {
+    let format = request
+        .as_ref()
+        .map_lr(|_| Format("json"), |_| Format("text"))
+        .into_inner();
+    request.with_extension(format)
+}
+
+/// Exposes the extension set by the handler as a header
+async fn expose_format(req: Request<Body>, next: Next<Body>) -> Response {
+    let mut response = next.run(req).await.into_response();
+    let format = response.extensions().get::<Format>().map(|f| f.0);
+    if let Some(format) = format {
+        response
+            .headers_mut()
+            .insert("x-format", HeaderValue::from_static(format));
+    }
+    response
+}
+
+async fn test_setup() -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let router = Router::new()
+        .route("/echo", post(echo))
+        .layer(middleware::from_fn(expose_format));
+    tokio::spawn(async move {
+        axum::Server::from_tcp(listener)
+            .unwrap()
+            .serve(router.into_make_service())
+            .await
+    });
+    addr
+}
+
+#[tokio::test]
+async fn middleware_reads_extension_of_both_variants() {
+    let addr = test_setup().await;
+    let client = reqwest::Client::new();
+
+    let response = client
+        .post(format!("http://{}/echo", addr))
+        .json("hello")
+        .send()
+        .await
+        .expect("Error sending request");
+    assert_eq!(response.headers()["x-format"], "json");
+
+    let response = client
+        .post(format!("http://{}/echo", addr))
+        .body("hello")
+        .send()
+        .await
+        .expect("Error sending request");
+    assert_eq!(response.headers()["x-format"], "text");
+}
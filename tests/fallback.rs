@@ -0,0 +1,74 @@
+use axum::{
+    body::Body,
+    extract::{FromRequest, RequestParts},
+    http::{header, Request, StatusCode},
+    Json,
+};
+use axum_either::Fallback;
+use serde::Deserialize;
+
+#[derive(Deserialize, Default, Debug, PartialEq)]
+struct Filters {
+    limit: Option<u32>,
+}
+
+async fn extract<T>(request: Request<Body>) -> T
+where
+    T: FromRequest<Body, Rejection = std::convert::Infallible>,
+{
+    let mut req = RequestParts::new(request);
+    match T::from_request(&mut req).await {
+        Ok(fallback) => fallback,
+        Err(infallible) => match infallible {},
+    }
+}
+
+fn json(body: &'static str) -> Request<Body> {
+    Request::post("/")
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(body))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn valid_body() {
+    let Fallback(Json(filters), rejection, _): Fallback<Json<Filters>> =
+        extract(json(r#"{"limit":10}"#)).await;
+    assert_eq!(filters.limit, Some(10));
+    assert_eq!(rejection, None);
+}
+
+#[tokio::test]
+async fn missing_body() {
+    let Fallback(Json(filters), rejection, _): Fallback<Json<Filters>> =
+        extract(Request::post("/").body(Body::empty()).unwrap()).await;
+    assert_eq!(filters, Filters::default());
+
+    let rejection = rejection.unwrap();
+    assert_eq!(rejection.status, StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    assert!(rejection.type_name.contains("JsonRejection"));
+}
+
+#[tokio::test]
+async fn malformed_body() {
+    let Fallback(Json(filters), rejection, _): Fallback<Json<Filters>> =
+        extract(json(r#"{"limit":"#)).await;
+    assert_eq!(filters, Filters::default());
+    assert_eq!(rejection.unwrap().status, StatusCode::BAD_REQUEST);
+}
+
+#[cfg(feature = "axum")]
+#[tokio::test]
+async fn query_format() {
+    use axum_either::FromQuery;
+
+    let request = Request::get("/?limit=5").body(Body::empty()).unwrap();
+    let Fallback(filters, rejection, _): Fallback<Filters, FromQuery> = extract(request).await;
+    assert_eq!(filters.limit, Some(5));
+    assert_eq!(rejection, None);
+
+    let request = Request::get("/?limit=many").body(Body::empty()).unwrap();
+    let Fallback(filters, rejection, _): Fallback<Filters, FromQuery> = extract(request).await;
+    assert_eq!(filters, Filters::default());
+    assert!(rejection.unwrap().type_name.contains("QueryRejection"));
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "axum")]
+
+include!("../examples/fallible_handler.rs");
+
+use axum::response::IntoResponse;
+use reqwest::{header, redirect::Policy};
+
+async fn test_setup() -> SocketAddr {
+    let listener = StdTcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { run(listener).await });
+    addr
+}
+
+async fn get(addr: SocketAddr, path: &str) -> reqwest::Response {
+    reqwest::Client::builder()
+        .redirect(Policy::none())
+        .build()
+        .unwrap()
+        .get(format!("http://{}{}", addr, path))
+        .send()
+        .await
+        .expect("Error sending request")
+}
+
+#[tokio::test]
+async fn json_left_responds_with_json() {
+    let addr = test_setup().await;
+    let response = get(addr, "/users/1").await;
+
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+    let user: User = response.json().await.expect("Failed to parse response");
+    assert_eq!(
+        user,
+        User {
+            name: "Reimu".into()
+        }
+    );
+}
+
+#[tokio::test]
+async fn redirect_right_redirects() {
+    let addr = test_setup().await;
+    for path in ["/users/2", "/users/2/page"] {
+        let response = get(addr, path).await;
+
+        assert_eq!(response.status(), reqwest::StatusCode::SEE_OTHER);
+        assert_eq!(response.headers()[header::LOCATION], "/users");
+    }
+}
+
+#[tokio::test]
+async fn ok_left_responds_with_html() {
+    let addr = test_setup().await;
+    let response = get(addr, "/users/1/page").await;
+
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "text/html; charset=utf-8"
+    );
+    assert_eq!(response.text().await.unwrap(), "<h1>Reimu</h1>");
+}
+
+#[tokio::test]
+async fn errors_respond_with_their_status() {
+    let addr = test_setup().await;
+    for path in ["/users/3", "/users/3/page"] {
+        assert_eq!(
+            get(addr, path).await.status(),
+            reqwest::StatusCode::NOT_FOUND
+        );
+    }
+}
+
+#[test]
+fn constructors_pick_their_side() {
+    let json: AxumEither<Json<u8>, Html<&str>> = AxumEither::json_left(1);
+    assert!(matches!(json, AxumEither::Left(Json(1))));
+    let html: AxumEither<Json<u8>, Html<&str>> = AxumEither::html_right("<p></p>");
+    assert!(matches!(html, AxumEither::Right(Html("<p></p>"))));
+    let json: AxumEither<Html<&str>, Json<u8>> = AxumEither::json_right(2);
+    assert!(matches!(json, AxumEither::Right(Json(2))));
+    let html: AxumEither<Html<&str>, Json<u8>> = AxumEither::html_left("<p></p>");
+    assert!(matches!(html, AxumEither::Left(Html("<p></p>"))));
+    let ok: Result<AxumEither<u8, Redirect>, StatusCode> = AxumEither::ok_left(3);
+    assert!(matches!(ok, Ok(AxumEither::Left(3))));
+    let redirect: AxumEither<Redirect, &str> = AxumEither::redirect_left("/");
+    assert_eq!(
+        redirect.into_response().headers()[axum::http::header::LOCATION],
+        "/"
+    );
+}
@@ -0,0 +1,89 @@
+#![cfg(feature = "axum")]
+
+use axum::{
+    body::Body,
+    extract::{FromRequest, Query, RequestParts},
+    http::{header, Request, StatusCode},
+    response::IntoResponse,
+    Form, Json,
+};
+use axum_either::{FirstOk, FirstOkRejection, OneOf4, RejectionInfo};
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Payload {
+    id: u32,
+}
+
+type Alternatives = (Json<Payload>, Form<Payload>, Query<Payload>, Json<Vec<u32>>);
+
+async fn extract(
+    uri: &str,
+    content_type: &str,
+    body: &'static str,
+) -> Result<
+    FirstOk<Alternatives>,
+    FirstOkRejection<impl RejectionInfo + IntoResponse + std::fmt::Debug>,
+> {
+    let mut req = RequestParts::new(
+        Request::post(uri)
+            .header(header::CONTENT_TYPE, content_type)
+            .body(Body::from(body))
+            .unwrap(),
+    );
+    FirstOk::<Alternatives>::from_request(&mut req).await
+}
+
+#[tokio::test]
+async fn first_position() {
+    let first_ok = extract("/", "application/json", r#"{"id":1}"#)
+        .await
+        .unwrap();
+    assert_eq!(first_ok.index, 0);
+    assert!(matches!(
+        first_ok.value,
+        OneOf4::V0(Json(Payload { id: 1 }))
+    ));
+}
+
+#[tokio::test]
+async fn second_position() {
+    let first_ok = extract("/", "application/x-www-form-urlencoded", "id=2")
+        .await
+        .unwrap();
+    assert_eq!(first_ok.index, 1);
+    assert!(matches!(
+        first_ok.value,
+        OneOf4::V1(Form(Payload { id: 2 }))
+    ));
+}
+
+#[tokio::test]
+async fn third_position() {
+    let first_ok = extract("/?id=3", "text/plain", "").await.unwrap();
+    assert_eq!(first_ok.index, 2);
+    assert!(matches!(
+        first_ok.value,
+        OneOf4::V2(Query(Payload { id: 3 }))
+    ));
+}
+
+#[tokio::test]
+async fn fourth_position_shares_body() {
+    // The first extractor consumes the body before rejecting it
+    let first_ok = extract("/", "application/json", "[4, 5]").await.unwrap();
+    assert_eq!(first_ok.index, 3);
+    assert!(matches!(first_ok.value, OneOf4::V3(Json(ref v)) if v == &[4, 5]));
+}
+
+#[tokio::test]
+async fn all_fail() {
+    let rejection = extract("/", "text/plain", "id=6").await.unwrap_err();
+
+    let mut attempts = Vec::new();
+    rejection.visit_attempts(&mut |attempt| attempts.push(attempt.status()));
+    assert_eq!(attempts.len(), 4);
+    assert_eq!(attempts[0], StatusCode::UNSUPPORTED_MEDIA_TYPE);
+
+    assert!(rejection.into_response().status().is_client_error());
+}
@@ -0,0 +1,129 @@
+use axum::{
+    body::Body,
+    extract::FromRequest,
+    http::{header, StatusCode},
+    response::IntoResponse,
+    Form, Json,
+};
+use axum_either::{AxumEither, OneOf3, OneOf5, VARIANT_HEADER};
+use serde::Deserialize;
+
+mod common;
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Payload {
+    id: u32,
+}
+
+type Flat = OneOf3<Json<Payload>, Form<Payload>, String>;
+
+async fn extract(content_type: &str, body: &'static str) -> Flat {
+    let mut req = common::post(content_type, body);
+    Flat::from_request(&mut req).await.unwrap()
+}
+
+#[tokio::test]
+async fn extracts_in_order() {
+    let flat = extract("application/json", r#"{"id":1}"#).await;
+    assert!(matches!(flat, OneOf3::V0(Json(Payload { id: 1 }))));
+
+    let flat = extract("application/x-www-form-urlencoded", "id=2").await;
+    assert!(matches!(flat, OneOf3::V1(Form(Payload { id: 2 }))));
+
+    let flat = extract("text/plain", "three").await;
+    assert!(matches!(flat, OneOf3::V2(ref s) if s == "three"));
+}
+
+#[test]
+fn responds_with_active_variant() {
+    let flat: OneOf3<StatusCode, &'static str, Json<u8>> = OneOf3::V1("text");
+    let response = axum_either::Tagged(flat).into_response();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "text/plain; charset=utf-8"
+    );
+    assert_eq!(response.headers()[VARIANT_HEADER], "1");
+
+    let flat: OneOf3<StatusCode, &'static str, Json<u8>> = OneOf3::V0(StatusCode::CREATED);
+    assert_eq!(flat.into_response().status(), StatusCode::CREATED);
+}
+
+#[test]
+fn combinators() {
+    let flat: OneOf3<u8, bool, u32> = OneOf3::V2(3);
+    assert_eq!(flat.map_v0(|v| v + 1), OneOf3::V2(3));
+    assert_eq!(flat.map_v2(|v| v * 2), OneOf3::V2(6));
+    assert_eq!(flat.fold(u32::from, u32::from, |v| v + 1), 4);
+
+    let same: OneOf5<u8, u8, u8, u8, u8> = OneOf5::V3(4);
+    assert_eq!(same.into_inner(), 4);
+}
+
+#[test]
+fn round_trip_three() {
+    let values: [OneOf3<u8, u16, u32>; 3] = [OneOf3::V0(0), OneOf3::V1(1), OneOf3::V2(2)];
+    let chains: [axum_either::one_of!(u8, u16, u32); 3] = [
+        AxumEither::Left(0),
+        AxumEither::Right(AxumEither::Left(1)),
+        AxumEither::Right(AxumEither::Right(2)),
+    ];
+    for (flat, chain) in values.into_iter().zip(chains) {
+        assert_eq!(<axum_either::one_of!(u8, u16, u32)>::from(flat), chain);
+        assert_eq!(OneOf3::from(chain), flat);
+    }
+}
+
+#[test]
+fn round_trip_five() {
+    type Chain = axum_either::one_of!(u8, u16, u32, u64, i8);
+    let values: [OneOf5<u8, u16, u32, u64, i8>; 5] = [
+        OneOf5::V0(0),
+        OneOf5::V1(1),
+        OneOf5::V2(2),
+        OneOf5::V3(3),
+        OneOf5::V4(4),
+    ];
+    for flat in values {
+        let chain = Chain::from(flat);
+        assert_eq!(OneOf5::from(chain), flat);
+    }
+
+    let last: Chain = OneOf5::V4(4).into();
+    assert_eq!(
+        last,
+        AxumEither::Right(AxumEither::Right(AxumEither::Right(AxumEither::Right(4))))
+    );
+    let middle: Chain = OneOf5::V2(2).into();
+    assert_eq!(
+        middle,
+        AxumEither::Right(AxumEither::Right(AxumEither::Left(2)))
+    );
+}
+
+#[tokio::test]
+async fn deep_extraction_rejects_like_the_chain() {
+    type Chain = axum_either::one_of!(Json<Payload>, Form<Payload>, Json<u8>, Json<u16>, Json<u32>);
+    type Flat = OneOf5<Json<Payload>, Form<Payload>, Json<u8>, Json<u16>, Json<u32>>;
+
+    let request = || common::post("text/plain", "text");
+
+    let flat: <Flat as FromRequest<Body>>::Rejection =
+        Flat::from_request(&mut request()).await.unwrap_err();
+    let chain: <Chain as FromRequest<Body>>::Rejection =
+        Chain::from_request(&mut request()).await.unwrap_err();
+    assert_eq!(flat.to_string(), chain.to_string());
+    assert_eq!(
+        flat.into_response().status(),
+        chain.into_response().status()
+    );
+}
+
+#[tokio::test]
+async fn deep_extraction_matches_the_last_variant() {
+    type Flat = OneOf5<Json<Payload>, Form<Payload>, Json<u8>, Json<u16>, String>;
+
+    let mut req = common::post("text/plain", "last");
+    let flat = Flat::from_request(&mut req).await.unwrap();
+    assert!(matches!(flat, OneOf5::V4(ref s) if s == "last"));
+}
@@ -84,7 +84,7 @@ async fn error_for_neither_json_or_form() {
     let addr = test_setup().await;
     let client = reqwest::Client::new();
     let response = client
-        .post(&format!("http://{}/hello", addr))
+        .post(format!("http://{}/hello", addr))
         .body("")
         .send()
         .await
@@ -100,13 +100,13 @@ async fn bye_returns_string_for_both() {
     };
     let client = reqwest::Client::new();
     let form_response = client
-        .post(&format!("http://{}/bye", addr))
+        .post(format!("http://{}/bye", addr))
         .form(&req)
         .send()
         .await
         .expect("Error sending request");
     let json_response = client
-        .post(&format!("http://{}/bye", addr))
+        .post(format!("http://{}/bye", addr))
         .json(&req)
         .send()
         .await
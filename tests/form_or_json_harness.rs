@@ -0,0 +1,133 @@
+#![cfg(feature = "test-util")]
+
+include!("../examples/form_or_json.rs");
+
+use axum::http::{header, StatusCode};
+use axum_either::test::Harness;
+
+fn harness() -> Harness<Router> {
+    Harness::new(router())
+}
+
+#[tokio::test]
+async fn json_hello_reports_the_left_variant() {
+    let harness = harness();
+    let tests = [
+        (
+            "Hassan",
+            HelloResponse {
+                name: "Hassan".into(),
+                msg: "Hello Hassan!".into(),
+            },
+        ),
+        (
+            "Kaworu Nagisa",
+            HelloResponse {
+                name: "Kaworu Nagisa".into(),
+                msg: "Hello Kaworu Nagisa!".into(),
+            },
+        ),
+        (
+            "Reimu Hakurei",
+            HelloResponse {
+                name: "Reimu Hakurei".into(),
+                msg: "Hello Reimu Hakurei!".into(),
+            },
+        ),
+    ];
+
+    for (name, expected_response) in tests {
+        let response = harness
+            .post("/hello")
+            .json(&HelloRequest { name: name.into() })
+            .send()
+            .await;
+        assert_eq!(response.variant(), Some(0));
+        assert_eq!(expected_response, response.json::<HelloResponse>());
+    }
+}
+
+#[tokio::test]
+async fn form_hello_reports_the_right_variant() {
+    let harness = harness();
+    let tests = [
+        ("Hassan", "Hi Hassan!"),
+        ("Kaworu Nagisa", "Hi Kaworu Nagisa!"),
+        ("Reimu Hakurei", "Hi Reimu Hakurei!"),
+    ];
+
+    for (name, expected_response) in tests {
+        let response = harness
+            .post("/hello")
+            .form(&HelloRequest { name: name.into() })
+            .send()
+            .await;
+        assert_eq!(response.variant(), Some(1));
+        assert_eq!(expected_response, response.text());
+    }
+}
+
+#[tokio::test]
+async fn greet_negotiates_json() {
+    let response = harness()
+        .post("/greet")
+        .accept("application/json")
+        .form(&HelloRequest {
+            name: "Reimu".into(),
+        })
+        .send()
+        .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.variant(), Some(0));
+    assert_eq!(
+        response.json::<HelloResponse>(),
+        HelloResponse {
+            name: "Reimu".into(),
+            msg: "Greetings Reimu!".into(),
+        }
+    );
+}
+
+#[tokio::test]
+async fn greet_negotiates_html() {
+    let response = harness()
+        .post("/greet")
+        .accept("text/html, application/json;q=0.5")
+        .json(&HelloRequest {
+            name: "Reimu".into(),
+        })
+        .send()
+        .await;
+    assert_eq!(response.variant(), Some(1));
+    assert_eq!(
+        response.header(header::CONTENT_TYPE.as_str()).unwrap(),
+        "text/html; charset=utf-8"
+    );
+    assert_eq!(response.text(), "<p>Greetings Reimu!</p>");
+}
+
+#[tokio::test]
+async fn greet_rejects_unacceptable_formats() {
+    let response = harness()
+        .post("/greet")
+        .accept("image/png")
+        .json(&HelloRequest {
+            name: "Reimu".into(),
+        })
+        .send()
+        .await;
+    assert_eq!(response.status(), StatusCode::NOT_ACCEPTABLE);
+    assert_eq!(response.variant(), None);
+}
+
+#[tokio::test]
+async fn greet_prefers_json_without_accept() {
+    let response = harness()
+        .post("/greet")
+        .json(&HelloRequest {
+            name: "Reimu".into(),
+        })
+        .send()
+        .await;
+    assert_eq!(response.variant(), Some(0));
+}
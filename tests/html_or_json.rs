@@ -0,0 +1,68 @@
+#![cfg(feature = "html-or-json")]
+
+use axum::{
+    http::{header, HeaderMap, StatusCode},
+    response::IntoResponse,
+};
+use axum_either::{Accept, HtmlOrJson};
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct User {
+    name: &'static str,
+}
+
+async fn respond(accept: Option<&'static str>) -> (StatusCode, Option<String>, String) {
+    let mut headers = HeaderMap::new();
+    if let Some(accept) = accept {
+        headers.insert(header::ACCEPT, accept.parse().unwrap());
+    }
+    let accept = Accept::from_headers(&headers);
+    let response = HtmlOrJson::new(&accept, User { name: "Marisa" }, |user| {
+        format!("<p>{}</p>", user.name)
+    })
+    .into_response();
+
+    let status = response.status();
+    let content_type = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .map(|value| value.to_str().unwrap().to_owned());
+    let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+    (
+        status,
+        content_type,
+        String::from_utf8(body.to_vec()).unwrap(),
+    )
+}
+
+#[tokio::test]
+async fn browsers_get_html() {
+    let (status, content_type, body) =
+        respond(Some("text/html,application/xhtml+xml,*/*;q=0.8")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type.unwrap(), "text/html; charset=utf-8");
+    assert_eq!(body, "<p>Marisa</p>");
+}
+
+#[tokio::test]
+async fn api_clients_get_json() {
+    let (_, content_type, body) = respond(Some("application/json")).await;
+    assert_eq!(content_type.unwrap(), "application/json");
+    assert_eq!(body, r#"{"name":"Marisa"}"#);
+}
+
+#[tokio::test]
+async fn no_accept_header_gets_json() {
+    let (_, content_type, _) = respond(None).await;
+    assert_eq!(content_type.unwrap(), "application/json");
+
+    let (_, content_type, _) = respond(Some("*/*")).await;
+    assert_eq!(content_type.unwrap(), "application/json");
+}
+
+#[tokio::test]
+async fn unacceptable_is_rejected() {
+    let (status, _, _) = respond(Some("image/png")).await;
+    assert_eq!(status, StatusCode::NOT_ACCEPTABLE);
+}
@@ -0,0 +1,46 @@
+use axum::{
+    extract::FromRequest,
+    http::{header, HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use axum_either::AxumEither;
+use std::convert::Infallible;
+
+mod common;
+
+async fn always_json() -> AxumEither<Json<u32>, Infallible> {
+    AxumEither::Left(Json(42))
+}
+
+async fn always_text() -> AxumEither<Infallible, &'static str> {
+    AxumEither::Right("text")
+}
+
+#[tokio::test]
+async fn infallible_side_responds() {
+    let response = always_json().await.into_response();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+    let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+    assert_eq!(&body[..], b"42");
+
+    let response = always_text().await.into_response();
+    let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+    assert_eq!(&body[..], b"text");
+}
+
+#[tokio::test]
+async fn infallible_rejection_extracts() {
+    let mut req = common::post_without_content_type("body");
+    let either = AxumEither::<HeaderMap, String>::from_request(&mut req)
+        .await
+        .unwrap();
+    assert!(matches!(either, AxumEither::Left(_)));
+
+    let mut req = common::post_without_content_type("body");
+    let either = AxumEither::<Json<u32>, HeaderMap>::from_request(&mut req)
+        .await
+        .unwrap();
+    assert!(matches!(either, AxumEither::Right(_)));
+}
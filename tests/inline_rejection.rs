@@ -0,0 +1,119 @@
+#![cfg(feature = "test-util")]
+
+use axum::{
+    extract::rejection::{FormRejection, JsonRejection},
+    http::StatusCode,
+    routing::post,
+    Form, Json, Router,
+};
+use axum_either::{test::Harness, AxumEither, AxumEitherRejection, RejectionInfo};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Request {
+    pub id: u32,
+}
+
+type Either = AxumEither<Json<Request>, Form<Request>>;
+
+async fn result(
+    request: Result<Either, AxumEitherRejection<JsonRejection, FormRejection>>,
+) -> (StatusCode, String) {
+    match request {
+        Ok(AxumEither::Left(Json(request))) => (StatusCode::OK, format!("json {}", request.id)),
+        Ok(AxumEither::Right(Form(request))) => (StatusCode::OK, format!("form {}", request.id)),
+        Err(rejection) => {
+            let names: Vec<_> = rejection
+                .collect_rejections()
+                .map(|attempt| attempt.name())
+                .collect();
+            (rejection.status(), names.join(","))
+        }
+    }
+}
+
+async fn option(request: Option<Either>) -> String {
+    match request {
+        Some(AxumEither::Left(Json(request))) => format!("json {}", request.id),
+        Some(AxumEither::Right(Form(request))) => format!("form {}", request.id),
+        None => "none".into(),
+    }
+}
+
+fn harness() -> Harness<Router> {
+    Harness::new(
+        Router::new()
+            .route("/result", post(result))
+            .route("/option", post(option)),
+    )
+}
+
+#[tokio::test]
+async fn result_left() {
+    let response = harness()
+        .post("/result")
+        .json(&Request { id: 1 })
+        .send()
+        .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "json 1");
+}
+
+#[tokio::test]
+async fn result_right() {
+    let response = harness()
+        .post("/result")
+        .form(&Request { id: 2 })
+        .send()
+        .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "form 2");
+}
+
+#[tokio::test]
+async fn result_gives_the_combined_rejection() {
+    let response = harness()
+        .post("/result")
+        .content_type("text/plain")
+        .body("id=3")
+        .send()
+        .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(response.text(), "axum::JsonRejection,axum::FormRejection");
+}
+
+#[tokio::test]
+async fn option_left() {
+    let response = harness()
+        .post("/option")
+        .json(&Request { id: 1 })
+        .send()
+        .await;
+    assert_eq!(response.text(), "json 1");
+}
+
+#[tokio::test]
+async fn option_right() {
+    let response = harness()
+        .post("/option")
+        .form(&Request { id: 2 })
+        .send()
+        .await;
+    assert_eq!(response.text(), "form 2");
+}
+
+#[tokio::test]
+async fn option_is_none_if_both_reject() {
+    let harness = harness();
+    let response = harness
+        .post("/option")
+        .content_type("text/plain")
+        .body("id=3")
+        .send()
+        .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "none");
+
+    let response = harness.post("/option").send().await;
+    assert_eq!(response.text(), "none");
+}
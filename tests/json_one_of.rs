@@ -0,0 +1,146 @@
+#![cfg(feature = "json-one-of")]
+
+use axum::{
+    body::{Bytes, Full},
+    extract::{FromRequest, RequestParts},
+    http::{header, HeaderMap, Request, StatusCode},
+};
+use axum_either::{
+    rejection::{JsonBodyRejection, JsonOneOfRejection},
+    AxumEither, JsonOneOf, RejectionInfo,
+};
+use http_body::Body;
+use serde::Deserialize;
+use std::{
+    convert::Infallible,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+};
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ById {
+    pub id: u32,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct ByName {
+    pub name: String,
+}
+
+/// Counts the bytes read from the body
+struct CountingBody {
+    inner: Full<Bytes>,
+    read: Arc<AtomicUsize>,
+}
+
+impl Body for CountingBody {
+    type Data = Bytes;
+    type Error = Infallible;
+
+    fn poll_data(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Self::Data, Self::Error>>> {
+        let data = Pin::new(&mut self.inner).poll_data(cx);
+        if let Poll::Ready(Some(Ok(data))) = &data {
+            self.read.fetch_add(data.len(), Ordering::SeqCst);
+        }
+        data
+    }
+
+    fn poll_trailers(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<Option<HeaderMap>, Self::Error>> {
+        Pin::new(&mut self.inner).poll_trailers(cx)
+    }
+}
+
+async fn extract(
+    content_type: &str,
+    body: &'static str,
+) -> (Result<JsonOneOf<ById, ByName>, JsonOneOfRejection>, usize) {
+    let read = Arc::new(AtomicUsize::new(0));
+    let mut req = RequestParts::new(
+        Request::post("/")
+            .header(header::CONTENT_TYPE, content_type)
+            .body(CountingBody {
+                inner: Full::from(body),
+                read: Arc::clone(&read),
+            })
+            .unwrap(),
+    );
+    let result = JsonOneOf::from_request(&mut req).await;
+    let read = read.load(Ordering::SeqCst);
+    (result, read)
+}
+
+#[tokio::test]
+async fn left_is_tried_first() {
+    let body = r#"{"id": 3}"#;
+    let (result, read) = extract("application/json", body).await;
+    assert_eq!(
+        result.unwrap().into_inner(),
+        AxumEither::Left(ById { id: 3 })
+    );
+    assert_eq!(read, body.len());
+}
+
+#[tokio::test]
+async fn right_is_parsed_from_the_same_value() {
+    let body = r#"{"name": "Reimu", "id": 3}"#;
+    let (result, read) = extract("application/json", body).await;
+    assert_eq!(
+        result.unwrap().into_inner(),
+        AxumEither::Right(ByName {
+            name: "Reimu".into()
+        })
+    );
+    // The body is read exactly once, even though both types were tried
+    assert_eq!(read, body.len());
+}
+
+#[tokio::test]
+async fn both_fail() {
+    let body = r#"{"title": "Reimu"}"#;
+    let (result, read) = extract("application/json", body).await;
+    let rejection = result.unwrap_err();
+    assert_eq!(read, body.len());
+    assert_eq!(rejection.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let message = rejection.to_string();
+    assert!(message.contains("unknown field `title`"), "{}", message);
+    assert!(message.contains("missing field `name`"), "{}", message);
+    match rejection {
+        JsonOneOfRejection::Deserialize { left, right } => {
+            assert!(left.to_string().contains("title"));
+            assert!(right.to_string().contains("name"));
+        }
+        rejection => panic!("unexpected rejection {:?}", rejection),
+    }
+}
+
+#[tokio::test]
+async fn invalid_json_and_content_type() {
+    let (result, _) = extract("application/json", r#"{"id": "#).await;
+    let rejection = result.unwrap_err();
+    assert!(matches!(
+        rejection,
+        JsonOneOfRejection::Json(JsonBodyRejection::Syntax(_))
+    ));
+    assert_eq!(rejection.status(), StatusCode::BAD_REQUEST);
+
+    let (result, read) = extract("text/plain", r#"{"id": 3}"#).await;
+    let rejection = result.unwrap_err();
+    assert!(matches!(
+        rejection,
+        JsonOneOfRejection::Json(JsonBodyRejection::MissingJsonContentType)
+    ));
+    assert_eq!(rejection.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    assert_eq!(read, 0);
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "schemars")]
+
+use axum_either::AxumEither;
+use schemars::{schema_for, JsonSchema};
+use serde_json::json;
+
+#[derive(JsonSchema)]
+#[allow(dead_code)]
+struct Pet {
+    name: String,
+}
+
+#[test]
+fn pair_schema() {
+    let schema = serde_json::to_value(schema_for!(AxumEither<String, bool>)).unwrap();
+    assert_eq!(
+        schema,
+        json!({
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "title": "String_or_Boolean",
+            "oneOf": [
+                { "type": "string" },
+                { "type": "boolean" }
+            ]
+        })
+    );
+}
+
+#[test]
+fn depth_three_chain_schema() {
+    let schema =
+        serde_json::to_value(schema_for!(axum_either::one_of!(String, bool, Pet))).unwrap();
+    assert_eq!(
+        schema,
+        json!({
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "title": "String_or_Boolean_or_Pet",
+            "oneOf": [
+                { "type": "string" },
+                { "type": "boolean" },
+                { "$ref": "#/definitions/Pet" }
+            ],
+            "definitions": {
+                "Pet": {
+                    "type": "object",
+                    "required": ["name"],
+                    "properties": {
+                        "name": { "type": "string" }
+                    }
+                }
+            }
+        })
+    );
+}
@@ -0,0 +1,93 @@
+#![cfg(feature = "negotiation")]
+
+use axum::{
+    http::{header, HeaderMap},
+    response::{Html, IntoResponse, Response},
+};
+use axum_either::{AcceptLanguage, AxumEither, LanguageNegotiate};
+
+fn accept(value: Option<&str>) -> AcceptLanguage {
+    let mut headers = HeaderMap::new();
+    if let Some(value) = value {
+        headers.insert(header::ACCEPT_LANGUAGE, value.parse().unwrap());
+    }
+    AcceptLanguage::from_headers(&headers)
+}
+
+fn not_found(accept: Option<&str>) -> LanguageNegotiate<Html<&'static str>, Html<&'static str>> {
+    LanguageNegotiate::new(
+        &self::accept(accept),
+        (),
+        ("en", |()| Html("Not found")),
+        ("de", |()| Html("Nicht gefunden")),
+    )
+}
+
+fn language(accept: Option<&str>) -> String {
+    not_found(accept).language().to_owned()
+}
+
+#[test]
+fn exact_match() {
+    assert_eq!(language(Some("de")), "de");
+    assert_eq!(language(Some("en")), "en");
+    assert_eq!(language(Some("DE")), "de");
+}
+
+#[test]
+fn prefix_match() {
+    assert_eq!(language(Some("de-AT")), "de");
+    assert_eq!(language(Some("en-GB, de;q=0.5")), "en");
+    assert_eq!(language(Some("de-CH;q=0.9, en-US;q=0.8")), "de");
+    // Prefixes end at subtags only
+    assert_eq!(accept(Some("d")).quality("de"), 0.0);
+    assert_eq!(accept(Some("de")).quality("den"), 0.0);
+}
+
+#[test]
+fn q_value_ordering() {
+    assert_eq!(language(Some("en;q=0.4, de;q=0.8")), "de");
+    assert_eq!(language(Some("de;q=0.4, en;q=0.8")), "en");
+    assert_eq!(language(Some("fr, de;q=0.2, *;q=0.1")), "de");
+    // More specific ranges decide the quality
+    assert_eq!(language(Some("*;q=0.9, de;q=0.1")), "en");
+}
+
+#[test]
+fn missing_header_defaults_to_left() {
+    assert_eq!(language(None), "en");
+    assert_eq!(language(Some("fr")), "en");
+    assert_eq!(language(Some("de;q=0.5, en;q=0.5")), "en");
+}
+
+#[test]
+fn responds_with_content_language_and_vary() {
+    let response = not_found(Some("de-AT")).into_response();
+    assert_eq!(response.headers()[header::CONTENT_LANGUAGE], "de");
+    assert_eq!(response.headers()[header::VARY], "Accept-Language");
+    assert!(matches!(
+        not_found(Some("de")).into_inner(),
+        AxumEither::Right(Html("Nicht gefunden"))
+    ));
+}
+
+#[test]
+fn localizations_keep_their_headers() {
+    let response: Response = LanguageNegotiate::new(
+        &accept(Some("en-US")),
+        (),
+        ("en", |()| {
+            (
+                [
+                    (header::CONTENT_LANGUAGE, "en-US"),
+                    (header::VARY, "Accept"),
+                ],
+                "Not found",
+            )
+        }),
+        ("de", |()| "Nicht gefunden"),
+    )
+    .into_response();
+    assert_eq!(response.headers()[header::CONTENT_LANGUAGE], "en-US");
+    assert_eq!(response.headers()[header::VARY], "Accept, Accept-Language");
+}
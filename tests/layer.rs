@@ -0,0 +1,62 @@
+#![cfg(feature = "tower")]
+
+use axum::{
+    body::Body,
+    http::{HeaderValue, Request},
+    middleware::{self, Next},
+    response::Response,
+    routing::get,
+    Router,
+};
+use axum_either::AxumEither;
+use std::net::{SocketAddr, TcpListener};
+use tower::layer::util::Identity;
+
+async fn authenticated(req: Request<Body>, next: Next<Body>) -> Response {
+    let mut response = next.run(req).await;
+    response
+        .headers_mut()
+        .insert("x-authenticated", HeaderValue::from_static("true"));
+    response
+}
+
+async fn test_setup(dev: bool) -> SocketAddr {
+    let layer = if dev {
+        AxumEither::Right(Identity::new())
+    } else {
+        AxumEither::Left(middleware::from_fn(authenticated))
+    };
+    let router = Router::new()
+        .route("/", get(|| async { "hello" }))
+        .layer(layer);
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::Server::from_tcp(listener)
+            .unwrap()
+            .serve(router.into_make_service())
+            .await
+    });
+    addr
+}
+
+#[tokio::test]
+async fn left_layer_applies_middleware() {
+    let addr = test_setup(false).await;
+    let response = reqwest::get(&format!("http://{}/", addr))
+        .await
+        .expect("Error sending request");
+    assert_eq!(response.headers()["x-authenticated"], "true");
+    assert_eq!(response.text().await.unwrap(), "hello");
+}
+
+#[tokio::test]
+async fn right_layer_skips_middleware() {
+    let addr = test_setup(true).await;
+    let response = reqwest::get(&format!("http://{}/", addr))
+        .await
+        .expect("Error sending request");
+    assert!(response.headers().get("x-authenticated").is_none());
+    assert_eq!(response.text().await.unwrap(), "hello");
+}
@@ -0,0 +1,195 @@
+#![cfg(feature = "axum")]
+
+use axum::{
+    extract::FromRequest,
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    Form, Json,
+};
+use axum_either::{
+    rejection::{LazyRejection, WithLazyRejection},
+    AxumEither, AxumEitherRejection, RejectionInfo, SingleRejection,
+};
+use serde::Deserialize;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+mod common;
+
+/// Counts how often it was rendered
+#[derive(Debug, Clone)]
+struct Counted(StatusCode, Arc<AtomicUsize>);
+
+impl IntoResponse for Counted {
+    fn into_response(self) -> Response {
+        self.1.fetch_add(1, Ordering::SeqCst);
+        (self.0, "rendered").into_response()
+    }
+}
+
+impl RejectionInfo for Counted {
+    fn status(&self) -> StatusCode {
+        self.0
+    }
+
+    fn message(&self) -> String {
+        "counted".into()
+    }
+}
+
+fn counted_rejection() -> (AxumEitherRejection<Counted, Counted>, Arc<AtomicUsize>) {
+    let renders = Arc::new(AtomicUsize::new(0));
+    let rejection = AxumEitherRejection::new(
+        Counted(StatusCode::UNSUPPORTED_MEDIA_TYPE, Arc::clone(&renders)),
+        Counted(StatusCode::UNPROCESSABLE_ENTITY, Arc::clone(&renders)),
+    );
+    (rejection, renders)
+}
+
+#[test]
+fn preferred_and_status_do_not_render() {
+    let (rejection, renders) = counted_rejection();
+    assert_eq!(rejection.status(), StatusCode::BAD_REQUEST);
+    assert!(matches!(rejection.preferred(), AxumEither::Left(_)));
+    assert_eq!(rejection.attempts().len(), 2);
+
+    let lazy = LazyRejection(rejection);
+    assert_eq!(lazy.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(renders.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn body_is_rendered_on_first_read() {
+    let (rejection, renders) = counted_rejection();
+    let response = LazyRejection(rejection).into_response();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(renders.load(Ordering::SeqCst), 0);
+
+    let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+    assert_eq!(renders.load(Ordering::SeqCst), 2);
+    assert!(String::from_utf8(body.to_vec())
+        .unwrap()
+        .starts_with("Could not parse request"));
+}
+
+#[test]
+fn headers_match_the_rendered_response() {
+    let (rejection, renders) = counted_rejection();
+    let response = LazyRejection(rejection.clone()).into_response();
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "text/plain; charset=utf-8"
+    );
+    assert_eq!(renders.load(Ordering::SeqCst), 0);
+
+    let rendered = rejection
+        .with_formatter::<SingleRejection>()
+        .into_response();
+    let lazy =
+        LazyRejection(counted_rejection().0.with_formatter::<SingleRejection>()).into_response();
+    assert_eq!(
+        lazy.headers()[header::CONTENT_TYPE],
+        rendered.headers()[header::CONTENT_TYPE]
+    );
+}
+
+/// Renders JSON but keeps the default `headers`
+#[derive(Debug, Clone)]
+struct JsonRejection;
+
+impl IntoResponse for JsonRejection {
+    fn into_response(self) -> Response {
+        (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(serde_json::json!({ "error": "json" })),
+        )
+            .into_response()
+    }
+}
+
+impl RejectionInfo for JsonRejection {
+    fn status(&self) -> StatusCode {
+        StatusCode::UNPROCESSABLE_ENTITY
+    }
+
+    fn message(&self) -> String {
+        "json".into()
+    }
+}
+
+/// Renders like [`JsonRejection`] and reports its headers
+#[derive(Debug, Clone)]
+struct TypedJsonRejection;
+
+impl IntoResponse for TypedJsonRejection {
+    fn into_response(self) -> Response {
+        JsonRejection.into_response()
+    }
+}
+
+impl RejectionInfo for TypedJsonRejection {
+    fn status(&self) -> StatusCode {
+        JsonRejection.status()
+    }
+
+    fn message(&self) -> String {
+        JsonRejection.message()
+    }
+
+    fn headers(&self) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        );
+        headers
+    }
+}
+
+#[tokio::test]
+async fn json_rejection_must_override_headers() {
+    let rendered = JsonRejection.into_response();
+    assert_eq!(rendered.headers()[header::CONTENT_TYPE], "application/json");
+
+    // The default headers claim plain text although the body is JSON
+    let lazy = LazyRejection(JsonRejection).into_response();
+    assert_eq!(
+        lazy.headers()[header::CONTENT_TYPE],
+        "text/plain; charset=utf-8"
+    );
+    let body = hyper::body::to_bytes(lazy.into_body()).await.unwrap();
+    assert_eq!(&body[..], br#"{"error":"json"}"#);
+
+    let lazy = LazyRejection(TypedJsonRejection).into_response();
+    assert_eq!(
+        lazy.headers()[header::CONTENT_TYPE],
+        rendered.headers()[header::CONTENT_TYPE]
+    );
+}
+
+#[test]
+fn discarded_body_is_never_rendered() {
+    let (rejection, renders) = counted_rejection();
+    let (status, body) = LazyRejection(rejection).into_response_parts_lazy();
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    drop(body);
+    assert_eq!(renders.load(Ordering::SeqCst), 0);
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Payload {
+    pub id: u32,
+}
+
+#[tokio::test]
+async fn extractor_wrapper_rejects_lazily() {
+    let mut req = common::post("text/plain", "id=3");
+    let rejection =
+        WithLazyRejection::<AxumEither<Json<Payload>, Form<Payload>>>::from_request(&mut req)
+            .await
+            .unwrap_err();
+    assert_eq!(rejection.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(rejection.into_response().status(), StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,137 @@
+use axum::{
+    body::Body,
+    extract::{FromRequest, RequestParts},
+    http::{header, Request, StatusCode},
+    response::IntoResponse,
+    Form, Json,
+};
+use axum_either::{rejection::LimitedRejection, AxumEither, Dispatch, Limited, RejectionInfo};
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug)]
+pub struct Upload {
+    pub data: String,
+}
+
+const JSON_LIMIT: usize = 1024;
+const FORM_LIMIT: usize = 64;
+
+type Either = AxumEither<Limited<Json<Upload>, JSON_LIMIT>, Limited<Form<Upload>, FORM_LIMIT>>;
+
+/// A json body of exactly `len` bytes
+fn json(len: usize) -> String {
+    format!(r#"{{"data":"{}"}}"#, "x".repeat(len - 11))
+}
+
+/// A form body of exactly `len` bytes
+fn form(len: usize) -> String {
+    format!("data={}", "x".repeat(len - 5))
+}
+
+fn request(content_type: &str, body: String, content_length: bool) -> RequestParts<Body> {
+    let mut request = Request::post("/").header(header::CONTENT_TYPE, content_type);
+    if content_length {
+        request = request.header(header::CONTENT_LENGTH, body.len());
+    }
+    RequestParts::new(request.body(Body::from(body)).unwrap())
+}
+
+async fn extract(content_type: &str, body: String, content_length: bool) -> Result<usize, String> {
+    let mut req = request(content_type, body, content_length);
+    match Dispatch::<Either>::from_request(&mut req).await {
+        Ok(Dispatch(AxumEither::Left(Limited(Json(upload))))) => Ok(upload.data.len()),
+        Ok(Dispatch(AxumEither::Right(Limited(Form(upload))))) => Ok(upload.data.len()),
+        Err(rejection) => {
+            assert_eq!(rejection.status(), StatusCode::PAYLOAD_TOO_LARGE);
+            Err(rejection.message())
+        }
+    }
+}
+
+#[tokio::test]
+async fn json_straddling_its_limit() {
+    for content_length in [false, true] {
+        assert_eq!(
+            extract("application/json", json(JSON_LIMIT), content_length).await,
+            Ok(JSON_LIMIT - 11)
+        );
+        let message = extract("application/json", json(JSON_LIMIT + 1), content_length)
+            .await
+            .unwrap_err();
+        assert!(
+            message.contains("The application/json body exceeds the limit of 1024 bytes"),
+            "{}",
+            message
+        );
+    }
+}
+
+#[tokio::test]
+async fn form_straddling_its_limit() {
+    let content_type = "application/x-www-form-urlencoded";
+    for content_length in [false, true] {
+        assert_eq!(
+            extract(content_type, form(FORM_LIMIT), content_length).await,
+            Ok(FORM_LIMIT - 5)
+        );
+        let message = extract(content_type, form(FORM_LIMIT + 1), content_length)
+            .await
+            .unwrap_err();
+        assert!(
+            message.contains(
+                "The application/x-www-form-urlencoded body exceeds the limit of 64 bytes"
+            ),
+            "{}",
+            message
+        );
+    }
+}
+
+#[tokio::test]
+async fn limits_apply_per_variant() {
+    // Larger than the form limit, but the json limit applies to json
+    assert_eq!(
+        extract("application/json", json(512), false).await,
+        Ok(512 - 11)
+    );
+}
+
+#[tokio::test]
+async fn plain_either_combines_the_rejection() {
+    let mut req = request("application/json", json(JSON_LIMIT + 1), false);
+    let rejection = Either::from_request(&mut req).await.unwrap_err();
+    assert!(matches!(
+        rejection.left(),
+        LimitedRejection::PayloadTooLarge {
+            limit: JSON_LIMIT,
+            ..
+        }
+    ));
+    assert_eq!(rejection.left().status(), StatusCode::PAYLOAD_TOO_LARGE);
+    assert_eq!(rejection.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn other_content_types_are_passed_through() {
+    let mut req = request("text/plain", "x".repeat(4096), true);
+    let rejection = Limited::<Json<Upload>, 16>::from_request(&mut req)
+        .await
+        .unwrap_err();
+    assert!(matches!(rejection, LimitedRejection::Inner(_)));
+    assert_eq!(rejection.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+}
+
+#[tokio::test]
+async fn too_large_responds_413() {
+    let mut req = request("application/json", json(64), false);
+    let rejection = Limited::<Json<Upload>, 32>::from_request(&mut req)
+        .await
+        .unwrap_err();
+    let response = rejection.into_response();
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+    assert_eq!(
+        &body[..],
+        b"The application/json body exceeds the limit of 32 bytes"
+    );
+}
@@ -0,0 +1,52 @@
+use axum_either::AxumEither;
+
+type Input = axum_either::one_of!(u8, u16, &'static str, char);
+type Output = AxumEither<u32, String>;
+
+fn collapse(input: Input) -> Output {
+    axum_either::map_one_of! {input,
+        a => 0: u32::from(a),
+        b => 0: u32::from(b),
+        s => 1: s.to_owned(),
+        c => 1: c.to_string(),
+    }
+}
+
+#[test]
+fn maps_every_input_arm_onto_two_outputs() {
+    assert_eq!(collapse(AxumEither::Left(1)), AxumEither::Left(1));
+    assert_eq!(
+        collapse(AxumEither::Right(AxumEither::Left(2))),
+        AxumEither::Left(2)
+    );
+    assert_eq!(
+        collapse(AxumEither::Right(AxumEither::Right(AxumEither::Left(
+            "three"
+        )))),
+        AxumEither::Right("three".to_owned())
+    );
+    assert_eq!(
+        collapse(AxumEither::Right(AxumEither::Right(AxumEither::Right('4')))),
+        AxumEither::Right("4".to_owned())
+    );
+}
+
+#[test]
+fn positions_may_reorder() {
+    let input: AxumEither<u8, &str> = AxumEither::Left(1);
+    let output: AxumEither<String, u8> = axum_either::map_one_of! {input,
+        a => 1: a,
+        s => 0: s.to_owned(),
+    };
+    assert_eq!(output, AxumEither::Right(1));
+}
+
+#[test]
+fn positions_reach_into_longer_outputs() {
+    let input: AxumEither<u8, &str> = AxumEither::Right("text");
+    let output: axum_either::one_of!(u8, bool, &str) = axum_either::map_one_of! {input,
+        a => 0: a,
+        s => 2: s,
+    };
+    assert_eq!(output, AxumEither::Right(AxumEither::Right("text")));
+}
@@ -0,0 +1,106 @@
+#![cfg(feature = "test-util")]
+
+use axum::{http::StatusCode, routing::post, Form, Json, Router};
+use axum_either::{test::Harness, AxumEither};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Request {
+    pub id: u32,
+}
+
+type Chain = axum_either::one_of!(Json<Request>, Form<Request>, String);
+
+fn checked(id: u32) -> Result<u32, StatusCode> {
+    if id == 0 {
+        Err(StatusCode::UNPROCESSABLE_ENTITY)
+    } else {
+        Ok(id)
+    }
+}
+
+/// `?` and `return` in the middle arm of a depth-3 chain, the block arm has no comma
+async fn handler(chain: Chain) -> Result<String, StatusCode> {
+    let id = axum_either::match_one_of! {chain,
+        Json(request) => checked(request.id)?,
+        Form(request) => {
+            if request.id == 7 {
+                return Ok("lucky".into());
+            }
+            checked(request.id)?
+        }
+        text => text.trim().parse().map_err(|_| StatusCode::BAD_REQUEST)?,
+    };
+    Ok(format!("id {}", id))
+}
+
+type Values<'a> = axum_either::one_of!(u32, &'a str, bool, char);
+
+/// `break` and `continue` in block arms leave the enclosing loop
+fn first_text(chains: Vec<Values<'_>>) -> Option<&str> {
+    let mut found = None;
+    for chain in chains {
+        axum_either::match_one_of! {chain,
+            _ => continue,
+            text => {
+                found = Some(text);
+                break
+            }
+            flag => {
+                if flag {
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+    found
+}
+
+fn harness() -> Harness<Router> {
+    Harness::new(Router::new().route("/", post(handler)))
+}
+
+#[tokio::test]
+async fn question_mark_in_arms() {
+    let response = harness().post("/").json(&Request { id: 1 }).send().await;
+    assert_eq!(response.text(), "id 1");
+
+    let response = harness().post("/").form(&Request { id: 0 }).send().await;
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let response = harness()
+        .post("/")
+        .content_type("text/plain")
+        .body("nope")
+        .send()
+        .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn early_return_in_middle_arm() {
+    let response = harness().post("/").form(&Request { id: 7 }).send().await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "lucky");
+
+    let response = harness().post("/").form(&Request { id: 8 }).send().await;
+    assert_eq!(response.text(), "id 8");
+}
+
+#[test]
+fn break_and_continue_in_arms() {
+    let chains = vec![
+        AxumEither::Left(1),
+        AxumEither::Right(AxumEither::Right(AxumEither::Right('c'))),
+        AxumEither::Right(AxumEither::Left("first")),
+        AxumEither::Right(AxumEither::Left("second")),
+    ];
+    assert_eq!(first_text(chains), Some("first"));
+
+    let chains = vec![
+        AxumEither::Right(AxumEither::Right(AxumEither::Left(true))),
+        AxumEither::Right(AxumEither::Left("never")),
+    ];
+    assert_eq!(first_text(chains), None);
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "axum")]
+
+use axum::{Form, Json};
+use axum_either::{AxumEither, MediaTypeDispatch, SupportedMediaTypes};
+use bytes::Bytes;
+use mime::Mime;
+
+struct Csv;
+
+impl SupportedMediaTypes for Csv {
+    fn supported_media_types() -> &'static [Mime] {
+        const CSV: &[Mime] = &[mime::TEXT_CSV];
+        CSV
+    }
+}
+
+fn parse(content_type: &str) -> Mime {
+    content_type.parse().unwrap()
+}
+
+#[test]
+fn built_in_media_types() {
+    assert!(String::supports(&parse("text/plain; charset=utf-8")));
+    assert!(!String::supports(&mime::APPLICATION_JSON));
+    assert!(Bytes::supports(&parse("image/png")));
+
+    assert!(Json::<u8>::supports(&mime::APPLICATION_JSON));
+    assert!(Json::<u8>::supports(&parse("application/problem+json")));
+    assert!(!Json::<u8>::supports(&mime::TEXT_PLAIN));
+    assert!(Form::<u8>::supports(&mime::APPLICATION_WWW_FORM_URLENCODED));
+    assert!(!Form::<u8>::supports(&mime::APPLICATION_JSON));
+}
+
+#[test]
+fn custom_extractor_is_dispatched() {
+    type Chain = axum_either::one_of!(Json<u8>, Form<u8>, Csv, Bytes);
+    assert_eq!(Chain::VARIANTS, 4);
+    assert_eq!(Chain::claims(&mime::TEXT_CSV), [2, 3]);
+    assert_eq!(Chain::claims(&mime::APPLICATION_JSON), [0, 3]);
+    assert!(AxumEither::<Json<u8>, Csv>::claims(&mime::IMAGE_PNG).is_empty());
+}
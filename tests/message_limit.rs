@@ -0,0 +1,99 @@
+use axum::{extract::FromRequest, response::IntoResponse, Form, Json};
+use axum_either::{
+    rejection::{DEFAULT_MESSAGE_LIMIT, TRUNCATION_MARKER},
+    AxumEither, AxumEitherRejection, BoxedEitherRejection, RejectionInfo,
+};
+use serde::Deserialize;
+
+mod common;
+
+#[derive(Deserialize, Debug)]
+pub struct Payload {
+    pub id: u32,
+}
+
+/// A json body whose deserialization error embeds 100 KiB of input
+async fn pathological_rejection() -> AxumEitherRejection<
+    axum::extract::rejection::JsonRejection,
+    axum::extract::rejection::FormRejection,
+> {
+    let body = format!(r#"{{"id": "{}"}}"#, "A".repeat(100 * 1024));
+    let mut req = common::post("application/json", body);
+    AxumEither::<Json<Payload>, Form<Payload>>::from_request(&mut req)
+        .await
+        .unwrap_err()
+}
+
+#[test]
+fn display_is_capped() {
+    let rejection = AxumEitherRejection::new("A".repeat(100 * 1024), "Expected a form");
+
+    let message = rejection.to_string();
+    assert!(message.len() <= DEFAULT_MESSAGE_LIMIT, "{}", message.len());
+    assert!(message.contains("left error: AAAA"));
+    assert!(message.contains(TRUNCATION_MARKER));
+    assert!(message.ends_with("right error: Expected a form"));
+}
+
+#[tokio::test]
+async fn rejection_info_message_is_capped() {
+    let message = pathological_rejection().await.message();
+    assert!(message.len() <= DEFAULT_MESSAGE_LIMIT, "{}", message.len());
+    assert!(message.contains("invalid type: string \"AAAA"));
+    assert!(message.contains("right error: Form requests must have"));
+}
+
+#[tokio::test]
+async fn default_body_is_capped() {
+    let response = pathological_rejection().await.into_response();
+    let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+    let body = std::str::from_utf8(&body).unwrap();
+    assert!(body.len() <= DEFAULT_MESSAGE_LIMIT, "{}", body.len());
+    assert!(body.starts_with("Could not parse request\n\tleft error: "));
+    assert!(body.contains("invalid type: string \"AAAA"));
+    assert!(body.contains(TRUNCATION_MARKER));
+    assert!(body.contains("right error: Form requests must have"));
+}
+
+#[tokio::test]
+async fn boxed_body_is_capped() {
+    let rejection = BoxedEitherRejection::from(pathological_rejection().await);
+    let body = hyper::body::to_bytes(rejection.into_response().into_body())
+        .await
+        .unwrap();
+    let body = std::str::from_utf8(&body).unwrap();
+    assert!(body.len() <= DEFAULT_MESSAGE_LIMIT, "{}", body.len());
+    assert!(body.contains("invalid type: string \"AAAA"));
+    assert!(body.contains(TRUNCATION_MARKER));
+    assert!(body.contains("error: Form requests must have"));
+}
+
+#[tokio::test]
+async fn custom_limit() {
+    let rejection = pathological_rejection().await;
+    let message = rejection.display_with_limit(256).to_string();
+    assert!(message.len() <= 256, "{}", message.len());
+    assert!(message.starts_with("Could not parse request, left error: "));
+    assert!(message.contains("right error: Form requests"));
+
+    let message = rejection.message_with_limit(256);
+    assert!(message.len() <= 256, "{}", message.len());
+    assert!(message.starts_with("Could not parse request\n\tleft error: "));
+    assert!(message.contains("right error: Form requests"));
+}
+
+#[test]
+fn short_messages_are_untouched() {
+    let rejection = AxumEitherRejection::new("ä".repeat(10), "b");
+    assert_eq!(
+        rejection.to_string(),
+        format!(
+            "Could not parse request, left error: {}, right error: b",
+            "ä".repeat(10)
+        )
+    );
+
+    let message = rejection.display_with_limit(60).to_string();
+    assert!(message.len() <= 60);
+    assert!(message.ends_with("right error: b"));
+}
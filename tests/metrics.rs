@@ -0,0 +1,140 @@
+#![cfg(feature = "metrics")]
+
+use axum::{
+    extract::FromRequest,
+    http::{header, HeaderMap, HeaderValue},
+    response::IntoResponse,
+    Form, Json,
+};
+use axum_either::{
+    metrics::{EXTRACTION_DURATION_SECONDS, MATCHED_TOTAL, NEGOTIATED_TOTAL, REJECTED_TOTAL},
+    one_of, Accept, AxumEither, Negotiate,
+};
+use metrics_util::debugging::{DebugValue, DebuggingRecorder, Snapshotter};
+use serde::Deserialize;
+
+mod common;
+
+#[derive(Deserialize)]
+#[allow(dead_code)]
+struct Payload {
+    id: u32,
+}
+
+type Chain = one_of!(Json<Payload>, Form<Payload>, String);
+
+async fn extract(content_type: &str, body: &'static str) -> Option<Chain> {
+    let mut req = common::post(content_type, body);
+    Chain::from_request(&mut req).await.ok()
+}
+
+/// The name, `variant` label and value of every recorded metric.
+///
+/// Taking a snapshot drains the histograms, so all histogram assertions share one snapshot.
+fn snapshot(snapshotter: &Snapshotter) -> Vec<(String, Option<String>, DebugValue)> {
+    snapshotter
+        .snapshot()
+        .into_vec()
+        .into_iter()
+        .map(|(key, _, _, value)| {
+            let variant = key
+                .key()
+                .labels()
+                .find(|label| label.key() == "variant")
+                .map(|label| label.value().to_owned());
+            (key.key().name().to_owned(), variant, value)
+        })
+        .collect()
+}
+
+/// The metrics of `snapshot` named `name`, only those with the given `variant` label if there
+/// is one
+fn filter<'a>(
+    snapshot: &'a [(String, Option<String>, DebugValue)],
+    name: &'a str,
+    variant: Option<&'a str>,
+) -> impl Iterator<Item = &'a DebugValue> {
+    snapshot
+        .iter()
+        .filter(move |(key, label, _)| {
+            key == name && variant.is_none_or(|variant| label.as_deref() == Some(variant))
+        })
+        .map(|(_, _, value)| value)
+}
+
+fn counter(snapshotter: &Snapshotter, name: &str, variant: Option<&str>) -> u64 {
+    filter(&snapshot(snapshotter), name, variant)
+        .map(|value| match value {
+            DebugValue::Counter(count) => *count,
+            _ => 0,
+        })
+        .sum()
+}
+
+/// The number of samples of the histogram `name` in `snapshot`
+fn samples(
+    snapshot: &[(String, Option<String>, DebugValue)],
+    name: &str,
+    variant: Option<&str>,
+) -> usize {
+    filter(snapshot, name, variant)
+        .map(|value| match value {
+            DebugValue::Histogram(samples) => samples.len(),
+            _ => 0,
+        })
+        .sum()
+}
+
+fn negotiate(accept: &'static str) {
+    let mut headers = HeaderMap::new();
+    headers.insert(header::ACCEPT, HeaderValue::from_static(accept));
+    let _ = Negotiate::new(
+        &Accept::from_headers(&headers),
+        1,
+        ("application/json", Json),
+        ("text/plain", |id: u32| id.to_string()),
+    )
+    .into_response();
+}
+
+#[tokio::test]
+async fn counts_matches_and_rejections() {
+    let recorder = DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+    recorder.install().unwrap();
+
+    let json = extract("application/json", r#"{"id":1}"#).await;
+    assert!(matches!(json, Some(AxumEither::Left(Json(_)))));
+    extract("application/x-www-form-urlencoded", "id=1").await;
+    extract("text/plain", "id").await;
+    // Without a body the string is empty, use an invalid utf-8 body to reject every variant
+    let mut req = common::post_without_content_type(vec![0xff]);
+    assert!(Chain::from_request(&mut req).await.is_err());
+
+    // Every extraction records its duration once
+    let durations = snapshot(&snapshotter);
+    for variant in ["0", "1", "2", "rejected"] {
+        assert_eq!(
+            samples(&durations, EXTRACTION_DURATION_SECONDS, Some(variant)),
+            1,
+            "{}",
+            variant
+        );
+    }
+    assert_eq!(samples(&durations, EXTRACTION_DURATION_SECONDS, None), 4);
+
+    assert_eq!(counter(&snapshotter, MATCHED_TOTAL, Some("0")), 1);
+    assert_eq!(counter(&snapshotter, MATCHED_TOTAL, Some("1")), 1);
+    assert_eq!(counter(&snapshotter, MATCHED_TOTAL, Some("2")), 1);
+    assert_eq!(counter(&snapshotter, REJECTED_TOTAL, None), 1);
+    // Nested eithers do not report separately
+    assert_eq!(counter(&snapshotter, MATCHED_TOTAL, None), 3);
+
+    negotiate("application/json");
+    negotiate("text/plain");
+    negotiate("text/plain, application/json;q=0.5");
+    negotiate("image/png");
+    assert_eq!(counter(&snapshotter, NEGOTIATED_TOTAL, Some("0")), 1);
+    assert_eq!(counter(&snapshotter, NEGOTIATED_TOTAL, Some("1")), 2);
+    assert_eq!(counter(&snapshotter, NEGOTIATED_TOTAL, Some("none")), 1);
+}
@@ -0,0 +1,166 @@
+#![cfg(feature = "negotiation")]
+
+use axum::{
+    body::Body,
+    extract::{FromRequest, RequestParts},
+    http::{header, Request, StatusCode},
+    response::{Html, IntoResponse, Response},
+    Json,
+};
+use axum_either::{Accept, Negotiate};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct User {
+    pub name: String,
+}
+
+async fn user(accept: Accept) -> Negotiate<Json<User>, Html<String>> {
+    let user = User {
+        name: "Reimu".into(),
+    };
+    Negotiate::new(
+        &accept,
+        user,
+        ("application/json", Json),
+        ("text/html", |user: User| {
+            Html(format!("<h1>{}</h1>", user.name))
+        }),
+    )
+}
+
+async fn respond(accept: Option<&str>) -> Response {
+    let mut request = Request::get("/");
+    if let Some(accept) = accept {
+        request = request.header(header::ACCEPT, accept);
+    }
+    let mut req = RequestParts::new(request.body(Body::empty()).unwrap());
+    let accept = Accept::from_request(&mut req).await.unwrap();
+    user(accept).await.into_response()
+}
+
+fn content_type(response: &Response) -> &str {
+    response.headers()[header::CONTENT_TYPE].to_str().unwrap()
+}
+
+#[tokio::test]
+async fn negotiates_json() {
+    let response = respond(Some("application/json")).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(content_type(&response), "application/json");
+}
+
+#[tokio::test]
+async fn negotiates_html() {
+    let response = respond(Some("text/html,application/xhtml+xml,*/*;q=0.8")).await;
+    assert!(content_type(&response).starts_with("text/html"));
+
+    let response = respond(Some("application/json;q=0.5, text/html")).await;
+    assert!(content_type(&response).starts_with("text/html"));
+}
+
+#[tokio::test]
+async fn wildcard_prefers_left() {
+    let response = respond(Some("*/*")).await;
+    assert_eq!(content_type(&response), "application/json");
+
+    let response = respond(None).await;
+    assert_eq!(content_type(&response), "application/json");
+}
+
+#[tokio::test]
+async fn unsatisfiable_accept_is_not_acceptable() {
+    let response = respond(Some("image/png")).await;
+    assert_eq!(response.status(), StatusCode::NOT_ACCEPTABLE);
+
+    let response = respond(Some("application/json;q=0, text/*;q=0")).await;
+    assert_eq!(response.status(), StatusCode::NOT_ACCEPTABLE);
+}
+
+fn vary(response: &Response) -> Vec<&str> {
+    response
+        .headers()
+        .get_all(header::VARY)
+        .iter()
+        .map(|value| value.to_str().unwrap())
+        .collect()
+}
+
+fn accept(value: &str) -> Accept {
+    let mut headers = axum::http::HeaderMap::new();
+    headers.insert(header::ACCEPT, value.parse().unwrap());
+    Accept::from_headers(&headers)
+}
+
+#[tokio::test]
+async fn negotiated_responses_vary_on_accept() {
+    assert_eq!(vary(&respond(Some("application/json")).await), ["Accept"]);
+    assert_eq!(vary(&respond(Some("text/html")).await), ["Accept"]);
+    assert_eq!(vary(&respond(Some("image/png")).await), ["Accept"]);
+}
+
+#[test]
+fn vary_merges_with_inner_values() {
+    let response = Negotiate::new(
+        &accept("application/json"),
+        (),
+        ("application/json", |()| {
+            ([(header::VARY, "Origin, Accept-Encoding")], Json(1))
+        }),
+        ("text/html", |()| ([(header::VARY, "Origin")], Html(""))),
+    )
+    .into_response();
+    assert_eq!(vary(&response), ["Origin, Accept-Encoding, Accept"]);
+}
+
+#[test]
+fn vary_is_not_duplicated() {
+    let response = Negotiate::<_, Html<&str>>::new(
+        &accept("application/json"),
+        (),
+        ("application/json", |()| {
+            ([(header::VARY, "accept")], Json(1))
+        }),
+        ("text/html", |()| unreachable!()),
+    )
+    .into_response();
+    assert_eq!(vary(&response), ["accept"]);
+
+    let response = Negotiate::<_, Html<&str>>::new(
+        &accept("application/json"),
+        (),
+        ("application/json", |()| ([(header::VARY, "*")], Json(1))),
+        ("text/html", |()| unreachable!()),
+    )
+    .into_response();
+    assert_eq!(vary(&response), ["*"]);
+}
+
+#[test]
+fn plain_either_responses_are_untouched() {
+    let response = axum_either::AxumEither::<Json<u8>, Html<&str>>::Left(Json(1)).into_response();
+    assert!(response.headers().get(header::VARY).is_none());
+    assert!(response.headers().get(header::CONTENT_LOCATION).is_none());
+}
+
+#[test]
+fn content_location_uses_the_negotiated_representation() {
+    let location = |value: &str| {
+        let response = Negotiate::new(
+            &accept(value),
+            1,
+            ("application/json", Json),
+            ("text/html", |id| Html(format!("<h1>{}</h1>", id))),
+        )
+        .content_location("/api/users/1", "/users/1")
+        .into_response();
+        response
+            .headers()
+            .get(header::CONTENT_LOCATION)
+            .map(|location| location.to_str().unwrap().to_owned())
+    };
+
+    assert_eq!(location("application/json").unwrap(), "/api/users/1");
+    assert_eq!(location("text/html").unwrap(), "/users/1");
+    assert_eq!(location("image/png"), None);
+}
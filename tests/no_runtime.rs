@@ -0,0 +1,122 @@
+//! Exercises the core without an async runtime, extraction is driven by a minimal executor.
+//!
+//! The core only depends on `axum-core`, `http`, `http-body`, `bytes`, `mime` and `async-trait`,
+//! check that tokio stays out of its dependency tree with
+//! `cargo tree --no-default-features -e normal -i tokio` and run this test with
+//! `cargo test --no-default-features --test no_runtime`. The test only uses
+//! types of those crates, so it does not pull in axum, hyper or tokio either.
+
+use axum_core::extract::{FromRequest, RequestParts};
+use axum_either::{AxumEither, OneOf3};
+use bytes::Bytes;
+use http::{header, Request, StatusCode};
+use http_body::Full;
+use std::{
+    future::Future,
+    sync::Arc,
+    task::{Context, Poll, Wake, Waker},
+    thread::{self, Thread},
+};
+
+/// Unparks the thread blocked in [`block_on`]
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Poll `future` to completion on the current thread, parking it while the future is pending
+fn block_on<F: Future>(future: F) -> F::Output {
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    let mut future = Box::pin(future);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+fn request(content_type: &str, body: &'static str) -> RequestParts<Full<Bytes>> {
+    RequestParts::new(
+        Request::post("/")
+            .header(header::CONTENT_TYPE, content_type)
+            .body(Full::from(body))
+            .unwrap(),
+    )
+}
+
+/// Extracts `text/plain` bodies, other content types are rejected without reading the body
+#[derive(Debug)]
+struct PlainText(String);
+
+#[async_trait::async_trait]
+impl<B> FromRequest<B> for PlainText
+where
+    B: Send,
+    String: FromRequest<B>,
+{
+    type Rejection = StatusCode;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        let is_text = req
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .is_some_and(|content_type| content_type == "text/plain");
+        if !is_text {
+            return Err(StatusCode::UNSUPPORTED_MEDIA_TYPE);
+        }
+        String::from_request(req)
+            .await
+            .map(Self)
+            .map_err(|_| StatusCode::BAD_REQUEST)
+    }
+}
+
+#[test]
+fn extracts_without_runtime() {
+    type Chain = axum_either::one_of!(PlainText, Bytes, String);
+
+    let chain = block_on(Chain::from_request(&mut request(
+        "application/octet-stream",
+        "[1,2]",
+    )))
+    .unwrap();
+    let len = axum_either::match_one_of! {chain,
+        _ => 0,
+        bytes => bytes.len(),
+        s => s.len(),
+    };
+    assert_eq!(len, 5);
+
+    let flat = block_on(OneOf3::<PlainText, Bytes, String>::from_request(
+        &mut request("text/plain", "text"),
+    ))
+    .unwrap();
+    assert!(matches!(flat, OneOf3::V0(PlainText(ref s)) if s == "text"));
+
+    let rejection = block_on(AxumEither::<PlainText, PlainText>::from_request(
+        &mut request("application/json", "{}"),
+    ))
+    .unwrap_err();
+    assert!(!rejection.to_string().is_empty());
+}
+
+#[test]
+fn combinators_without_runtime() {
+    let either: axum_either::one_of!(u8, u16, u32) = AxumEither::Right(AxumEither::Left(2));
+    let mapped = axum_either::map_one_of! {either,
+        v => v + 1,
+        v => v * 2,
+        v => v,
+    };
+    assert_eq!(mapped, AxumEither::Right(AxumEither::Left(4)));
+
+    let either: AxumEither<u8, &str> = AxumEither::Left(1);
+    assert_eq!(either.map_left(u32::from).left(), Some(1));
+    assert_eq!(either.map_right(str::len).right(), None);
+    assert_eq!(OneOf3::<u8, u8, u8>::V1(3).into_inner(), 3);
+}
@@ -0,0 +1,120 @@
+#![cfg(feature = "observer")]
+
+use axum::{
+    body::Body,
+    extract::{FromRequest, RequestParts},
+    http::{header, Request},
+    Form, Json,
+};
+use axum_either::{
+    observe::{EitherEvent, EitherObserver, Observer},
+    AxumEither, OneOf3, OneOf4,
+};
+use serde::Deserialize;
+use std::sync::{Arc, Mutex};
+
+#[derive(Deserialize, Debug)]
+pub struct Payload {
+    pub id: u32,
+}
+
+#[derive(Clone, Default)]
+pub struct Recorder(Arc<Mutex<Vec<EitherEvent>>>);
+
+impl EitherObserver for Recorder {
+    fn observe(&self, event: EitherEvent) {
+        self.0.lock().unwrap().push(event);
+    }
+}
+
+impl Recorder {
+    fn events(&self) -> Vec<EitherEvent> {
+        self.0.lock().unwrap().clone()
+    }
+}
+
+fn request(recorder: &Recorder, content_type: &str, body: &'static str) -> RequestParts<Body> {
+    RequestParts::new(
+        Request::post("/")
+            .header(header::CONTENT_TYPE, content_type)
+            .extension(Observer::new(recorder.clone()))
+            .body(Body::from(body))
+            .unwrap(),
+    )
+}
+
+#[tokio::test]
+async fn reports_matched_right() {
+    let recorder = Recorder::default();
+    let mut req = request(&recorder, "application/x-www-form-urlencoded", "id=3");
+    AxumEither::<Json<Payload>, Form<Payload>>::from_request(&mut req)
+        .await
+        .unwrap();
+    assert_eq!(recorder.events(), [EitherEvent::Matched(1)]);
+}
+
+#[tokio::test]
+async fn reports_rejected_all() {
+    let recorder = Recorder::default();
+    let mut req = request(&recorder, "text/plain", "id=3");
+    AxumEither::<Json<Payload>, Form<Payload>>::from_request(&mut req)
+        .await
+        .unwrap_err();
+    assert_eq!(recorder.events(), [EitherEvent::RejectedAll]);
+}
+
+#[tokio::test]
+async fn reports_flattened_index_once() {
+    let recorder = Recorder::default();
+    let mut req = request(&recorder, "text/plain", "id=3");
+    <axum_either::one_of!(Json<Payload>, Form<Payload>, String)>::from_request(&mut req)
+        .await
+        .unwrap();
+    assert_eq!(recorder.events(), [EitherEvent::Matched(2)]);
+
+    let recorder = Recorder::default();
+    let mut req = request(&recorder, "application/x-www-form-urlencoded", "id=3");
+    <axum_either::one_of!(Json<Payload>, Form<Payload>, String)>::from_request(&mut req)
+        .await
+        .unwrap();
+    assert_eq!(recorder.events(), [EitherEvent::Matched(1)]);
+}
+
+#[tokio::test]
+async fn reports_nested_rejection_once() {
+    let recorder = Recorder::default();
+    let mut req = request(&recorder, "text/plain", "id=3");
+    <axum_either::one_of!(Json<Payload>, Form<Payload>, Json<u32>)>::from_request(&mut req)
+        .await
+        .unwrap_err();
+    assert_eq!(recorder.events(), [EitherEvent::RejectedAll]);
+}
+
+#[tokio::test]
+async fn flat_reports_like_the_chain() {
+    let recorder = Recorder::default();
+    let mut req = request(&recorder, "text/plain", "id=3");
+    OneOf3::<Json<Payload>, Form<Payload>, String>::from_request(&mut req)
+        .await
+        .unwrap();
+    assert_eq!(recorder.events(), [EitherEvent::Matched(2)]);
+
+    let recorder = Recorder::default();
+    let mut req = request(&recorder, "text/plain", "id=3");
+    OneOf3::<Json<Payload>, Form<Payload>, Json<u32>>::from_request(&mut req)
+        .await
+        .unwrap_err();
+    assert_eq!(recorder.events(), [EitherEvent::RejectedAll]);
+}
+
+#[tokio::test]
+async fn flat_reports_nested_either_index() {
+    let recorder = Recorder::default();
+    let mut req = request(&recorder, "text/plain", "id=3");
+    OneOf4::<Json<Payload>, AxumEither<Form<Payload>, String>, Json<u8>, Json<u16>>::from_request(
+        &mut req,
+    )
+    .await
+    .unwrap();
+    assert_eq!(recorder.events(), [EitherEvent::Matched(2)]);
+}
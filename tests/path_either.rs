@@ -0,0 +1,109 @@
+#![cfg(feature = "axum")]
+
+include!("../examples/path_either.rs");
+
+use reqwest::StatusCode;
+
+async fn test_setup() -> SocketAddr {
+    let listener = StdTcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { run(listener).await });
+    addr
+}
+
+async fn get(addr: SocketAddr, path: &str) -> (StatusCode, String) {
+    let response = reqwest::get(&format!("http://{}{}", addr, path))
+        .await
+        .expect("Error sending request");
+    let status = response.status();
+    (status, response.text().await.unwrap())
+}
+
+#[tokio::test]
+async fn numeric_ids_are_left() {
+    let addr = test_setup().await;
+    assert_eq!(
+        get(addr, "/items/42").await,
+        (StatusCode::OK, "id 42".into())
+    );
+    assert_eq!(get(addr, "/items/0").await, (StatusCode::OK, "id 0".into()));
+    // Leading zeros are parsed as a number
+    assert_eq!(
+        get(addr, "/items/00123").await,
+        (StatusCode::OK, "id 123".into())
+    );
+}
+
+#[tokio::test]
+async fn everything_else_is_a_slug() {
+    let addr = test_setup().await;
+    for (path, slug) in [
+        ("/items/hello-world", "hello-world"),
+        ("/items/-1", "-1"),
+        ("/items/1.5", "1.5"),
+        ("/items/123abc", "123abc"),
+        ("/items/18446744073709551616", "18446744073709551616"),
+        ("/items/caf%C3%A9", "café"),
+    ] {
+        assert_eq!(
+            get(addr, path).await,
+            (StatusCode::OK, format!("slug {}", slug))
+        );
+    }
+}
+
+#[tokio::test]
+async fn empty_segments_do_not_match() {
+    let addr = test_setup().await;
+    assert_eq!(get(addr, "/items/").await.0, StatusCode::NOT_FOUND);
+    assert_eq!(get(addr, "/items").await.0, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn rejection_shows_both_interpretations() {
+    let addr = test_setup().await;
+    assert_eq!(
+        get(addr, "/flags/3").await,
+        (StatusCode::OK, "flag 3".into())
+    );
+    assert_eq!(
+        get(addr, "/flags/true").await,
+        (StatusCode::OK, "flags true".into())
+    );
+
+    let (status, body) = get(addr, "/flags/maybe").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(body.contains("`u64`"), "{}", body);
+    assert!(body.contains("`bool`"), "{}", body);
+}
+
+/// The status of the path rejection without rendering it and the status of its response
+async fn rejection_statuses(
+    rejection: Result<axum::extract::Path<(u32, u32)>, axum::extract::rejection::PathRejection>,
+) -> String {
+    use axum::response::IntoResponse;
+    use axum_either::RejectionInfo;
+
+    let rejection = rejection.unwrap_err();
+    let status = rejection.status();
+    format!("{} {}", status, rejection.into_response().status())
+}
+
+#[tokio::test]
+async fn path_rejection_status_matches_response() {
+    use axum::{body::Body, http::Request, routing::get};
+    use tower::ServiceExt;
+
+    let router = Router::new()
+        .route("/one/:a", get(rejection_statuses))
+        .route("/two/:a/:b", get(rejection_statuses));
+    for (uri, expected) in [
+        ("/one/1", "500 Internal Server Error"),
+        ("/two/1/x", "400 Bad Request"),
+    ] {
+        let request = Request::get(uri).body(Body::empty()).unwrap();
+        let response = router.clone().oneshot(request).await.unwrap();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        assert_eq!(body, format!("{} {}", expected, expected), "{}", uri);
+    }
+}
@@ -0,0 +1,71 @@
+use axum_either::AxumEither;
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Counts how often the inner future was polled, the futures are `!Unpin` async blocks
+struct CountPolls<L, R> {
+    inner: Pin<Box<AxumEither<L, R>>>,
+    polls: usize,
+}
+
+impl<L, R> Future for CountPolls<L, R>
+where
+    L: Future<Output = u32>,
+    R: Future<Output = u32>,
+{
+    type Output = (u32, usize);
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.polls += 1;
+        let output = match self.inner.as_mut().as_pin_mut() {
+            AxumEither::Left(l) => l.poll(cx),
+            AxumEither::Right(r) => r.poll(cx).map(|r| r * 10),
+        };
+        output.map(|output| (output, self.polls))
+    }
+}
+
+async fn yield_once() {
+    let mut yielded = false;
+    std::future::poll_fn(|cx| {
+        if yielded {
+            Poll::Ready(())
+        } else {
+            yielded = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    })
+    .await
+}
+
+fn count_polls<L, R>(inner: AxumEither<L, R>) -> CountPolls<L, R> {
+    CountPolls {
+        inner: Box::pin(inner),
+        polls: 0,
+    }
+}
+
+#[tokio::test]
+async fn custom_future_polls_the_pinned_side() {
+    let left = count_polls(AxumEither::<_, std::future::Ready<u32>>::Left(async {
+        yield_once().await;
+        1
+    }));
+    assert_eq!(left.await, (1, 2));
+
+    let right = count_polls(AxumEither::<std::future::Ready<u32>, _>::Right(async { 2 }));
+    assert_eq!(right.await, (20, 1));
+}
+
+#[test]
+fn as_pin_ref_projects_the_active_side() {
+    let either: Pin<Box<AxumEither<u8, String>>> = Box::pin(AxumEither::Right("right".into()));
+    match either.as_ref().as_pin_ref() {
+        AxumEither::Left(_) => panic!("expected the right side"),
+        AxumEither::Right(r) => assert_eq!(r.len(), 5),
+    }
+}
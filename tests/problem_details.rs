@@ -0,0 +1,115 @@
+#![cfg(feature = "problem-details")]
+
+use axum::{
+    http::{header, HeaderMap, StatusCode},
+    response::IntoResponse,
+};
+use axum_either::{
+    problem::{Problem, ProblemType, PROBLEM_JSON},
+    AxumEitherRejection, ProblemDetails, RejectionFormatter,
+};
+
+pub struct InvalidRequest;
+
+impl ProblemType for InvalidRequest {
+    const TYPE: &'static str = "https://example.com/problems/invalid-request";
+    const TITLE: &'static str = "Invalid request";
+}
+
+async fn problem(response: axum::response::Response) -> Problem {
+    assert_eq!(response.headers()[header::CONTENT_TYPE], PROBLEM_JSON);
+    let bytes = hyper::body::to_bytes(response.into_body()).await.unwrap();
+    serde_json::from_slice(&bytes).expect("Failed to parse problem")
+}
+
+#[tokio::test]
+async fn renders_problem_details() {
+    let rejection = AxumEitherRejection::new(
+        (StatusCode::UNSUPPORTED_MEDIA_TYPE, "left"),
+        (StatusCode::UNPROCESSABLE_ENTITY, "right"),
+    )
+    .with_formatter::<ProblemDetails>();
+    let response = rejection.into_response();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let problem = problem(response).await;
+    assert_eq!(problem.problem_type, "about:blank");
+    assert_eq!(problem.status, 400);
+    assert_eq!(problem.attempts.len(), 2);
+    assert_eq!(problem.attempts[0].status, 415);
+    assert_eq!(problem.attempts[0].detail, "left");
+    assert_eq!(problem.attempts[1].status, 422);
+    assert_eq!(problem.attempts[1].detail, "right");
+}
+
+#[tokio::test]
+async fn uses_configured_problem_type() {
+    let rejection =
+        AxumEitherRejection::new(StatusCode::BAD_REQUEST, StatusCode::SERVICE_UNAVAILABLE)
+            .with_formatter::<ProblemDetails<InvalidRequest>>();
+    let response = rejection.into_response();
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+    let problem = problem(response).await;
+    assert_eq!(problem.problem_type, InvalidRequest::TYPE);
+    assert_eq!(problem.title, "Invalid request");
+    assert_eq!(problem.status, 500);
+    // Empty bodies are described by the reason phrase
+    assert_eq!(problem.attempts[0].detail, "Bad Request");
+    assert_eq!(problem.attempts[1].detail, "Service Unavailable");
+}
+
+#[tokio::test]
+async fn flattens_nested_chains() {
+    let rejection = AxumEitherRejection::new(
+        (StatusCode::UNSUPPORTED_MEDIA_TYPE, "json"),
+        AxumEitherRejection::new(
+            (StatusCode::UNSUPPORTED_MEDIA_TYPE, "form"),
+            (StatusCode::UNPROCESSABLE_ENTITY, "query"),
+        ),
+    )
+    .with_formatter::<ProblemDetails>();
+
+    let problem = problem(rejection.into_response()).await;
+    let attempts: Vec<_> = problem
+        .attempts
+        .iter()
+        .map(|attempt| (attempt.status, attempt.detail.as_str()))
+        .collect();
+    assert_eq!(attempts, [(415, "json"), (415, "form"), (422, "query")]);
+}
+
+#[tokio::test]
+async fn forwards_headers_of_preferred_side() {
+    let rejection = AxumEitherRejection::new(
+        (
+            StatusCode::METHOD_NOT_ALLOWED,
+            [(header::ALLOW, "GET")],
+            "left",
+        ),
+        (StatusCode::BAD_REQUEST, [(header::ALLOW, "PUT")], "right"),
+    )
+    .with_formatter::<ProblemDetails>();
+    let response = rejection.into_response();
+    assert_eq!(response.headers()[header::ALLOW], "GET");
+    assert_eq!(response.headers()[header::CONTENT_TYPE], PROBLEM_JSON);
+
+    let mut allow = HeaderMap::new();
+    allow.insert(header::ALLOW, "GET".parse().unwrap());
+    let headers = <ProblemDetails as RejectionFormatter>::headers(
+        (StatusCode::METHOD_NOT_ALLOWED, allow),
+        (StatusCode::BAD_REQUEST, HeaderMap::new()),
+    );
+    assert_eq!(headers[header::ALLOW], "GET");
+    assert_eq!(headers[header::CONTENT_TYPE], PROBLEM_JSON);
+}
+
+#[tokio::test]
+async fn plain_text_stays_default() {
+    let response =
+        AxumEitherRejection::new(StatusCode::BAD_REQUEST, StatusCode::BAD_REQUEST).into_response();
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "text/plain; charset=utf-8"
+    );
+}
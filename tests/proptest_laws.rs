@@ -0,0 +1,50 @@
+#![cfg(feature = "proptest")]
+
+use axum_either::{
+    proptest::{strategy, EitherParameters},
+    AxumEither,
+};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn map_lr_identity(either in any::<AxumEither<u32, String>>()) {
+        prop_assert_eq!(either.clone().map_lr(|l| l, |r| r), either);
+    }
+
+    #[test]
+    fn map_lr_composition(either in any::<axum_either::one_of!(u8, i16, bool)>()) {
+        let f = |l: u8| u32::from(l) * 3;
+        let g = |l: u32| l + 1;
+        let h = |r: AxumEither<i16, bool>| r.map_left(i32::from);
+        let k = |r: AxumEither<i32, bool>| r.map_right(|b| !b);
+
+        prop_assert_eq!(
+            either.map_lr(f, h).map_lr(g, k),
+            either.map_lr(|l| g(f(l)), |r| k(h(r)))
+        );
+    }
+
+    #[test]
+    fn map_lr_is_map_left_then_map_right(either in any::<AxumEither<u8, u8>>()) {
+        prop_assert_eq!(
+            either.map_lr(|l| l / 2, |r| r.wrapping_add(1)),
+            either.map_left(|l| l / 2).map_right(|r| r.wrapping_add(1))
+        );
+    }
+
+    #[test]
+    fn left_weight_100_is_always_left(either in strategy(any::<u8>(), any::<u8>(), 100)) {
+        prop_assert!(either.left().is_some());
+    }
+
+    #[test]
+    fn left_weight_0_is_always_right(
+        either in any_with::<AxumEither<u8, u8>>(EitherParameters {
+            left_weight: 0,
+            ..Default::default()
+        })
+    ) {
+        prop_assert!(either.right().is_some());
+    }
+}
@@ -0,0 +1,121 @@
+#![cfg(feature = "qs-form")]
+
+use axum::{
+    body::Body,
+    extract::{FromRequest, RequestParts},
+    http::{Method, Request, StatusCode},
+    Form, Json,
+};
+use axum_either::{
+    rejection::QsFormRejection, AxumEither, MediaTypeDispatch, QsForm, RejectionInfo,
+};
+use serde::Deserialize;
+
+mod common;
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct Item {
+    pub id: u32,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct Order {
+    pub customer: String,
+    pub items: Vec<Item>,
+}
+
+type Either = AxumEither<Json<Order>, QsForm<Order>>;
+
+fn order() -> Order {
+    Order {
+        customer: "Marisa".into(),
+        items: vec![
+            Item {
+                id: 3,
+                tags: vec!["a".into(), "b".into()],
+            },
+            Item {
+                id: 4,
+                tags: vec![],
+            },
+        ],
+    }
+}
+
+const NESTED: &str =
+    "customer=Marisa&items[0][id]=3&items[0][tags][0]=a&items[0][tags][1]=b&items[1][id]=4";
+
+#[tokio::test]
+async fn nested_form_wins_over_json() {
+    let mut req = common::post("application/x-www-form-urlencoded", NESTED);
+    match Either::from_request(&mut req).await.unwrap() {
+        AxumEither::Right(QsForm(extracted)) => assert_eq!(extracted, order()),
+        AxumEither::Left(_) => panic!("the json side accepted a form"),
+    }
+}
+
+#[tokio::test]
+async fn axum_form_rejects_the_same_payload() {
+    let mut req = common::post("application/x-www-form-urlencoded", NESTED);
+    assert!(Form::<Order>::from_request(&mut req).await.is_err());
+}
+
+#[tokio::test]
+async fn percent_encoded_brackets() {
+    let mut req = common::post(
+        "application/x-www-form-urlencoded; charset=utf-8",
+        "customer=Marisa&items%5B0%5D%5Bid%5D=3&items%5B0%5D%5Btags%5D%5B0%5D=a\
+         &items%5B0%5D%5Btags%5D%5B1%5D=b&items%5B1%5D%5Bid%5D=4",
+    );
+    let QsForm(extracted) = QsForm::<Order>::from_request(&mut req).await.unwrap();
+    assert_eq!(extracted, order());
+}
+
+#[tokio::test]
+async fn json_still_wins_for_json() {
+    let mut req = common::post(
+        "application/json",
+        r#"{"customer":"Marisa","items":[{"id":3,"tags":["a","b"]},{"id":4,"tags":[]}]}"#,
+    );
+    match Either::from_request(&mut req).await.unwrap() {
+        AxumEither::Left(Json(extracted)) => assert_eq!(extracted, order()),
+        AxumEither::Right(_) => panic!("the form side accepted json"),
+    }
+}
+
+#[tokio::test]
+async fn get_requests_read_the_query() {
+    let mut req = RequestParts::new(
+        Request::builder()
+            .method(Method::GET)
+            .uri(format!("/?{}", NESTED))
+            .body(Body::empty())
+            .unwrap(),
+    );
+    let QsForm(extracted) = QsForm::<Order>::from_request(&mut req).await.unwrap();
+    assert_eq!(extracted, order());
+}
+
+#[tokio::test]
+async fn rejections() {
+    let mut req = common::post("text/plain", NESTED);
+    let rejection = QsForm::<Order>::from_request(&mut req).await.unwrap_err();
+    assert!(matches!(rejection, QsFormRejection::InvalidContentType));
+    assert_eq!(rejection.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+
+    let mut req = common::post(
+        "application/x-www-form-urlencoded",
+        "customer=Marisa&items[0][id]=x",
+    );
+    let rejection = QsForm::<Order>::from_request(&mut req).await.unwrap_err();
+    assert!(matches!(rejection, QsFormRejection::Deserialize(_)));
+    assert_eq!(rejection.status(), StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[test]
+fn dispatches_forms() {
+    assert_eq!(Either::claims(&mime::APPLICATION_WWW_FORM_URLENCODED), [1]);
+    assert_eq!(Either::claims(&mime::APPLICATION_JSON), [0]);
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "recorder")]
+
+include!("../examples/variant_stats.rs");
+
+async fn test_setup() -> SocketAddr {
+    let listener = StdTcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { run(listener).await });
+    addr
+}
+
+async fn counts(client: &reqwest::Client, addr: SocketAddr) -> HashMap<String, u64> {
+    client
+        .get(format!("http://{}/admin/variants", addr))
+        .send()
+        .await
+        .expect("Error sending request")
+        .json()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn counts_mixed_format_traffic() {
+    let addr = test_setup().await;
+    let client = reqwest::Client::new();
+
+    for name in ["Reimu", "Marisa", "Sakuya"] {
+        let response = client
+            .post(format!("http://{}/hello", addr))
+            .json(&HashMap::from([("name", name)]))
+            .send()
+            .await
+            .expect("Error sending request");
+        assert_eq!(response.text().await.unwrap(), format!("Hello {}!", name));
+    }
+    let response = client
+        .post(format!("http://{}/hello", addr))
+        .form(&[("name", "Youmu")])
+        .send()
+        .await
+        .expect("Error sending request");
+    assert_eq!(response.text().await.unwrap(), "Hello Youmu!");
+
+    let counts = counts(&client, addr).await;
+    assert_eq!(counts.len(), 2);
+    assert_eq!(counts["axum::Json<recorder::HelloRequest>"], 3);
+    assert_eq!(counts["axum::Form<recorder::HelloRequest>"], 1);
+}
+
+#[tokio::test]
+async fn rejected_requests_are_not_counted() {
+    let addr = test_setup().await;
+    let client = reqwest::Client::new();
+
+    let response = client
+        .post(format!("http://{}/hello", addr))
+        .body("name=Reimu")
+        .send()
+        .await
+        .expect("Error sending request");
+    assert!(response.status().is_client_error());
+
+    assert!(counts(&client, addr).await.is_empty());
+}
+
+#[tokio::test]
+async fn handle_can_be_reset() {
+    let handle = VariantRecorderLayer::new().handle();
+    assert!(handle.counts().is_empty());
+    handle.reset();
+    assert!(handle.counts().is_empty());
+}
@@ -0,0 +1,55 @@
+include!("../examples/redirect_or_json.rs");
+
+use reqwest::{header, redirect::Policy, StatusCode};
+
+async fn test_setup() -> SocketAddr {
+    let listener = StdTcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { run(listener).await });
+    addr
+}
+
+fn client() -> reqwest::Client {
+    reqwest::Client::builder()
+        .redirect(Policy::none())
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn form_redirects() {
+    let addr = test_setup().await;
+    let response = client()
+        .post(format!("http://{}/subscribe", addr))
+        .form(&SubscribeRequest {
+            email: "reimu@example.com".into(),
+        })
+        .send()
+        .await
+        .expect("Error sending request");
+
+    assert_eq!(response.status(), StatusCode::SEE_OTHER);
+    assert_eq!(response.headers()[header::LOCATION], "/subscribed");
+}
+
+#[tokio::test]
+async fn json_responds_with_body() {
+    let addr = test_setup().await;
+    let response = client()
+        .post(format!("http://{}/subscribe", addr))
+        .json(&SubscribeRequest {
+            email: "reimu@example.com".into(),
+        })
+        .send()
+        .await
+        .expect("Error sending request");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let response: SubscribeResponse = response.json().await.expect("Failed to parse response");
+    assert_eq!(
+        response,
+        SubscribeResponse {
+            subscribed: "reimu@example.com".into()
+        }
+    );
+}
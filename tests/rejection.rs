@@ -0,0 +1,563 @@
+use axum::{
+    extract::FromRequest,
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Form, Json,
+};
+use axum_either::{
+    AxumEither, AxumEitherRejection, BothRejections, EscalateServerErrors, PreferClientErrors,
+    PreferLeftRejection, Redacted, RejectionFormatter, SingleRejection, WithFormatter,
+};
+use serde::Deserialize;
+
+mod common;
+
+#[derive(Deserialize, Debug)]
+pub struct Payload {
+    pub id: u32,
+}
+
+type JsonOrForm = AxumEither<Json<Payload>, Form<Payload>>;
+
+async fn body_text(response: Response) -> String {
+    let bytes = hyper::body::to_bytes(response.into_body()).await.unwrap();
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+#[tokio::test]
+async fn single_rejection_reports_content_type_match() {
+    let rejection = AxumEitherRejection::new(
+        (StatusCode::UNSUPPORTED_MEDIA_TYPE, "left"),
+        (StatusCode::UNPROCESSABLE_ENTITY, "right"),
+    )
+    .with_formatter::<SingleRejection>();
+    let response = rejection.into_response();
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body_text(response).await, "right");
+}
+
+#[tokio::test]
+async fn rejection_can_be_built_from_its_fields() {
+    let rejection = AxumEitherRejection {
+        left_error: (StatusCode::UNSUPPORTED_MEDIA_TYPE, "left"),
+        right_error: (StatusCode::UNPROCESSABLE_ENTITY, "right"),
+    };
+    let response = rejection
+        .with_formatter::<SingleRejection>()
+        .into_response();
+    assert_eq!(body_text(response).await, "right");
+}
+
+#[tokio::test]
+async fn single_rejection_defaults_to_left() {
+    let rejection = AxumEitherRejection::new(
+        (StatusCode::UNSUPPORTED_MEDIA_TYPE, "left"),
+        (StatusCode::UNSUPPORTED_MEDIA_TYPE, "right"),
+    )
+    .with_formatter::<SingleRejection>();
+    let response = rejection.into_response();
+    assert_eq!(response.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    assert_eq!(body_text(response).await, "left");
+
+    let rejection = AxumEitherRejection::new(
+        (StatusCode::BAD_REQUEST, "left"),
+        (StatusCode::BAD_REQUEST, "right"),
+    )
+    .with_formatter::<SingleRejection>();
+    assert_eq!(body_text(rejection.into_response()).await, "left");
+}
+
+#[tokio::test]
+async fn prefer_left_rejection_hides_json_error_for_forms() {
+    let mut req = common::post("application/x-www-form-urlencoded", "name=Reimu");
+    let rejection = PreferLeftRejection::<JsonOrForm>::from_request(&mut req)
+        .await
+        .expect_err("the form is missing the id field");
+    assert_eq!(
+        rejection.into_inner().left_error.into_response().status(),
+        StatusCode::UNSUPPORTED_MEDIA_TYPE
+    );
+
+    let mut req = common::post("application/x-www-form-urlencoded", "name=Reimu");
+    let response = PreferLeftRejection::<JsonOrForm>::from_request(&mut req)
+        .await
+        .expect_err("the form is missing the id field")
+        .into_response();
+    assert_ne!(response.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    assert!(!body_text(response).await.contains("application/json"));
+}
+
+#[tokio::test]
+async fn prefer_left_rejection_falls_back_to_left_error() {
+    let mut req = common::post("text/plain", "id=10");
+    let response = PreferLeftRejection::<JsonOrForm>::from_request(&mut req)
+        .await
+        .expect_err("neither side accepts text/plain")
+        .into_response();
+    assert_eq!(response.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    assert!(body_text(response).await.contains("application/json"));
+}
+
+#[tokio::test]
+async fn prefer_left_rejection_extracts() {
+    let mut req = common::post("application/x-www-form-urlencoded", "id=10");
+    let either = PreferLeftRejection::<JsonOrForm>::from_request(&mut req)
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(either.right().map(|Form(p)| p.id), Some(10));
+}
+
+#[test]
+fn both_rejections_forwards_left_headers() {
+    let rejection = AxumEitherRejection::new(
+        (
+            StatusCode::UNAUTHORIZED,
+            [(header::WWW_AUTHENTICATE, "Bearer")],
+            "missing token",
+        ),
+        (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            [(header::ALLOW, "POST")],
+            "invalid body",
+        ),
+    );
+    let response = rejection.into_response();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(response.headers()[header::WWW_AUTHENTICATE], "Bearer");
+    assert!(response.headers().get(header::ALLOW).is_none());
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "text/plain; charset=utf-8"
+    );
+}
+
+#[test]
+fn both_rejections_forwards_server_error_headers() {
+    let rejection = AxumEitherRejection::new(
+        (
+            StatusCode::UNAUTHORIZED,
+            [(header::WWW_AUTHENTICATE, "Bearer")],
+            "missing token",
+        ),
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            [(header::RETRY_AFTER, "120")],
+            "rate limited",
+        ),
+    );
+    let response = rejection.into_response();
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(response.headers()[header::RETRY_AFTER], "120");
+    assert!(response.headers().get(header::WWW_AUTHENTICATE).is_none());
+}
+
+#[tokio::test]
+async fn redacted_rejection_hides_request_content() {
+    let mut req = common::post("application/json", r#"{"id": "MARKER-1234"}"#);
+    let response = WithFormatter::<JsonOrForm, Redacted<SingleRejection>>::from_request(&mut req)
+        .await
+        .expect_err("id is not a number")
+        .into_response();
+    assert!(response.status().is_client_error());
+    assert!(!body_text(response).await.contains("MARKER-1234"));
+
+    let mut req = common::post("application/json", r#"{"id": "MARKER-1234"}"#);
+    let response = WithFormatter::<JsonOrForm, Redacted>::from_request(&mut req)
+        .await
+        .expect_err("id is not a number")
+        .into_response();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert!(!body_text(response).await.contains("MARKER-1234"));
+}
+
+#[tokio::test]
+async fn redacted_rejection_caps_body() {
+    let long = "MARKER".repeat(1000);
+    let rejection = AxumEitherRejection::new(
+        (StatusCode::BAD_REQUEST, long.clone()),
+        (StatusCode::BAD_REQUEST, long),
+    )
+    .with_formatter::<Redacted<BothRejections, 32>>();
+    let response = rejection.into_response();
+    assert!(response.headers().get(header::CONTENT_LENGTH).is_none());
+    assert!(body_text(response).await.len() <= 32);
+}
+
+#[tokio::test]
+async fn redacted_rejection_describes_kind() {
+    let mut req = common::post("application/json", r#"{"id": "MARKER-1234"}"#);
+    let response = WithFormatter::<JsonOrForm, Redacted<SingleRejection>>::from_request(&mut req)
+        .await
+        .expect_err("id is not a number")
+        .into_response();
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "text/plain; charset=utf-8"
+    );
+    assert_eq!(body_text(response).await, "JSON deserialization failed");
+}
+
+#[tokio::test]
+async fn redacted_rejection_keeps_content_type() {
+    let rejection = AxumEitherRejection::new(
+        (
+            StatusCode::BAD_REQUEST,
+            [(header::CONTENT_TYPE, "application/json")],
+            r#"{"value":"MARKER"}"#,
+        ),
+        StatusCode::UNSUPPORTED_MEDIA_TYPE,
+    )
+    .with_formatter::<Redacted<SingleRejection>>();
+    let response = rejection.into_response();
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+    let body: serde_json::Value = serde_json::from_str(&body_text(response).await).unwrap();
+    assert_eq!(body["message"], "The request could not be parsed");
+}
+
+#[tokio::test]
+async fn redacted_rejection_sends_truncated_json_as_text() {
+    let rejection = AxumEitherRejection::new(
+        (
+            StatusCode::BAD_REQUEST,
+            [(header::CONTENT_TYPE, "application/json")],
+            r#"{"value":"MARKER"}"#,
+        ),
+        StatusCode::UNSUPPORTED_MEDIA_TYPE,
+    )
+    .with_formatter::<Redacted<SingleRejection, 16>>();
+    let response = rejection.into_response();
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "text/plain; charset=utf-8"
+    );
+    assert_eq!(body_text(response).await, r#"{"message":"The "#);
+}
+
+/// Responds with a non ASCII body regardless of the rejections
+struct Umlauts;
+
+impl RejectionFormatter for Umlauts {
+    fn format(_left: Response, _right: Response) -> Response {
+        "äöü".into_response()
+    }
+}
+
+#[tokio::test]
+async fn redacted_rejection_truncates_on_char_boundaries() {
+    let rejection = AxumEitherRejection::new(StatusCode::BAD_REQUEST, StatusCode::BAD_REQUEST)
+        .with_formatter::<Redacted<Umlauts, 5>>();
+    assert_eq!(body_text(rejection.into_response()).await, "äö");
+}
+
+#[tokio::test]
+async fn map_left_error_replaces_left_error() {
+    let rejection = AxumEitherRejection::new(
+        (StatusCode::UNPROCESSABLE_ENTITY, "left"),
+        (StatusCode::UNSUPPORTED_MEDIA_TYPE, "right"),
+    )
+    .with_formatter::<SingleRejection>()
+    .map_left_error(|_| (StatusCode::BAD_REQUEST, "friendly"));
+    assert_eq!(rejection.right_error.1, "right");
+
+    let response = rejection.into_response();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(body_text(response).await, "friendly");
+}
+
+#[tokio::test]
+async fn map_right_error_replaces_right_error() {
+    let rejection = AxumEitherRejection::new(
+        (StatusCode::UNSUPPORTED_MEDIA_TYPE, "left"),
+        (StatusCode::UNPROCESSABLE_ENTITY, "right"),
+    )
+    .with_formatter::<SingleRejection>()
+    .map_right_error(|(status, _)| (status, "friendly"));
+    assert_eq!(rejection.left_error.1, "left");
+
+    let response = rejection.into_response();
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body_text(response).await, "friendly");
+}
+
+#[test]
+fn map_errors_maps_both_sides() {
+    let rejection = AxumEitherRejection::new(StatusCode::BAD_REQUEST, StatusCode::NOT_FOUND)
+        .map_errors(|l| l.as_u16().to_string(), |r| r.as_u16().to_string());
+    assert_eq!(rejection.into_errors(), ("400".into(), "404".into()));
+}
+
+#[test]
+fn accessors_return_both_errors() {
+    let rejection = AxumEitherRejection::new(StatusCode::BAD_REQUEST, StatusCode::NOT_FOUND);
+    assert_eq!(*rejection.left(), StatusCode::BAD_REQUEST);
+    assert_eq!(*rejection.right(), StatusCode::NOT_FOUND);
+    assert_eq!(
+        rejection.into_parts(),
+        (StatusCode::BAD_REQUEST, StatusCode::NOT_FOUND)
+    );
+}
+
+#[test]
+fn into_either_by_selects_side() {
+    let rejection = AxumEitherRejection::new(
+        StatusCode::UNSUPPORTED_MEDIA_TYPE,
+        StatusCode::UNPROCESSABLE_ENTITY,
+    );
+    let interesting = |l: &StatusCode, _: &StatusCode| *l != StatusCode::UNSUPPORTED_MEDIA_TYPE;
+    assert_eq!(
+        rejection.into_either_by(interesting),
+        AxumEither::Right(StatusCode::UNPROCESSABLE_ENTITY)
+    );
+
+    let rejection = AxumEitherRejection::new(
+        StatusCode::UNPROCESSABLE_ENTITY,
+        StatusCode::UNSUPPORTED_MEDIA_TYPE,
+    );
+    assert_eq!(
+        rejection.into_either_by(interesting),
+        AxumEither::Left(StatusCode::UNPROCESSABLE_ENTITY)
+    );
+}
+
+#[test]
+fn biased_conversions_keep_one_side() {
+    let rejection = AxumEitherRejection::new(StatusCode::BAD_REQUEST, StatusCode::NOT_FOUND);
+    assert_eq!(
+        rejection.into_left_biased(),
+        AxumEither::Left(StatusCode::BAD_REQUEST)
+    );
+    assert_eq!(
+        rejection.into_right_biased(),
+        AxumEither::Right(StatusCode::NOT_FOUND)
+    );
+}
+
+#[test]
+fn into_rejection_with_only_builds_the_missing_side() {
+    use std::cell::Cell;
+
+    let left_calls = Cell::new(0);
+    let right_calls = Cell::new(0);
+    let missing_left = || {
+        left_calls.set(left_calls.get() + 1);
+        StatusCode::UNSUPPORTED_MEDIA_TYPE
+    };
+    let missing_right = || {
+        right_calls.set(right_calls.get() + 1);
+        StatusCode::NOT_FOUND
+    };
+
+    let error: AxumEither<StatusCode, StatusCode> = AxumEither::Left(StatusCode::BAD_REQUEST);
+    let rejection = error.into_rejection_with(missing_left, missing_right);
+    assert_eq!(
+        rejection.into_parts(),
+        (StatusCode::BAD_REQUEST, StatusCode::NOT_FOUND)
+    );
+    assert_eq!((left_calls.get(), right_calls.get()), (0, 1));
+
+    let error: AxumEither<StatusCode, StatusCode> = AxumEither::Right(StatusCode::CONFLICT);
+    let rejection = error.into_rejection_with(missing_left, missing_right);
+    assert_eq!(
+        rejection.into_parts(),
+        (StatusCode::UNSUPPORTED_MEDIA_TYPE, StatusCode::CONFLICT)
+    );
+    assert_eq!((left_calls.get(), right_calls.get()), (1, 1));
+
+    let roundtrip = AxumEither::<StatusCode, StatusCode>::Right(StatusCode::CONFLICT)
+        .into_rejection_with(missing_left, missing_right)
+        .into_right_biased();
+    assert_eq!(roundtrip, AxumEither::Right(StatusCode::CONFLICT));
+}
+
+fn status_with<F: RejectionFormatter>(left: StatusCode, right: StatusCode) -> StatusCode {
+    AxumEitherRejection::new(left, right)
+        .with_formatter::<F>()
+        .into_response()
+        .status()
+}
+
+#[test]
+fn escalate_server_errors_policy() {
+    type F = BothRejections<EscalateServerErrors>;
+    let client = StatusCode::UNPROCESSABLE_ENTITY;
+    let server = StatusCode::SERVICE_UNAVAILABLE;
+    assert_eq!(
+        status_with::<F>(client, server),
+        StatusCode::INTERNAL_SERVER_ERROR
+    );
+    assert_eq!(
+        status_with::<F>(server, client),
+        StatusCode::INTERNAL_SERVER_ERROR
+    );
+    assert_eq!(
+        status_with::<F>(server, server),
+        StatusCode::INTERNAL_SERVER_ERROR
+    );
+    assert_eq!(status_with::<F>(client, client), StatusCode::BAD_REQUEST);
+}
+
+#[test]
+fn prefer_client_errors_policy() {
+    type F = BothRejections<PreferClientErrors>;
+    let client = StatusCode::UNPROCESSABLE_ENTITY;
+    let server = StatusCode::SERVICE_UNAVAILABLE;
+    assert_eq!(status_with::<F>(client, server), StatusCode::BAD_REQUEST);
+    assert_eq!(status_with::<F>(server, client), StatusCode::BAD_REQUEST);
+    assert_eq!(
+        status_with::<F>(server, server),
+        StatusCode::INTERNAL_SERVER_ERROR
+    );
+    assert_eq!(status_with::<F>(client, client), StatusCode::BAD_REQUEST);
+}
+
+#[test]
+fn prefer_client_errors_forwards_client_headers() {
+    let rejection = AxumEitherRejection::new(
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            [(header::RETRY_AFTER, "120")],
+            "backend down",
+        ),
+        (
+            StatusCode::UNAUTHORIZED,
+            [(header::WWW_AUTHENTICATE, "Bearer")],
+            "missing token",
+        ),
+    )
+    .with_formatter::<BothRejections<PreferClientErrors>>();
+    let response = rejection.into_response();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(response.headers()[header::WWW_AUTHENTICATE], "Bearer");
+    assert!(response.headers().get(header::RETRY_AFTER).is_none());
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Measurement {
+    #[serde(rename = "größe")]
+    pub size: u32,
+}
+
+#[tokio::test]
+async fn rejection_declares_utf8_charset() {
+    let mut req = common::post("application/json", r#"{"größe": "groß"}"#);
+    let response = AxumEither::<Json<Measurement>, Form<Measurement>>::from_request(&mut req)
+        .await
+        .expect_err("größe is not a number")
+        .into_response();
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        BothRejections::<EscalateServerErrors>::CONTENT_TYPE
+    );
+    assert!(body_text(response)
+        .await
+        .starts_with("Could not parse request"));
+
+    let mut req = common::post("application/json", r#"{"größe": "groß"}"#);
+    let response =
+        PreferLeftRejection::<AxumEither<Json<Measurement>, Form<Measurement>>>::from_request(
+            &mut req,
+        )
+        .await
+        .expect_err("größe is not a number")
+        .into_response();
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "text/plain; charset=utf-8"
+    );
+    assert!(body_text(response).await.contains("\"groß\""));
+}
+
+/// Checks that `preferred` selects the side whose headers end up in the rendered response
+fn assert_preferred_matches_response<F: RejectionFormatter>(left: StatusCode, right: StatusCode) {
+    let rejection = AxumEitherRejection::new(
+        (left, [(header::WARNING, "left")], "left"),
+        (right, [(header::WARNING, "right")], "right"),
+    );
+    let rendered = rejection.with_formatter::<F>().into_response();
+
+    let rejection = AxumEitherRejection::new(left, right).with_formatter::<F>();
+    let expected = match rejection.preferred() {
+        AxumEither::Left(_) => "left",
+        AxumEither::Right(_) => "right",
+    };
+    assert_eq!(
+        rendered.headers()[header::WARNING],
+        expected,
+        "{} {}",
+        left,
+        right
+    );
+    assert_eq!(
+        rejection.into_preferred().map_lr(Some, Some).into_inner(),
+        Some(if expected == "left" { left } else { right })
+    );
+}
+
+#[test]
+fn preferred_follows_status_matrix() {
+    let statuses = [
+        StatusCode::BAD_REQUEST,
+        StatusCode::UNSUPPORTED_MEDIA_TYPE,
+        StatusCode::INTERNAL_SERVER_ERROR,
+        StatusCode::SERVICE_UNAVAILABLE,
+    ];
+    for left in statuses {
+        for right in statuses {
+            assert_preferred_matches_response::<BothRejections>(left, right);
+            assert_preferred_matches_response::<BothRejections<PreferClientErrors>>(left, right);
+            assert_preferred_matches_response::<SingleRejection>(left, right);
+        }
+    }
+}
+
+#[test]
+fn preferred_status_matrix() {
+    let client = StatusCode::UNPROCESSABLE_ENTITY;
+    let server = StatusCode::BAD_GATEWAY;
+    let media = StatusCode::UNSUPPORTED_MEDIA_TYPE;
+
+    let preferred = |l: StatusCode, r: StatusCode| AxumEitherRejection::new(l, r).into_preferred();
+    assert_eq!(preferred(client, server), AxumEither::Right(server));
+    assert_eq!(preferred(server, client), AxumEither::Left(server));
+    assert_eq!(preferred(server, server), AxumEither::Left(server));
+    assert_eq!(preferred(client, client), AxumEither::Left(client));
+
+    let preferred = |l: StatusCode, r: StatusCode| {
+        AxumEitherRejection::new(l, r)
+            .with_formatter::<SingleRejection>()
+            .into_preferred()
+    };
+    assert_eq!(preferred(media, client), AxumEither::Right(client));
+    assert_eq!(preferred(media, media), AxumEither::Left(media));
+    assert_eq!(preferred(client, media), AxumEither::Left(client));
+}
+
+#[tokio::test]
+async fn both_rejections_renders_both_errors() {
+    let rejection = AxumEitherRejection::new(
+        (
+            StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            [("x-left", "1")],
+            "left",
+        ),
+        (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            [("x-right", "1")],
+            "right",
+        ),
+    );
+    let response = rejection.into_response();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        BothRejections::<EscalateServerErrors>::CONTENT_TYPE
+    );
+    assert!(response.headers().contains_key("x-left"));
+    assert!(!response.headers().contains_key("x-right"));
+
+    assert_eq!(
+        body_text(response).await,
+        "Could not parse request\n\tleft error: left\n\tright error: right"
+    );
+}
@@ -0,0 +1,37 @@
+#![cfg(all(feature = "serde", feature = "axum"))]
+
+use axum::{extract::FromRequest, Form, Json};
+use axum_either::AxumEither;
+use serde::Deserialize;
+use serde_json::json;
+
+mod common;
+
+#[derive(Deserialize, Debug)]
+pub struct Payload {
+    pub id: u32,
+}
+
+#[tokio::test]
+async fn serializes_json_form_double_rejection() {
+    let mut req = common::post("text/plain", "id=3");
+    let rejection = AxumEither::<Json<Payload>, Form<Payload>>::from_request(&mut req)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        serde_json::to_value(&rejection).unwrap(),
+        json!([
+            {
+                "type_name": "axum::extract::rejection::JsonRejection",
+                "status": 415,
+                "message": "Expected request with `Content-Type: application/json`",
+            },
+            {
+                "type_name": "axum::extract::rejection::FormRejection",
+                "status": 415,
+                "message": "Form requests must have `Content-Type: application/x-www-form-urlencoded`",
+            },
+        ])
+    );
+}
@@ -0,0 +1,168 @@
+use axum::{
+    http::{header, StatusCode},
+    response::{AppendHeaders, IntoResponse},
+    Json,
+};
+use axum_either::{AxumEither, Negotiate, Tagged, VARIANT_HEADER};
+
+type CacheHeaders = AxumEither<
+    [(header::HeaderName, &'static str); 1],
+    AppendHeaders<header::HeaderName, &'static str, 2>,
+>;
+
+#[test]
+fn either_parts_left() {
+    let parts: CacheHeaders = AxumEither::Left([(header::CACHE_CONTROL, "max-age=3600")]);
+    let response = (parts, Json(1)).into_response();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[header::CACHE_CONTROL], "max-age=3600");
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+}
+
+#[test]
+fn either_parts_right() {
+    let parts: CacheHeaders = AxumEither::Right(AppendHeaders([
+        (header::VARY, "accept"),
+        (header::VARY, "accept-language"),
+    ]));
+    let response = (StatusCode::CREATED, parts, Json(1)).into_response();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let vary: Vec<_> = response.headers().get_all(header::VARY).iter().collect();
+    assert_eq!(vary, ["accept", "accept-language"]);
+}
+
+#[test]
+fn either_parts_error() {
+    type Parts =
+        AxumEither<[(&'static str, &'static str); 1], [(header::HeaderName, &'static str); 1]>;
+    let parts: Parts = AxumEither::Left([("invalid header name", "value")]);
+    let response = (parts, "body").into_response();
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[test]
+fn tagged_response_has_flattened_index() {
+    type Chain = axum_either::one_of!(&'static str, String, Json<u8>, StatusCode);
+
+    let responses: [(Chain, &str); 4] = [
+        (AxumEither::Left("a"), "0"),
+        (AxumEither::Right(AxumEither::Left("b".into())), "1"),
+        (
+            AxumEither::Right(AxumEither::Right(AxumEither::Left(Json(3)))),
+            "2",
+        ),
+        (
+            AxumEither::Right(AxumEither::Right(AxumEither::Right(StatusCode::CREATED))),
+            "3",
+        ),
+    ];
+    for (either, index) in responses {
+        let response = either.tag_variant().into_response();
+        assert_eq!(response.headers()[VARIANT_HEADER], index);
+    }
+
+    let response = Tagged(AxumEither::<(), ()>::Right(())).into_response();
+    assert_eq!(response.headers()[VARIANT_HEADER], "1");
+}
+
+#[test]
+fn tagged_negotiate_chain_has_flattened_index() {
+    type Chain = Negotiate<Json<u8>, Negotiate<&'static str, StatusCode>>;
+
+    let responses: [(Chain, &str); 3] = [
+        (Negotiate(Some(AxumEither::Left(Json(1)))), "0"),
+        (
+            Negotiate(Some(AxumEither::Right(Negotiate(Some(AxumEither::Left(
+                "text",
+            )))))),
+            "1",
+        ),
+        (
+            Negotiate(Some(AxumEither::Right(Negotiate(Some(AxumEither::Right(
+                StatusCode::CREATED,
+            )))))),
+            "2",
+        ),
+    ];
+    for (negotiated, index) in responses {
+        let response = Tagged(negotiated).into_response();
+        assert_eq!(response.headers()[VARIANT_HEADER], index);
+    }
+}
+
+#[test]
+fn untagged_response_has_no_header() {
+    let either: axum_either::one_of!(&'static str, String, StatusCode) =
+        AxumEither::Right(AxumEither::Left("b".into()));
+    let response = either.into_response();
+    assert!(response.headers().get(VARIANT_HEADER).is_none());
+}
+
+#[tokio::test]
+async fn with_status_overrides_both_variants() {
+    let either: AxumEither<Json<u8>, (StatusCode, &'static str)> = AxumEither::Left(Json(1));
+    let response = either.with_status(StatusCode::CREATED).into_response();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+
+    let either: AxumEither<Json<u8>, (StatusCode, &'static str)> =
+        AxumEither::Right((StatusCode::ACCEPTED, "queued"));
+    let response = either.with_status(StatusCode::CREATED).into_response();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "text/plain; charset=utf-8"
+    );
+    let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+    assert_eq!(&body[..], b"queued");
+}
+
+#[tokio::test]
+async fn to_response_shares_bytes() {
+    let page = bytes::Bytes::from_static(b"<h1>cached</h1>");
+    let cached: AxumEither<axum::response::Html<bytes::Bytes>, Json<u8>> =
+        AxumEither::Left(axum::response::Html(page.clone()));
+
+    for _ in 0..2 {
+        let response = cached.to_response();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        assert_eq!(body, page);
+        assert_eq!(body.as_ptr(), page.as_ptr());
+    }
+}
+
+#[test]
+fn into_response_with_runs_once_per_variant() {
+    let variants: [AxumEither<Json<u8>, StatusCode>; 2] = [
+        AxumEither::Left(Json(1)),
+        AxumEither::Right(StatusCode::ACCEPTED),
+    ];
+    for either in variants {
+        let mut calls = 0;
+        let response = either.into_response_with(|mut response| {
+            calls += 1;
+            response
+                .headers_mut()
+                .insert("x-debug", header::HeaderValue::from_static("1"));
+            response
+        });
+        assert_eq!(calls, 1);
+        assert_eq!(response.headers()["x-debug"], "1");
+    }
+}
+
+#[test]
+fn rejection_into_response_with() {
+    let mut calls = 0;
+    let rejection = axum_either::AxumEitherRejection::new(
+        (StatusCode::UNSUPPORTED_MEDIA_TYPE, "left"),
+        (StatusCode::UNPROCESSABLE_ENTITY, "right"),
+    );
+    let response = rejection.into_response_with(|mut response| {
+        calls += 1;
+        *response.status_mut() = StatusCode::IM_A_TEAPOT;
+        response
+    });
+    assert_eq!(calls, 1);
+    assert_eq!(response.status(), StatusCode::IM_A_TEAPOT);
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "router-ext")]
+
+use axum::{Form, Json, Router};
+use axum_either::routing::RouterExt;
+use reqwest::{header, StatusCode};
+use serde::Deserialize;
+use std::net::{SocketAddr, TcpListener};
+
+#[derive(Deserialize)]
+struct Hello {
+    name: String,
+}
+
+async fn json_hello(Json(hello): Json<Hello>) -> String {
+    format!("json {}", hello.name)
+}
+
+async fn form_hello(Form(hello): Form<Hello>) -> String {
+    format!("form {}", hello.name)
+}
+
+async fn test_setup() -> SocketAddr {
+    let router = Router::new().route_negotiated("/hello", json_hello, form_hello);
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::Server::from_tcp(listener)
+            .unwrap()
+            .serve(router.into_make_service())
+            .await
+    });
+    addr
+}
+
+async fn post(addr: SocketAddr, content_type: &str, body: &'static str) -> reqwest::Response {
+    reqwest::Client::new()
+        .post(format!("http://{}/hello", addr))
+        .header(header::CONTENT_TYPE, content_type)
+        .body(body)
+        .send()
+        .await
+        .expect("Error sending request")
+}
+
+#[tokio::test]
+async fn json_reaches_json_handler() {
+    let addr = test_setup().await;
+    let response = post(addr, "application/json", r#"{"name":"Reimu"}"#).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text().await.unwrap(), "json Reimu");
+}
+
+#[tokio::test]
+async fn form_reaches_form_handler() {
+    let addr = test_setup().await;
+    let response = post(addr, "application/x-www-form-urlencoded", "name=Marisa").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text().await.unwrap(), "form Marisa");
+}
+
+#[tokio::test]
+async fn unsupported_content_type() {
+    let addr = test_setup().await;
+    let response = post(addr, "text/plain", "Sanae").await;
+    assert_eq!(response.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+}
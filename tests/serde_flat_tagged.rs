@@ -0,0 +1,93 @@
+#![cfg(feature = "serde")]
+
+use axum_either::{serde::flat_tagged, AxumEither, OneOf3, OneOf5};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Flat {
+    #[serde(with = "axum_either::serde::flat_tagged")]
+    value: OneOf5<u8, u8, String, bool, Vec<u8>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Nested {
+    #[serde(
+        serialize_with = "flat_tagged::serialize::<5, _, _>",
+        deserialize_with = "flat_tagged::deserialize::<5, _, _>"
+    )]
+    value: axum_either::one_of!(u8, u8, String, bool, Vec<u8>),
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Pair {
+    #[serde(with = "axum_either::serde::flat_tagged")]
+    value: AxumEither<u8, u8>,
+}
+
+fn flat_values() -> [OneOf5<u8, u8, String, bool, Vec<u8>>; 5] {
+    [
+        OneOf5::V0(1),
+        OneOf5::V1(2),
+        OneOf5::V2("three".into()),
+        OneOf5::V3(true),
+        OneOf5::V4(vec![5]),
+    ]
+}
+
+#[test]
+fn flat_and_nested_share_the_format() {
+    for (index, value) in flat_values().into_iter().enumerate() {
+        let flat = Flat { value };
+        let json = serde_json::to_string(&flat).unwrap();
+        assert!(
+            json.starts_with(&format!(r#"{{"value":{{"variant":{},"value":"#, index)),
+            "{}",
+            json
+        );
+
+        let nested: Nested = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&nested).unwrap(), json);
+        assert_eq!(OneOf5::from(nested.value), flat.value);
+
+        let back: Flat = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, flat);
+    }
+}
+
+#[test]
+fn nested_round_trip_keeps_equal_types_apart() {
+    let nested = Nested {
+        value: AxumEither::Right(AxumEither::Left(2)),
+    };
+    let json = serde_json::to_string(&nested).unwrap();
+    assert_eq!(json, r#"{"value":{"variant":1,"value":2}}"#);
+    assert_eq!(serde_json::from_str::<Nested>(&json).unwrap(), nested);
+}
+
+#[test]
+fn pair_uses_indices_zero_and_one() {
+    let pair = Pair {
+        value: AxumEither::Right(3),
+    };
+    let json = serde_json::to_string(&pair).unwrap();
+    assert_eq!(json, r#"{"value":{"variant":1,"value":3}}"#);
+    assert_eq!(serde_json::from_str::<Pair>(&json).unwrap(), pair);
+}
+
+#[test]
+fn keys_may_appear_in_any_order() {
+    let flat: OneOf3<u8, String, bool> = flat_tagged::deserialize(
+        &mut serde_json::Deserializer::from_str(r#"{"value":"text","variant":1}"#),
+    )
+    .unwrap();
+    assert_eq!(flat, OneOf3::V1("text".into()));
+}
+
+#[test]
+fn out_of_range_variant_is_rejected() {
+    let error = serde_json::from_str::<Flat>(r#"{"value":{"variant":5,"value":1}}"#).unwrap_err();
+    assert!(error.to_string().contains("below 5"), "{}", error);
+
+    let error = serde_json::from_str::<Nested>(r#"{"value":{"variant":7,"value":1}}"#).unwrap_err();
+    assert!(error.to_string().contains("below"), "{}", error);
+}
@@ -0,0 +1,82 @@
+#![cfg(feature = "serde")]
+
+use axum_either::{
+    serde::adjacently_tagged::{self, Keys},
+    AxumEither,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Tagged {
+    #[serde(with = "axum_either::serde::tagged")]
+    name: AxumEither<String, String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Adjacent {
+    #[serde(with = "axum_either::serde::adjacently_tagged")]
+    name: AxumEither<String, String>,
+}
+
+struct TypeData;
+
+impl Keys for TypeData {
+    const TAG: &'static str = "type";
+    const CONTENT: &'static str = "data";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct CustomKeys {
+    #[serde(
+        serialize_with = "adjacently_tagged::serialize_with_keys::<TypeData, _, _, _>",
+        deserialize_with = "adjacently_tagged::deserialize_with_keys::<TypeData, _, _, _>"
+    )]
+    name: AxumEither<String, String>,
+}
+
+#[test]
+fn tagged_keeps_variant() {
+    let right = Tagged {
+        name: AxumEither::Right("same".into()),
+    };
+    let json = serde_json::to_string(&right).unwrap();
+    assert_eq!(json, r#"{"name":{"right":"same"}}"#);
+    assert_eq!(serde_json::from_str::<Tagged>(&json).unwrap(), right);
+
+    // The untagged representation loses the variant
+    let untagged = serde_json::to_string(&right.name).unwrap();
+    assert_eq!(
+        serde_json::from_str::<AxumEither<String, String>>(&untagged).unwrap(),
+        AxumEither::Left("same".into())
+    );
+}
+
+#[test]
+fn adjacently_tagged_keeps_variant() {
+    for name in [
+        AxumEither::Left("same".into()),
+        AxumEither::Right("same".into()),
+    ] {
+        let adjacent = Adjacent { name };
+        let json = serde_json::to_string(&adjacent).unwrap();
+        assert_eq!(serde_json::from_str::<Adjacent>(&json).unwrap(), adjacent);
+    }
+
+    let json = r#"{"name":{"content":"same","tag":"right"}}"#;
+    assert_eq!(
+        serde_json::from_str::<Adjacent>(json).unwrap().name,
+        AxumEither::Right("same".into())
+    );
+}
+
+#[test]
+fn adjacently_tagged_custom_keys() {
+    let custom = CustomKeys {
+        name: AxumEither::Right("same".into()),
+    };
+    let json = serde_json::to_string(&custom).unwrap();
+    assert_eq!(json, r#"{"name":{"type":"right","data":"same"}}"#);
+    assert_eq!(serde_json::from_str::<CustomKeys>(&json).unwrap(), custom);
+
+    assert!(serde_json::from_str::<CustomKeys>(r#"{"name":{"type":"up","data":""}}"#).is_err());
+}
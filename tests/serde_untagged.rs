@@ -0,0 +1,58 @@
+#![cfg(feature = "serde")]
+
+use axum_either::AxumEither;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Job {
+    id: u32,
+    payload: AxumEither<Vec<u8>, String>,
+}
+
+#[test]
+fn serializes_active_variant() {
+    let left: AxumEither<u32, String> = AxumEither::Left(3);
+    assert_eq!(serde_json::to_string(&left).unwrap(), "3");
+    let right: AxumEither<u32, String> = AxumEither::Right("three".into());
+    assert_eq!(serde_json::to_string(&right).unwrap(), "\"three\"");
+}
+
+#[test]
+fn round_trip() {
+    for payload in [
+        AxumEither::Left(vec![1, 2, 3]),
+        AxumEither::Right("text".to_owned()),
+    ] {
+        let job = Job { id: 1, payload };
+        let json = serde_json::to_string(&job).unwrap();
+        assert_eq!(serde_json::from_str::<Job>(&json).unwrap(), job);
+    }
+}
+
+#[test]
+fn overlapping_representation_prefers_left() {
+    let right: AxumEither<u64, u32> = AxumEither::Right(7);
+    let json = serde_json::to_string(&right).unwrap();
+    assert_eq!(
+        serde_json::from_str::<AxumEither<u64, u32>>(&json).unwrap(),
+        AxumEither::Left(7)
+    );
+
+    // Only the right side accepts values above u8::MAX
+    let either: AxumEither<u8, u32> = serde_json::from_str("300").unwrap();
+    assert_eq!(either, AxumEither::Right(300));
+}
+
+#[test]
+fn nested_chain() {
+    let either: axum_either::one_of!(u8, bool, String) = serde_json::from_str("true").unwrap();
+    assert_eq!(either, AxumEither::Right(AxumEither::Left(true)));
+}
+
+#[test]
+fn error_names_both_failures() {
+    let error = serde_json::from_str::<AxumEither<u8, bool>>("\"text\"").unwrap_err();
+    let message = error.to_string();
+    assert!(message.contains("left error"), "{}", message);
+    assert!(message.contains("right error"), "{}", message);
+}
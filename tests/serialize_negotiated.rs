@@ -0,0 +1,121 @@
+#![cfg(feature = "any-format")]
+
+use axum::{
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+use axum_either::{Accept, SerializeNegotiated};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+struct User {
+    name: String,
+    age: u32,
+}
+
+fn user() -> User {
+    User {
+        name: "Reimu".into(),
+        age: 17,
+    }
+}
+
+fn accept(value: &str) -> Accept {
+    let mut headers = HeaderMap::new();
+    headers.insert(header::ACCEPT, value.parse().unwrap());
+    Accept::from_headers(&headers)
+}
+
+fn content_type(response: &Response) -> &str {
+    response.headers()[header::CONTENT_TYPE].to_str().unwrap()
+}
+
+async fn body(response: Response) -> Vec<u8> {
+    hyper::body::to_bytes(response.into_body())
+        .await
+        .unwrap()
+        .to_vec()
+}
+
+#[tokio::test]
+async fn responds_with_json() {
+    for accept in [accept("application/json"), accept("*/*"), Accept::default()] {
+        let response = SerializeNegotiated::new(&accept, user()).into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(content_type(&response), "application/json");
+        assert_eq!(response.headers()[header::VARY], "Accept");
+        let body = body(response).await;
+        assert_eq!(serde_json::from_slice::<User>(&body).unwrap(), user());
+    }
+}
+
+#[tokio::test]
+async fn pretty_json_is_indented() {
+    let response = SerializeNegotiated::new(&accept("application/json"), user())
+        .pretty()
+        .into_response();
+    let body = body(response).await;
+    assert_eq!(body, serde_json::to_vec_pretty(&user()).unwrap());
+}
+
+#[tokio::test]
+async fn nothing_acceptable_lists_producible_types() {
+    let negotiated = SerializeNegotiated::new(&accept("image/png"), user());
+    let expected = format!(
+        "Expected one of: {}",
+        negotiated.media_types().collect::<Vec<_>>().join(", ")
+    );
+    let response = negotiated.into_response();
+    assert_eq!(response.status(), StatusCode::NOT_ACCEPTABLE);
+    assert_eq!(response.headers()[header::VARY], "Accept");
+    assert_eq!(body(response).await, expected.into_bytes());
+}
+
+#[tokio::test]
+async fn registered_encoders_are_negotiated() {
+    let negotiated = |value: &str| {
+        SerializeNegotiated::new(&accept(value), user())
+            .encoder("text/plain", |user| Ok(user.name.clone().into_bytes()))
+    };
+    assert!(negotiated("*/*")
+        .media_types()
+        .any(|media_type| media_type == "text/plain"));
+
+    let response = negotiated("text/plain, application/json;q=0.5").into_response();
+    assert_eq!(content_type(&response), "text/plain");
+    assert_eq!(body(response).await, b"Reimu");
+
+    // Ties are won by the built in json encoder registered first
+    let response = negotiated("text/plain, application/json").into_response();
+    assert_eq!(content_type(&response), "application/json");
+}
+
+#[tokio::test]
+async fn failing_encoders_are_server_errors() {
+    let response = SerializeNegotiated::new(&accept("text/plain"), user())
+        .encoder("text/plain", |_| Err("not encodable".into()))
+        .into_response();
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[cfg(feature = "msgpack")]
+#[tokio::test]
+async fn responds_with_msgpack() {
+    let response = SerializeNegotiated::new(&accept("application/msgpack"), user()).into_response();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(content_type(&response), "application/msgpack");
+    let body = body(response).await;
+    assert_eq!(rmp_serde::from_slice::<User>(&body).unwrap(), user());
+}
+
+#[cfg(feature = "cbor")]
+#[tokio::test]
+async fn responds_with_cbor() {
+    let response = SerializeNegotiated::new(&accept("application/cbor"), user()).into_response();
+    assert_eq!(content_type(&response), "application/cbor");
+    let body = body(response).await;
+    assert_eq!(
+        ciborium::de::from_reader::<User, _>(&body[..]).unwrap(),
+        user()
+    );
+}
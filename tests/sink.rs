@@ -0,0 +1,63 @@
+#![cfg(feature = "futures")]
+
+use axum_either::AxumEither;
+use futures_util::{Sink, SinkExt};
+use std::{
+    convert::Infallible,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Collects items and records whether it was closed
+#[derive(Default, Debug)]
+struct Collect {
+    items: Vec<u32>,
+    closed: bool,
+}
+
+impl Sink<u32> for Collect {
+    type Error = Infallible;
+
+    fn poll_ready(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, item: u32) -> Result<(), Infallible> {
+        self.items.push(item);
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+        self.closed = true;
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[tokio::test]
+async fn sends_to_left() {
+    let mut sink: AxumEither<Collect, Vec<u32>> = AxumEither::Left(Collect::default());
+    sink.send(1).await.unwrap();
+    sink.send(2).await.unwrap();
+    sink.close().await.unwrap();
+
+    let collect = sink.left().unwrap();
+    assert_eq!(collect.items, [1, 2]);
+    assert!(collect.closed);
+}
+
+#[tokio::test]
+async fn sends_to_right() {
+    let mut sink: AxumEither<Vec<u32>, Collect> = AxumEither::Right(Collect::default());
+    sink.send_all(&mut futures_util::stream::iter([Ok(3), Ok(4)]))
+        .await
+        .unwrap();
+    sink.close().await.unwrap();
+
+    let collect = sink.right().unwrap();
+    assert_eq!(collect.items, [3, 4]);
+    assert!(collect.closed);
+}
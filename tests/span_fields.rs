@@ -0,0 +1,137 @@
+#![cfg(feature = "tracing")]
+
+use axum::{
+    body::Body,
+    extract::{FromRequest, Query, RequestParts},
+    http::{header, Request, StatusCode},
+    response::IntoResponse,
+    Form, Json,
+};
+use axum_either::{one_of, AxumEither, AxumEitherRejection, SingleRejection};
+use serde::Deserialize;
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    sync::{Arc, Mutex},
+};
+use tracing::{
+    field::{Empty, Field, Visit},
+    span, Event, Instrument, Subscriber,
+};
+use tracing_subscriber::{layer::Context, prelude::*, Layer};
+
+mod common;
+
+#[derive(Deserialize)]
+#[allow(dead_code)]
+struct Payload {
+    id: u32,
+}
+
+type Chain = one_of!(Json<Payload>, Form<Payload>, Query<Payload>);
+
+/// Captures the values recorded on spans after their creation
+#[derive(Clone, Default)]
+struct Recorded(Arc<Mutex<HashMap<String, String>>>);
+
+impl Visit for Recorded {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0
+            .lock()
+            .unwrap()
+            .insert(field.name().into(), value.into());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0
+            .lock()
+            .unwrap()
+            .insert(field.name().into(), format!("{:?}", value));
+    }
+}
+
+impl<S: Subscriber> Layer<S> for Recorded {
+    fn on_record(&self, _span: &span::Id, values: &span::Record<'_>, _ctx: Context<'_, S>) {
+        values.record(&mut self.clone());
+    }
+
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        event.record(&mut self.clone());
+    }
+}
+
+async fn extract(mut req: RequestParts<Body>) -> (Option<Chain>, HashMap<String, String>) {
+    let recorded = Recorded::default();
+    let _guard = tracing_subscriber::registry()
+        .with(recorded.clone())
+        .set_default();
+
+    let span = tracing::info_span!(
+        "request",
+        axum_either.variant_index = Empty,
+        axum_either.variant_type = Empty,
+        axum_either.attempts = Empty,
+    );
+    let either = Chain::from_request(&mut req).instrument(span).await.ok();
+
+    let fields = recorded.0.lock().unwrap().clone();
+    (either, fields)
+}
+
+#[tokio::test]
+async fn records_matched_variant() {
+    let (either, fields) = extract(common::post("application/x-www-form-urlencoded", "id=1")).await;
+    assert!(matches!(
+        either,
+        Some(AxumEither::Right(AxumEither::Left(Form(_))))
+    ));
+    assert_eq!(fields["axum_either.variant_index"], "1");
+    assert_eq!(
+        fields["axum_either.variant_type"],
+        "axum::Form<span_fields::Payload>"
+    );
+    assert_eq!(fields["axum_either.attempts"], "2");
+
+    // The query does not need the body which was rejected by both body extractors
+    let req = Request::post("/?id=2")
+        .header(header::CONTENT_TYPE, "text/plain")
+        .body(Body::from("id"))
+        .unwrap();
+    let (either, fields) = extract(RequestParts::new(req)).await;
+    assert!(matches!(
+        either,
+        Some(AxumEither::Right(AxumEither::Right(Query(_))))
+    ));
+    assert_eq!(fields["axum_either.variant_index"], "2");
+    assert_eq!(fields["axum_either.attempts"], "3");
+}
+
+#[tokio::test]
+async fn records_attempts_of_rejected_request() {
+    let (either, fields) = extract(common::post("text/plain", "id")).await;
+    assert!(either.is_none());
+    assert_eq!(fields["axum_either.attempts"], "3");
+    assert!(!fields.contains_key("axum_either.variant_index"));
+    assert!(!fields.contains_key("axum_either.variant_type"));
+}
+
+#[test]
+fn single_rejection_logs_the_discarded_error() {
+    let recorded = Recorded::default();
+    let _guard = tracing_subscriber::registry()
+        .with(recorded.clone())
+        .set_default();
+
+    let response = AxumEitherRejection::new(
+        (StatusCode::UNSUPPORTED_MEDIA_TYPE, "Expected json"),
+        (StatusCode::UNPROCESSABLE_ENTITY, "Missing field `id`"),
+    )
+    .with_formatter::<SingleRejection>()
+    .into_response();
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let fields = recorded.0.lock().unwrap().clone();
+    assert_eq!(fields["error"], "Expected json");
+    assert_eq!(fields["status"], "415");
+    assert!(fields["rejection"].contains("StatusCode"));
+}
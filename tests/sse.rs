@@ -0,0 +1,79 @@
+#![cfg(feature = "sse")]
+
+use axum::{
+    http::{header, HeaderMap},
+    response::sse::Event,
+    routing::get,
+    Json, Router,
+};
+use axum_either::{sse::SseOr, AxumEither};
+use futures_util::stream;
+use std::{
+    convert::Infallible,
+    net::{SocketAddr, TcpListener},
+    time::Duration,
+};
+
+async fn counter(headers: HeaderMap) -> SseOr<Json<u32>> {
+    let live = headers
+        .get(header::ACCEPT)
+        .is_some_and(|accept| accept == "text/event-stream");
+    if live {
+        // Never ends, a buffered response would never be received
+        AxumEither::left_sse(stream::unfold(0u32, |n| async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            Some((
+                Ok::<_, Infallible>(Event::default().data(n.to_string())),
+                n + 1,
+            ))
+        }))
+    } else {
+        AxumEither::Right(Json(0))
+    }
+}
+
+async fn test_setup() -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let router = Router::new().route("/counter", get(counter));
+    tokio::spawn(async move {
+        axum::Server::from_tcp(listener)
+            .unwrap()
+            .serve(router.into_make_service())
+            .await
+    });
+    addr
+}
+
+#[tokio::test]
+async fn sse_streams_events() {
+    let addr = test_setup().await;
+    let mut response = reqwest::Client::new()
+        .get(format!("http://{}/counter", addr))
+        .header("Accept", "text/event-stream")
+        .send()
+        .await
+        .expect("Error sending request");
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "text/event-stream"
+    );
+
+    let mut received = String::new();
+    while received.matches("data:").count() < 2 {
+        let chunk = response.chunk().await.unwrap().expect("stream ended");
+        received.push_str(std::str::from_utf8(&chunk).unwrap());
+    }
+}
+
+#[tokio::test]
+async fn json_responds_immediately() {
+    let addr = test_setup().await;
+    let response: u32 = reqwest::get(&format!("http://{}/counter", addr))
+        .await
+        .expect("Error sending request")
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(response, 0);
+}
@@ -0,0 +1,140 @@
+#![cfg(feature = "test-util")]
+
+//! The status selection of the formatters for every combination of rejection statuses, checked
+//! with the mock extractors of the `test-util` feature.
+
+use axum::{http::StatusCode, response::IntoResponse};
+use axum_either::{
+    prelude::*,
+    test::{self, AlwaysOk, AlwaysReject, MockRejection},
+    BothRejections, EscalateServerErrors, PreferClientErrors, RejectionFormatter, SingleRejection,
+    WithFormatter,
+};
+
+type Rejecting<const L: u16, const R: u16> =
+    AxumEither<AlwaysReject<MockRejection<L>>, AlwaysReject<MockRejection<R>>>;
+
+/// The status of the rendered rejection of `Rejecting<L, R>` with the formatter `F`, checking
+/// that it is the one reported without rendering
+async fn status_with<F, const L: u16, const R: u16>() -> StatusCode
+where
+    F: RejectionFormatter + std::fmt::Debug,
+{
+    let rejection = test::extract::<WithFormatter<Rejecting<L, R>, F>, _>(test::empty_request())
+        .await
+        .unwrap_err();
+    let status = rejection.status();
+    assert_eq!(rejection.into_response().status(), status, "{} {}", L, R);
+    status
+}
+
+async fn check_statuses<const L: u16, const R: u16>() {
+    let left = StatusCode::from_u16(L).unwrap();
+    let right = StatusCode::from_u16(R).unwrap();
+
+    let escalated = if left.is_server_error() || right.is_server_error() {
+        StatusCode::INTERNAL_SERVER_ERROR
+    } else {
+        StatusCode::BAD_REQUEST
+    };
+    assert_eq!(
+        status_with::<BothRejections<EscalateServerErrors>, L, R>().await,
+        escalated,
+        "{} {}",
+        L,
+        R
+    );
+
+    let prefer_client = if left.is_server_error() && right.is_server_error() {
+        StatusCode::INTERNAL_SERVER_ERROR
+    } else {
+        StatusCode::BAD_REQUEST
+    };
+    assert_eq!(
+        status_with::<BothRejections<PreferClientErrors>, L, R>().await,
+        prefer_client,
+        "{} {}",
+        L,
+        R
+    );
+
+    let single = if left == StatusCode::UNSUPPORTED_MEDIA_TYPE
+        && right != StatusCode::UNSUPPORTED_MEDIA_TYPE
+    {
+        right
+    } else {
+        left
+    };
+    assert_eq!(
+        status_with::<SingleRejection, L, R>().await,
+        single,
+        "{} {}",
+        L,
+        R
+    );
+}
+
+/// Call `$check::<L, R>()` for every pair of the given statuses
+macro_rules! for_each_pair {
+    ($check:ident [$($status:literal),+]) => {
+        for_each_pair!(@rows $check [$($status),+] $($status),+)
+    };
+    (@rows $check:ident $all:tt $($left:literal),+) => {
+        $(for_each_pair!(@row $check $left $all);)+
+    };
+    (@row $check:ident $left:literal [$($right:literal),+]) => {
+        $($check::<$left, $right>().await;)+
+    };
+}
+
+#[tokio::test]
+async fn status_matrix() {
+    for_each_pair!(check_statuses [400, 401, 415, 422, 500, 503]);
+}
+
+#[tokio::test]
+async fn first_successful_variant_is_extracted() {
+    type Chain = one_of!(AlwaysReject, AlwaysOk<u8>, AlwaysOk<u16>);
+    let either = test::extract::<Chain, _>(test::empty_request())
+        .await
+        .unwrap();
+    assert!(matches!(either, AxumEither::Right(AxumEither::Left(_))));
+
+    type Flat = OneOf3<AlwaysReject, AlwaysReject, AlwaysOk<u16>>;
+    let flat = test::extract::<Flat, _>(test::empty_request())
+        .await
+        .unwrap();
+    assert!(matches!(flat, OneOf3::V2(_)));
+}
+
+#[tokio::test]
+async fn attempts_keep_extraction_order() {
+    type Chain = one_of!(
+        AlwaysReject<MockRejection<415>>,
+        AlwaysReject<MockRejection<422>>,
+        AlwaysReject<MockRejection<503>>
+    );
+    let rejection = test::extract::<Chain, _>(test::empty_request())
+        .await
+        .unwrap_err();
+    let statuses: Vec<_> = rejection
+        .attempts()
+        .iter()
+        .map(|attempt| attempt.status().as_u16())
+        .collect();
+    assert_eq!(statuses, [415, 422, 503]);
+}
+
+#[tokio::test]
+async fn state_is_available_as_extension() {
+    #[derive(Clone)]
+    struct AppName(&'static str);
+
+    let axum::Extension(name) = test::extract_with_state::<axum::Extension<AppName>, _, _>(
+        test::empty_request(),
+        AppName("either"),
+    )
+    .await
+    .unwrap();
+    assert_eq!(name.0, "either");
+}
@@ -0,0 +1,74 @@
+#![cfg(feature = "test-util")]
+
+use axum::{
+    extract::rejection::{FormRejection, JsonRejection},
+    http::StatusCode,
+    response::IntoResponse,
+    Form, Json,
+};
+use axum_either::{prelude::*, test};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct Payload {
+    pub id: u32,
+}
+
+type JsonFormString = one_of!(Json<Payload>, Form<Payload>, String);
+
+#[tokio::test]
+async fn json_is_extracted_as_json() {
+    let either = test::extract::<JsonFormString, _>(test::json_request(&Payload { id: 1 }))
+        .await
+        .unwrap();
+    assert!(matches!(either, AxumEither::Left(Json(Payload { id: 1 }))));
+}
+
+#[tokio::test]
+async fn form_is_extracted_as_form() {
+    let either = test::extract::<JsonFormString, _>(test::form_request(&Payload { id: 2 }))
+        .await
+        .unwrap();
+    assert!(matches!(
+        either,
+        AxumEither::Right(AxumEither::Left(Form(Payload { id: 2 })))
+    ));
+}
+
+#[tokio::test]
+async fn unknown_content_type_falls_through_to_string() {
+    let either = test::extract::<JsonFormString, _>(test::raw_request("text/plain", "id=3"))
+        .await
+        .unwrap();
+    assert_eq!(either.right().and_then(AxumEither::right).unwrap(), "id=3");
+}
+
+#[tokio::test]
+async fn invalid_json_is_not_retried_as_form() {
+    let rejection = test::extract::<AxumEither<Json<Payload>, Form<Payload>>, _>(
+        test::raw_request("application/json", r#"{"id": "three"}"#),
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(rejection.left(), JsonRejection::JsonDataError(_)));
+    assert!(matches!(
+        rejection.right(),
+        FormRejection::InvalidFormContentType(_)
+    ));
+    assert_eq!(rejection.into_response().status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn empty_request_is_rejected_by_both_sides() {
+    let rejection =
+        test::extract::<AxumEither<Json<Payload>, Form<Payload>>, _>(test::empty_request())
+            .await
+            .unwrap_err();
+    let statuses: Vec<_> = rejection
+        .attempts()
+        .iter()
+        .map(|attempt| attempt.status())
+        .collect();
+    assert_eq!(statuses[0], StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    assert_eq!(statuses.len(), 2);
+}
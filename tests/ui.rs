@@ -0,0 +1,9 @@
+//! Compile the misuse cases of the macros and compare their errors with the committed
+//! `.stderr` files, regenerate them with `TRYBUILD=overwrite cargo test --test ui`.
+
+#[test]
+fn ui() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/fail/*.rs");
+    cases.pass("tests/ui/pass/*.rs");
+}
@@ -0,0 +1,8 @@
+use axum_either::AxumEither;
+
+fn main() {
+    let either: AxumEither<u8, u16> = AxumEither::Left(0);
+    let _mapped = axum_either::map_one_of! {either,
+        value, value,
+    };
+}
@@ -0,0 +1,9 @@
+use axum_either::AxumEither;
+
+fn main() {
+    let either: AxumEither<u8, u16> = AxumEither::Left(0);
+    let _mapped: AxumEither<u8, u16> = axum_either::map_one_of! {either,
+        value => 0: value,
+        value => 9: value,
+    };
+}
@@ -0,0 +1,9 @@
+use axum_either::AxumEither;
+
+fn main() {
+    let either: AxumEither<u8, u16> = AxumEither::Left(0);
+    axum_either::match_one_of! {either,
+        value if value > 3 => u16::from(value),
+        value => value,
+    };
+}
@@ -0,0 +1,8 @@
+use axum_either::AxumEither;
+
+fn main() {
+    let either: AxumEither<u8, u16> = AxumEither::Left(0);
+    axum_either::match_one_of! {either,
+        value => u16::from(value),
+    };
+}
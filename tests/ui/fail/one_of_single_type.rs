@@ -0,0 +1,3 @@
+fn main() {
+    let _value: axum_either::one_of!(u8) = 0;
+}
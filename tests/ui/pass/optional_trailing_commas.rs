@@ -0,0 +1,18 @@
+use axum_either::AxumEither;
+
+fn main() {
+    let either: axum_either::one_of!(u8, u16, u32,) = AxumEither::Right(AxumEither::Left(2));
+    let value = axum_either::match_one_of! {either,
+        value => u32::from(value),
+        value => u32::from(value),
+        value => value
+    };
+    assert_eq!(value, 2);
+
+    let either: axum_either::one_of!(u8, u16) = AxumEither::Left(1);
+    let mapped = axum_either::map_one_of! {either,
+        value => value + 1,
+        value => value + 2
+    };
+    assert_eq!(mapped, AxumEither::Left(2));
+}
@@ -0,0 +1,78 @@
+use axum::{
+    async_trait,
+    body::Body,
+    extract::{FromRequest, RequestParts},
+    http::{Request, StatusCode},
+    routing::post,
+    Json, Router,
+};
+use axum_either::AxumEither;
+use http_body::Limited;
+use std::task::{Context, Poll};
+use tower::Service;
+
+/// Reads only the request parts, so it is implemented for every body type
+pub struct ApiVersion(pub String);
+
+#[async_trait]
+impl<B: Send> FromRequest<B> for ApiVersion {
+    type Rejection = StatusCode;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        req.headers()
+            .get("x-api-version")
+            .and_then(|version| version.to_str().ok())
+            .map(|version| Self(version.to_owned()))
+            .ok_or(StatusCode::BAD_REQUEST)
+    }
+}
+
+/// Limits the body before the inner service sees it, changing the body type
+#[derive(Clone)]
+pub struct MapRequestBody<S>(S);
+
+impl<S> Service<Request<Body>> for MapRequestBody<S>
+where
+    S: Service<Request<Limited<Body>>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.0.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<Body>) -> Self::Future {
+        self.0.call(req.map(|body| Limited::new(body, 1024)))
+    }
+}
+
+async fn handler(request: AxumEither<ApiVersion, Json<u32>>) -> String {
+    match request {
+        AxumEither::Left(ApiVersion(version)) => version,
+        AxumEither::Right(Json(value)) => value.to_string(),
+    }
+}
+
+async fn nested(request: AxumEither<Json<u32>, AxumEither<ApiVersion, String>>) -> String {
+    request
+        .map_lr(
+            |Json(value)| value.to_string(),
+            |right| right.map_left(|ApiVersion(version)| version).into_inner(),
+        )
+        .into_inner()
+}
+
+fn assert_extractor<T: FromRequest<B>, B>() {}
+
+fn main() {
+    assert_extractor::<AxumEither<ApiVersion, Json<u32>>, Body>();
+    assert_extractor::<AxumEither<ApiVersion, Json<u32>>, Limited<Body>>();
+    assert_extractor::<AxumEither<Json<u32>, ApiVersion>, Limited<Body>>();
+
+    let router: Router<Limited<Body>> = Router::new()
+        .route("/", post(handler))
+        .route("/nested", post(nested));
+    let _service = MapRequestBody(router);
+}
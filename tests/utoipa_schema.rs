@@ -0,0 +1,79 @@
+#![cfg(feature = "utoipa")]
+
+use axum_either::AxumEither;
+use utoipa::{
+    openapi::{ComponentsBuilder, OpenApiBuilder},
+    ToSchema,
+};
+
+#[derive(ToSchema)]
+#[allow(dead_code)]
+struct Cat {
+    lives: u8,
+}
+
+#[derive(ToSchema)]
+#[allow(dead_code)]
+struct Dog {
+    name: String,
+}
+
+#[derive(ToSchema)]
+#[allow(dead_code)]
+struct Fish {
+    fins: u8,
+}
+
+#[test]
+fn nested_chain_is_flattened() {
+    let (name, _) = <axum_either::one_of!(Cat, Dog, Fish)>::schema();
+    assert_eq!(name, "CatOrDogOrFish");
+
+    let openapi = OpenApiBuilder::new()
+        .components(Some(
+            ComponentsBuilder::new()
+                .schema_from::<axum_either::one_of!(Cat, Dog, Fish)>()
+                .build(),
+        ))
+        .build();
+    let document: serde_json::Value = serde_json::from_str(&openapi.to_json().unwrap()).unwrap();
+
+    let one_of = document["components"]["schemas"]["CatOrDogOrFish"]["oneOf"]
+        .as_array()
+        .unwrap();
+    assert_eq!(one_of.len(), 3);
+    assert!(one_of[0]["properties"]["lives"].is_object());
+    assert!(one_of[1]["properties"]["name"].is_object());
+    assert!(one_of[2]["properties"]["fins"].is_object());
+}
+
+#[test]
+fn names_are_stable() {
+    let (first, _) = AxumEither::<Cat, Dog>::schema();
+    let (second, _) = AxumEither::<Cat, Dog>::schema();
+    assert_eq!(first, "CatOrDog");
+    assert!(std::ptr::eq(first, second));
+}
+
+#[cfg(feature = "axum")]
+#[test]
+fn request_body_lists_every_media_type() {
+    use axum::{response::Html, Form, Json};
+    use utoipa::IntoResponses;
+
+    let body = <axum_either::one_of!(Json<Cat>, Form<Cat>, String)>::openapi_request_body();
+    let media_types: Vec<_> = body.content.keys().map(String::as_str).collect();
+    assert_eq!(
+        media_types,
+        [
+            "application/json",
+            "application/x-www-form-urlencoded",
+            "text/plain; charset=utf-8"
+        ]
+    );
+
+    let responses = AxumEither::<Json<Dog>, Html<String>>::responses();
+    let response = serde_json::to_value(&responses["200"]).unwrap();
+    assert!(response["content"]["application/json"].is_object());
+    assert!(response["content"]["text/html; charset=utf-8"].is_object());
+}
@@ -0,0 +1,63 @@
+#![cfg(feature = "validation")]
+
+use axum::{extract::FromRequest, http::StatusCode, response::IntoResponse};
+use axum_either::ValidatedEither;
+use serde::Deserialize;
+use validator::Validate;
+
+mod common;
+
+#[derive(Deserialize, Validate)]
+struct SignUp {
+    #[validate(length(min = 3))]
+    name: String,
+}
+
+async fn extract(content_type: &str, body: &'static str) -> Result<String, (StatusCode, String)> {
+    let mut req = common::post(content_type, body);
+    match ValidatedEither::<SignUp>::from_request(&mut req).await {
+        Ok(sign_up) => Ok(sign_up.into_inner().name),
+        Err(rejection) => {
+            let response = rejection.into_response();
+            let status = response.status();
+            let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+            Err((status, String::from_utf8(body.to_vec()).unwrap()))
+        }
+    }
+}
+
+#[tokio::test]
+async fn valid_payload_from_both_formats() {
+    assert_eq!(
+        extract("application/json", r#"{"name":"Reimu"}"#).await,
+        Ok("Reimu".to_owned())
+    );
+    assert_eq!(
+        extract("application/x-www-form-urlencoded", "name=Reimu").await,
+        Ok("Reimu".to_owned())
+    );
+}
+
+#[tokio::test]
+async fn invalid_field_has_same_body_for_both_formats() {
+    let (json_status, json_body) = extract("application/json", r#"{"name":"R"}"#)
+        .await
+        .unwrap_err();
+    let (form_status, form_body) = extract("application/x-www-form-urlencoded", "name=R")
+        .await
+        .unwrap_err();
+
+    assert_eq!(json_status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(form_status, StatusCode::UNPROCESSABLE_ENTITY);
+    // The parameters of a validation error are a map without a stable order
+    let errors: serde_json::Value = serde_json::from_str(&json_body).unwrap();
+    let form_errors: serde_json::Value = serde_json::from_str(&form_body).unwrap();
+    assert_eq!(errors, form_errors);
+    assert!(errors["name"].is_array());
+}
+
+#[tokio::test]
+async fn extraction_failure_is_not_a_validation_failure() {
+    let (status, _) = extract("text/plain", "name=Reimu").await.unwrap_err();
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
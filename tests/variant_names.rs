@@ -0,0 +1,70 @@
+use axum::{extract::FromRequest, http::StatusCode, Form, Json};
+use axum_either::rejection::Attempt;
+use serde::Deserialize;
+
+mod common;
+
+#[derive(Deserialize, Debug)]
+pub struct HelloRequest {
+    pub name: String,
+}
+
+#[test]
+fn module_paths_are_stripped() {
+    let attempt = Attempt::new(
+        "axum::json::Json<alloc::vec::Vec<my_crate::api::HelloRequest>>",
+        StatusCode::BAD_REQUEST,
+        "",
+    );
+    assert_eq!(attempt.name(), "axum::Json<Vec<my_crate::HelloRequest>>");
+
+    let attempt = Attempt::new(
+        "(core::option::Option<&str>, [u8; 4])",
+        StatusCode::BAD_REQUEST,
+        "",
+    );
+    assert_eq!(attempt.name(), "(Option<&str>, [u8; 4])");
+
+    let attempt = Attempt::new("u32", StatusCode::BAD_REQUEST, "");
+    assert_eq!(attempt.name(), "u32");
+}
+
+#[test]
+fn names_are_stable() {
+    let type_name = std::any::type_name::<Json<HelloRequest>>();
+    let first = Attempt::new(type_name, StatusCode::BAD_REQUEST, "").name();
+    let second = Attempt::new(type_name, StatusCode::BAD_REQUEST, "").name();
+    assert_eq!(first, "axum::Json<variant_names::HelloRequest>");
+    assert_eq!(first, second);
+    assert!(
+        std::ptr::eq(first, second),
+        "the name is only computed once"
+    );
+}
+
+#[test]
+fn names_of_different_crates_differ() {
+    let axum = Attempt::new("axum::json::Json<u32>", StatusCode::BAD_REQUEST, "");
+    let extra = Attempt::new("axum_extra::json::Json<u32>", StatusCode::BAD_REQUEST, "");
+    assert_ne!(axum.name(), extra.name());
+}
+
+#[tokio::test]
+async fn flattened_chain_reports_rejection_names() {
+    let mut req = common::post("text/plain", "name=Reimu");
+    let rejection =
+        <axum_either::one_of!(Json<HelloRequest>, Form<HelloRequest>, Json<u32>)>::from_request(
+            &mut req,
+        )
+        .await
+        .unwrap_err();
+    let names: Vec<_> = rejection.attempts().iter().map(Attempt::name).collect();
+    assert_eq!(
+        names,
+        [
+            "axum::JsonRejection",
+            "axum::FormRejection",
+            "axum::JsonRejection"
+        ]
+    );
+}
@@ -0,0 +1,116 @@
+#![cfg(feature = "test-util")]
+
+use axum::{
+    http::{header, HeaderMap, StatusCode},
+    response::{Html, IntoResponse},
+    routing::post,
+    Json, Router,
+};
+use axum_either::{test::Harness, AxumEither, WithStatus, VARIANT_HEADER};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct Item {
+    pub id: u32,
+}
+
+type Upsert = AxumEither<WithStatus<Json<Item>>, Json<Item>>;
+
+async fn upsert(Json(item): Json<Item>) -> Upsert {
+    if item.id == 0 {
+        AxumEither::left_with_status(StatusCode::CREATED, Json(Item { id: 1 }))
+    } else {
+        AxumEither::Right(Json(item))
+    }
+}
+
+fn harness() -> Harness<Router> {
+    Harness::new(Router::new().route("/items", post(upsert)))
+}
+
+#[tokio::test]
+async fn per_variant_status_end_to_end() {
+    let harness = harness();
+
+    let created = harness.post("/items").json(&Item { id: 0 }).send().await;
+    assert_eq!(created.status(), StatusCode::CREATED);
+    assert_eq!(created.text(), r#"{"id":1}"#);
+
+    let updated = harness.post("/items").json(&Item { id: 4 }).send().await;
+    assert_eq!(updated.status(), StatusCode::OK);
+    assert_eq!(updated.text(), r#"{"id":4}"#);
+}
+
+#[test]
+fn right_with_status_keeps_left_status() {
+    let either: AxumEither<(StatusCode, &str), WithStatus<&str>> =
+        AxumEither::right_with_status(StatusCode::ACCEPTED, "queued");
+    assert_eq!(either.into_response().status(), StatusCode::ACCEPTED);
+
+    let either: AxumEither<(StatusCode, &str), WithStatus<&str>> =
+        AxumEither::Left((StatusCode::CONFLICT, "taken"));
+    assert_eq!(either.into_response().status(), StatusCode::CONFLICT);
+}
+
+type Chain = axum_either::one_of!((StatusCode, Json<u32>), Html<&'static str>, StatusCode);
+
+fn chain(index: usize) -> Chain {
+    match index {
+        0 => AxumEither::Left((StatusCode::CREATED, Json(1))),
+        1 => AxumEither::Right(AxumEither::Left(Html("<p>ok</p>"))),
+        _ => AxumEither::Right(AxumEither::Right(StatusCode::NO_CONTENT)),
+    }
+}
+
+#[test]
+fn tuples_inside_variants_set_their_status() {
+    let statuses: Vec<_> = (0..3)
+        .map(|index| chain(index).into_response().status())
+        .collect();
+    assert_eq!(
+        statuses,
+        [StatusCode::CREATED, StatusCode::OK, StatusCode::NO_CONTENT]
+    );
+
+    let response = chain(0).into_response();
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+    assert!(response.headers().get(VARIANT_HEADER).is_none());
+}
+
+#[test]
+fn outer_tuples_override_variant_status() {
+    let mut headers = HeaderMap::new();
+    headers.insert(header::CACHE_CONTROL, "no-store".parse().unwrap());
+
+    for index in 0..3 {
+        let response = (StatusCode::ACCEPTED, headers.clone(), chain(index)).into_response();
+        assert_eq!(response.status(), StatusCode::ACCEPTED);
+        assert_eq!(response.headers()[header::CACHE_CONTROL], "no-store");
+    }
+}
+
+#[test]
+fn map_one_of_keeps_tuples_as_values() {
+    // The arms see the whole tuple, mapping it does not flatten or move the status
+    let mapped = axum_either::map_one_of! {chain(0),
+        (status, Json(value)) => (status, Json(value + 1)),
+        html => html,
+        status => status,
+    };
+    let response = mapped.into_response();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let mapped = axum_either::map_one_of! {chain(0),
+        (_, json) => json,
+        html => html,
+        status => status,
+    };
+    assert_eq!(mapped.into_response().status(), StatusCode::OK);
+
+    let mapped = axum_either::map_one_of! {chain(0),
+        (_, json) => WithStatus(StatusCode::CREATED, json),
+        html => html,
+        status => status,
+    };
+    assert_eq!(mapped.into_response().status(), StatusCode::CREATED);
+}
@@ -0,0 +1,104 @@
+#![cfg(feature = "serde")]
+
+use axum_either::{AxumEither, FlatIndex, OneOf3, VariantTag};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+struct User {
+    name: String,
+}
+
+type Cached = AxumEither<User, String>;
+
+/// A cache which only knows the tags, the values are stored as json
+#[derive(Default)]
+struct Cache(HashMap<&'static str, (VariantTag, Value)>);
+
+impl Cache {
+    fn insert(&mut self, key: &'static str, value: Cached) {
+        let entry = value.into_tagged(
+            |user| serde_json::to_value(user).unwrap(),
+            |message| serde_json::to_value(message).unwrap(),
+        );
+        self.0.insert(key, entry);
+    }
+
+    fn get(&self, key: &str) -> Option<Result<Cached, serde_json::Error>> {
+        let (tag, value) = self.0.get(key)?.clone();
+        Some(AxumEither::try_from_tagged(
+            tag,
+            value,
+            serde_json::from_value,
+            serde_json::from_value,
+        ))
+    }
+}
+
+#[test]
+fn round_trip_through_type_erased_cache() {
+    let mut cache = Cache::default();
+    let user: Cached = AxumEither::Left(User {
+        name: "Reimu".into(),
+    });
+    let message: Cached = AxumEither::Right("not found".into());
+    cache.insert("user", user.clone());
+    cache.insert("message", message.clone());
+
+    assert_eq!(cache.0["user"].0, VariantTag::Left);
+    assert_eq!(cache.0["message"].0, VariantTag::Right);
+    assert_eq!(cache.get("user").unwrap().unwrap(), user);
+    assert_eq!(cache.get("message").unwrap().unwrap(), message);
+    assert!(cache.get("missing").is_none());
+}
+
+#[test]
+fn both_sides_with_the_same_representation_keep_their_side() {
+    let right: AxumEither<String, String> = AxumEither::Right("same".into());
+    let (tag, value) = right.clone().into_tagged(Value::String, Value::String);
+    let restored = AxumEither::from_tagged(
+        tag,
+        value,
+        |v| v.as_str().unwrap().to_owned(),
+        |v| v.as_str().unwrap().to_owned(),
+    );
+    assert_eq!(restored, right);
+}
+
+#[test]
+fn wrong_representation_is_an_error() {
+    let restored: Result<Cached, _> = AxumEither::try_from_tagged(
+        VariantTag::Left,
+        Value::from(3),
+        serde_json::from_value,
+        serde_json::from_value,
+    );
+    assert!(restored.is_err());
+}
+
+#[test]
+fn flat_index_of_chains_and_flat_enums() {
+    type Chain = axum_either::one_of!(u8, u16, u32, u64);
+    let chains: [Chain; 4] = [
+        AxumEither::Left(0),
+        AxumEither::Right(AxumEither::Left(1)),
+        AxumEither::Right(AxumEither::Right(AxumEither::Left(2))),
+        AxumEither::Right(AxumEither::Right(AxumEither::Right(3))),
+    ];
+    for (index, chain) in chains.iter().enumerate() {
+        assert_eq!(FlatIndex::<4>::flat_index(chain), index);
+    }
+
+    type Three = axum_either::one_of!(u8, u16, u32);
+    let flat: OneOf3<u8, u16, u32> = OneOf3::V1(1);
+    assert_eq!(flat.flat_index(), 1);
+    assert_eq!(FlatIndex::<3>::flat_index(&Three::from(flat)), 1);
+}
+
+#[test]
+fn tag_indices() {
+    assert_eq!(VariantTag::Left.index(), 0);
+    assert_eq!(VariantTag::Right.index(), 1);
+    assert_eq!(AxumEither::<u8, u8>::Right(1).tag(), VariantTag::Right);
+}
@@ -0,0 +1,131 @@
+use axum::{
+    async_trait,
+    body::{Body, Bytes},
+    extract::{FromRequest, RequestParts},
+    http::StatusCode,
+    BoxError,
+};
+use axum_either::{AxumEither, Dispatch, FirstOk, OneOf3, SupportedMediaTypes, Text};
+use mime::Mime;
+use std::sync::{Arc, Mutex};
+
+mod common;
+
+/// The addresses of the bodies the extractors received
+#[derive(Clone, Default)]
+struct Seen(Arc<Mutex<Vec<usize>>>);
+
+/// Reads the whole body and only accepts the request if `ACCEPT` is set
+struct ReadBody<const ACCEPT: bool>(Bytes);
+
+#[async_trait]
+impl<B, const ACCEPT: bool> FromRequest<B> for ReadBody<ACCEPT>
+where
+    B: http_body::Body + Send,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+{
+    type Rejection = StatusCode;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        let bytes = Bytes::from_request(req)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        let seen = req.extensions().get::<Seen>().unwrap();
+        seen.0.lock().unwrap().push(bytes.as_ptr() as usize);
+        if ACCEPT {
+            Ok(Self(bytes))
+        } else {
+            Err(StatusCode::BAD_REQUEST)
+        }
+    }
+}
+
+impl<const ACCEPT: bool> SupportedMediaTypes for ReadBody<ACCEPT> {
+    fn supported_media_types() -> &'static [Mime] {
+        const ANY: &[Mime] = &[mime::STAR_STAR];
+        ANY
+    }
+}
+
+fn request(payload: &Bytes) -> (RequestParts<Body>, Seen) {
+    let seen = Seen::default();
+    let mut req = common::post("application/octet-stream", payload.clone());
+    req.extensions_mut().insert(seen.clone());
+    (req, seen)
+}
+
+#[tokio::test]
+async fn first_ok_reuses_buffer_across_three_attempts() {
+    let payload = Bytes::from(vec![7u8; 64 * 1024]);
+    let (mut req, seen) = request(&payload);
+
+    let first_ok =
+        FirstOk::<(ReadBody<false>, ReadBody<false>, ReadBody<true>)>::from_request(&mut req)
+            .await
+            .unwrap();
+    let accepted = match first_ok.value {
+        OneOf3::V2(ReadBody(bytes)) => bytes,
+        _ => panic!("the last extractor accepts the request"),
+    };
+
+    let seen = seen.0.lock().unwrap();
+    assert_eq!(seen.len(), 3);
+    assert!(seen.iter().all(|ptr| *ptr == payload.as_ptr() as usize));
+    assert_eq!(accepted.as_ptr(), payload.as_ptr());
+}
+
+#[tokio::test]
+async fn dispatch_reuses_buffer_across_claims() {
+    let payload = Bytes::from(vec![7u8; 64 * 1024]);
+    let (mut req, seen) = request(&payload);
+
+    type Chain = axum_either::one_of!(ReadBody<false>, ReadBody<false>, ReadBody<true>);
+    let Dispatch(either) = Dispatch::<Chain>::from_request(&mut req).await.unwrap();
+    assert!(matches!(
+        either,
+        AxumEither::Right(AxumEither::Right(ReadBody(_)))
+    ));
+
+    let seen = seen.0.lock().unwrap();
+    assert_eq!(seen.len(), 3);
+    assert!(seen.iter().all(|ptr| *ptr == payload.as_ptr() as usize));
+}
+
+/// The string of `Text` takes over the buffer the last attempt receives
+#[tokio::test]
+async fn trailing_text_reuses_the_buffer() {
+    let payload = Bytes::from("a".repeat(64 * 1024));
+    let ptr = payload.as_ptr() as usize;
+    let seen = Seen::default();
+    let mut req = common::post("text/plain", payload);
+    req.extensions_mut().insert(seen.clone());
+
+    let first_ok = FirstOk::<(ReadBody<false>, ReadBody<false>, Text)>::from_request(&mut req)
+        .await
+        .unwrap();
+    let text = match first_ok.value {
+        OneOf3::V2(Text(text)) => text,
+        _ => panic!("the last extractor accepts the request"),
+    };
+    assert_eq!(seen.0.lock().unwrap().len(), 2);
+    assert_eq!(text.len(), 64 * 1024);
+    assert_eq!(text.as_ptr() as usize, ptr);
+}
+
+#[tokio::test]
+async fn dispatched_text_reuses_the_buffer() {
+    let payload = Bytes::from("a".repeat(64 * 1024));
+    let ptr = payload.as_ptr() as usize;
+    let seen = Seen::default();
+    let mut req = common::post("text/plain", payload);
+    req.extensions_mut().insert(seen.clone());
+
+    type Chain = AxumEither<ReadBody<false>, Text>;
+    let Dispatch(either) = Dispatch::<Chain>::from_request(&mut req).await.unwrap();
+    let text = match either {
+        AxumEither::Right(Text(text)) => text,
+        _ => panic!("the last extractor accepts the request"),
+    };
+    assert_eq!(text.as_ptr() as usize, ptr);
+}